@@ -17,10 +17,25 @@ pub struct Model {
     pub is_active: bool,
     pub created_at: DateTimeWithTimeZone,
     pub updated_at: DateTimeWithTimeZone,
+    pub organization_id: Option<Uuid>,
+    pub branch_id: Option<Uuid>,
+    pub is_shared: bool,
+    pub banned_by: Option<Uuid>,
+    pub lifted_at: Option<DateTimeWithTimeZone>,
+    pub lifted_by: Option<Uuid>,
+    pub lift_reason: Option<String>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::branches::Entity",
+        from = "Column::BranchId",
+        to = "super::branches::Column::Id",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    Branches,
     #[sea_orm(
         belongs_to = "super::customers::Entity",
         from = "Column::CustomerId",
@@ -29,6 +44,28 @@ pub enum Relation {
         on_delete = "Cascade"
     )]
     Customers,
+    #[sea_orm(
+        belongs_to = "super::employees::Entity",
+        from = "Column::BannedBy",
+        to = "super::employees::Column::Id",
+        on_update = "NoAction",
+        on_delete = "SetNull"
+    )]
+    Employees,
+    #[sea_orm(
+        belongs_to = "super::organizations::Entity",
+        from = "Column::OrganizationId",
+        to = "super::organizations::Column::Id",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    Organizations,
+}
+
+impl Related<super::branches::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Branches.def()
+    }
 }
 
 impl Related<super::customers::Entity> for Entity {
@@ -37,4 +74,16 @@ impl Related<super::customers::Entity> for Entity {
     }
 }
 
+impl Related<super::employees::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Employees.def()
+    }
+}
+
+impl Related<super::organizations::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Organizations.def()
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
@@ -22,6 +22,10 @@ pub struct Model {
     pub unit_amount: Decimal,
     pub total_amount: Decimal,
     pub booked_on_time: Option<DateTimeWithTimeZone>,
+    pub tax_amount: Decimal,
+    pub included_tax_amount: Decimal,
+    pub service_charge: Decimal,
+    pub discount_amount: Decimal,
     pub created_at: DateTimeWithTimeZone,
     pub updated_at: DateTimeWithTimeZone,
 }
@@ -52,6 +56,8 @@ pub enum Relation {
         on_delete = "Cascade"
     )]
     Events,
+    #[sea_orm(has_many = "super::orders::Entity")]
+    Orders,
     #[sea_orm(
         belongs_to = "super::organizations::Entity",
         from = "Column::OrganizationId",
@@ -60,6 +66,14 @@ pub enum Relation {
         on_delete = "Cascade"
     )]
     Organizations,
+    #[sea_orm(has_many = "super::promo_redemptions::Entity")]
+    PromoRedemptions,
+    #[sea_orm(has_one = "super::receipts::Entity")]
+    Receipts,
+    #[sea_orm(has_many = "super::tabs::Entity")]
+    Tabs,
+    #[sea_orm(has_many = "super::tax_lines::Entity")]
+    TaxLines,
     #[sea_orm(
         belongs_to = "super::transactions::Entity",
         from = "Column::PaymentId",
@@ -88,12 +102,42 @@ impl Related<super::events::Entity> for Entity {
     }
 }
 
+impl Related<super::orders::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Orders.def()
+    }
+}
+
 impl Related<super::organizations::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Organizations.def()
     }
 }
 
+impl Related<super::promo_redemptions::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::PromoRedemptions.def()
+    }
+}
+
+impl Related<super::receipts::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Receipts.def()
+    }
+}
+
+impl Related<super::tabs::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Tabs.def()
+    }
+}
+
+impl Related<super::tax_lines::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TaxLines.def()
+    }
+}
+
 impl Related<super::transactions::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Transactions.def()
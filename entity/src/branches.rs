@@ -19,16 +19,21 @@ pub struct Model {
     pub country_id: Uuid,
     pub created_at: DateTimeWithTimeZone,
     pub updated_at: DateTimeWithTimeZone,
+    pub timezone: String,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
     #[sea_orm(has_many = "super::accounts::Entity")]
     Accounts,
+    #[sea_orm(has_many = "super::ban_records::Entity")]
+    BanRecords,
     #[sea_orm(has_many = "super::bookings::Entity")]
     Bookings,
     #[sea_orm(has_many = "super::carousels::Entity")]
     Carousels,
+    #[sea_orm(has_many = "super::categories::Entity")]
+    Categories,
     #[sea_orm(
         belongs_to = "super::countries::Entity",
         from = "Column::CountryId",
@@ -49,6 +54,8 @@ pub enum Relation {
     Freebies,
     #[sea_orm(has_many = "super::guest_bookings::Entity")]
     GuestBookings,
+    #[sea_orm(has_many = "super::order_events::Entity")]
+    OrderEvents,
     #[sea_orm(has_many = "super::orders::Entity")]
     Orders,
     #[sea_orm(
@@ -59,16 +66,36 @@ pub enum Relation {
         on_delete = "Cascade"
     )]
     Organizations,
+    #[sea_orm(has_many = "super::price_rules::Entity")]
+    PriceRules,
+    #[sea_orm(has_many = "super::purchase_orders::Entity")]
+    PurchaseOrders,
+    #[sea_orm(has_many = "super::receipts::Entity")]
+    Receipts,
+    #[sea_orm(has_many = "super::recipe_items::Entity")]
+    RecipeItems,
     #[sea_orm(has_many = "super::schedules::Entity")]
     Schedules,
+    #[sea_orm(has_many = "super::stock_adjustments::Entity")]
+    StockAdjustments,
     #[sea_orm(has_many = "super::stock_drinks::Entity")]
     StockDrinks,
     #[sea_orm(has_many = "super::stock_foods::Entity")]
     StockFoods,
+    #[sea_orm(has_many = "super::stock_takes::Entity")]
+    StockTakes,
     #[sea_orm(has_many = "super::suppliers::Entity")]
     Suppliers,
+    #[sea_orm(has_many = "super::tabs::Entity")]
+    Tabs,
+    #[sea_orm(has_many = "super::ticket_events::Entity")]
+    TicketEvents,
     #[sea_orm(has_many = "super::till_sessions::Entity")]
     TillSessions,
+    #[sea_orm(has_many = "super::tip_payouts::Entity")]
+    TipPayouts,
+    #[sea_orm(has_many = "super::tip_pool_rules::Entity")]
+    TipPoolRules,
     #[sea_orm(has_many = "super::tips::Entity")]
     Tips,
     #[sea_orm(has_many = "super::transactions::Entity")]
@@ -83,6 +110,12 @@ impl Related<super::accounts::Entity> for Entity {
     }
 }
 
+impl Related<super::ban_records::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::BanRecords.def()
+    }
+}
+
 impl Related<super::bookings::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Bookings.def()
@@ -95,6 +128,12 @@ impl Related<super::carousels::Entity> for Entity {
     }
 }
 
+impl Related<super::categories::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Categories.def()
+    }
+}
+
 impl Related<super::countries::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Countries.def()
@@ -137,6 +176,12 @@ impl Related<super::guest_bookings::Entity> for Entity {
     }
 }
 
+impl Related<super::order_events::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::OrderEvents.def()
+    }
+}
+
 impl Related<super::orders::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Orders.def()
@@ -149,12 +194,42 @@ impl Related<super::organizations::Entity> for Entity {
     }
 }
 
+impl Related<super::price_rules::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::PriceRules.def()
+    }
+}
+
+impl Related<super::purchase_orders::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::PurchaseOrders.def()
+    }
+}
+
+impl Related<super::receipts::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Receipts.def()
+    }
+}
+
+impl Related<super::recipe_items::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::RecipeItems.def()
+    }
+}
+
 impl Related<super::schedules::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Schedules.def()
     }
 }
 
+impl Related<super::stock_adjustments::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::StockAdjustments.def()
+    }
+}
+
 impl Related<super::stock_drinks::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::StockDrinks.def()
@@ -167,18 +242,48 @@ impl Related<super::stock_foods::Entity> for Entity {
     }
 }
 
+impl Related<super::stock_takes::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::StockTakes.def()
+    }
+}
+
 impl Related<super::suppliers::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Suppliers.def()
     }
 }
 
+impl Related<super::tabs::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Tabs.def()
+    }
+}
+
+impl Related<super::ticket_events::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TicketEvents.def()
+    }
+}
+
 impl Related<super::till_sessions::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::TillSessions.def()
     }
 }
 
+impl Related<super::tip_payouts::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TipPayouts.def()
+    }
+}
+
+impl Related<super::tip_pool_rules::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TipPoolRules.def()
+    }
+}
+
 impl Related<super::tips::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Tips.def()
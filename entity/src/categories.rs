@@ -11,18 +11,69 @@ pub struct Model {
     pub name: String,
     pub description: Option<String>,
     pub category_type: Option<String>,
+    pub organization_id: Option<Uuid>,
+    pub branch_id: Option<Uuid>,
+    pub parent_id: Option<Uuid>,
+    pub sort_order: i32,
+    pub is_active: bool,
+    pub available_from: Option<Time>,
+    pub available_until: Option<Time>,
     pub created_at: DateTimeWithTimeZone,
     pub updated_at: DateTimeWithTimeZone,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::branches::Entity",
+        from = "Column::BranchId",
+        to = "super::branches::Column::Id",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    Branches,
+    #[sea_orm(has_many = "super::custom_cocktails::Entity")]
+    CustomCocktails,
+    #[sea_orm(
+        belongs_to = "super::organizations::Entity",
+        from = "Column::OrganizationId",
+        to = "super::organizations::Column::Id",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    Organizations,
+    #[sea_orm(
+        belongs_to = "Entity",
+        from = "Column::ParentId",
+        to = "Column::Id",
+        on_update = "NoAction",
+        on_delete = "Restrict"
+    )]
+    SelfRef,
     #[sea_orm(has_many = "super::stock_drinks::Entity")]
     StockDrinks,
     #[sea_orm(has_many = "super::stock_foods::Entity")]
     StockFoods,
 }
 
+impl Related<super::branches::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Branches.def()
+    }
+}
+
+impl Related<super::custom_cocktails::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::CustomCocktails.def()
+    }
+}
+
+impl Related<super::organizations::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Organizations.def()
+    }
+}
+
 impl Related<super::stock_drinks::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::StockDrinks.def()
@@ -14,6 +14,16 @@ pub struct Model {
     pub name: String,
     pub ingredients: Option<Vec<String>>,
     pub is_approved: bool,
+    pub description: Option<String>,
+    pub cocktail_status: String,
+    pub price: Option<Decimal>,
+    pub category_id: Option<Uuid>,
+    pub is_available: bool,
+    pub credit_creator: bool,
+    pub reward_amount: Decimal,
+    pub reviewed_by: Option<Uuid>,
+    pub reviewed_at: Option<DateTimeWithTimeZone>,
+    pub review_note: Option<String>,
     pub created_at: DateTimeWithTimeZone,
     pub updated_at: DateTimeWithTimeZone,
 }
@@ -28,6 +38,14 @@ pub enum Relation {
         on_delete = "Cascade"
     )]
     Branches,
+    #[sea_orm(
+        belongs_to = "super::categories::Entity",
+        from = "Column::CategoryId",
+        to = "super::categories::Column::Id",
+        on_update = "NoAction",
+        on_delete = "SetNull"
+    )]
+    Categories,
     #[sea_orm(
         belongs_to = "super::customers::Entity",
         from = "Column::CustomerId",
@@ -36,6 +54,16 @@ pub enum Relation {
         on_delete = "Cascade"
     )]
     Customers,
+    #[sea_orm(
+        belongs_to = "super::employees::Entity",
+        from = "Column::ReviewedBy",
+        to = "super::employees::Column::Id",
+        on_update = "NoAction",
+        on_delete = "SetNull"
+    )]
+    Employees,
+    #[sea_orm(has_many = "super::order_items::Entity")]
+    OrderItems,
     #[sea_orm(
         belongs_to = "super::organizations::Entity",
         from = "Column::OrganizationId",
@@ -44,6 +72,8 @@ pub enum Relation {
         on_delete = "Cascade"
     )]
     Organizations,
+    #[sea_orm(has_many = "super::recipe_items::Entity")]
+    RecipeItems,
 }
 
 impl Related<super::branches::Entity> for Entity {
@@ -52,16 +82,40 @@ impl Related<super::branches::Entity> for Entity {
     }
 }
 
+impl Related<super::categories::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Categories.def()
+    }
+}
+
 impl Related<super::customers::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Customers.def()
     }
 }
 
+impl Related<super::employees::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Employees.def()
+    }
+}
+
+impl Related<super::order_items::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::OrderItems.def()
+    }
+}
+
 impl Related<super::organizations::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Organizations.def()
     }
 }
 
+impl Related<super::recipe_items::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::RecipeItems.def()
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
@@ -49,6 +49,18 @@ pub enum Relation {
     CustomCocktails,
     #[sea_orm(has_many = "super::orders::Entity")]
     Orders,
+    #[sea_orm(has_many = "super::promo_redemptions::Entity")]
+    PromoRedemptions,
+    #[sea_orm(has_many = "super::receipts::Entity")]
+    Receipts,
+    #[sea_orm(has_many = "super::tab_shares::Entity")]
+    TabShares,
+    #[sea_orm(has_many = "super::tabs::Entity")]
+    Tabs,
+    #[sea_orm(has_many = "super::ticket_listings::Entity")]
+    TicketListings,
+    #[sea_orm(has_many = "super::ticket_purchases::Entity")]
+    TicketPurchases,
     #[sea_orm(has_many = "super::transactions::Entity")]
     Transactions,
     #[sea_orm(has_many = "super::wallets::Entity")]
@@ -85,6 +97,42 @@ impl Related<super::orders::Entity> for Entity {
     }
 }
 
+impl Related<super::promo_redemptions::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::PromoRedemptions.def()
+    }
+}
+
+impl Related<super::receipts::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Receipts.def()
+    }
+}
+
+impl Related<super::tab_shares::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TabShares.def()
+    }
+}
+
+impl Related<super::tabs::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Tabs.def()
+    }
+}
+
+impl Related<super::ticket_listings::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TicketListings.def()
+    }
+}
+
+impl Related<super::ticket_purchases::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TicketPurchases.def()
+    }
+}
+
 impl Related<super::transactions::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Transactions.def()
@@ -43,6 +43,8 @@ pub enum Relation {
         on_delete = "Cascade"
     )]
     Organizations,
+    #[sea_orm(has_many = "super::tip_pool_shares::Entity")]
+    TipPoolShares,
 }
 
 impl Related<super::branches::Entity> for Entity {
@@ -63,4 +65,10 @@ impl Related<super::organizations::Entity> for Entity {
     }
 }
 
+impl Related<super::tip_pool_shares::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TipPoolShares.def()
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
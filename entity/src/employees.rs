@@ -47,6 +47,10 @@ pub struct Model {
 pub enum Relation {
     #[sea_orm(has_many = "super::accounts::Entity")]
     Accounts,
+    #[sea_orm(has_many = "super::approval_logs::Entity")]
+    ApprovalLogs,
+    #[sea_orm(has_many = "super::ban_records::Entity")]
+    BanRecords,
     #[sea_orm(
         belongs_to = "super::branches::Entity",
         from = "Column::BranchId",
@@ -55,6 +59,8 @@ pub enum Relation {
         on_delete = "Cascade"
     )]
     Branches,
+    #[sea_orm(has_many = "super::custom_cocktails::Entity")]
+    CustomCocktails,
     #[sea_orm(
         belongs_to = "super::departments::Entity",
         from = "Column::DepartmentId",
@@ -63,6 +69,10 @@ pub enum Relation {
         on_delete = "Cascade"
     )]
     Departments,
+    #[sea_orm(has_many = "super::goods_receipts::Entity")]
+    GoodsReceipts,
+    #[sea_orm(has_many = "super::orders::Entity")]
+    Orders,
     #[sea_orm(
         belongs_to = "super::organizations::Entity",
         from = "Column::OrganizationId",
@@ -71,6 +81,8 @@ pub enum Relation {
         on_delete = "Cascade"
     )]
     Organizations,
+    #[sea_orm(has_many = "super::purchase_orders::Entity")]
+    PurchaseOrders,
     #[sea_orm(
         belongs_to = "super::role_permissions::Entity",
         from = "Column::RolePermissions",
@@ -83,8 +95,24 @@ pub enum Relation {
     StaffLeaves,
     #[sea_orm(has_many = "super::staff_shifts::Entity")]
     StaffShifts,
+    #[sea_orm(has_many = "super::stock_adjustments::Entity")]
+    StockAdjustments,
+    #[sea_orm(has_many = "super::stock_take_counts::Entity")]
+    StockTakeCounts,
+    #[sea_orm(has_many = "super::stock_takes::Entity")]
+    StockTakes,
+    #[sea_orm(has_many = "super::stock_transfers::Entity")]
+    StockTransfers,
+    #[sea_orm(has_many = "super::tabs::Entity")]
+    Tabs,
+    #[sea_orm(has_many = "super::ticket_events::Entity")]
+    TicketEvents,
+    #[sea_orm(has_many = "super::tickets::Entity")]
+    Tickets,
     #[sea_orm(has_many = "super::till_sessions::Entity")]
     TillSessions,
+    #[sea_orm(has_many = "super::tip_allocations::Entity")]
+    TipAllocations,
     #[sea_orm(has_many = "super::wallets::Entity")]
     Wallets,
 }
@@ -95,24 +123,60 @@ impl Related<super::accounts::Entity> for Entity {
     }
 }
 
+impl Related<super::approval_logs::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ApprovalLogs.def()
+    }
+}
+
+impl Related<super::ban_records::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::BanRecords.def()
+    }
+}
+
 impl Related<super::branches::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Branches.def()
     }
 }
 
+impl Related<super::custom_cocktails::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::CustomCocktails.def()
+    }
+}
+
 impl Related<super::departments::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Departments.def()
     }
 }
 
+impl Related<super::goods_receipts::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::GoodsReceipts.def()
+    }
+}
+
+impl Related<super::orders::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Orders.def()
+    }
+}
+
 impl Related<super::organizations::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Organizations.def()
     }
 }
 
+impl Related<super::purchase_orders::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::PurchaseOrders.def()
+    }
+}
+
 impl Related<super::role_permissions::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::RolePermissions.def()
@@ -131,12 +195,60 @@ impl Related<super::staff_shifts::Entity> for Entity {
     }
 }
 
+impl Related<super::stock_adjustments::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::StockAdjustments.def()
+    }
+}
+
+impl Related<super::stock_take_counts::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::StockTakeCounts.def()
+    }
+}
+
+impl Related<super::stock_takes::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::StockTakes.def()
+    }
+}
+
+impl Related<super::stock_transfers::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::StockTransfers.def()
+    }
+}
+
+impl Related<super::tabs::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Tabs.def()
+    }
+}
+
+impl Related<super::ticket_events::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TicketEvents.def()
+    }
+}
+
+impl Related<super::tickets::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Tickets.def()
+    }
+}
+
 impl Related<super::till_sessions::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::TillSessions.def()
     }
 }
 
+impl Related<super::tip_allocations::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TipAllocations.def()
+    }
+}
+
 impl Related<super::wallets::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Wallets.def()
@@ -3,6 +3,7 @@
 pub mod prelude;
 
 pub mod accounts;
+pub mod approval_logs;
 pub mod ban_records;
 pub mod bookings;
 pub mod branches;
@@ -17,25 +18,49 @@ pub mod employees;
 pub mod events;
 pub mod externals;
 pub mod freebies;
+pub mod goods_receipts;
 pub mod guest_bookings;
 pub mod medias;
+pub mod order_events;
 pub mod order_items;
 pub mod orders;
 pub mod organizations;
 pub mod permissions;
+pub mod price_rules;
+pub mod promo_redemptions;
 pub mod promos;
+pub mod purchase_order_lines;
+pub mod purchase_orders;
+pub mod receipts;
+pub mod recipe_items;
 pub mod referrals;
 pub mod role_permissions;
 pub mod roles;
 pub mod schedules;
 pub mod staff_leaves;
 pub mod staff_shifts;
+pub mod stock_adjustments;
 pub mod stock_drinks;
 pub mod stock_foods;
+pub mod stock_take_counts;
+pub mod stock_takes;
+pub mod stock_transfer_lines;
+pub mod stock_transfers;
 pub mod suppliers;
+pub mod tab_shares;
+pub mod tabs;
+pub mod tax_lines;
+pub mod tax_rules;
 pub mod ticket_events;
+pub mod ticket_listings;
+pub mod ticket_purchases;
 pub mod tickets;
+pub mod till_reconciliations;
 pub mod till_sessions;
+pub mod tip_allocations;
+pub mod tip_payouts;
+pub mod tip_pool_rules;
+pub mod tip_pool_shares;
 pub mod tips;
 pub mod transactions;
 pub mod users;
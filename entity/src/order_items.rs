@@ -12,14 +12,27 @@ pub struct Model {
     pub stock_drink_id: Option<Uuid>,
     pub stock_food_id: Option<Uuid>,
     pub item_type: String,
+    pub item_name: Option<String>,
     pub price: Decimal,
     pub quantity: i32,
+    pub custom_cocktail_id: Option<Uuid>,
+    pub has_recipe: bool,
+    pub discount_amount: Decimal,
+    pub unit_cost: Option<Decimal>,
     pub created_at: DateTimeWithTimeZone,
     pub updated_at: DateTimeWithTimeZone,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::custom_cocktails::Entity",
+        from = "Column::CustomCocktailId",
+        to = "super::custom_cocktails::Column::Id",
+        on_update = "NoAction",
+        on_delete = "SetNull"
+    )]
+    CustomCocktails,
     #[sea_orm(
         belongs_to = "super::orders::Entity",
         from = "Column::OrderId",
@@ -46,6 +59,12 @@ pub enum Relation {
     StockFoods,
 }
 
+impl Related<super::custom_cocktails::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::CustomCocktails.def()
+    }
+}
+
 impl Related<super::orders::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Orders.def()
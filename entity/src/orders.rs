@@ -12,22 +12,40 @@ pub struct Model {
     pub organization_id: Uuid,
     pub branch_id: Uuid,
     pub order_number: String,
-    pub payment_id: Uuid,
+    pub payment_id: Option<Uuid>,
     pub total_amount: Decimal,
     pub is_discount_applied: bool,
     pub is_cancelled: bool,
-    pub served_by: Uuid,
+    pub served_by: Option<Uuid>,
     pub order_status: String,
     pub till_session_id: Option<Uuid>,
     pub is_tip_applied: bool,
     pub tip_amount: Option<Decimal>,
     pub order_state: String,
+    pub status_reason: Option<String>,
+    pub refund_transaction_id: Option<Uuid>,
+    pub booking_id: Option<Uuid>,
+    pub released_at: Option<DateTimeWithTimeZone>,
+    pub tab_id: Option<Uuid>,
+    pub tax_amount: Decimal,
+    pub included_tax_amount: Decimal,
+    pub service_charge: Decimal,
+    pub discount_amount: Decimal,
+    pub promo_reference: Option<String>,
     pub created_at: DateTimeWithTimeZone,
     pub updated_at: DateTimeWithTimeZone,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::bookings::Entity",
+        from = "Column::BookingId",
+        to = "super::bookings::Column::Id",
+        on_update = "NoAction",
+        on_delete = "SetNull"
+    )]
+    Bookings,
     #[sea_orm(
         belongs_to = "super::branches::Entity",
         from = "Column::BranchId",
@@ -44,6 +62,16 @@ pub enum Relation {
         on_delete = "Cascade"
     )]
     Customers,
+    #[sea_orm(
+        belongs_to = "super::employees::Entity",
+        from = "Column::ServedBy",
+        to = "super::employees::Column::Id",
+        on_update = "NoAction",
+        on_delete = "SetNull"
+    )]
+    Employees,
+    #[sea_orm(has_many = "super::order_events::Entity")]
+    OrderEvents,
     #[sea_orm(has_many = "super::order_items::Entity")]
     OrderItems,
     #[sea_orm(
@@ -54,6 +82,20 @@ pub enum Relation {
         on_delete = "Cascade"
     )]
     Organizations,
+    #[sea_orm(has_many = "super::promo_redemptions::Entity")]
+    PromoRedemptions,
+    #[sea_orm(has_one = "super::receipts::Entity")]
+    Receipts,
+    #[sea_orm(
+        belongs_to = "super::tabs::Entity",
+        from = "Column::TabId",
+        to = "super::tabs::Column::Id",
+        on_update = "NoAction",
+        on_delete = "SetNull"
+    )]
+    Tabs,
+    #[sea_orm(has_many = "super::tax_lines::Entity")]
+    TaxLines,
     #[sea_orm(
         belongs_to = "super::till_sessions::Entity",
         from = "Column::TillSessionId",
@@ -74,6 +116,12 @@ pub enum Relation {
     Transactions,
 }
 
+impl Related<super::bookings::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Bookings.def()
+    }
+}
+
 impl Related<super::branches::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Branches.def()
@@ -86,6 +134,18 @@ impl Related<super::customers::Entity> for Entity {
     }
 }
 
+impl Related<super::employees::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Employees.def()
+    }
+}
+
+impl Related<super::order_events::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::OrderEvents.def()
+    }
+}
+
 impl Related<super::order_items::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::OrderItems.def()
@@ -98,6 +158,30 @@ impl Related<super::organizations::Entity> for Entity {
     }
 }
 
+impl Related<super::promo_redemptions::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::PromoRedemptions.def()
+    }
+}
+
+impl Related<super::receipts::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Receipts.def()
+    }
+}
+
+impl Related<super::tabs::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Tabs.def()
+    }
+}
+
+impl Related<super::tax_lines::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TaxLines.def()
+    }
+}
+
 impl Related<super::till_sessions::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::TillSessions.def()
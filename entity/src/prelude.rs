@@ -1,6 +1,7 @@
 //! `SeaORM` Entity, @generated by sea-orm-codegen 1.1.3
 
 pub use super::accounts::Entity as Accounts;
+pub use super::approval_logs::Entity as ApprovalLogs;
 pub use super::ban_records::Entity as BanRecords;
 pub use super::bookings::Entity as Bookings;
 pub use super::branches::Entity as Branches;
@@ -15,25 +16,49 @@ pub use super::employees::Entity as Employees;
 pub use super::events::Entity as Events;
 pub use super::externals::Entity as Externals;
 pub use super::freebies::Entity as Freebies;
+pub use super::goods_receipts::Entity as GoodsReceipts;
 pub use super::guest_bookings::Entity as GuestBookings;
 pub use super::medias::Entity as Medias;
+pub use super::order_events::Entity as OrderEvents;
 pub use super::order_items::Entity as OrderItems;
 pub use super::orders::Entity as Orders;
 pub use super::organizations::Entity as Organizations;
 pub use super::permissions::Entity as Permissions;
+pub use super::price_rules::Entity as PriceRules;
+pub use super::promo_redemptions::Entity as PromoRedemptions;
 pub use super::promos::Entity as Promos;
+pub use super::purchase_order_lines::Entity as PurchaseOrderLines;
+pub use super::purchase_orders::Entity as PurchaseOrders;
+pub use super::receipts::Entity as Receipts;
+pub use super::recipe_items::Entity as RecipeItems;
 pub use super::referrals::Entity as Referrals;
 pub use super::role_permissions::Entity as RolePermissions;
 pub use super::roles::Entity as Roles;
 pub use super::schedules::Entity as Schedules;
 pub use super::staff_leaves::Entity as StaffLeaves;
 pub use super::staff_shifts::Entity as StaffShifts;
+pub use super::stock_adjustments::Entity as StockAdjustments;
 pub use super::stock_drinks::Entity as StockDrinks;
 pub use super::stock_foods::Entity as StockFoods;
+pub use super::stock_take_counts::Entity as StockTakeCounts;
+pub use super::stock_takes::Entity as StockTakes;
+pub use super::stock_transfer_lines::Entity as StockTransferLines;
+pub use super::stock_transfers::Entity as StockTransfers;
 pub use super::suppliers::Entity as Suppliers;
+pub use super::tab_shares::Entity as TabShares;
+pub use super::tabs::Entity as Tabs;
+pub use super::tax_lines::Entity as TaxLines;
+pub use super::tax_rules::Entity as TaxRules;
 pub use super::ticket_events::Entity as TicketEvents;
+pub use super::ticket_listings::Entity as TicketListings;
+pub use super::ticket_purchases::Entity as TicketPurchases;
 pub use super::tickets::Entity as Tickets;
+pub use super::till_reconciliations::Entity as TillReconciliations;
 pub use super::till_sessions::Entity as TillSessions;
+pub use super::tip_allocations::Entity as TipAllocations;
+pub use super::tip_payouts::Entity as TipPayouts;
+pub use super::tip_pool_rules::Entity as TipPoolRules;
+pub use super::tip_pool_shares::Entity as TipPoolShares;
 pub use super::tips::Entity as Tips;
 pub use super::transactions::Entity as Transactions;
 pub use super::users::Entity as Users;
@@ -15,9 +15,49 @@ pub struct Model {
     pub valid_to: DateTimeWithTimeZone,
     pub created_at: DateTimeWithTimeZone,
     pub updated_at: DateTimeWithTimeZone,
+    pub organization_id: Option<Uuid>,
+    pub code: Option<String>,
+    pub promo_type: String,
+    pub applies_to: String,
+    pub max_discount: Option<Decimal>,
+    pub min_spend: Option<Decimal>,
+    pub branch_ids: Option<Vec<Uuid>>,
+    pub schedule_ids: Option<Vec<Uuid>>,
+    pub category_ids: Option<Vec<Uuid>>,
+    pub ticket_ids: Option<Vec<Uuid>>,
+    pub start_time: Option<Time>,
+    pub end_time: Option<Time>,
+    pub weekdays: Option<Vec<i32>>,
+    pub usage_limit: Option<i32>,
+    pub per_customer_limit: Option<i32>,
+    pub is_stackable: bool,
+    pub is_active: bool,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
-pub enum Relation {}
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::organizations::Entity",
+        from = "Column::OrganizationId",
+        to = "super::organizations::Column::Id",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    Organizations,
+    #[sea_orm(has_many = "super::promo_redemptions::Entity")]
+    PromoRedemptions,
+}
+
+impl Related<super::organizations::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Organizations.def()
+    }
+}
+
+impl Related<super::promo_redemptions::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::PromoRedemptions.def()
+    }
+}
 
 impl ActiveModelBehavior for ActiveModel {}
@@ -25,6 +25,12 @@ pub struct Model {
     pub stock_status: String,
     pub category_id: Uuid,
     pub supplier_id: Uuid,
+    pub reorder_threshold: i32,
+    pub low_stock_alerted_at: Option<DateTimeWithTimeZone>,
+    pub measure_unit: String,
+    pub unit_size: Decimal,
+    pub open_measure: Decimal,
+    pub sku: Option<String>,
     pub created_at: DateTimeWithTimeZone,
     pub updated_at: DateTimeWithTimeZone,
 }
@@ -57,6 +63,12 @@ pub enum Relation {
         on_delete = "Cascade"
     )]
     Organizations,
+    #[sea_orm(has_many = "super::purchase_order_lines::Entity")]
+    PurchaseOrderLines,
+    #[sea_orm(has_many = "super::stock_adjustments::Entity")]
+    StockAdjustments,
+    #[sea_orm(has_many = "super::stock_take_counts::Entity")]
+    StockTakeCounts,
     #[sea_orm(
         belongs_to = "super::suppliers::Entity",
         from = "Column::SupplierId",
@@ -91,6 +103,24 @@ impl Related<super::organizations::Entity> for Entity {
     }
 }
 
+impl Related<super::purchase_order_lines::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::PurchaseOrderLines.def()
+    }
+}
+
+impl Related<super::stock_adjustments::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::StockAdjustments.def()
+    }
+}
+
+impl Related<super::stock_take_counts::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::StockTakeCounts.def()
+    }
+}
+
 impl Related<super::suppliers::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Suppliers.def()
@@ -16,6 +16,7 @@ pub struct Model {
     pub description: Option<String>,
     pub image_id: Option<String>,
     pub is_custom: bool,
+    pub sku: Option<String>,
     pub created_at: DateTimeWithTimeZone,
     pub updated_at: DateTimeWithTimeZone,
 }
@@ -48,6 +49,8 @@ pub enum Relation {
         on_delete = "Cascade"
     )]
     Organizations,
+    #[sea_orm(has_many = "super::recipe_items::Entity")]
+    RecipeItems,
 }
 
 impl Related<super::branches::Entity> for Entity {
@@ -74,4 +77,10 @@ impl Related<super::organizations::Entity> for Entity {
     }
 }
 
+impl Related<super::recipe_items::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::RecipeItems.def()
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
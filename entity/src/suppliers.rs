@@ -14,6 +14,7 @@ pub struct Model {
     pub contact: String,
     pub email: String,
     pub delivery_dates: DateTimeWithTimeZone,
+    pub delivery_weekdays: Option<Vec<i32>>,
     pub created_at: DateTimeWithTimeZone,
     pub updated_at: DateTimeWithTimeZone,
 }
@@ -36,6 +37,8 @@ pub enum Relation {
         on_delete = "Cascade"
     )]
     Organizations,
+    #[sea_orm(has_many = "super::purchase_orders::Entity")]
+    PurchaseOrders,
     #[sea_orm(has_many = "super::stock_drinks::Entity")]
     StockDrinks,
 }
@@ -52,6 +55,12 @@ impl Related<super::organizations::Entity> for Entity {
     }
 }
 
+impl Related<super::purchase_orders::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::PurchaseOrders.def()
+    }
+}
+
 impl Related<super::stock_drinks::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::StockDrinks.def()
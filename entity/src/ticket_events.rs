@@ -21,14 +21,82 @@ pub struct Model {
     pub max_age_limit: i32,
     pub created_at: DateTimeWithTimeZone,
     pub updated_at: DateTimeWithTimeZone,
+    pub organization_id: Uuid,
+    pub branch_id: Uuid,
+    #[sea_orm(unique)]
+    pub slug: Option<String>,
+    pub cancellation_reason: Option<String>,
+    pub created_by: Option<Uuid>,
+    pub approval_status: String,
+    pub approval_comment: Option<String>,
+    pub approved_by: Option<Uuid>,
+    pub approved_at: Option<DateTimeWithTimeZone>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::branches::Entity",
+        from = "Column::BranchId",
+        to = "super::branches::Column::Id",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    Branches,
+    #[sea_orm(
+        belongs_to = "super::employees::Entity",
+        from = "Column::CreatedBy",
+        to = "super::employees::Column::Id",
+        on_update = "NoAction",
+        on_delete = "SetNull"
+    )]
+    Employees,
+    #[sea_orm(
+        belongs_to = "super::organizations::Entity",
+        from = "Column::OrganizationId",
+        to = "super::organizations::Column::Id",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    Organizations,
+    #[sea_orm(has_many = "super::ticket_listings::Entity")]
+    TicketListings,
+    #[sea_orm(has_many = "super::ticket_purchases::Entity")]
+    TicketPurchases,
     #[sea_orm(has_many = "super::tickets::Entity")]
     Tickets,
 }
 
+impl Related<super::branches::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Branches.def()
+    }
+}
+
+impl Related<super::employees::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Employees.def()
+    }
+}
+
+impl Related<super::organizations::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Organizations.def()
+    }
+}
+
+impl Related<super::ticket_listings::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TicketListings.def()
+    }
+}
+
+impl Related<super::ticket_purchases::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TicketPurchases.def()
+    }
+}
+
 impl Related<super::tickets::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Tickets.def()
@@ -23,10 +23,23 @@ pub struct Model {
     pub ticket_event_id: Uuid,
     pub created_at: DateTimeWithTimeZone,
     pub updated_at: DateTimeWithTimeZone,
+    pub quantity: i32,
+    pub approval_status: String,
+    pub approval_comment: Option<String>,
+    pub approved_at: Option<DateTimeWithTimeZone>,
+    pub created_by: Option<Uuid>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::employees::Entity",
+        from = "Column::CreatedBy",
+        to = "super::employees::Column::Id",
+        on_update = "NoAction",
+        on_delete = "SetNull"
+    )]
+    Employees,
     #[sea_orm(
         belongs_to = "super::ticket_events::Entity",
         from = "Column::TicketEventId",
@@ -35,6 +48,14 @@ pub enum Relation {
         on_delete = "Cascade"
     )]
     TicketEvents,
+    #[sea_orm(has_many = "super::ticket_purchases::Entity")]
+    TicketPurchases,
+}
+
+impl Related<super::employees::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Employees.def()
+    }
 }
 
 impl Related<super::ticket_events::Entity> for Entity {
@@ -43,4 +64,10 @@ impl Related<super::ticket_events::Entity> for Entity {
     }
 }
 
+impl Related<super::ticket_purchases::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TicketPurchases.def()
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
@@ -13,6 +13,13 @@ pub struct Model {
     pub organization_id: Uuid,
     pub start_time: Option<Time>,
     pub end_time: Option<Time>,
+    pub session_status: String,
+    pub opening_float: Decimal,
+    pub opened_at: DateTimeWithTimeZone,
+    pub closed_at: Option<DateTimeWithTimeZone>,
+    pub closed_by: Option<Uuid>,
+    pub counted_cash: Option<Decimal>,
+    pub notes: Option<String>,
     pub created_at: DateTimeWithTimeZone,
     pub updated_at: DateTimeWithTimeZone,
 }
@@ -45,6 +52,10 @@ pub enum Relation {
         on_delete = "Cascade"
     )]
     Organizations,
+    #[sea_orm(has_many = "super::stock_takes::Entity")]
+    StockTakes,
+    #[sea_orm(has_many = "super::till_reconciliations::Entity")]
+    TillReconciliations,
 }
 
 impl Related<super::branches::Entity> for Entity {
@@ -71,4 +82,16 @@ impl Related<super::organizations::Entity> for Entity {
     }
 }
 
+impl Related<super::stock_takes::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::StockTakes.def()
+    }
+}
+
+impl Related<super::till_reconciliations::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TillReconciliations.def()
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
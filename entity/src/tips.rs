@@ -12,6 +12,8 @@ pub struct Model {
     pub branch_id: Uuid,
     pub order_id: Uuid,
     pub amount: Decimal,
+    pub tip_status: String,
+    pub payout_id: Option<Uuid>,
     pub created_at: DateTimeWithTimeZone,
     pub updated_at: DateTimeWithTimeZone,
 }
@@ -42,6 +44,14 @@ pub enum Relation {
         on_delete = "Cascade"
     )]
     Organizations,
+    #[sea_orm(
+        belongs_to = "super::tip_payouts::Entity",
+        from = "Column::PayoutId",
+        to = "super::tip_payouts::Column::Id",
+        on_update = "NoAction",
+        on_delete = "SetNull"
+    )]
+    TipPayouts,
 }
 
 impl Related<super::branches::Entity> for Entity {
@@ -62,4 +72,10 @@ impl Related<super::organizations::Entity> for Entity {
     }
 }
 
+impl Related<super::tip_payouts::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TipPayouts.def()
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
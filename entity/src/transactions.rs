@@ -56,6 +56,14 @@ pub enum Relation {
         on_delete = "Cascade"
     )]
     Organizations,
+    #[sea_orm(has_many = "super::receipts::Entity")]
+    Receipts,
+    #[sea_orm(has_many = "super::tab_shares::Entity")]
+    TabShares,
+    #[sea_orm(has_many = "super::ticket_purchases::Entity")]
+    TicketPurchases,
+    #[sea_orm(has_many = "super::tip_allocations::Entity")]
+    TipAllocations,
     #[sea_orm(has_many = "super::wallet_topups::Entity")]
     WalletTopups,
 }
@@ -96,6 +104,30 @@ impl Related<super::organizations::Entity> for Entity {
     }
 }
 
+impl Related<super::receipts::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Receipts.def()
+    }
+}
+
+impl Related<super::tab_shares::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TabShares.def()
+    }
+}
+
+impl Related<super::ticket_purchases::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TicketPurchases.def()
+    }
+}
+
+impl Related<super::tip_allocations::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TipAllocations.def()
+    }
+}
+
 impl Related<super::wallet_topups::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::WalletTopups.def()
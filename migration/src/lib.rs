@@ -39,6 +39,57 @@ mod m20250312_113839_create_permissions;
 mod m20250312_114351_create_role_permissions;
 mod m20250317_161334_create_users;
 mod m20250408_170934_create_ban_records;
+mod m20250420_093012_alter_ban_records;
+mod m20250422_101500_alter_ticket_events;
+mod m20250422_103000_create_ticket_purchases;
+mod m20250423_090000_add_ticket_approvals;
+mod m20250423_091500_create_approval_logs;
+mod m20250424_100000_add_ticket_transfers;
+mod m20250424_101500_create_ticket_listings;
+mod m20250425_090000_alter_orders;
+mod m20250426_090000_create_order_events;
+mod m20250427_090000_add_booking_pre_orders;
+mod m20250428_090000_alter_till_sessions;
+mod m20250428_091500_create_till_reconciliations;
+mod m20250429_090000_create_tip_pool_rules;
+mod m20250429_091000_create_tip_pool_shares;
+mod m20250429_092000_create_tip_payouts;
+mod m20250429_093000_create_tip_allocations;
+mod m20250429_094000_alter_tips;
+mod m20250430_090000_create_tabs;
+mod m20250430_091000_create_tab_shares;
+mod m20250430_092000_add_order_tabs;
+mod m20250501_090000_create_receipts;
+mod m20250502_090000_create_tax_rules;
+mod m20250502_091000_create_tax_lines;
+mod m20250502_092000_add_tax_amounts;
+mod m20250503_090000_add_promo_rules;
+mod m20250503_091000_create_promo_redemptions;
+mod m20250503_092000_add_discount_amounts;
+mod m20250504_090000_create_price_rules;
+mod m20250505_090000_add_reorder_settings;
+mod m20250506_090000_add_supplier_delivery_days;
+mod m20250506_091000_create_purchase_orders;
+mod m20250506_092000_create_purchase_order_lines;
+mod m20250506_093000_create_goods_receipts;
+mod m20250507_090000_create_stock_takes;
+mod m20250507_091000_create_stock_take_counts;
+mod m20250507_092000_create_stock_adjustments;
+mod m20250508_090000_alter_custom_cocktails;
+mod m20250508_091000_create_custom_cocktail_ingredients;
+mod m20250508_092000_add_cocktail_order_items;
+mod m20250509_090000_add_stock_drink_measures;
+mod m20250509_091000_create_recipe_items;
+mod m20250509_092000_move_cocktail_ingredients;
+mod m20250509_093000_add_order_item_recipes;
+mod m20250510_090000_alter_categories;
+mod m20250511_090000_add_stock_skus;
+mod m20250512_090000_create_stock_transfers;
+mod m20250512_091000_create_stock_transfer_lines;
+mod m20250512_092000_add_stock_adjustment_transfers;
+mod m20250513_090000_add_branch_timezones;
+mod m20250513_091000_add_order_item_costs;
+mod m20250514_090000_drop_tax_rule_countries;
 
 pub struct Migrator;
 
@@ -85,6 +136,57 @@ impl MigratorTrait for Migrator {
             Box::new(m20250312_112345_create_referrals::Migration),
             Box::new(m20250317_161334_create_users::Migration),
             Box::new(m20250408_170934_create_ban_records::Migration),
+            Box::new(m20250420_093012_alter_ban_records::Migration),
+            Box::new(m20250422_101500_alter_ticket_events::Migration),
+            Box::new(m20250422_103000_create_ticket_purchases::Migration),
+            Box::new(m20250423_090000_add_ticket_approvals::Migration),
+            Box::new(m20250423_091500_create_approval_logs::Migration),
+            Box::new(m20250424_100000_add_ticket_transfers::Migration),
+            Box::new(m20250424_101500_create_ticket_listings::Migration),
+            Box::new(m20250425_090000_alter_orders::Migration),
+            Box::new(m20250426_090000_create_order_events::Migration),
+            Box::new(m20250427_090000_add_booking_pre_orders::Migration),
+            Box::new(m20250428_090000_alter_till_sessions::Migration),
+            Box::new(m20250428_091500_create_till_reconciliations::Migration),
+            Box::new(m20250429_090000_create_tip_pool_rules::Migration),
+            Box::new(m20250429_091000_create_tip_pool_shares::Migration),
+            Box::new(m20250429_092000_create_tip_payouts::Migration),
+            Box::new(m20250429_093000_create_tip_allocations::Migration),
+            Box::new(m20250429_094000_alter_tips::Migration),
+            Box::new(m20250430_090000_create_tabs::Migration),
+            Box::new(m20250430_091000_create_tab_shares::Migration),
+            Box::new(m20250430_092000_add_order_tabs::Migration),
+            Box::new(m20250501_090000_create_receipts::Migration),
+            Box::new(m20250502_090000_create_tax_rules::Migration),
+            Box::new(m20250502_091000_create_tax_lines::Migration),
+            Box::new(m20250502_092000_add_tax_amounts::Migration),
+            Box::new(m20250503_090000_add_promo_rules::Migration),
+            Box::new(m20250503_091000_create_promo_redemptions::Migration),
+            Box::new(m20250503_092000_add_discount_amounts::Migration),
+            Box::new(m20250504_090000_create_price_rules::Migration),
+            Box::new(m20250505_090000_add_reorder_settings::Migration),
+            Box::new(m20250506_090000_add_supplier_delivery_days::Migration),
+            Box::new(m20250506_091000_create_purchase_orders::Migration),
+            Box::new(m20250506_092000_create_purchase_order_lines::Migration),
+            Box::new(m20250506_093000_create_goods_receipts::Migration),
+            Box::new(m20250507_090000_create_stock_takes::Migration),
+            Box::new(m20250507_091000_create_stock_take_counts::Migration),
+            Box::new(m20250507_092000_create_stock_adjustments::Migration),
+            Box::new(m20250508_090000_alter_custom_cocktails::Migration),
+            Box::new(m20250508_091000_create_custom_cocktail_ingredients::Migration),
+            Box::new(m20250508_092000_add_cocktail_order_items::Migration),
+            Box::new(m20250509_090000_add_stock_drink_measures::Migration),
+            Box::new(m20250509_091000_create_recipe_items::Migration),
+            Box::new(m20250509_092000_move_cocktail_ingredients::Migration),
+            Box::new(m20250509_093000_add_order_item_recipes::Migration),
+            Box::new(m20250510_090000_alter_categories::Migration),
+            Box::new(m20250511_090000_add_stock_skus::Migration),
+            Box::new(m20250512_090000_create_stock_transfers::Migration),
+            Box::new(m20250512_091000_create_stock_transfer_lines::Migration),
+            Box::new(m20250512_092000_add_stock_adjustment_transfers::Migration),
+            Box::new(m20250513_090000_add_branch_timezones::Migration),
+            Box::new(m20250513_091000_add_order_item_costs::Migration),
+            Box::new(m20250514_090000_drop_tax_rule_countries::Migration),
         ]
     }
 }
@@ -88,7 +88,7 @@ impl MigrationTrait for Migration {
 }
 
 #[derive(DeriveIden)]
-enum CustomCocktails {
+pub enum CustomCocktails {
     Table,
     Id,
     OrganizationId,
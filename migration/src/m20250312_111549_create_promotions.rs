@@ -61,7 +61,7 @@ impl MigrationTrait for Migration {
 }
 
 #[derive(DeriveIden)]
-enum Promos {
+pub enum Promos {
     Table,
     Id,
     Title,
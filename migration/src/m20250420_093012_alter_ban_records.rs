@@ -0,0 +1,151 @@
+use sea_orm_migration::prelude::*;
+
+use crate::{
+    m20250311_102524_create_organizations::Organizations,
+    m20250311_111857_create_branches::Branches, m20250311_114321_create_staff::Employees,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(BanRecords::Table)
+                    .add_column(ColumnDef::new(BanRecords::OrganizationId).uuid())
+                    .add_column(ColumnDef::new(BanRecords::BranchId).uuid())
+                    .add_column(
+                        ColumnDef::new(BanRecords::IsShared)
+                            .boolean()
+                            .not_null()
+                            .default(false),
+                    )
+                    .add_column(ColumnDef::new(BanRecords::BannedBy).uuid())
+                    .add_column(ColumnDef::new(BanRecords::LiftedAt).timestamp_with_time_zone())
+                    .add_column(ColumnDef::new(BanRecords::LiftedBy).uuid())
+                    .add_column(ColumnDef::new(BanRecords::LiftReason).string())
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .name("fk_ban_records_organization_id")
+                            .from_tbl(BanRecords::Table)
+                            .from_col(BanRecords::OrganizationId)
+                            .to_tbl(Organizations::Table)
+                            .to_col(Organizations::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .name("fk_ban_records_branch_id")
+                            .from_tbl(BanRecords::Table)
+                            .from_col(BanRecords::BranchId)
+                            .to_tbl(Branches::Table)
+                            .to_col(Branches::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .name("fk_ban_records_banned_by")
+                            .from_tbl(BanRecords::Table)
+                            .from_col(BanRecords::BannedBy)
+                            .to_tbl(Employees::Table)
+                            .to_col(Employees::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Postgres names inline column checks `<table>_<column>_check`
+        let db = manager.get_connection();
+
+        db.execute_unprepared(
+            "ALTER TABLE ban_records DROP CONSTRAINT IF EXISTS ban_records_banned_duration_type_check",
+        )
+        .await?;
+
+        db.execute_unprepared(&format!(
+            "ALTER TABLE ban_records ADD CONSTRAINT ban_records_banned_duration_type_check CHECK (banned_duration_type IN ({}))",
+            [
+                BannedDurationType::Hours,
+                BannedDurationType::Days,
+                BannedDurationType::Weeks,
+                BannedDurationType::Months,
+                BannedDurationType::Permanent,
+            ]
+            .iter()
+            .map(|t| format!("'{}'", t.as_str()))
+            .collect::<Vec<_>>()
+            .join(", ")
+        ))
+        .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        db.execute_unprepared(
+            "ALTER TABLE ban_records DROP CONSTRAINT IF EXISTS ban_records_banned_duration_type_check",
+        )
+        .await?;
+
+        db.execute_unprepared(
+            "ALTER TABLE ban_records ADD CONSTRAINT ban_records_banned_duration_type_check CHECK (banned_duration_type IN ('DAYS', 'WEEKS', 'MONTHS'))",
+        )
+        .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(BanRecords::Table)
+                    .drop_foreign_key(Alias::new("fk_ban_records_organization_id"))
+                    .drop_foreign_key(Alias::new("fk_ban_records_branch_id"))
+                    .drop_foreign_key(Alias::new("fk_ban_records_banned_by"))
+                    .drop_column(BanRecords::OrganizationId)
+                    .drop_column(BanRecords::BranchId)
+                    .drop_column(BanRecords::IsShared)
+                    .drop_column(BanRecords::BannedBy)
+                    .drop_column(BanRecords::LiftedAt)
+                    .drop_column(BanRecords::LiftedBy)
+                    .drop_column(BanRecords::LiftReason)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum BanRecords {
+    Table,
+    OrganizationId,
+    BranchId,
+    IsShared,
+    BannedBy,
+    LiftedAt,
+    LiftedBy,
+    LiftReason,
+}
+
+enum BannedDurationType {
+    Hours,
+    Days,
+    Weeks,
+    Months,
+    Permanent,
+}
+
+impl BannedDurationType {
+    pub fn as_str(&self) -> &str {
+        match self {
+            BannedDurationType::Hours => "HOURS",
+            BannedDurationType::Days => "DAYS",
+            BannedDurationType::Weeks => "WEEKS",
+            BannedDurationType::Months => "MONTHS",
+            BannedDurationType::Permanent => "PERMANENT",
+        }
+    }
+}
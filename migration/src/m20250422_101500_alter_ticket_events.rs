@@ -0,0 +1,115 @@
+use sea_orm_migration::prelude::*;
+
+use crate::{
+    m20250311_102524_create_organizations::Organizations,
+    m20250311_111857_create_branches::Branches, m20250311_114321_create_staff::Employees,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TicketEvents::Table)
+                    .add_column(
+                        ColumnDef::new(TicketEvents::OrganizationId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .add_column(ColumnDef::new(TicketEvents::BranchId).uuid().not_null())
+                    .add_column(ColumnDef::new(TicketEvents::Slug).string().unique_key())
+                    .add_column(ColumnDef::new(TicketEvents::CancellationReason).string())
+                    .add_column(ColumnDef::new(TicketEvents::CreatedBy).uuid())
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .name("fk_ticket_events_organization_id")
+                            .from_tbl(TicketEvents::Table)
+                            .from_col(TicketEvents::OrganizationId)
+                            .to_tbl(Organizations::Table)
+                            .to_col(Organizations::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .name("fk_ticket_events_branch_id")
+                            .from_tbl(TicketEvents::Table)
+                            .from_col(TicketEvents::BranchId)
+                            .to_tbl(Branches::Table)
+                            .to_col(Branches::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .name("fk_ticket_events_created_by")
+                            .from_tbl(TicketEvents::Table)
+                            .from_col(TicketEvents::CreatedBy)
+                            .to_tbl(Employees::Table)
+                            .to_col(Employees::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Tickets::Table)
+                    .add_column(
+                        ColumnDef::new(Tickets::Quantity)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Tickets::Table)
+                    .drop_column(Tickets::Quantity)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TicketEvents::Table)
+                    .drop_foreign_key(Alias::new("fk_ticket_events_organization_id"))
+                    .drop_foreign_key(Alias::new("fk_ticket_events_branch_id"))
+                    .drop_foreign_key(Alias::new("fk_ticket_events_created_by"))
+                    .drop_column(TicketEvents::OrganizationId)
+                    .drop_column(TicketEvents::BranchId)
+                    .drop_column(TicketEvents::Slug)
+                    .drop_column(TicketEvents::CancellationReason)
+                    .drop_column(TicketEvents::CreatedBy)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum TicketEvents {
+    Table,
+    OrganizationId,
+    BranchId,
+    Slug,
+    CancellationReason,
+    CreatedBy,
+}
+
+#[derive(DeriveIden)]
+enum Tickets {
+    Table,
+    Quantity,
+}
@@ -0,0 +1,167 @@
+use sea_orm_migration::prelude::*;
+
+use crate::{
+    m20250311_135726_create_customers::Customers,
+    m20250311_165323_create_transactions::Transactions,
+    m20250312_092226_create_ticket_events::TicketEvents,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(TicketPurchases::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(TicketPurchases::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .default(Expr::cust("gen_random_uuid()")),
+                    )
+                    .col(ColumnDef::new(TicketPurchases::TicketId).uuid().not_null())
+                    .col(
+                        ColumnDef::new(TicketPurchases::TicketEventId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(TicketPurchases::CustomerId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(TicketPurchases::TransactionId).uuid())
+                    .col(
+                        ColumnDef::new(TicketPurchases::Quantity)
+                            .integer()
+                            .not_null()
+                            .default(1),
+                    )
+                    .col(
+                        ColumnDef::new(TicketPurchases::UnitPrice)
+                            .decimal()
+                            .not_null()
+                            .default(0.0),
+                    )
+                    .col(
+                        ColumnDef::new(TicketPurchases::TotalAmount)
+                            .decimal()
+                            .not_null()
+                            .default(0.0),
+                    )
+                    .col(
+                        ColumnDef::new(TicketPurchases::PassCode)
+                            .string()
+                            .not_null()
+                            .unique_key(),
+                    )
+                    .col(
+                        ColumnDef::new(TicketPurchases::QrCodeId)
+                            .uuid()
+                            .not_null()
+                            .default(Expr::cust("gen_random_uuid()")),
+                    )
+                    .col(
+                        ColumnDef::new(TicketPurchases::PurchaseStatus)
+                            .string()
+                            .not_null()
+                            .check(Expr::col(TicketPurchases::PurchaseStatus).is_in(vec![
+                                PurchaseStatusEnum::Active.as_str(),
+                                PurchaseStatusEnum::Refunded.as_str(),
+                            ]))
+                            .default(PurchaseStatusEnum::Active.as_str()),
+                    )
+                    .col(ColumnDef::new(TicketPurchases::RefundTransactionId).uuid())
+                    .col(ColumnDef::new(TicketPurchases::RefundedAt).timestamp_with_time_zone())
+                    .col(
+                        ColumnDef::new(TicketPurchases::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(TicketPurchases::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(TicketPurchases::Table, TicketPurchases::TicketId)
+                            .to(Tickets::Table, Tickets::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(TicketPurchases::Table, TicketPurchases::TicketEventId)
+                            .to(TicketEvents::Table, TicketEvents::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(TicketPurchases::Table, TicketPurchases::CustomerId)
+                            .to(Customers::Table, Customers::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(TicketPurchases::Table, TicketPurchases::TransactionId)
+                            .to(Transactions::Table, Transactions::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(TicketPurchases::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum TicketPurchases {
+    Table,
+    Id,
+    TicketId,
+    TicketEventId,
+    CustomerId,
+    TransactionId,
+    Quantity,
+    UnitPrice,
+    TotalAmount,
+    PassCode,
+    QrCodeId,
+    PurchaseStatus,
+    RefundTransactionId,
+    RefundedAt,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Tickets {
+    Table,
+    Id,
+}
+
+enum PurchaseStatusEnum {
+    Active,
+    Refunded,
+}
+
+impl PurchaseStatusEnum {
+    fn as_str(&self) -> &'static str {
+        match self {
+            PurchaseStatusEnum::Active => "ACTIVE",
+            PurchaseStatusEnum::Refunded => "REFUNDED",
+        }
+    }
+}
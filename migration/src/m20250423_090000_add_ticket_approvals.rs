@@ -0,0 +1,135 @@
+use sea_orm_migration::prelude::*;
+
+use crate::m20250311_114321_create_staff::Employees;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Tickets::Table)
+                    .add_column(
+                        ColumnDef::new(Tickets::ApprovalStatus)
+                            .string()
+                            .not_null()
+                            .check(Expr::col(Tickets::ApprovalStatus).is_in(vec![
+                                ApprovalStatusEnum::Pending.as_str(),
+                                ApprovalStatusEnum::Approved.as_str(),
+                                ApprovalStatusEnum::Rejected.as_str(),
+                            ]))
+                            .default(ApprovalStatusEnum::Pending.as_str()),
+                    )
+                    .add_column(ColumnDef::new(Tickets::ApprovalComment).string())
+                    .add_column(ColumnDef::new(Tickets::ApprovedAt).timestamp_with_time_zone())
+                    .add_column(ColumnDef::new(Tickets::CreatedBy).uuid())
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .name("fk_tickets_created_by")
+                            .from_tbl(Tickets::Table)
+                            .from_col(Tickets::CreatedBy)
+                            .to_tbl(Employees::Table)
+                            .to_col(Employees::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TicketEvents::Table)
+                    .add_column(
+                        ColumnDef::new(TicketEvents::ApprovalStatus)
+                            .string()
+                            .not_null()
+                            .check(Expr::col(TicketEvents::ApprovalStatus).is_in(vec![
+                                ApprovalStatusEnum::Pending.as_str(),
+                                ApprovalStatusEnum::Approved.as_str(),
+                                ApprovalStatusEnum::Rejected.as_str(),
+                            ]))
+                            .default(ApprovalStatusEnum::Pending.as_str()),
+                    )
+                    .add_column(ColumnDef::new(TicketEvents::ApprovalComment).string())
+                    .add_column(ColumnDef::new(TicketEvents::ApprovedBy).uuid())
+                    .add_column(ColumnDef::new(TicketEvents::ApprovedAt).timestamp_with_time_zone())
+                    .to_owned(),
+            )
+            .await?;
+
+        // Tickets already flagged as approved keep their standing under the new workflow
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "UPDATE tickets SET approval_status = 'APPROVED', approved_at = updated_at WHERE is_approved = true",
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TicketEvents::Table)
+                    .drop_column(TicketEvents::ApprovalStatus)
+                    .drop_column(TicketEvents::ApprovalComment)
+                    .drop_column(TicketEvents::ApprovedBy)
+                    .drop_column(TicketEvents::ApprovedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Tickets::Table)
+                    .drop_foreign_key(Alias::new("fk_tickets_created_by"))
+                    .drop_column(Tickets::ApprovalStatus)
+                    .drop_column(Tickets::ApprovalComment)
+                    .drop_column(Tickets::ApprovedAt)
+                    .drop_column(Tickets::CreatedBy)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Tickets {
+    Table,
+    ApprovalStatus,
+    ApprovalComment,
+    ApprovedAt,
+    CreatedBy,
+}
+
+#[derive(DeriveIden)]
+enum TicketEvents {
+    Table,
+    ApprovalStatus,
+    ApprovalComment,
+    ApprovedBy,
+    ApprovedAt,
+}
+
+enum ApprovalStatusEnum {
+    Pending,
+    Approved,
+    Rejected,
+}
+
+impl ApprovalStatusEnum {
+    fn as_str(&self) -> &'static str {
+        match self {
+            ApprovalStatusEnum::Pending => "PENDING",
+            ApprovalStatusEnum::Approved => "APPROVED",
+            ApprovalStatusEnum::Rejected => "REJECTED",
+        }
+    }
+}
@@ -0,0 +1,111 @@
+use sea_orm_migration::prelude::*;
+
+use crate::{
+    m20250311_102524_create_organizations::Organizations, m20250311_114321_create_staff::Employees,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(ApprovalLogs::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(ApprovalLogs::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .default(Expr::cust("gen_random_uuid()")),
+                    )
+                    .col(
+                        ColumnDef::new(ApprovalLogs::OrganizationId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(ApprovalLogs::EntityType)
+                            .string()
+                            .not_null()
+                            .check(Expr::col(ApprovalLogs::EntityType).is_in(vec![
+                                EntityTypeEnum::Ticket.as_str(),
+                                EntityTypeEnum::TicketEvent.as_str(),
+                            ])),
+                    )
+                    .col(ColumnDef::new(ApprovalLogs::EntityId).uuid().not_null())
+                    .col(ColumnDef::new(ApprovalLogs::FromStatus).string())
+                    .col(ColumnDef::new(ApprovalLogs::ToStatus).string().not_null())
+                    .col(ColumnDef::new(ApprovalLogs::Comment).string())
+                    .col(ColumnDef::new(ApprovalLogs::ActorId).uuid())
+                    .col(
+                        ColumnDef::new(ApprovalLogs::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(ApprovalLogs::Table, ApprovalLogs::OrganizationId)
+                            .to(Organizations::Table, Organizations::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(ApprovalLogs::Table, ApprovalLogs::ActorId)
+                            .to(Employees::Table, Employees::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_approval_logs_entity")
+                    .table(ApprovalLogs::Table)
+                    .col(ApprovalLogs::EntityType)
+                    .col(ApprovalLogs::EntityId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(ApprovalLogs::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum ApprovalLogs {
+    Table,
+    Id,
+    OrganizationId,
+    EntityType,
+    EntityId,
+    FromStatus,
+    ToStatus,
+    Comment,
+    ActorId,
+    CreatedAt,
+}
+
+enum EntityTypeEnum {
+    Ticket,
+    TicketEvent,
+}
+
+impl EntityTypeEnum {
+    fn as_str(&self) -> &'static str {
+        match self {
+            EntityTypeEnum::Ticket => "TICKET",
+            EntityTypeEnum::TicketEvent => "TICKET_EVENT",
+        }
+    }
+}
@@ -0,0 +1,85 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TicketPurchases::Table)
+                    .add_column(ColumnDef::new(TicketPurchases::TransferredFrom).uuid())
+                    .add_column(
+                        ColumnDef::new(TicketPurchases::TransferredAt).timestamp_with_time_zone(),
+                    )
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .name("fk_ticket_purchases_transferred_from")
+                            .from_tbl(TicketPurchases::Table)
+                            .from_col(TicketPurchases::TransferredFrom)
+                            .to_tbl(TicketPurchases::Table)
+                            .to_col(TicketPurchases::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Passes handed on to another customer stay on record, but no longer admit anyone
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "ALTER TABLE ticket_purchases DROP CONSTRAINT IF EXISTS ticket_purchases_purchase_status_check;
+                 ALTER TABLE ticket_purchases ADD CONSTRAINT ticket_purchases_purchase_status_check
+                    CHECK (purchase_status IN ('ACTIVE', 'REFUNDED', 'TRANSFERRED'));",
+            )
+            .await?;
+
+        // Resale proceeds are paid out to the seller's wallet
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "ALTER TABLE transactions DROP CONSTRAINT IF EXISTS transactions_transaction_type_check;
+                 ALTER TABLE transactions ADD CONSTRAINT transactions_transaction_type_check
+                    CHECK (transaction_type IN ('TOPUP', 'PAYMENT', 'WITHDRAWAL', 'REFUND', 'TIP', 'PAYOUT'));",
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "ALTER TABLE transactions DROP CONSTRAINT IF EXISTS transactions_transaction_type_check;
+                 ALTER TABLE transactions ADD CONSTRAINT transactions_transaction_type_check
+                    CHECK (transaction_type IN ('TOPUP', 'PAYMENT', 'WITHDRAWAL', 'REFUND', 'TIP'));
+                 ALTER TABLE ticket_purchases DROP CONSTRAINT IF EXISTS ticket_purchases_purchase_status_check;
+                 ALTER TABLE ticket_purchases ADD CONSTRAINT ticket_purchases_purchase_status_check
+                    CHECK (purchase_status IN ('ACTIVE', 'REFUNDED'));",
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TicketPurchases::Table)
+                    .drop_foreign_key(Alias::new("fk_ticket_purchases_transferred_from"))
+                    .drop_column(TicketPurchases::TransferredFrom)
+                    .drop_column(TicketPurchases::TransferredAt)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum TicketPurchases {
+    Table,
+    Id,
+    TransferredFrom,
+    TransferredAt,
+}
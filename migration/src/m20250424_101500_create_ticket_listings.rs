@@ -0,0 +1,149 @@
+use sea_orm_migration::prelude::*;
+
+use crate::{
+    m20250311_102524_create_organizations::Organizations,
+    m20250311_135726_create_customers::Customers,
+    m20250312_092226_create_ticket_events::TicketEvents,
+    m20250422_103000_create_ticket_purchases::TicketPurchases,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(TicketListings::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(TicketListings::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .default(Expr::cust("gen_random_uuid()")),
+                    )
+                    .col(ColumnDef::new(TicketListings::PurchaseId).uuid().not_null())
+                    .col(
+                        ColumnDef::new(TicketListings::TicketEventId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(TicketListings::OrganizationId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(TicketListings::SellerId).uuid().not_null())
+                    .col(ColumnDef::new(TicketListings::BuyerId).uuid())
+                    .col(
+                        ColumnDef::new(TicketListings::AskingPrice)
+                            .decimal()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(TicketListings::FeeAmount)
+                            .decimal()
+                            .not_null()
+                            .default(0.0),
+                    )
+                    .col(
+                        ColumnDef::new(TicketListings::ListingStatus)
+                            .string()
+                            .not_null()
+                            .check(Expr::col(TicketListings::ListingStatus).is_in(vec![
+                                ListingStatusEnum::Active.as_str(),
+                                ListingStatusEnum::Sold.as_str(),
+                                ListingStatusEnum::Cancelled.as_str(),
+                            ]))
+                            .default(ListingStatusEnum::Active.as_str()),
+                    )
+                    .col(ColumnDef::new(TicketListings::SoldAt).timestamp_with_time_zone())
+                    .col(
+                        ColumnDef::new(TicketListings::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(TicketListings::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(TicketListings::Table, TicketListings::PurchaseId)
+                            .to(TicketPurchases::Table, TicketPurchases::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(TicketListings::Table, TicketListings::TicketEventId)
+                            .to(TicketEvents::Table, TicketEvents::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(TicketListings::Table, TicketListings::OrganizationId)
+                            .to(Organizations::Table, Organizations::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(TicketListings::Table, TicketListings::SellerId)
+                            .to(Customers::Table, Customers::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(TicketListings::Table, TicketListings::BuyerId)
+                            .to(Customers::Table, Customers::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(TicketListings::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum TicketListings {
+    Table,
+    Id,
+    PurchaseId,
+    TicketEventId,
+    OrganizationId,
+    SellerId,
+    BuyerId,
+    AskingPrice,
+    FeeAmount,
+    ListingStatus,
+    SoldAt,
+    CreatedAt,
+    UpdatedAt,
+}
+
+enum ListingStatusEnum {
+    Active,
+    Sold,
+    Cancelled,
+}
+
+impl ListingStatusEnum {
+    fn as_str(&self) -> &'static str {
+        match self {
+            ListingStatusEnum::Active => "ACTIVE",
+            ListingStatusEnum::Sold => "SOLD",
+            ListingStatusEnum::Cancelled => "CANCELLED",
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use sea_orm_migration::prelude::*;
+
+use crate::m20250311_114321_create_staff::Employees;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Orders::Table)
+                    .modify_column(ColumnDef::new(Orders::ServedBy).uuid().null())
+                    .add_column(ColumnDef::new(Orders::StatusReason).string())
+                    .add_column(ColumnDef::new(Orders::RefundTransactionId).uuid())
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .name("fk_orders_served_by")
+                            .from_tbl(Orders::Table)
+                            .from_col(Orders::ServedBy)
+                            .to_tbl(Employees::Table)
+                            .to_col(Employees::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_orders_branch_order_number")
+                    .table(Orders::Table)
+                    .col(Orders::BranchId)
+                    .col(Orders::OrderNumber)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(OrderItems::Table)
+                    .add_column(ColumnDef::new(OrderItems::ItemName).string())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(OrderItems::Table)
+                    .drop_column(OrderItems::ItemName)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_orders_branch_order_number")
+                    .table(Orders::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Orders::Table)
+                    .drop_foreign_key(Alias::new("fk_orders_served_by"))
+                    .drop_column(Orders::StatusReason)
+                    .drop_column(Orders::RefundTransactionId)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Orders {
+    Table,
+    BranchId,
+    OrderNumber,
+    ServedBy,
+    StatusReason,
+    RefundTransactionId,
+}
+
+#[derive(DeriveIden)]
+enum OrderItems {
+    Table,
+    ItemName,
+}
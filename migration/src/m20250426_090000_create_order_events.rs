@@ -0,0 +1,146 @@
+use sea_orm_migration::prelude::*;
+
+use crate::{
+    m20250311_102524_create_organizations::Organizations,
+    m20250311_111857_create_branches::Branches, m20250311_183402_create_orders::Orders,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(OrderEvents::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(OrderEvents::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(OrderEvents::OrganizationId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(OrderEvents::BranchId).uuid().not_null())
+                    .col(ColumnDef::new(OrderEvents::OrderId).uuid().not_null())
+                    .col(
+                        ColumnDef::new(OrderEvents::Station)
+                            .string()
+                            .not_null()
+                            .check(Expr::col(OrderEvents::Station).is_in(vec![
+                                StationEnum::Bar.as_str(),
+                                StationEnum::Kitchen.as_str(),
+                            ])),
+                    )
+                    .col(
+                        ColumnDef::new(OrderEvents::EventType)
+                            .string()
+                            .not_null()
+                            .check(Expr::col(OrderEvents::EventType).is_in(vec![
+                                EventTypeEnum::OrderCreated.as_str(),
+                                EventTypeEnum::StatusChanged.as_str(),
+                                EventTypeEnum::OrderCancelled.as_str(),
+                            ])),
+                    )
+                    .col(
+                        ColumnDef::new(OrderEvents::Payload)
+                            .json_binary()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(OrderEvents::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(OrderEvents::Table, OrderEvents::OrganizationId)
+                            .to(Organizations::Table, Organizations::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(OrderEvents::Table, OrderEvents::BranchId)
+                            .to(Branches::Table, Branches::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(OrderEvents::Table, OrderEvents::OrderId)
+                            .to(Orders::Table, Orders::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_order_events_branch_station")
+                    .table(OrderEvents::Table)
+                    .col(OrderEvents::BranchId)
+                    .col(OrderEvents::Station)
+                    .col(OrderEvents::Id)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(OrderEvents::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum OrderEvents {
+    Table,
+    Id,
+    OrganizationId,
+    BranchId,
+    OrderId,
+    Station,
+    EventType,
+    Payload,
+    CreatedAt,
+}
+
+enum StationEnum {
+    Bar,
+    Kitchen,
+}
+
+impl StationEnum {
+    fn as_str(&self) -> &'static str {
+        match self {
+            StationEnum::Bar => "BAR",
+            StationEnum::Kitchen => "KITCHEN",
+        }
+    }
+}
+
+enum EventTypeEnum {
+    OrderCreated,
+    StatusChanged,
+    OrderCancelled,
+}
+
+impl EventTypeEnum {
+    fn as_str(&self) -> &'static str {
+        match self {
+            EventTypeEnum::OrderCreated => "ORDER_CREATED",
+            EventTypeEnum::StatusChanged => "STATUS_CHANGED",
+            EventTypeEnum::OrderCancelled => "ORDER_CANCELLED",
+        }
+    }
+}
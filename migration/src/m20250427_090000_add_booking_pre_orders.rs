@@ -0,0 +1,69 @@
+use sea_orm_migration::prelude::*;
+
+use crate::m20250311_174956_create_bookings::Bookings;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Orders::Table)
+                    .add_column(ColumnDef::new(Orders::BookingId).uuid())
+                    .add_column(ColumnDef::new(Orders::ReleasedAt).timestamp_with_time_zone())
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .name("fk_orders_booking_id")
+                            .from_tbl(Orders::Table)
+                            .from_col(Orders::BookingId)
+                            .to_tbl(Bookings::Table)
+                            .to_col(Bookings::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_orders_booking_id")
+                    .table(Orders::Table)
+                    .col(Orders::BookingId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_orders_booking_id")
+                    .table(Orders::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Orders::Table)
+                    .drop_foreign_key(Alias::new("fk_orders_booking_id"))
+                    .drop_column(Orders::BookingId)
+                    .drop_column(Orders::ReleasedAt)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Orders {
+    Table,
+    BookingId,
+    ReleasedAt,
+}
@@ -0,0 +1,114 @@
+use sea_orm_migration::prelude::*;
+
+use crate::m20250311_114321_create_staff::Employees;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TillSessions::Table)
+                    .add_column(
+                        ColumnDef::new(TillSessions::SessionStatus)
+                            .string()
+                            .not_null()
+                            .check(Expr::col(TillSessions::SessionStatus).is_in(vec![
+                                SessionStatusEnum::Open.as_str(),
+                                SessionStatusEnum::Closed.as_str(),
+                            ]))
+                            .default(SessionStatusEnum::Open.as_str()),
+                    )
+                    .add_column(
+                        ColumnDef::new(TillSessions::OpeningFloat)
+                            .decimal()
+                            .not_null()
+                            .default(0.0),
+                    )
+                    .add_column(
+                        ColumnDef::new(TillSessions::OpenedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .add_column(ColumnDef::new(TillSessions::ClosedAt).timestamp_with_time_zone())
+                    .add_column(ColumnDef::new(TillSessions::ClosedBy).uuid())
+                    .add_column(ColumnDef::new(TillSessions::CountedCash).decimal())
+                    .add_column(ColumnDef::new(TillSessions::Notes).string())
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .name("fk_till_sessions_closed_by")
+                            .from_tbl(TillSessions::Table)
+                            .from_col(TillSessions::ClosedBy)
+                            .to_tbl(Employees::Table)
+                            .to_col(Employees::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // An employee works one till at a time
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "CREATE UNIQUE INDEX IF NOT EXISTS idx_till_sessions_open_employee
+                    ON till_sessions (employee_id) WHERE session_status = 'OPEN';",
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared("DROP INDEX IF EXISTS idx_till_sessions_open_employee;")
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TillSessions::Table)
+                    .drop_foreign_key(Alias::new("fk_till_sessions_closed_by"))
+                    .drop_column(TillSessions::SessionStatus)
+                    .drop_column(TillSessions::OpeningFloat)
+                    .drop_column(TillSessions::OpenedAt)
+                    .drop_column(TillSessions::ClosedAt)
+                    .drop_column(TillSessions::ClosedBy)
+                    .drop_column(TillSessions::CountedCash)
+                    .drop_column(TillSessions::Notes)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum TillSessions {
+    Table,
+    SessionStatus,
+    OpeningFloat,
+    OpenedAt,
+    ClosedAt,
+    ClosedBy,
+    CountedCash,
+    Notes,
+}
+
+enum SessionStatusEnum {
+    Open,
+    Closed,
+}
+
+impl SessionStatusEnum {
+    fn as_str(&self) -> &'static str {
+        match self {
+            SessionStatusEnum::Open => "OPEN",
+            SessionStatusEnum::Closed => "CLOSED",
+        }
+    }
+}
@@ -0,0 +1,111 @@
+use sea_orm_migration::prelude::*;
+
+use crate::m20250312_105209_create_till_sessions::TillSessions;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(TillReconciliations::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(TillReconciliations::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .default(Expr::cust("gen_random_uuid()")),
+                    )
+                    .col(
+                        ColumnDef::new(TillReconciliations::TillSessionId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(TillReconciliations::PaymentChannel)
+                            .string()
+                            .not_null()
+                            .check(Expr::col(TillReconciliations::PaymentChannel).is_in(vec![
+                                PaymentChannelEnum::Cash.as_str(),
+                                PaymentChannelEnum::Card.as_str(),
+                                PaymentChannelEnum::Wallet.as_str(),
+                            ])),
+                    )
+                    .col(
+                        ColumnDef::new(TillReconciliations::ExpectedAmount)
+                            .decimal()
+                            .not_null()
+                            .default(0.0),
+                    )
+                    .col(ColumnDef::new(TillReconciliations::CountedAmount).decimal())
+                    .col(ColumnDef::new(TillReconciliations::Variance).decimal())
+                    .col(
+                        ColumnDef::new(TillReconciliations::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(
+                                TillReconciliations::Table,
+                                TillReconciliations::TillSessionId,
+                            )
+                            .to(TillSessions::Table, TillSessions::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_till_reconciliations_session_channel")
+                    .table(TillReconciliations::Table)
+                    .col(TillReconciliations::TillSessionId)
+                    .col(TillReconciliations::PaymentChannel)
+                    .unique()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(TillReconciliations::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum TillReconciliations {
+    Table,
+    Id,
+    TillSessionId,
+    PaymentChannel,
+    ExpectedAmount,
+    CountedAmount,
+    Variance,
+    CreatedAt,
+}
+
+enum PaymentChannelEnum {
+    Cash,
+    Card,
+    Wallet,
+}
+
+impl PaymentChannelEnum {
+    fn as_str(&self) -> &'static str {
+        match self {
+            PaymentChannelEnum::Cash => "CASH",
+            PaymentChannelEnum::Card => "CARD",
+            PaymentChannelEnum::Wallet => "WALLET",
+        }
+    }
+}
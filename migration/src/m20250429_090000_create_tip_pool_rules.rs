@@ -0,0 +1,114 @@
+use sea_orm_migration::prelude::*;
+
+use crate::{
+    m20250311_102524_create_organizations::Organizations,
+    m20250311_111857_create_branches::Branches, m20250311_114321_create_staff::Employees,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(TipPoolRules::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(TipPoolRules::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .default(Expr::cust("gen_random_uuid()")),
+                    )
+                    .col(
+                        ColumnDef::new(TipPoolRules::OrganizationId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(TipPoolRules::BranchId)
+                            .uuid()
+                            .not_null()
+                            .unique_key(),
+                    )
+                    .col(
+                        ColumnDef::new(TipPoolRules::SplitMethod)
+                            .string()
+                            .not_null()
+                            .check(Expr::col(TipPoolRules::SplitMethod).is_in(vec![
+                                SplitMethodEnum::Hours.as_str(),
+                                SplitMethodEnum::Department.as_str(),
+                            ]))
+                            .default(SplitMethodEnum::Hours.as_str()),
+                    )
+                    .col(ColumnDef::new(TipPoolRules::CreatedBy).uuid())
+                    .col(
+                        ColumnDef::new(TipPoolRules::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(TipPoolRules::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(TipPoolRules::Table, TipPoolRules::OrganizationId)
+                            .to(Organizations::Table, Organizations::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(TipPoolRules::Table, TipPoolRules::BranchId)
+                            .to(Branches::Table, Branches::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(TipPoolRules::Table, TipPoolRules::CreatedBy)
+                            .to(Employees::Table, Employees::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(TipPoolRules::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum TipPoolRules {
+    Table,
+    Id,
+    OrganizationId,
+    BranchId,
+    SplitMethod,
+    CreatedBy,
+    CreatedAt,
+    UpdatedAt,
+}
+
+enum SplitMethodEnum {
+    Hours,
+    Department,
+}
+
+impl SplitMethodEnum {
+    fn as_str(&self) -> &'static str {
+        match self {
+            SplitMethodEnum::Hours => "HOURS",
+            SplitMethodEnum::Department => "DEPARTMENT",
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use sea_orm_migration::prelude::*;
+
+use crate::{
+    m20250311_113056_create_departments::Departments,
+    m20250429_090000_create_tip_pool_rules::TipPoolRules,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(TipPoolShares::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(TipPoolShares::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .default(Expr::cust("gen_random_uuid()")),
+                    )
+                    .col(ColumnDef::new(TipPoolShares::RuleId).uuid().not_null())
+                    .col(
+                        ColumnDef::new(TipPoolShares::DepartmentId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(TipPoolShares::SharePercent)
+                            .decimal()
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(TipPoolShares::Table, TipPoolShares::RuleId)
+                            .to(TipPoolRules::Table, TipPoolRules::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(TipPoolShares::Table, TipPoolShares::DepartmentId)
+                            .to(Departments::Table, Departments::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_tip_pool_shares_rule_department")
+                    .table(TipPoolShares::Table)
+                    .col(TipPoolShares::RuleId)
+                    .col(TipPoolShares::DepartmentId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(TipPoolShares::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum TipPoolShares {
+    Table,
+    Id,
+    RuleId,
+    DepartmentId,
+    SharePercent,
+}
@@ -0,0 +1,86 @@
+use sea_orm_migration::prelude::*;
+
+use crate::{
+    m20250311_102524_create_organizations::Organizations,
+    m20250311_111857_create_branches::Branches, m20250311_114321_create_staff::Employees,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(TipPayouts::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(TipPayouts::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .default(Expr::cust("gen_random_uuid()")),
+                    )
+                    .col(ColumnDef::new(TipPayouts::OrganizationId).uuid().not_null())
+                    .col(ColumnDef::new(TipPayouts::BranchId).uuid().not_null())
+                    .col(ColumnDef::new(TipPayouts::PeriodStart).date().not_null())
+                    .col(ColumnDef::new(TipPayouts::PeriodEnd).date().not_null())
+                    .col(ColumnDef::new(TipPayouts::SplitMethod).string().not_null())
+                    .col(
+                        ColumnDef::new(TipPayouts::TotalAmount)
+                            .decimal()
+                            .not_null()
+                            .default(0.0),
+                    )
+                    .col(ColumnDef::new(TipPayouts::CreatedBy).uuid())
+                    .col(
+                        ColumnDef::new(TipPayouts::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(TipPayouts::Table, TipPayouts::OrganizationId)
+                            .to(Organizations::Table, Organizations::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(TipPayouts::Table, TipPayouts::BranchId)
+                            .to(Branches::Table, Branches::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(TipPayouts::Table, TipPayouts::CreatedBy)
+                            .to(Employees::Table, Employees::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(TipPayouts::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum TipPayouts {
+    Table,
+    Id,
+    OrganizationId,
+    BranchId,
+    PeriodStart,
+    PeriodEnd,
+    SplitMethod,
+    TotalAmount,
+    CreatedBy,
+    CreatedAt,
+}
@@ -0,0 +1,93 @@
+use sea_orm_migration::prelude::*;
+
+use crate::{
+    m20250311_114321_create_staff::Employees, m20250311_165323_create_transactions::Transactions,
+    m20250429_092000_create_tip_payouts::TipPayouts,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(TipAllocations::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(TipAllocations::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .default(Expr::cust("gen_random_uuid()")),
+                    )
+                    .col(ColumnDef::new(TipAllocations::PayoutId).uuid().not_null())
+                    .col(ColumnDef::new(TipAllocations::EmployeeId).uuid().not_null())
+                    .col(
+                        ColumnDef::new(TipAllocations::DepartmentId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(TipAllocations::HoursWorked)
+                            .decimal()
+                            .not_null()
+                            .default(0.0),
+                    )
+                    .col(
+                        ColumnDef::new(TipAllocations::Amount)
+                            .decimal()
+                            .not_null()
+                            .default(0.0),
+                    )
+                    .col(ColumnDef::new(TipAllocations::TransactionId).uuid())
+                    .col(
+                        ColumnDef::new(TipAllocations::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(TipAllocations::Table, TipAllocations::PayoutId)
+                            .to(TipPayouts::Table, TipPayouts::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(TipAllocations::Table, TipAllocations::EmployeeId)
+                            .to(Employees::Table, Employees::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(TipAllocations::Table, TipAllocations::TransactionId)
+                            .to(Transactions::Table, Transactions::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(TipAllocations::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum TipAllocations {
+    Table,
+    Id,
+    PayoutId,
+    EmployeeId,
+    DepartmentId,
+    HoursWorked,
+    Amount,
+    TransactionId,
+    CreatedAt,
+}
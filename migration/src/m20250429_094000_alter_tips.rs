@@ -0,0 +1,97 @@
+use sea_orm_migration::prelude::*;
+
+use crate::m20250429_092000_create_tip_payouts::TipPayouts;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Tips::Table)
+                    .add_column(
+                        ColumnDef::new(Tips::TipStatus)
+                            .string()
+                            .not_null()
+                            .check(Expr::col(Tips::TipStatus).is_in(vec![
+                                TipStatusEnum::Pending.as_str(),
+                                TipStatusEnum::PaidOut.as_str(),
+                                TipStatusEnum::Voided.as_str(),
+                            ]))
+                            .default(TipStatusEnum::Pending.as_str()),
+                    )
+                    .add_column(ColumnDef::new(Tips::PayoutId).uuid())
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .name("fk_tips_payout_id")
+                            .from_tbl(Tips::Table)
+                            .from_col(Tips::PayoutId)
+                            .to_tbl(TipPayouts::Table)
+                            .to_col(TipPayouts::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_tips_order_id")
+                    .table(Tips::Table)
+                    .col(Tips::OrderId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_tips_order_id")
+                    .table(Tips::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Tips::Table)
+                    .drop_foreign_key(Alias::new("fk_tips_payout_id"))
+                    .drop_column(Tips::TipStatus)
+                    .drop_column(Tips::PayoutId)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Tips {
+    Table,
+    OrderId,
+    TipStatus,
+    PayoutId,
+}
+
+enum TipStatusEnum {
+    Pending,
+    PaidOut,
+    Voided,
+}
+
+impl TipStatusEnum {
+    fn as_str(&self) -> &'static str {
+        match self {
+            TipStatusEnum::Pending => "PENDING",
+            TipStatusEnum::PaidOut => "PAIDOUT",
+            TipStatusEnum::Voided => "VOIDED",
+        }
+    }
+}
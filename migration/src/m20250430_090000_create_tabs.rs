@@ -0,0 +1,136 @@
+use sea_orm_migration::prelude::*;
+
+use crate::{
+    m20250311_102524_create_organizations::Organizations,
+    m20250311_111857_create_branches::Branches, m20250311_114321_create_staff::Employees,
+    m20250311_135726_create_customers::Customers, m20250311_174956_create_bookings::Bookings,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Tabs::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Tabs::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .default(Expr::cust("gen_random_uuid()")),
+                    )
+                    .col(ColumnDef::new(Tabs::OrganizationId).uuid().not_null())
+                    .col(ColumnDef::new(Tabs::BranchId).uuid().not_null())
+                    .col(ColumnDef::new(Tabs::CustomerId).uuid().not_null())
+                    .col(ColumnDef::new(Tabs::BookingId).uuid())
+                    .col(ColumnDef::new(Tabs::TableLabel).string())
+                    .col(
+                        ColumnDef::new(Tabs::TabStatus)
+                            .string()
+                            .not_null()
+                            .check(Expr::col(Tabs::TabStatus).is_in(vec![
+                                TabStatusEnum::Open.as_str(),
+                                TabStatusEnum::Settling.as_str(),
+                                TabStatusEnum::Settled.as_str(),
+                            ]))
+                            .default(TabStatusEnum::Open.as_str()),
+                    )
+                    .col(
+                        ColumnDef::new(Tabs::PaidAmount)
+                            .decimal()
+                            .not_null()
+                            .default(0.0),
+                    )
+                    .col(ColumnDef::new(Tabs::OpenedBy).uuid())
+                    .col(ColumnDef::new(Tabs::SettledAt).timestamp_with_time_zone())
+                    .col(
+                        ColumnDef::new(Tabs::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(Tabs::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(Tabs::Table, Tabs::OrganizationId)
+                            .to(Organizations::Table, Organizations::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(Tabs::Table, Tabs::BranchId)
+                            .to(Branches::Table, Branches::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(Tabs::Table, Tabs::CustomerId)
+                            .to(Customers::Table, Customers::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(Tabs::Table, Tabs::BookingId)
+                            .to(Bookings::Table, Bookings::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(Tabs::Table, Tabs::OpenedBy)
+                            .to(Employees::Table, Employees::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Tabs::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum Tabs {
+    Table,
+    Id,
+    OrganizationId,
+    BranchId,
+    CustomerId,
+    BookingId,
+    TableLabel,
+    TabStatus,
+    PaidAmount,
+    OpenedBy,
+    SettledAt,
+    CreatedAt,
+    UpdatedAt,
+}
+
+enum TabStatusEnum {
+    Open,
+    Settling,
+    Settled,
+}
+
+impl TabStatusEnum {
+    fn as_str(&self) -> &'static str {
+        match self {
+            TabStatusEnum::Open => "OPEN",
+            TabStatusEnum::Settling => "SETTLING",
+            TabStatusEnum::Settled => "SETTLED",
+        }
+    }
+}
@@ -0,0 +1,146 @@
+use sea_orm_migration::prelude::*;
+
+use crate::{
+    m20250311_135726_create_customers::Customers,
+    m20250311_165323_create_transactions::Transactions, m20250430_090000_create_tabs::Tabs,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(TabShares::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(TabShares::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .default(Expr::cust("gen_random_uuid()")),
+                    )
+                    .col(ColumnDef::new(TabShares::TabId).uuid().not_null())
+                    .col(ColumnDef::new(TabShares::CustomerId).uuid().not_null())
+                    .col(
+                        ColumnDef::new(TabShares::SplitType)
+                            .string()
+                            .not_null()
+                            .check(Expr::col(TabShares::SplitType).is_in(vec![
+                                SplitTypeEnum::Even.as_str(),
+                                SplitTypeEnum::Items.as_str(),
+                                SplitTypeEnum::Custom.as_str(),
+                            ])),
+                    )
+                    .col(ColumnDef::new(TabShares::ItemIds).array(ColumnType::Uuid))
+                    .col(
+                        ColumnDef::new(TabShares::Amount)
+                            .decimal()
+                            .not_null()
+                            .default(0.0),
+                    )
+                    .col(
+                        ColumnDef::new(TabShares::ShareStatus)
+                            .string()
+                            .not_null()
+                            .check(Expr::col(TabShares::ShareStatus).is_in(vec![
+                                ShareStatusEnum::Pending.as_str(),
+                                ShareStatusEnum::Paid.as_str(),
+                                ShareStatusEnum::Cancelled.as_str(),
+                            ]))
+                            .default(ShareStatusEnum::Pending.as_str()),
+                    )
+                    .col(ColumnDef::new(TabShares::TransactionId).uuid())
+                    .col(ColumnDef::new(TabShares::PaidAt).timestamp_with_time_zone())
+                    .col(
+                        ColumnDef::new(TabShares::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(TabShares::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(TabShares::Table, TabShares::TabId)
+                            .to(Tabs::Table, Tabs::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(TabShares::Table, TabShares::CustomerId)
+                            .to(Customers::Table, Customers::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(TabShares::Table, TabShares::TransactionId)
+                            .to(Transactions::Table, Transactions::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(TabShares::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum TabShares {
+    Table,
+    Id,
+    TabId,
+    CustomerId,
+    SplitType,
+    ItemIds,
+    Amount,
+    ShareStatus,
+    TransactionId,
+    PaidAt,
+    CreatedAt,
+    UpdatedAt,
+}
+
+enum SplitTypeEnum {
+    Even,
+    Items,
+    Custom,
+}
+
+impl SplitTypeEnum {
+    fn as_str(&self) -> &'static str {
+        match self {
+            SplitTypeEnum::Even => "EVEN",
+            SplitTypeEnum::Items => "ITEMS",
+            SplitTypeEnum::Custom => "CUSTOM",
+        }
+    }
+}
+
+enum ShareStatusEnum {
+    Pending,
+    Paid,
+    Cancelled,
+}
+
+impl ShareStatusEnum {
+    fn as_str(&self) -> &'static str {
+        match self {
+            ShareStatusEnum::Pending => "PENDING",
+            ShareStatusEnum::Paid => "PAID",
+            ShareStatusEnum::Cancelled => "CANCELLED",
+        }
+    }
+}
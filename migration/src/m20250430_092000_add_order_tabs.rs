@@ -0,0 +1,70 @@
+use sea_orm_migration::prelude::*;
+
+use crate::m20250430_090000_create_tabs::Tabs;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Orders on a tab are paid when the tab is settled, not when they are placed
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Orders::Table)
+                    .modify_column(ColumnDef::new(Orders::PaymentId).uuid().null())
+                    .add_column(ColumnDef::new(Orders::TabId).uuid())
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .name("fk_orders_tab_id")
+                            .from_tbl(Orders::Table)
+                            .from_col(Orders::TabId)
+                            .to_tbl(Tabs::Table)
+                            .to_col(Tabs::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_orders_tab_id")
+                    .table(Orders::Table)
+                    .col(Orders::TabId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_orders_tab_id")
+                    .table(Orders::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Orders::Table)
+                    .drop_foreign_key(Alias::new("fk_orders_tab_id"))
+                    .drop_column(Orders::TabId)
+                    .modify_column(ColumnDef::new(Orders::PaymentId).uuid().not_null())
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Orders {
+    Table,
+    PaymentId,
+    TabId,
+}
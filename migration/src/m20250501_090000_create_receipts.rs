@@ -0,0 +1,164 @@
+use sea_orm_migration::prelude::*;
+
+use crate::{
+    m20250311_102524_create_organizations::Organizations,
+    m20250311_111857_create_branches::Branches, m20250311_135726_create_customers::Customers,
+    m20250311_165323_create_transactions::Transactions, m20250311_174956_create_bookings::Bookings,
+    m20250311_183402_create_orders::Orders,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Receipt numbers come from a sequence so they never repeat across branches
+        manager
+            .get_connection()
+            .execute_unprepared("CREATE SEQUENCE IF NOT EXISTS receipt_number_seq")
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(Receipts::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Receipts::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .default(Expr::cust("gen_random_uuid()")),
+                    )
+                    .col(
+                        ColumnDef::new(Receipts::ReceiptNumber)
+                            .string()
+                            .not_null()
+                            .unique_key()
+                            .default(Expr::cust(
+                                "'RCT-' || lpad(nextval('receipt_number_seq')::text, 8, '0')",
+                            )),
+                    )
+                    .col(ColumnDef::new(Receipts::OrganizationId).uuid().not_null())
+                    .col(ColumnDef::new(Receipts::BranchId).uuid().not_null())
+                    .col(ColumnDef::new(Receipts::CustomerId).uuid().not_null())
+                    .col(ColumnDef::new(Receipts::OrderId).uuid().unique_key())
+                    .col(ColumnDef::new(Receipts::BookingId).uuid().unique_key())
+                    .col(ColumnDef::new(Receipts::TransactionId).uuid())
+                    .col(
+                        ColumnDef::new(Receipts::Subtotal)
+                            .decimal()
+                            .not_null()
+                            .default(0.0),
+                    )
+                    .col(
+                        ColumnDef::new(Receipts::DiscountAmount)
+                            .decimal()
+                            .not_null()
+                            .default(0.0),
+                    )
+                    .col(
+                        ColumnDef::new(Receipts::TaxAmount)
+                            .decimal()
+                            .not_null()
+                            .default(0.0),
+                    )
+                    .col(
+                        ColumnDef::new(Receipts::TipAmount)
+                            .decimal()
+                            .not_null()
+                            .default(0.0),
+                    )
+                    .col(
+                        ColumnDef::new(Receipts::TotalAmount)
+                            .decimal()
+                            .not_null()
+                            .default(0.0),
+                    )
+                    .col(ColumnDef::new(Receipts::EmailedAt).timestamp_with_time_zone())
+                    .col(
+                        ColumnDef::new(Receipts::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .check(
+                        Expr::col(Receipts::OrderId)
+                            .is_not_null()
+                            .or(Expr::col(Receipts::BookingId).is_not_null()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(Receipts::Table, Receipts::OrganizationId)
+                            .to(Organizations::Table, Organizations::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(Receipts::Table, Receipts::BranchId)
+                            .to(Branches::Table, Branches::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(Receipts::Table, Receipts::CustomerId)
+                            .to(Customers::Table, Customers::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(Receipts::Table, Receipts::OrderId)
+                            .to(Orders::Table, Orders::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(Receipts::Table, Receipts::BookingId)
+                            .to(Bookings::Table, Bookings::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(Receipts::Table, Receipts::TransactionId)
+                            .to(Transactions::Table, Transactions::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Receipts::Table).to_owned())
+            .await?;
+
+        manager
+            .get_connection()
+            .execute_unprepared("DROP SEQUENCE IF EXISTS receipt_number_seq")
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum Receipts {
+    Table,
+    Id,
+    ReceiptNumber,
+    OrganizationId,
+    BranchId,
+    CustomerId,
+    OrderId,
+    BookingId,
+    TransactionId,
+    Subtotal,
+    DiscountAmount,
+    TaxAmount,
+    TipAmount,
+    TotalAmount,
+    EmailedAt,
+    CreatedAt,
+}
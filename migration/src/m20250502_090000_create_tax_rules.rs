@@ -0,0 +1,166 @@
+use sea_orm_migration::prelude::*;
+
+use crate::{
+    m20250311_100140_create_countries::Countries,
+    m20250311_102524_create_organizations::Organizations,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(TaxRules::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(TaxRules::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .default(Expr::cust("gen_random_uuid()")),
+                    )
+                    .col(ColumnDef::new(TaxRules::OrganizationId).uuid())
+                    .col(ColumnDef::new(TaxRules::CountryId).uuid())
+                    .col(ColumnDef::new(TaxRules::Name).string().not_null())
+                    .col(ColumnDef::new(TaxRules::Code).string().not_null())
+                    .col(
+                        ColumnDef::new(TaxRules::RuleType)
+                            .string()
+                            .not_null()
+                            .check(Expr::col(TaxRules::RuleType).is_in(vec![
+                                RuleTypeEnum::Tax.as_str(),
+                                RuleTypeEnum::Service.as_str(),
+                            ]))
+                            .default(RuleTypeEnum::Tax.as_str()),
+                    )
+                    .col(ColumnDef::new(TaxRules::Rate).decimal().not_null())
+                    .col(
+                        ColumnDef::new(TaxRules::IsInclusive)
+                            .boolean()
+                            .not_null()
+                            .default(false),
+                    )
+                    .col(
+                        ColumnDef::new(TaxRules::IsCompound)
+                            .boolean()
+                            .not_null()
+                            .default(false),
+                    )
+                    .col(
+                        ColumnDef::new(TaxRules::Priority)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(TaxRules::AppliesTo)
+                            .string()
+                            .not_null()
+                            .check(Expr::col(TaxRules::AppliesTo).is_in(vec![
+                                AppliesToEnum::All.as_str(),
+                                AppliesToEnum::Orders.as_str(),
+                                AppliesToEnum::Bookings.as_str(),
+                            ]))
+                            .default(AppliesToEnum::All.as_str()),
+                    )
+                    .col(ColumnDef::new(TaxRules::ExemptCategoryIds).array(ColumnType::Uuid))
+                    .col(
+                        ColumnDef::new(TaxRules::IsActive)
+                            .boolean()
+                            .not_null()
+                            .default(true),
+                    )
+                    .col(
+                        ColumnDef::new(TaxRules::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(TaxRules::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .check(
+                        Expr::col(TaxRules::OrganizationId)
+                            .is_not_null()
+                            .or(Expr::col(TaxRules::CountryId).is_not_null()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(TaxRules::Table, TaxRules::OrganizationId)
+                            .to(Organizations::Table, Organizations::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(TaxRules::Table, TaxRules::CountryId)
+                            .to(Countries::Table, Countries::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(TaxRules::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum TaxRules {
+    Table,
+    Id,
+    OrganizationId,
+    CountryId,
+    Name,
+    Code,
+    RuleType,
+    Rate,
+    IsInclusive,
+    IsCompound,
+    Priority,
+    AppliesTo,
+    ExemptCategoryIds,
+    IsActive,
+    CreatedAt,
+    UpdatedAt,
+}
+
+enum RuleTypeEnum {
+    Tax,
+    Service,
+}
+
+impl RuleTypeEnum {
+    fn as_str(&self) -> &'static str {
+        match self {
+            RuleTypeEnum::Tax => "TAX",
+            RuleTypeEnum::Service => "SERVICE",
+        }
+    }
+}
+
+enum AppliesToEnum {
+    All,
+    Orders,
+    Bookings,
+}
+
+impl AppliesToEnum {
+    fn as_str(&self) -> &'static str {
+        match self {
+            AppliesToEnum::All => "ALL",
+            AppliesToEnum::Orders => "ORDERS",
+            AppliesToEnum::Bookings => "BOOKINGS",
+        }
+    }
+}
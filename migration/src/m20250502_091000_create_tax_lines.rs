@@ -0,0 +1,132 @@
+use sea_orm_migration::prelude::*;
+
+use crate::{
+    m20250311_174956_create_bookings::Bookings, m20250311_183402_create_orders::Orders,
+    m20250502_090000_create_tax_rules::TaxRules,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(TaxLines::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(TaxLines::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .default(Expr::cust("gen_random_uuid()")),
+                    )
+                    .col(ColumnDef::new(TaxLines::OrderId).uuid())
+                    .col(ColumnDef::new(TaxLines::BookingId).uuid())
+                    .col(ColumnDef::new(TaxLines::TaxRuleId).uuid())
+                    .col(ColumnDef::new(TaxLines::Name).string().not_null())
+                    .col(ColumnDef::new(TaxLines::Code).string().not_null())
+                    .col(
+                        ColumnDef::new(TaxLines::LineType)
+                            .string()
+                            .not_null()
+                            .check(Expr::col(TaxLines::LineType).is_in(vec!["TAX", "SERVICE"])),
+                    )
+                    .col(ColumnDef::new(TaxLines::Rate).decimal().not_null())
+                    .col(
+                        ColumnDef::new(TaxLines::IsInclusive)
+                            .boolean()
+                            .not_null()
+                            .default(false),
+                    )
+                    .col(
+                        ColumnDef::new(TaxLines::TaxableAmount)
+                            .decimal()
+                            .not_null()
+                            .default(0.0),
+                    )
+                    .col(
+                        ColumnDef::new(TaxLines::Amount)
+                            .decimal()
+                            .not_null()
+                            .default(0.0),
+                    )
+                    .col(
+                        ColumnDef::new(TaxLines::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .check(
+                        Expr::col(TaxLines::OrderId)
+                            .is_not_null()
+                            .or(Expr::col(TaxLines::BookingId).is_not_null()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(TaxLines::Table, TaxLines::OrderId)
+                            .to(Orders::Table, Orders::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(TaxLines::Table, TaxLines::BookingId)
+                            .to(Bookings::Table, Bookings::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(TaxLines::Table, TaxLines::TaxRuleId)
+                            .to(TaxRules::Table, TaxRules::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_tax_lines_order_id")
+                    .table(TaxLines::Table)
+                    .col(TaxLines::OrderId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_tax_lines_booking_id")
+                    .table(TaxLines::Table)
+                    .col(TaxLines::BookingId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(TaxLines::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum TaxLines {
+    Table,
+    Id,
+    OrderId,
+    BookingId,
+    TaxRuleId,
+    Name,
+    Code,
+    LineType,
+    Rate,
+    IsInclusive,
+    TaxableAmount,
+    Amount,
+    CreatedAt,
+}
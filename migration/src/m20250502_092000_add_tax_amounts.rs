@@ -0,0 +1,140 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Tax added on top of the listed prices, tax already inside them, and the service
+        // charge, all part of `total_amount`
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Orders::Table)
+                    .add_column(
+                        ColumnDef::new(Orders::TaxAmount)
+                            .decimal()
+                            .not_null()
+                            .default(0.0),
+                    )
+                    .add_column(
+                        ColumnDef::new(Orders::IncludedTaxAmount)
+                            .decimal()
+                            .not_null()
+                            .default(0.0),
+                    )
+                    .add_column(
+                        ColumnDef::new(Orders::ServiceCharge)
+                            .decimal()
+                            .not_null()
+                            .default(0.0),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Bookings::Table)
+                    .add_column(
+                        ColumnDef::new(Bookings::TaxAmount)
+                            .decimal()
+                            .not_null()
+                            .default(0.0),
+                    )
+                    .add_column(
+                        ColumnDef::new(Bookings::IncludedTaxAmount)
+                            .decimal()
+                            .not_null()
+                            .default(0.0),
+                    )
+                    .add_column(
+                        ColumnDef::new(Bookings::ServiceCharge)
+                            .decimal()
+                            .not_null()
+                            .default(0.0),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Receipts::Table)
+                    .add_column(
+                        ColumnDef::new(Receipts::IncludedTaxAmount)
+                            .decimal()
+                            .not_null()
+                            .default(0.0),
+                    )
+                    .add_column(
+                        ColumnDef::new(Receipts::ServiceCharge)
+                            .decimal()
+                            .not_null()
+                            .default(0.0),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Receipts::Table)
+                    .drop_column(Receipts::IncludedTaxAmount)
+                    .drop_column(Receipts::ServiceCharge)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Bookings::Table)
+                    .drop_column(Bookings::TaxAmount)
+                    .drop_column(Bookings::IncludedTaxAmount)
+                    .drop_column(Bookings::ServiceCharge)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Orders::Table)
+                    .drop_column(Orders::TaxAmount)
+                    .drop_column(Orders::IncludedTaxAmount)
+                    .drop_column(Orders::ServiceCharge)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Orders {
+    Table,
+    TaxAmount,
+    IncludedTaxAmount,
+    ServiceCharge,
+}
+
+#[derive(DeriveIden)]
+enum Bookings {
+    Table,
+    TaxAmount,
+    IncludedTaxAmount,
+    ServiceCharge,
+}
+
+#[derive(DeriveIden)]
+enum Receipts {
+    Table,
+    IncludedTaxAmount,
+    ServiceCharge,
+}
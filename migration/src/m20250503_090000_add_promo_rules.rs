@@ -0,0 +1,179 @@
+use sea_orm_migration::prelude::*;
+
+use crate::{
+    m20250311_102524_create_organizations::Organizations,
+    m20250312_111549_create_promotions::Promos,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Promos become an organization's own. Code promos are redeemed by entering the code,
+        // automatic ones apply whenever their scope and time window match.
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Promos::Table)
+                    .add_column(ColumnDef::new(PromoRules::OrganizationId).uuid())
+                    .add_column(ColumnDef::new(PromoRules::Code).string())
+                    .add_column(
+                        ColumnDef::new(PromoRules::PromoType)
+                            .string()
+                            .not_null()
+                            .check(Expr::col(PromoRules::PromoType).is_in(vec![
+                                PromoTypeEnum::Code.as_str(),
+                                PromoTypeEnum::Automatic.as_str(),
+                            ]))
+                            .default(PromoTypeEnum::Code.as_str()),
+                    )
+                    .add_column(
+                        ColumnDef::new(PromoRules::AppliesTo)
+                            .string()
+                            .not_null()
+                            .check(Expr::col(PromoRules::AppliesTo).is_in(vec![
+                                AppliesToEnum::Orders.as_str(),
+                                AppliesToEnum::Bookings.as_str(),
+                                AppliesToEnum::Tickets.as_str(),
+                            ]))
+                            .default(AppliesToEnum::Orders.as_str()),
+                    )
+                    .add_column(ColumnDef::new(PromoRules::MaxDiscount).decimal())
+                    .add_column(ColumnDef::new(PromoRules::MinSpend).decimal())
+                    .add_column(ColumnDef::new(PromoRules::BranchIds).array(ColumnType::Uuid))
+                    .add_column(ColumnDef::new(PromoRules::ScheduleIds).array(ColumnType::Uuid))
+                    .add_column(ColumnDef::new(PromoRules::CategoryIds).array(ColumnType::Uuid))
+                    .add_column(ColumnDef::new(PromoRules::TicketIds).array(ColumnType::Uuid))
+                    .add_column(ColumnDef::new(PromoRules::StartTime).time())
+                    .add_column(ColumnDef::new(PromoRules::EndTime).time())
+                    .add_column(ColumnDef::new(PromoRules::Weekdays).array(ColumnType::Integer))
+                    .add_column(ColumnDef::new(PromoRules::UsageLimit).integer())
+                    .add_column(ColumnDef::new(PromoRules::PerCustomerLimit).integer())
+                    .add_column(
+                        ColumnDef::new(PromoRules::IsStackable)
+                            .boolean()
+                            .not_null()
+                            .default(false),
+                    )
+                    .add_column(
+                        ColumnDef::new(PromoRules::IsActive)
+                            .boolean()
+                            .not_null()
+                            .default(true),
+                    )
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .name("fk_promos_organization_id")
+                            .from_tbl(Promos::Table)
+                            .from_col(PromoRules::OrganizationId)
+                            .to_tbl(Organizations::Table)
+                            .to_col(Organizations::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_promos_organization_code")
+                    .table(Promos::Table)
+                    .col(PromoRules::OrganizationId)
+                    .col(PromoRules::Code)
+                    .unique()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_promos_organization_code")
+                    .table(Promos::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Promos::Table)
+                    .drop_foreign_key(Alias::new("fk_promos_organization_id"))
+                    .drop_column(PromoRules::OrganizationId)
+                    .drop_column(PromoRules::Code)
+                    .drop_column(PromoRules::PromoType)
+                    .drop_column(PromoRules::AppliesTo)
+                    .drop_column(PromoRules::MaxDiscount)
+                    .drop_column(PromoRules::MinSpend)
+                    .drop_column(PromoRules::BranchIds)
+                    .drop_column(PromoRules::ScheduleIds)
+                    .drop_column(PromoRules::CategoryIds)
+                    .drop_column(PromoRules::TicketIds)
+                    .drop_column(PromoRules::StartTime)
+                    .drop_column(PromoRules::EndTime)
+                    .drop_column(PromoRules::Weekdays)
+                    .drop_column(PromoRules::UsageLimit)
+                    .drop_column(PromoRules::PerCustomerLimit)
+                    .drop_column(PromoRules::IsStackable)
+                    .drop_column(PromoRules::IsActive)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum PromoRules {
+    OrganizationId,
+    Code,
+    PromoType,
+    AppliesTo,
+    MaxDiscount,
+    MinSpend,
+    BranchIds,
+    ScheduleIds,
+    CategoryIds,
+    TicketIds,
+    StartTime,
+    EndTime,
+    Weekdays,
+    UsageLimit,
+    PerCustomerLimit,
+    IsStackable,
+    IsActive,
+}
+
+enum PromoTypeEnum {
+    Code,
+    Automatic,
+}
+
+impl PromoTypeEnum {
+    fn as_str(&self) -> &'static str {
+        match self {
+            PromoTypeEnum::Code => "CODE",
+            PromoTypeEnum::Automatic => "AUTOMATIC",
+        }
+    }
+}
+
+enum AppliesToEnum {
+    Orders,
+    Bookings,
+    Tickets,
+}
+
+impl AppliesToEnum {
+    fn as_str(&self) -> &'static str {
+        match self {
+            AppliesToEnum::Orders => "ORDERS",
+            AppliesToEnum::Bookings => "BOOKINGS",
+            AppliesToEnum::Tickets => "TICKETS",
+        }
+    }
+}
@@ -0,0 +1,151 @@
+use sea_orm_migration::prelude::*;
+
+use crate::{
+    m20250311_135726_create_customers::Customers, m20250311_174956_create_bookings::Bookings,
+    m20250311_183402_create_orders::Orders, m20250312_111549_create_promotions::Promos,
+    m20250422_103000_create_ticket_purchases::TicketPurchases,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(PromoRedemptions::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(PromoRedemptions::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .default(Expr::cust("gen_random_uuid()")),
+                    )
+                    .col(ColumnDef::new(PromoRedemptions::PromoId).uuid().not_null())
+                    .col(
+                        ColumnDef::new(PromoRedemptions::CustomerId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(PromoRedemptions::OrderId).uuid())
+                    .col(ColumnDef::new(PromoRedemptions::BookingId).uuid())
+                    .col(ColumnDef::new(PromoRedemptions::TicketPurchaseId).uuid())
+                    .col(ColumnDef::new(PromoRedemptions::Code).string())
+                    .col(
+                        ColumnDef::new(PromoRedemptions::DiscountAmount)
+                            .decimal()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(PromoRedemptions::RedemptionStatus)
+                            .string()
+                            .not_null()
+                            .check(Expr::col(PromoRedemptions::RedemptionStatus).is_in(vec![
+                                RedemptionStatusEnum::Active.as_str(),
+                                RedemptionStatusEnum::Void.as_str(),
+                            ]))
+                            .default(RedemptionStatusEnum::Active.as_str()),
+                    )
+                    .col(
+                        ColumnDef::new(PromoRedemptions::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(PromoRedemptions::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .check(
+                        Expr::col(PromoRedemptions::OrderId)
+                            .is_not_null()
+                            .or(Expr::col(PromoRedemptions::BookingId).is_not_null())
+                            .or(Expr::col(PromoRedemptions::TicketPurchaseId).is_not_null()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(PromoRedemptions::Table, PromoRedemptions::PromoId)
+                            .to(Promos::Table, Promos::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(PromoRedemptions::Table, PromoRedemptions::CustomerId)
+                            .to(Customers::Table, Customers::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(PromoRedemptions::Table, PromoRedemptions::OrderId)
+                            .to(Orders::Table, Orders::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(PromoRedemptions::Table, PromoRedemptions::BookingId)
+                            .to(Bookings::Table, Bookings::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(PromoRedemptions::Table, PromoRedemptions::TicketPurchaseId)
+                            .to(TicketPurchases::Table, TicketPurchases::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_promo_redemptions_promo_customer")
+                    .table(PromoRedemptions::Table)
+                    .col(PromoRedemptions::PromoId)
+                    .col(PromoRedemptions::CustomerId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(PromoRedemptions::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum PromoRedemptions {
+    Table,
+    Id,
+    PromoId,
+    CustomerId,
+    OrderId,
+    BookingId,
+    TicketPurchaseId,
+    Code,
+    DiscountAmount,
+    RedemptionStatus,
+    CreatedAt,
+    UpdatedAt,
+}
+
+enum RedemptionStatusEnum {
+    Active,
+    Void,
+}
+
+impl RedemptionStatusEnum {
+    fn as_str(&self) -> &'static str {
+        match self {
+            RedemptionStatusEnum::Active => "ACTIVE",
+            RedemptionStatusEnum::Void => "VOID",
+        }
+    }
+}
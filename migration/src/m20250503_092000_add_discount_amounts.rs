@@ -0,0 +1,103 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // What promos took off the price, already left out of `total_amount`. The order keeps
+        // the codes or titles of the promos applied so staff can see why it was discounted.
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Orders::Table)
+                    .add_column(
+                        ColumnDef::new(Orders::DiscountAmount)
+                            .decimal()
+                            .not_null()
+                            .default(0.0),
+                    )
+                    .add_column(ColumnDef::new(Orders::PromoReference).string())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Bookings::Table)
+                    .add_column(
+                        ColumnDef::new(Bookings::DiscountAmount)
+                            .decimal()
+                            .not_null()
+                            .default(0.0),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TicketPurchases::Table)
+                    .add_column(
+                        ColumnDef::new(TicketPurchases::DiscountAmount)
+                            .decimal()
+                            .not_null()
+                            .default(0.0),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TicketPurchases::Table)
+                    .drop_column(TicketPurchases::DiscountAmount)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Bookings::Table)
+                    .drop_column(Bookings::DiscountAmount)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Orders::Table)
+                    .drop_column(Orders::DiscountAmount)
+                    .drop_column(Orders::PromoReference)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Orders {
+    Table,
+    DiscountAmount,
+    PromoReference,
+}
+
+#[derive(DeriveIden)]
+enum Bookings {
+    Table,
+    DiscountAmount,
+}
+
+#[derive(DeriveIden)]
+enum TicketPurchases {
+    Table,
+    DiscountAmount,
+}
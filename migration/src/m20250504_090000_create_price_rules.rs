@@ -0,0 +1,134 @@
+use sea_orm_migration::prelude::*;
+
+use crate::{
+    m20250311_102524_create_organizations::Organizations,
+    m20250311_111857_create_branches::Branches,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(PriceRules::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(PriceRules::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .default(Expr::cust("gen_random_uuid()")),
+                    )
+                    .col(ColumnDef::new(PriceRules::OrganizationId).uuid().not_null())
+                    .col(ColumnDef::new(PriceRules::BranchId).uuid().not_null())
+                    .col(ColumnDef::new(PriceRules::Name).string().not_null())
+                    .col(
+                        ColumnDef::new(PriceRules::AdjustmentType)
+                            .string()
+                            .not_null()
+                            .check(Expr::col(PriceRules::AdjustmentType).is_in(vec![
+                                AdjustmentTypeEnum::Discount.as_str(),
+                                AdjustmentTypeEnum::Markup.as_str(),
+                            ])),
+                    )
+                    .col(ColumnDef::new(PriceRules::Rate).decimal().not_null())
+                    .col(ColumnDef::new(PriceRules::CategoryIds).array(ColumnType::Uuid))
+                    .col(ColumnDef::new(PriceRules::DrinkIds).array(ColumnType::Uuid))
+                    .col(ColumnDef::new(PriceRules::Weekdays).array(ColumnType::Integer))
+                    .col(ColumnDef::new(PriceRules::StartTime).time().not_null())
+                    .col(ColumnDef::new(PriceRules::EndTime).time().not_null())
+                    .col(
+                        ColumnDef::new(PriceRules::Priority)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(PriceRules::IsActive)
+                            .boolean()
+                            .not_null()
+                            .default(true),
+                    )
+                    .col(
+                        ColumnDef::new(PriceRules::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(PriceRules::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(PriceRules::Table, PriceRules::OrganizationId)
+                            .to(Organizations::Table, Organizations::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(PriceRules::Table, PriceRules::BranchId)
+                            .to(Branches::Table, Branches::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_price_rules_branch_id")
+                    .table(PriceRules::Table)
+                    .col(PriceRules::BranchId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(PriceRules::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum PriceRules {
+    Table,
+    Id,
+    OrganizationId,
+    BranchId,
+    Name,
+    AdjustmentType,
+    Rate,
+    CategoryIds,
+    DrinkIds,
+    Weekdays,
+    StartTime,
+    EndTime,
+    Priority,
+    IsActive,
+    CreatedAt,
+    UpdatedAt,
+}
+
+enum AdjustmentTypeEnum {
+    Discount,
+    Markup,
+}
+
+impl AdjustmentTypeEnum {
+    fn as_str(&self) -> &'static str {
+        match self {
+            AdjustmentTypeEnum::Discount => "DISCOUNT",
+            AdjustmentTypeEnum::Markup => "MARKUP",
+        }
+    }
+}
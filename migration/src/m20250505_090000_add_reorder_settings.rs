@@ -0,0 +1,47 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // The stock level a drink is reordered at, and when the branch was last told it got
+        // there so the alert goes out once per dip rather than on every run.
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(StockDrinks::Table)
+                    .add_column(
+                        ColumnDef::new(StockDrinks::ReorderThreshold)
+                            .integer()
+                            .not_null()
+                            .default(10),
+                    )
+                    .add_column(
+                        ColumnDef::new(StockDrinks::LowStockAlertedAt).timestamp_with_time_zone(),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(StockDrinks::Table)
+                    .drop_column(StockDrinks::ReorderThreshold)
+                    .drop_column(StockDrinks::LowStockAlertedAt)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum StockDrinks {
+    Table,
+    ReorderThreshold,
+    LowStockAlertedAt,
+}
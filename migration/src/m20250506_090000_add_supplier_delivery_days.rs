@@ -0,0 +1,38 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Weekdays the supplier delivers on, 1 for Monday through 7 for Sunday
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Suppliers::Table)
+                    .add_column(
+                        ColumnDef::new(Suppliers::DeliveryWeekdays).array(ColumnType::Integer),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Suppliers::Table)
+                    .drop_column(Suppliers::DeliveryWeekdays)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Suppliers {
+    Table,
+    DeliveryWeekdays,
+}
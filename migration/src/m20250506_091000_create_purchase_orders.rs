@@ -0,0 +1,156 @@
+use sea_orm_migration::prelude::*;
+
+use crate::{
+    m20250311_102524_create_organizations::Organizations,
+    m20250311_111857_create_branches::Branches, m20250311_114321_create_staff::Employees,
+    m20250311_134626_create_suppliers::Suppliers,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(PurchaseOrders::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(PurchaseOrders::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .default(Expr::cust("gen_random_uuid()")),
+                    )
+                    .col(
+                        ColumnDef::new(PurchaseOrders::OrganizationId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(PurchaseOrders::BranchId).uuid().not_null())
+                    .col(ColumnDef::new(PurchaseOrders::SupplierId).uuid().not_null())
+                    .col(ColumnDef::new(PurchaseOrders::PoNumber).string().not_null())
+                    .col(
+                        ColumnDef::new(PurchaseOrders::PoStatus)
+                            .string()
+                            .not_null()
+                            .check(Expr::col(PurchaseOrders::PoStatus).is_in(vec![
+                                PoStatusEnum::Draft.as_str(),
+                                PoStatusEnum::Sent.as_str(),
+                                PoStatusEnum::Partial.as_str(),
+                                PoStatusEnum::Received.as_str(),
+                                PoStatusEnum::Cancelled.as_str(),
+                            ]))
+                            .default(PoStatusEnum::Draft.as_str()),
+                    )
+                    .col(ColumnDef::new(PurchaseOrders::ExpectedOn).date())
+                    .col(ColumnDef::new(PurchaseOrders::Notes).string())
+                    .col(
+                        ColumnDef::new(PurchaseOrders::TotalAmount)
+                            .decimal()
+                            .not_null()
+                            .default(0.0),
+                    )
+                    .col(ColumnDef::new(PurchaseOrders::CreatedBy).uuid().not_null())
+                    .col(ColumnDef::new(PurchaseOrders::SentAt).timestamp_with_time_zone())
+                    .col(ColumnDef::new(PurchaseOrders::ReceivedAt).timestamp_with_time_zone())
+                    .col(
+                        ColumnDef::new(PurchaseOrders::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(PurchaseOrders::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(PurchaseOrders::Table, PurchaseOrders::OrganizationId)
+                            .to(Organizations::Table, Organizations::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(PurchaseOrders::Table, PurchaseOrders::BranchId)
+                            .to(Branches::Table, Branches::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(PurchaseOrders::Table, PurchaseOrders::SupplierId)
+                            .to(Suppliers::Table, Suppliers::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(PurchaseOrders::Table, PurchaseOrders::CreatedBy)
+                            .to(Employees::Table, Employees::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_purchase_orders_branch_po_number")
+                    .table(PurchaseOrders::Table)
+                    .col(PurchaseOrders::BranchId)
+                    .col(PurchaseOrders::PoNumber)
+                    .unique()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(PurchaseOrders::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum PurchaseOrders {
+    Table,
+    Id,
+    OrganizationId,
+    BranchId,
+    SupplierId,
+    PoNumber,
+    PoStatus,
+    ExpectedOn,
+    Notes,
+    TotalAmount,
+    CreatedBy,
+    SentAt,
+    ReceivedAt,
+    CreatedAt,
+    UpdatedAt,
+}
+
+enum PoStatusEnum {
+    Draft,
+    Sent,
+    Partial,
+    Received,
+    Cancelled,
+}
+
+impl PoStatusEnum {
+    fn as_str(&self) -> &'static str {
+        match self {
+            PoStatusEnum::Draft => "DRAFT",
+            PoStatusEnum::Sent => "SENT",
+            PoStatusEnum::Partial => "PARTIAL",
+            PoStatusEnum::Received => "RECEIVED",
+            PoStatusEnum::Cancelled => "CANCELLED",
+        }
+    }
+}
@@ -0,0 +1,103 @@
+use sea_orm_migration::prelude::*;
+
+use crate::{
+    m20250311_132020_create_stock_drinks::StockDrinks,
+    m20250506_091000_create_purchase_orders::PurchaseOrders,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(PurchaseOrderLines::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(PurchaseOrderLines::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .default(Expr::cust("gen_random_uuid()")),
+                    )
+                    .col(
+                        ColumnDef::new(PurchaseOrderLines::PurchaseOrderId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(PurchaseOrderLines::StockDrinkId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(PurchaseOrderLines::QuantityOrdered)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(PurchaseOrderLines::QuantityReceived)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(PurchaseOrderLines::UnitCost)
+                            .decimal()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(PurchaseOrderLines::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(PurchaseOrderLines::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .check(
+                        Expr::col(PurchaseOrderLines::QuantityReceived)
+                            .lte(Expr::col(PurchaseOrderLines::QuantityOrdered)),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(PurchaseOrderLines::Table, PurchaseOrderLines::PurchaseOrderId)
+                            .to(PurchaseOrders::Table, PurchaseOrders::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(PurchaseOrderLines::Table, PurchaseOrderLines::StockDrinkId)
+                            .to(StockDrinks::Table, StockDrinks::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(PurchaseOrderLines::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum PurchaseOrderLines {
+    Table,
+    Id,
+    PurchaseOrderId,
+    StockDrinkId,
+    QuantityOrdered,
+    QuantityReceived,
+    UnitCost,
+    CreatedAt,
+    UpdatedAt,
+}
@@ -0,0 +1,101 @@
+use sea_orm_migration::prelude::*;
+
+use crate::{
+    m20250311_114321_create_staff::Employees,
+    m20250506_091000_create_purchase_orders::PurchaseOrders,
+    m20250506_092000_create_purchase_order_lines::PurchaseOrderLines,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // One delivery of a purchase order line, at the cost actually invoiced. The variance is
+        // what the delivery cost over the price agreed on the order, negative when cheaper.
+        manager
+            .create_table(
+                Table::create()
+                    .table(GoodsReceipts::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(GoodsReceipts::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .default(Expr::cust("gen_random_uuid()")),
+                    )
+                    .col(
+                        ColumnDef::new(GoodsReceipts::PurchaseOrderId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(GoodsReceipts::PurchaseOrderLineId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(GoodsReceipts::Quantity).integer().not_null())
+                    .col(ColumnDef::new(GoodsReceipts::UnitCost).decimal().not_null())
+                    .col(
+                        ColumnDef::new(GoodsReceipts::PriceVariance)
+                            .decimal()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(GoodsReceipts::ReceivedBy).uuid().not_null())
+                    .col(
+                        ColumnDef::new(GoodsReceipts::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(GoodsReceipts::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(GoodsReceipts::Table, GoodsReceipts::PurchaseOrderId)
+                            .to(PurchaseOrders::Table, PurchaseOrders::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(GoodsReceipts::Table, GoodsReceipts::PurchaseOrderLineId)
+                            .to(PurchaseOrderLines::Table, PurchaseOrderLines::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(GoodsReceipts::Table, GoodsReceipts::ReceivedBy)
+                            .to(Employees::Table, Employees::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(GoodsReceipts::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum GoodsReceipts {
+    Table,
+    Id,
+    PurchaseOrderId,
+    PurchaseOrderLineId,
+    Quantity,
+    UnitCost,
+    PriceVariance,
+    ReceivedBy,
+    CreatedAt,
+    UpdatedAt,
+}
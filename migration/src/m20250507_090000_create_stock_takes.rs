@@ -0,0 +1,138 @@
+use sea_orm_migration::prelude::*;
+
+use crate::{
+    m20250311_102524_create_organizations::Organizations,
+    m20250311_111857_create_branches::Branches, m20250311_114321_create_staff::Employees,
+    m20250312_105209_create_till_sessions::TillSessions,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // A count of a branch's drinks, optionally at the end of a bartender's till session so
+        // shrinkage can be put down to that shift.
+        manager
+            .create_table(
+                Table::create()
+                    .table(StockTakes::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(StockTakes::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .default(Expr::cust("gen_random_uuid()")),
+                    )
+                    .col(ColumnDef::new(StockTakes::OrganizationId).uuid().not_null())
+                    .col(ColumnDef::new(StockTakes::BranchId).uuid().not_null())
+                    .col(ColumnDef::new(StockTakes::TillSessionId).uuid())
+                    .col(
+                        ColumnDef::new(StockTakes::TakeStatus)
+                            .string()
+                            .not_null()
+                            .check(Expr::col(StockTakes::TakeStatus).is_in(vec![
+                                TakeStatusEnum::Open.as_str(),
+                                TakeStatusEnum::Approved.as_str(),
+                                TakeStatusEnum::Cancelled.as_str(),
+                            ]))
+                            .default(TakeStatusEnum::Open.as_str()),
+                    )
+                    .col(ColumnDef::new(StockTakes::Notes).string())
+                    .col(ColumnDef::new(StockTakes::StartedBy).uuid().not_null())
+                    .col(ColumnDef::new(StockTakes::ApprovedBy).uuid())
+                    .col(ColumnDef::new(StockTakes::ApprovedAt).timestamp_with_time_zone())
+                    .col(
+                        ColumnDef::new(StockTakes::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(StockTakes::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(StockTakes::Table, StockTakes::OrganizationId)
+                            .to(Organizations::Table, Organizations::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(StockTakes::Table, StockTakes::BranchId)
+                            .to(Branches::Table, Branches::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(StockTakes::Table, StockTakes::TillSessionId)
+                            .to(TillSessions::Table, TillSessions::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(StockTakes::Table, StockTakes::StartedBy)
+                            .to(Employees::Table, Employees::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // At most one stock take in progress per branch
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_stock_takes_branch_open")
+                    .table(StockTakes::Table)
+                    .col(StockTakes::BranchId)
+                    .unique()
+                    .and_where(Expr::col(StockTakes::TakeStatus).eq(TakeStatusEnum::Open.as_str()))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(StockTakes::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum StockTakes {
+    Table,
+    Id,
+    OrganizationId,
+    BranchId,
+    TillSessionId,
+    TakeStatus,
+    Notes,
+    StartedBy,
+    ApprovedBy,
+    ApprovedAt,
+    CreatedAt,
+    UpdatedAt,
+}
+
+enum TakeStatusEnum {
+    Open,
+    Approved,
+    Cancelled,
+}
+
+impl TakeStatusEnum {
+    fn as_str(&self) -> &'static str {
+        match self {
+            TakeStatusEnum::Open => "OPEN",
+            TakeStatusEnum::Approved => "APPROVED",
+            TakeStatusEnum::Cancelled => "CANCELLED",
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use sea_orm_migration::prelude::*;
+
+use crate::{
+    m20250311_114321_create_staff::Employees, m20250311_132020_create_stock_drinks::StockDrinks,
+    m20250507_090000_create_stock_takes::StockTakes,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // One employee's count of a drink in one pass of a stock take, next to what the system
+        // held when it was counted.
+        manager
+            .create_table(
+                Table::create()
+                    .table(StockTakeCounts::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(StockTakeCounts::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .default(Expr::cust("gen_random_uuid()")),
+                    )
+                    .col(
+                        ColumnDef::new(StockTakeCounts::StockTakeId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(StockTakeCounts::StockDrinkId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(StockTakeCounts::Pass)
+                            .integer()
+                            .not_null()
+                            .default(1),
+                    )
+                    .col(
+                        ColumnDef::new(StockTakeCounts::CountedQuantity)
+                            .integer()
+                            .not_null()
+                            .check(Expr::col(StockTakeCounts::CountedQuantity).gte(0)),
+                    )
+                    .col(
+                        ColumnDef::new(StockTakeCounts::ExpectedQuantity)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(StockTakeCounts::CountedBy).uuid().not_null())
+                    .col(
+                        ColumnDef::new(StockTakeCounts::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(StockTakeCounts::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(StockTakeCounts::Table, StockTakeCounts::StockTakeId)
+                            .to(StockTakes::Table, StockTakes::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(StockTakeCounts::Table, StockTakeCounts::StockDrinkId)
+                            .to(StockDrinks::Table, StockDrinks::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(StockTakeCounts::Table, StockTakeCounts::CountedBy)
+                            .to(Employees::Table, Employees::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(StockTakeCounts::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum StockTakeCounts {
+    Table,
+    Id,
+    StockTakeId,
+    StockDrinkId,
+    Pass,
+    CountedQuantity,
+    ExpectedQuantity,
+    CountedBy,
+    CreatedAt,
+    UpdatedAt,
+}
@@ -0,0 +1,172 @@
+use sea_orm_migration::prelude::*;
+
+use crate::{
+    m20250311_102524_create_organizations::Organizations,
+    m20250311_111857_create_branches::Branches, m20250311_114321_create_staff::Employees,
+    m20250311_132020_create_stock_drinks::StockDrinks,
+    m20250507_090000_create_stock_takes::StockTakes,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // A change to a drink's stock outside of sales and deliveries. Quantity is signed, a loss
+        // is negative, and unit cost is what the drink cost at the time so losses can be valued.
+        manager
+            .create_table(
+                Table::create()
+                    .table(StockAdjustments::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(StockAdjustments::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .default(Expr::cust("gen_random_uuid()")),
+                    )
+                    .col(
+                        ColumnDef::new(StockAdjustments::OrganizationId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(StockAdjustments::BranchId).uuid().not_null())
+                    .col(
+                        ColumnDef::new(StockAdjustments::StockDrinkId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(StockAdjustments::StockTakeId).uuid())
+                    .col(
+                        ColumnDef::new(StockAdjustments::ExpectedQuantity)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(StockAdjustments::CountedQuantity)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(StockAdjustments::Quantity)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(StockAdjustments::Reason)
+                            .string()
+                            .not_null()
+                            .check(Expr::col(StockAdjustments::Reason).is_in(vec![
+                                ReasonEnum::Breakage.as_str(),
+                                ReasonEnum::Spillage.as_str(),
+                                ReasonEnum::Theft.as_str(),
+                                ReasonEnum::Comp.as_str(),
+                                ReasonEnum::Miscount.as_str(),
+                                ReasonEnum::Unexplained.as_str(),
+                            ])),
+                    )
+                    .col(
+                        ColumnDef::new(StockAdjustments::UnitCost)
+                            .decimal()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(StockAdjustments::CreatedBy)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(StockAdjustments::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(StockAdjustments::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(StockAdjustments::Table, StockAdjustments::OrganizationId)
+                            .to(Organizations::Table, Organizations::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(StockAdjustments::Table, StockAdjustments::BranchId)
+                            .to(Branches::Table, Branches::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(StockAdjustments::Table, StockAdjustments::StockDrinkId)
+                            .to(StockDrinks::Table, StockDrinks::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(StockAdjustments::Table, StockAdjustments::StockTakeId)
+                            .to(StockTakes::Table, StockTakes::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(StockAdjustments::Table, StockAdjustments::CreatedBy)
+                            .to(Employees::Table, Employees::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(StockAdjustments::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum StockAdjustments {
+    Table,
+    Id,
+    OrganizationId,
+    BranchId,
+    StockDrinkId,
+    StockTakeId,
+    ExpectedQuantity,
+    CountedQuantity,
+    Quantity,
+    Reason,
+    UnitCost,
+    CreatedBy,
+    CreatedAt,
+    UpdatedAt,
+}
+
+enum ReasonEnum {
+    Breakage,
+    Spillage,
+    Theft,
+    Comp,
+    Miscount,
+    Unexplained,
+}
+
+impl ReasonEnum {
+    fn as_str(&self) -> &'static str {
+        match self {
+            ReasonEnum::Breakage => "BREAKAGE",
+            ReasonEnum::Spillage => "SPILLAGE",
+            ReasonEnum::Theft => "THEFT",
+            ReasonEnum::Comp => "COMP",
+            ReasonEnum::Miscount => "MISCOUNT",
+            ReasonEnum::Unexplained => "UNEXPLAINED",
+        }
+    }
+}
@@ -0,0 +1,141 @@
+use sea_orm_migration::prelude::*;
+
+use crate::{
+    m20250311_114321_create_staff::Employees, m20250311_134128_create_category::Categories,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Customers' cocktails go through review. An approved one is sold at its price under a
+        // category like any menu item, and may credit and reward the customer who made it.
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(CustomCocktails::Table)
+                    .add_column(ColumnDef::new(CustomCocktails::Description).string())
+                    .add_column(
+                        ColumnDef::new(CustomCocktails::CocktailStatus)
+                            .string()
+                            .not_null()
+                            .check(Expr::col(CustomCocktails::CocktailStatus).is_in(vec![
+                                CocktailStatusEnum::Pending.as_str(),
+                                CocktailStatusEnum::Approved.as_str(),
+                                CocktailStatusEnum::Rejected.as_str(),
+                            ]))
+                            .default(CocktailStatusEnum::Pending.as_str()),
+                    )
+                    .add_column(ColumnDef::new(CustomCocktails::Price).decimal())
+                    .add_column(ColumnDef::new(CustomCocktails::CategoryId).uuid())
+                    .add_column(
+                        ColumnDef::new(CustomCocktails::IsAvailable)
+                            .boolean()
+                            .not_null()
+                            .default(true),
+                    )
+                    .add_column(
+                        ColumnDef::new(CustomCocktails::CreditCreator)
+                            .boolean()
+                            .not_null()
+                            .default(true),
+                    )
+                    .add_column(
+                        ColumnDef::new(CustomCocktails::RewardAmount)
+                            .decimal()
+                            .not_null()
+                            .default(0.0),
+                    )
+                    .add_column(ColumnDef::new(CustomCocktails::ReviewedBy).uuid())
+                    .add_column(
+                        ColumnDef::new(CustomCocktails::ReviewedAt).timestamp_with_time_zone(),
+                    )
+                    .add_column(ColumnDef::new(CustomCocktails::ReviewNote).string())
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .name("fk_custom_cocktails_category_id")
+                            .from_tbl(CustomCocktails::Table)
+                            .from_col(CustomCocktails::CategoryId)
+                            .to_tbl(Categories::Table)
+                            .to_col(Categories::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .name("fk_custom_cocktails_reviewed_by")
+                            .from_tbl(CustomCocktails::Table)
+                            .from_col(CustomCocktails::ReviewedBy)
+                            .to_tbl(Employees::Table)
+                            .to_col(Employees::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Cocktails approved before the review workflow keep their approval
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "UPDATE custom_cocktails SET cocktail_status = 'APPROVED' WHERE is_approved",
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(CustomCocktails::Table)
+                    .drop_foreign_key(Alias::new("fk_custom_cocktails_category_id"))
+                    .drop_foreign_key(Alias::new("fk_custom_cocktails_reviewed_by"))
+                    .drop_column(CustomCocktails::Description)
+                    .drop_column(CustomCocktails::CocktailStatus)
+                    .drop_column(CustomCocktails::Price)
+                    .drop_column(CustomCocktails::CategoryId)
+                    .drop_column(CustomCocktails::IsAvailable)
+                    .drop_column(CustomCocktails::CreditCreator)
+                    .drop_column(CustomCocktails::RewardAmount)
+                    .drop_column(CustomCocktails::ReviewedBy)
+                    .drop_column(CustomCocktails::ReviewedAt)
+                    .drop_column(CustomCocktails::ReviewNote)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum CustomCocktails {
+    Table,
+    Description,
+    CocktailStatus,
+    Price,
+    CategoryId,
+    IsAvailable,
+    CreditCreator,
+    RewardAmount,
+    ReviewedBy,
+    ReviewedAt,
+    ReviewNote,
+}
+
+enum CocktailStatusEnum {
+    Pending,
+    Approved,
+    Rejected,
+}
+
+impl CocktailStatusEnum {
+    fn as_str(&self) -> &'static str {
+        match self {
+            CocktailStatusEnum::Pending => "PENDING",
+            CocktailStatusEnum::Approved => "APPROVED",
+            CocktailStatusEnum::Rejected => "REJECTED",
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use sea_orm_migration::prelude::*;
+
+use crate::{
+    m20250311_132020_create_stock_drinks::StockDrinks,
+    m20250311_140939_create_custom_cocktails::CustomCocktails,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // The stock a cocktail is made from, in units of each drink per cocktail served
+        manager
+            .create_table(
+                Table::create()
+                    .table(CustomCocktailIngredients::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(CustomCocktailIngredients::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .default(Expr::cust("gen_random_uuid()")),
+                    )
+                    .col(
+                        ColumnDef::new(CustomCocktailIngredients::CustomCocktailId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(CustomCocktailIngredients::StockDrinkId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(CustomCocktailIngredients::Quantity)
+                            .integer()
+                            .not_null()
+                            .check(Expr::col(CustomCocktailIngredients::Quantity).gt(0)),
+                    )
+                    .col(
+                        ColumnDef::new(CustomCocktailIngredients::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(CustomCocktailIngredients::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(
+                                CustomCocktailIngredients::Table,
+                                CustomCocktailIngredients::CustomCocktailId,
+                            )
+                            .to(CustomCocktails::Table, CustomCocktails::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(
+                                CustomCocktailIngredients::Table,
+                                CustomCocktailIngredients::StockDrinkId,
+                            )
+                            .to(StockDrinks::Table, StockDrinks::Id)
+                            .on_delete(ForeignKeyAction::Restrict),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_custom_cocktail_ingredients_cocktail_drink")
+                    .table(CustomCocktailIngredients::Table)
+                    .col(CustomCocktailIngredients::CustomCocktailId)
+                    .col(CustomCocktailIngredients::StockDrinkId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(CustomCocktailIngredients::Table)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum CustomCocktailIngredients {
+    Table,
+    Id,
+    CustomCocktailId,
+    StockDrinkId,
+    Quantity,
+    CreatedAt,
+    UpdatedAt,
+}
@@ -0,0 +1,75 @@
+use sea_orm_migration::prelude::*;
+
+use crate::m20250311_140939_create_custom_cocktails::CustomCocktails;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(OrderItems::Table)
+                    .add_column(ColumnDef::new(OrderItems::CustomCocktailId).uuid())
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .name("fk_order_items_custom_cocktail_id")
+                            .from_tbl(OrderItems::Table)
+                            .from_col(OrderItems::CustomCocktailId)
+                            .to_tbl(CustomCocktails::Table)
+                            .to_col(CustomCocktails::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Approved customer cocktails are ordered like drinks, and their creators can be
+        // rewarded to their wallet
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "ALTER TABLE order_items DROP CONSTRAINT IF EXISTS order_items_item_type_check;
+                 ALTER TABLE order_items ADD CONSTRAINT order_items_item_type_check
+                    CHECK (item_type IN ('DRINK', 'FOOD', 'COCKTAIL'));
+                 ALTER TABLE transactions DROP CONSTRAINT IF EXISTS transactions_transaction_type_check;
+                 ALTER TABLE transactions ADD CONSTRAINT transactions_transaction_type_check
+                    CHECK (transaction_type IN ('TOPUP', 'PAYMENT', 'WITHDRAWAL', 'REFUND', 'TIP', 'PAYOUT', 'REWARD'));",
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "ALTER TABLE transactions DROP CONSTRAINT IF EXISTS transactions_transaction_type_check;
+                 ALTER TABLE transactions ADD CONSTRAINT transactions_transaction_type_check
+                    CHECK (transaction_type IN ('TOPUP', 'PAYMENT', 'WITHDRAWAL', 'REFUND', 'TIP', 'PAYOUT'));
+                 ALTER TABLE order_items DROP CONSTRAINT IF EXISTS order_items_item_type_check;
+                 ALTER TABLE order_items ADD CONSTRAINT order_items_item_type_check
+                    CHECK (item_type IN ('DRINK', 'FOOD'));",
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(OrderItems::Table)
+                    .drop_foreign_key(Alias::new("fk_order_items_custom_cocktail_id"))
+                    .drop_column(OrderItems::CustomCocktailId)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum OrderItems {
+    Table,
+    CustomCocktailId,
+}
@@ -0,0 +1,81 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // What one unit of a drink holds, e.g. a 700 ML bottle, so recipes can pour part of it.
+        // The opened unit's leftover is tracked apart from the sealed units on the shelf.
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(StockDrinks::Table)
+                    .add_column(
+                        ColumnDef::new(StockDrinks::MeasureUnit)
+                            .string()
+                            .not_null()
+                            .check(Expr::col(StockDrinks::MeasureUnit).is_in(vec![
+                                MeasureUnitEnum::Piece.as_str(),
+                                MeasureUnitEnum::Ml.as_str(),
+                                MeasureUnitEnum::G.as_str(),
+                            ]))
+                            .default(MeasureUnitEnum::Piece.as_str()),
+                    )
+                    .add_column(
+                        ColumnDef::new(StockDrinks::UnitSize)
+                            .decimal()
+                            .not_null()
+                            .check(Expr::col(StockDrinks::UnitSize).gt(0))
+                            .default(1.0),
+                    )
+                    .add_column(
+                        ColumnDef::new(StockDrinks::OpenMeasure)
+                            .decimal()
+                            .not_null()
+                            .check(Expr::col(StockDrinks::OpenMeasure).gte(0))
+                            .default(0.0),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(StockDrinks::Table)
+                    .drop_column(StockDrinks::MeasureUnit)
+                    .drop_column(StockDrinks::UnitSize)
+                    .drop_column(StockDrinks::OpenMeasure)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum StockDrinks {
+    Table,
+    MeasureUnit,
+    UnitSize,
+    OpenMeasure,
+}
+
+enum MeasureUnitEnum {
+    Piece,
+    Ml,
+    G,
+}
+
+impl MeasureUnitEnum {
+    fn as_str(&self) -> &'static str {
+        match self {
+            MeasureUnitEnum::Piece => "PIECE",
+            MeasureUnitEnum::Ml => "ML",
+            MeasureUnitEnum::G => "G",
+        }
+    }
+}
@@ -0,0 +1,203 @@
+use sea_orm_migration::prelude::*;
+
+use crate::{
+    m20250311_102524_create_organizations::Organizations,
+    m20250311_111857_create_branches::Branches, m20250311_132020_create_stock_drinks::StockDrinks,
+    m20250311_133559_create_stock_foods::StockFoods,
+    m20250311_140939_create_custom_cocktails::CustomCocktails,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // One component of a sellable drink, food or custom cocktail: the stock drink it uses
+        // and how much of it goes into one serving
+        manager
+            .create_table(
+                Table::create()
+                    .table(RecipeItems::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(RecipeItems::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .default(Expr::cust("gen_random_uuid()")),
+                    )
+                    .col(
+                        ColumnDef::new(RecipeItems::OrganizationId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(RecipeItems::BranchId).uuid().not_null())
+                    .col(
+                        ColumnDef::new(RecipeItems::ItemType)
+                            .string()
+                            .not_null()
+                            .check(Expr::col(RecipeItems::ItemType).is_in(vec![
+                                ItemTypeEnum::Drink.as_str(),
+                                ItemTypeEnum::Food.as_str(),
+                                ItemTypeEnum::Cocktail.as_str(),
+                            ])),
+                    )
+                    .col(ColumnDef::new(RecipeItems::StockDrinkId).uuid())
+                    .col(ColumnDef::new(RecipeItems::StockFoodId).uuid())
+                    .col(ColumnDef::new(RecipeItems::CustomCocktailId).uuid())
+                    .col(ColumnDef::new(RecipeItems::ComponentId).uuid().not_null())
+                    .col(
+                        ColumnDef::new(RecipeItems::Quantity)
+                            .decimal()
+                            .not_null()
+                            .check(Expr::col(RecipeItems::Quantity).gt(0)),
+                    )
+                    .col(ColumnDef::new(RecipeItems::Unit).string().not_null().check(
+                        Expr::col(RecipeItems::Unit).is_in(vec![
+                            RecipeUnitEnum::Unit.as_str(),
+                            RecipeUnitEnum::Piece.as_str(),
+                            RecipeUnitEnum::Ml.as_str(),
+                            RecipeUnitEnum::Cl.as_str(),
+                            RecipeUnitEnum::L.as_str(),
+                            RecipeUnitEnum::G.as_str(),
+                            RecipeUnitEnum::Kg.as_str(),
+                        ]),
+                    ))
+                    .col(
+                        ColumnDef::new(RecipeItems::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(RecipeItems::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(RecipeItems::Table, RecipeItems::OrganizationId)
+                            .to(Organizations::Table, Organizations::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(RecipeItems::Table, RecipeItems::BranchId)
+                            .to(Branches::Table, Branches::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(RecipeItems::Table, RecipeItems::StockDrinkId)
+                            .to(StockDrinks::Table, StockDrinks::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(RecipeItems::Table, RecipeItems::StockFoodId)
+                            .to(StockFoods::Table, StockFoods::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(RecipeItems::Table, RecipeItems::CustomCocktailId)
+                            .to(CustomCocktails::Table, CustomCocktails::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(RecipeItems::Table, RecipeItems::ComponentId)
+                            .to(StockDrinks::Table, StockDrinks::Id)
+                            .on_delete(ForeignKeyAction::Restrict),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // A line belongs to exactly one sellable item, and an item can't be its own component
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "ALTER TABLE recipe_items ADD CONSTRAINT recipe_items_item_check
+                    CHECK (num_nonnulls(stock_drink_id, stock_food_id, custom_cocktail_id) = 1);
+                 ALTER TABLE recipe_items ADD CONSTRAINT recipe_items_component_check
+                    CHECK (stock_drink_id IS NULL OR stock_drink_id <> component_id);",
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_recipe_items_component_id")
+                    .table(RecipeItems::Table)
+                    .col(RecipeItems::ComponentId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(RecipeItems::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum RecipeItems {
+    Table,
+    Id,
+    OrganizationId,
+    BranchId,
+    ItemType,
+    StockDrinkId,
+    StockFoodId,
+    CustomCocktailId,
+    ComponentId,
+    Quantity,
+    Unit,
+    CreatedAt,
+    UpdatedAt,
+}
+
+enum ItemTypeEnum {
+    Drink,
+    Food,
+    Cocktail,
+}
+
+impl ItemTypeEnum {
+    fn as_str(&self) -> &'static str {
+        match self {
+            ItemTypeEnum::Drink => "DRINK",
+            ItemTypeEnum::Food => "FOOD",
+            ItemTypeEnum::Cocktail => "COCKTAIL",
+        }
+    }
+}
+
+enum RecipeUnitEnum {
+    Unit,
+    Piece,
+    Ml,
+    Cl,
+    L,
+    G,
+    Kg,
+}
+
+impl RecipeUnitEnum {
+    fn as_str(&self) -> &'static str {
+        match self {
+            RecipeUnitEnum::Unit => "UNIT",
+            RecipeUnitEnum::Piece => "PIECE",
+            RecipeUnitEnum::Ml => "ML",
+            RecipeUnitEnum::Cl => "CL",
+            RecipeUnitEnum::L => "L",
+            RecipeUnitEnum::G => "G",
+            RecipeUnitEnum::Kg => "KG",
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Custom cocktails are made from recipes like the rest of the menu. Their ingredients
+        // were whole units of each drink.
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "INSERT INTO recipe_items
+                    (organization_id, branch_id, item_type, custom_cocktail_id, component_id, quantity, unit)
+                 SELECT c.organization_id, c.branch_id, 'COCKTAIL', i.custom_cocktail_id, i.stock_drink_id, i.quantity, 'UNIT'
+                 FROM custom_cocktail_ingredients i
+                 JOIN custom_cocktails c ON c.id = i.custom_cocktail_id;",
+            )
+            .await?;
+
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(CustomCocktailIngredients::Table)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Only whole-unit cocktail lines fit the old table
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "CREATE TABLE custom_cocktail_ingredients (
+                    id uuid NOT NULL PRIMARY KEY DEFAULT gen_random_uuid(),
+                    custom_cocktail_id uuid NOT NULL REFERENCES custom_cocktails (id) ON DELETE CASCADE,
+                    stock_drink_id uuid NOT NULL REFERENCES stock_drinks (id) ON DELETE RESTRICT,
+                    quantity integer NOT NULL CHECK (quantity > 0),
+                    created_at timestamp with time zone NOT NULL DEFAULT CURRENT_TIMESTAMP,
+                    updated_at timestamp with time zone NOT NULL DEFAULT CURRENT_TIMESTAMP
+                 );
+                 CREATE UNIQUE INDEX idx_custom_cocktail_ingredients_cocktail_drink
+                    ON custom_cocktail_ingredients (custom_cocktail_id, stock_drink_id);
+                 INSERT INTO custom_cocktail_ingredients (custom_cocktail_id, stock_drink_id, quantity)
+                 SELECT custom_cocktail_id, component_id, SUM(CEIL(quantity))::integer
+                 FROM recipe_items
+                 WHERE custom_cocktail_id IS NOT NULL AND unit = 'UNIT'
+                 GROUP BY custom_cocktail_id, component_id;
+                 DELETE FROM recipe_items WHERE custom_cocktail_id IS NOT NULL;",
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum CustomCocktailIngredients {
+    Table,
+}
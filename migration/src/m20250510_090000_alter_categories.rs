@@ -0,0 +1,144 @@
+use sea_orm_migration::prelude::*;
+
+use crate::{
+    m20250311_102524_create_organizations::Organizations,
+    m20250311_111857_create_branches::Branches,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Categories belong to an organization, either for all its branches or for one. Those
+        // without an organization are shared. They nest under a parent, are ordered on the menu
+        // and may only be served in a daily window, e.g. a kitchen closing at 2am.
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Categories::Table)
+                    .add_column(ColumnDef::new(Categories::OrganizationId).uuid())
+                    .add_column(ColumnDef::new(Categories::BranchId).uuid())
+                    .add_column(ColumnDef::new(Categories::ParentId).uuid())
+                    .add_column(
+                        ColumnDef::new(Categories::SortOrder)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .add_column(
+                        ColumnDef::new(Categories::IsActive)
+                            .boolean()
+                            .not_null()
+                            .default(true),
+                    )
+                    .add_column(ColumnDef::new(Categories::AvailableFrom).time())
+                    .add_column(ColumnDef::new(Categories::AvailableUntil).time())
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .name("fk_categories_organization_id")
+                            .from_tbl(Categories::Table)
+                            .from_col(Categories::OrganizationId)
+                            .to_tbl(Organizations::Table)
+                            .to_col(Organizations::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .name("fk_categories_branch_id")
+                            .from_tbl(Categories::Table)
+                            .from_col(Categories::BranchId)
+                            .to_tbl(Branches::Table)
+                            .to_col(Branches::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .name("fk_categories_parent_id")
+                            .from_tbl(Categories::Table)
+                            .from_col(Categories::ParentId)
+                            .to_tbl(Categories::Table)
+                            .to_col(Categories::Id)
+                            .on_delete(ForeignKeyAction::Restrict),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "ALTER TABLE categories ADD CONSTRAINT categories_branch_check
+                    CHECK (branch_id IS NULL OR organization_id IS NOT NULL);
+                 ALTER TABLE categories ADD CONSTRAINT categories_parent_check
+                    CHECK (parent_id IS NULL OR parent_id <> id);
+                 ALTER TABLE categories ADD CONSTRAINT categories_window_check
+                    CHECK ((available_from IS NULL) = (available_until IS NULL)
+                        AND (available_from IS NULL OR available_from <> available_until));",
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_categories_organization_id_branch_id")
+                    .table(Categories::Table)
+                    .col(Categories::OrganizationId)
+                    .col(Categories::BranchId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_categories_organization_id_branch_id")
+                    .table(Categories::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "ALTER TABLE categories DROP CONSTRAINT categories_branch_check;
+                 ALTER TABLE categories DROP CONSTRAINT categories_parent_check;
+                 ALTER TABLE categories DROP CONSTRAINT categories_window_check;",
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Categories::Table)
+                    .drop_foreign_key(Alias::new("fk_categories_organization_id"))
+                    .drop_foreign_key(Alias::new("fk_categories_branch_id"))
+                    .drop_foreign_key(Alias::new("fk_categories_parent_id"))
+                    .drop_column(Categories::OrganizationId)
+                    .drop_column(Categories::BranchId)
+                    .drop_column(Categories::ParentId)
+                    .drop_column(Categories::SortOrder)
+                    .drop_column(Categories::IsActive)
+                    .drop_column(Categories::AvailableFrom)
+                    .drop_column(Categories::AvailableUntil)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Categories {
+    Table,
+    Id,
+    OrganizationId,
+    BranchId,
+    ParentId,
+    SortOrder,
+    IsActive,
+    AvailableFrom,
+    AvailableUntil,
+}
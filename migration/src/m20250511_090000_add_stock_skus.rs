@@ -0,0 +1,105 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // The branch's own code for a drink or dish, so bulk imports can match rows whose
+        // name has changed. Items without one are matched by name.
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(StockDrinks::Table)
+                    .add_column(ColumnDef::new(StockDrinks::Sku).string())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(StockFoods::Table)
+                    .add_column(ColumnDef::new(StockFoods::Sku).string())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_stock_drinks_branch_sku")
+                    .table(StockDrinks::Table)
+                    .col(StockDrinks::BranchId)
+                    .col(StockDrinks::Sku)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_stock_foods_branch_sku")
+                    .table(StockFoods::Table)
+                    .col(StockFoods::BranchId)
+                    .col(StockFoods::Sku)
+                    .unique()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_stock_foods_branch_sku")
+                    .table(StockFoods::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_stock_drinks_branch_sku")
+                    .table(StockDrinks::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(StockFoods::Table)
+                    .drop_column(StockFoods::Sku)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(StockDrinks::Table)
+                    .drop_column(StockDrinks::Sku)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum StockDrinks {
+    Table,
+    BranchId,
+    Sku,
+}
+
+#[derive(DeriveIden)]
+enum StockFoods {
+    Table,
+    BranchId,
+    Sku,
+}
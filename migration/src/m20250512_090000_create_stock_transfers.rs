@@ -0,0 +1,169 @@
+use sea_orm_migration::prelude::*;
+
+use crate::{
+    m20250311_102524_create_organizations::Organizations,
+    m20250311_111857_create_branches::Branches, m20250311_114321_create_staff::Employees,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Stock one branch asks another of its organization for. It leaves the sending branch
+        // when dispatched and is in transit until the requesting branch receives it.
+        manager
+            .create_table(
+                Table::create()
+                    .table(StockTransfers::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(StockTransfers::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .default(Expr::cust("gen_random_uuid()")),
+                    )
+                    .col(
+                        ColumnDef::new(StockTransfers::OrganizationId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(StockTransfers::FromBranchId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(StockTransfers::ToBranchId).uuid().not_null())
+                    .col(
+                        ColumnDef::new(StockTransfers::TransferStatus)
+                            .string()
+                            .not_null()
+                            .check(Expr::col(StockTransfers::TransferStatus).is_in(vec![
+                                TransferStatusEnum::Requested.as_str(),
+                                TransferStatusEnum::InTransit.as_str(),
+                                TransferStatusEnum::Received.as_str(),
+                                TransferStatusEnum::Cancelled.as_str(),
+                            ]))
+                            .default(TransferStatusEnum::Requested.as_str()),
+                    )
+                    .col(ColumnDef::new(StockTransfers::Notes).string())
+                    .col(ColumnDef::new(StockTransfers::DiscrepancyNote).string())
+                    .col(
+                        ColumnDef::new(StockTransfers::RequestedBy)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(StockTransfers::DispatchedBy).uuid())
+                    .col(ColumnDef::new(StockTransfers::DispatchedAt).timestamp_with_time_zone())
+                    .col(ColumnDef::new(StockTransfers::ReceivedBy).uuid())
+                    .col(ColumnDef::new(StockTransfers::ReceivedAt).timestamp_with_time_zone())
+                    .col(
+                        ColumnDef::new(StockTransfers::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(StockTransfers::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .check(
+                        Expr::col(StockTransfers::FromBranchId)
+                            .ne(Expr::col(StockTransfers::ToBranchId)),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(StockTransfers::Table, StockTransfers::OrganizationId)
+                            .to(Organizations::Table, Organizations::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(StockTransfers::Table, StockTransfers::FromBranchId)
+                            .to(Branches::Table, Branches::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(StockTransfers::Table, StockTransfers::ToBranchId)
+                            .to(Branches::Table, Branches::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(StockTransfers::Table, StockTransfers::RequestedBy)
+                            .to(Employees::Table, Employees::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_stock_transfers_from_branch_id")
+                    .table(StockTransfers::Table)
+                    .col(StockTransfers::FromBranchId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_stock_transfers_to_branch_id")
+                    .table(StockTransfers::Table)
+                    .col(StockTransfers::ToBranchId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(StockTransfers::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum StockTransfers {
+    Table,
+    Id,
+    OrganizationId,
+    FromBranchId,
+    ToBranchId,
+    TransferStatus,
+    Notes,
+    DiscrepancyNote,
+    RequestedBy,
+    DispatchedBy,
+    DispatchedAt,
+    ReceivedBy,
+    ReceivedAt,
+    CreatedAt,
+    UpdatedAt,
+}
+
+enum TransferStatusEnum {
+    Requested,
+    InTransit,
+    Received,
+    Cancelled,
+}
+
+impl TransferStatusEnum {
+    fn as_str(&self) -> &'static str {
+        match self {
+            TransferStatusEnum::Requested => "REQUESTED",
+            TransferStatusEnum::InTransit => "INTRANSIT",
+            TransferStatusEnum::Received => "RECEIVED",
+            TransferStatusEnum::Cancelled => "CANCELLED",
+        }
+    }
+}
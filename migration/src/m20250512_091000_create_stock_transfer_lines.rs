@@ -0,0 +1,133 @@
+use sea_orm_migration::prelude::*;
+
+use crate::{
+    m20250311_132020_create_stock_drinks::StockDrinks,
+    m20250512_090000_create_stock_transfers::StockTransfers,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // A drink on a transfer, as the sending branch stocks it and as the requesting branch
+        // does. Quantities are in the drink's units, and unit cost is what the sending branch
+        // paid when it was dispatched.
+        manager
+            .create_table(
+                Table::create()
+                    .table(StockTransferLines::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(StockTransferLines::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .default(Expr::cust("gen_random_uuid()")),
+                    )
+                    .col(
+                        ColumnDef::new(StockTransferLines::StockTransferId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(StockTransferLines::FromDrinkId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(StockTransferLines::ToDrinkId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(StockTransferLines::QuantityRequested)
+                            .integer()
+                            .not_null()
+                            .check(Expr::col(StockTransferLines::QuantityRequested).gt(0)),
+                    )
+                    .col(
+                        ColumnDef::new(StockTransferLines::QuantityDispatched)
+                            .integer()
+                            .check(Expr::col(StockTransferLines::QuantityDispatched).gte(0)),
+                    )
+                    .col(
+                        ColumnDef::new(StockTransferLines::QuantityReceived)
+                            .integer()
+                            .check(Expr::col(StockTransferLines::QuantityReceived).gte(0)),
+                    )
+                    .col(
+                        ColumnDef::new(StockTransferLines::UnitCost)
+                            .decimal()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(StockTransferLines::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(StockTransferLines::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(StockTransferLines::Table, StockTransferLines::StockTransferId)
+                            .to(StockTransfers::Table, StockTransfers::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(StockTransferLines::Table, StockTransferLines::FromDrinkId)
+                            .to(StockDrinks::Table, StockDrinks::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(StockTransferLines::Table, StockTransferLines::ToDrinkId)
+                            .to(StockDrinks::Table, StockDrinks::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // A drink appears once per transfer
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_stock_transfer_lines_transfer_drink")
+                    .table(StockTransferLines::Table)
+                    .col(StockTransferLines::StockTransferId)
+                    .col(StockTransferLines::FromDrinkId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(StockTransferLines::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum StockTransferLines {
+    Table,
+    Id,
+    StockTransferId,
+    FromDrinkId,
+    ToDrinkId,
+    QuantityRequested,
+    QuantityDispatched,
+    QuantityReceived,
+    UnitCost,
+    CreatedAt,
+    UpdatedAt,
+}
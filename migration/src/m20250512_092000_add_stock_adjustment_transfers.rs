@@ -0,0 +1,70 @@
+use sea_orm_migration::prelude::*;
+
+use crate::m20250512_090000_create_stock_transfers::StockTransfers;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(StockAdjustments::Table)
+                    .add_column(ColumnDef::new(StockAdjustments::StockTransferId).uuid())
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .name("fk_stock_adjustments_stock_transfer_id")
+                            .from_tbl(StockAdjustments::Table)
+                            .from_col(StockAdjustments::StockTransferId)
+                            .to_tbl(StockTransfers::Table)
+                            .to_col(StockTransfers::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Stock sent to and received from other branches is kept in each branch's stock
+        // history next to stock take corrections
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "ALTER TABLE stock_adjustments DROP CONSTRAINT IF EXISTS stock_adjustments_reason_check;
+                 ALTER TABLE stock_adjustments ADD CONSTRAINT stock_adjustments_reason_check
+                    CHECK (reason IN ('BREAKAGE', 'SPILLAGE', 'THEFT', 'COMP', 'MISCOUNT', 'UNEXPLAINED', 'TRANSFEROUT', 'TRANSFERIN'));",
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "DELETE FROM stock_adjustments WHERE reason IN ('TRANSFEROUT', 'TRANSFERIN');
+                 ALTER TABLE stock_adjustments DROP CONSTRAINT IF EXISTS stock_adjustments_reason_check;
+                 ALTER TABLE stock_adjustments ADD CONSTRAINT stock_adjustments_reason_check
+                    CHECK (reason IN ('BREAKAGE', 'SPILLAGE', 'THEFT', 'COMP', 'MISCOUNT', 'UNEXPLAINED'));",
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(StockAdjustments::Table)
+                    .drop_foreign_key(Alias::new("fk_stock_adjustments_stock_transfer_id"))
+                    .drop_column(StockAdjustments::StockTransferId)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum StockAdjustments {
+    Table,
+    StockTransferId,
+}
@@ -0,0 +1,42 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // The IANA timezone the venue keeps its hours in, so time windows, business days and
+        // reports follow the venue's clock rather than the server's
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Branches::Table)
+                    .add_column(
+                        ColumnDef::new(Branches::Timezone)
+                            .string()
+                            .not_null()
+                            .default("UTC"),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Branches::Table)
+                    .drop_column(Branches::Timezone)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Branches {
+    Table,
+    Timezone,
+}
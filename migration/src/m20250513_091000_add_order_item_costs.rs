@@ -0,0 +1,63 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // What the promos took off each line and what a unit cost to make when it sold, so
+        // sales reports show what was actually taken and the margin at the time
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(OrderItems::Table)
+                    .add_column(
+                        ColumnDef::new(OrderItems::DiscountAmount)
+                            .decimal()
+                            .not_null()
+                            .default(0.0),
+                    )
+                    .add_column(ColumnDef::new(OrderItems::UnitCost).decimal())
+                    .to_owned(),
+            )
+            .await?;
+
+        // Orders placed before lines kept their own discount spread it over their lines by
+        // amount
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "UPDATE order_items SET discount_amount =
+                    ROUND(orders.discount_amount * order_items.price * order_items.quantity / lines.subtotal, 2)
+                 FROM orders,
+                    (SELECT order_id, SUM(price * quantity) AS subtotal FROM order_items GROUP BY order_id) lines
+                 WHERE orders.id = order_items.order_id
+                    AND lines.order_id = order_items.order_id
+                    AND orders.discount_amount > 0
+                    AND lines.subtotal > 0;",
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(OrderItems::Table)
+                    .drop_column(OrderItems::DiscountAmount)
+                    .drop_column(OrderItems::UnitCost)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum OrderItems {
+    Table,
+    DiscountAmount,
+    UnitCost,
+}
@@ -0,0 +1,54 @@
+use sea_orm_migration::prelude::*;
+
+use crate::m20250311_100140_create_countries::Countries;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Tax rules are set by each organization, nobody can manage country-wide ones
+        manager
+            .get_connection()
+            .execute_unprepared("DELETE FROM tax_rules WHERE organization_id IS NULL;")
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TaxRules::Table)
+                    .drop_column(TaxRules::CountryId)
+                    .modify_column(ColumnDef::new(TaxRules::OrganizationId).uuid().not_null())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TaxRules::Table)
+                    .modify_column(ColumnDef::new(TaxRules::OrganizationId).uuid().null())
+                    .add_column(ColumnDef::new(TaxRules::CountryId).uuid())
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .from_tbl(TaxRules::Table)
+                            .from_col(TaxRules::CountryId)
+                            .to_tbl(Countries::Table)
+                            .to_col(Countries::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum TaxRules {
+    Table,
+    OrganizationId,
+    CountryId,
+}
@@ -0,0 +1,411 @@
+use std::sync::Arc;
+
+use actix_web::{http::header, web, HttpMessage, HttpRequest, HttpResponse};
+use serde_json::json;
+
+use crate::{
+    app::{
+        analytics::{
+            dtos::dto::{
+                get_branch_sales, get_category_sales, get_item_sales, get_performers,
+                get_sales_heatmap,
+            },
+            models::model::{RankBy, SalesQuery},
+            services::service::{
+                branch_sales_csv, category_sales_csv, heatmap_csv, item_sales_csv,
+            },
+        },
+        employees::models::model::EmployeeResponse,
+        permissions::{dtos::dto::has_permission, models::model::ModelAction},
+    },
+    libs::error::Error,
+    utils::{
+        json_validator::ValidatedQuery,
+        models::{HttpClientResponse, ResponseCode},
+    },
+    AppState,
+};
+
+/// Performers listed at each end of the ranking when the query doesn't say.
+const DEFAULT_PERFORMERS: usize = 10;
+
+/// A report as a CSV download, named after the report and its period.
+fn csv_attachment(report: &str, query: &SalesQuery, body: String) -> HttpResponse {
+    HttpResponse::Ok()
+        .content_type("text/csv; charset=utf-8")
+        .insert_header((
+            header::CONTENT_DISPOSITION,
+            format!(
+                "attachment; filename=\"{}-{}-{}.csv\"",
+                report, query.period_start, query.period_end
+            ),
+        ))
+        .body(body)
+}
+
+pub async fn item_report(
+    req: HttpRequest,
+    query: ValidatedQuery<SalesQuery>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    if !has_permission(model.role_permissions, ModelAction::ViewReports, &state)
+        .await
+        .unwrap_or(false)
+    {
+        return Ok(HttpResponse::Forbidden().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            "Not Permitted To View Reports".to_string(),
+            json!({}),
+        )));
+    }
+
+    match get_item_sales(query.0.filter(model.organization_id), &state).await {
+        Ok(res) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Item Sales Fetched Successfully".to_string(),
+            json!(res),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Error Retrieving: {}", e),
+            json!([]),
+        ))),
+    }
+}
+
+pub async fn category_report(
+    req: HttpRequest,
+    query: ValidatedQuery<SalesQuery>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    if !has_permission(model.role_permissions, ModelAction::ViewReports, &state)
+        .await
+        .unwrap_or(false)
+    {
+        return Ok(HttpResponse::Forbidden().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            "Not Permitted To View Reports".to_string(),
+            json!({}),
+        )));
+    }
+
+    match get_category_sales(query.0.filter(model.organization_id), &state).await {
+        Ok(res) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Category Sales Fetched Successfully".to_string(),
+            json!(res),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Error Retrieving: {}", e),
+            json!([]),
+        ))),
+    }
+}
+
+pub async fn branch_report(
+    req: HttpRequest,
+    query: ValidatedQuery<SalesQuery>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    if !has_permission(model.role_permissions, ModelAction::ViewReports, &state)
+        .await
+        .unwrap_or(false)
+    {
+        return Ok(HttpResponse::Forbidden().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            "Not Permitted To View Reports".to_string(),
+            json!({}),
+        )));
+    }
+
+    match get_branch_sales(query.0.filter(model.organization_id), &state).await {
+        Ok(res) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Branch Sales Fetched Successfully".to_string(),
+            json!(res),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Error Retrieving: {}", e),
+            json!([]),
+        ))),
+    }
+}
+
+pub async fn performer_report(
+    req: HttpRequest,
+    query: ValidatedQuery<SalesQuery>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    if !has_permission(model.role_permissions, ModelAction::ViewReports, &state)
+        .await
+        .unwrap_or(false)
+    {
+        return Ok(HttpResponse::Forbidden().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            "Not Permitted To View Reports".to_string(),
+            json!({}),
+        )));
+    }
+
+    let rank_by = query
+        .0
+        .rank_by
+        .as_deref()
+        .and_then(|rank_by| rank_by.parse().ok())
+        .unwrap_or(RankBy::Revenue);
+    let limit = query.0.limit.unwrap_or(DEFAULT_PERFORMERS);
+
+    match get_performers(
+        query.0.filter(model.organization_id),
+        rank_by,
+        limit,
+        &state,
+    )
+    .await
+    {
+        Ok(res) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Performers Fetched Successfully".to_string(),
+            json!(res),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Error Retrieving: {}", e),
+            json!({}),
+        ))),
+    }
+}
+
+pub async fn heatmap_report(
+    req: HttpRequest,
+    query: ValidatedQuery<SalesQuery>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    if !has_permission(model.role_permissions, ModelAction::ViewReports, &state)
+        .await
+        .unwrap_or(false)
+    {
+        return Ok(HttpResponse::Forbidden().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            "Not Permitted To View Reports".to_string(),
+            json!({}),
+        )));
+    }
+
+    match get_sales_heatmap(query.0.filter(model.organization_id), &state).await {
+        Ok(res) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Sales Heatmap Fetched Successfully".to_string(),
+            json!(res),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Error Retrieving: {}", e),
+            json!([]),
+        ))),
+    }
+}
+
+pub async fn export_item_report(
+    req: HttpRequest,
+    query: ValidatedQuery<SalesQuery>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    if !has_permission(model.role_permissions, ModelAction::ViewReports, &state)
+        .await
+        .unwrap_or(false)
+    {
+        return Ok(HttpResponse::Forbidden().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            "Not Permitted To View Reports".to_string(),
+            json!({}),
+        )));
+    }
+
+    match get_item_sales(query.0.filter(model.organization_id), &state).await {
+        Ok(res) => Ok(csv_attachment("item-sales", &query.0, item_sales_csv(&res))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Error Exporting: {}", e),
+            json!({}),
+        ))),
+    }
+}
+
+pub async fn export_category_report(
+    req: HttpRequest,
+    query: ValidatedQuery<SalesQuery>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    if !has_permission(model.role_permissions, ModelAction::ViewReports, &state)
+        .await
+        .unwrap_or(false)
+    {
+        return Ok(HttpResponse::Forbidden().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            "Not Permitted To View Reports".to_string(),
+            json!({}),
+        )));
+    }
+
+    match get_category_sales(query.0.filter(model.organization_id), &state).await {
+        Ok(res) => Ok(csv_attachment(
+            "category-sales",
+            &query.0,
+            category_sales_csv(&res),
+        )),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Error Exporting: {}", e),
+            json!({}),
+        ))),
+    }
+}
+
+pub async fn export_branch_report(
+    req: HttpRequest,
+    query: ValidatedQuery<SalesQuery>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    if !has_permission(model.role_permissions, ModelAction::ViewReports, &state)
+        .await
+        .unwrap_or(false)
+    {
+        return Ok(HttpResponse::Forbidden().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            "Not Permitted To View Reports".to_string(),
+            json!({}),
+        )));
+    }
+
+    match get_branch_sales(query.0.filter(model.organization_id), &state).await {
+        Ok(res) => Ok(csv_attachment(
+            "branch-sales",
+            &query.0,
+            branch_sales_csv(&res),
+        )),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Error Exporting: {}", e),
+            json!({}),
+        ))),
+    }
+}
+
+pub async fn export_heatmap_report(
+    req: HttpRequest,
+    query: ValidatedQuery<SalesQuery>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    if !has_permission(model.role_permissions, ModelAction::ViewReports, &state)
+        .await
+        .unwrap_or(false)
+    {
+        return Ok(HttpResponse::Forbidden().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            "Not Permitted To View Reports".to_string(),
+            json!({}),
+        )));
+    }
+
+    match get_sales_heatmap(query.0.filter(model.organization_id), &state).await {
+        Ok(res) => Ok(csv_attachment("sales-heatmap", &query.0, heatmap_csv(&res))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Error Exporting: {}", e),
+            json!({}),
+        ))),
+    }
+}
@@ -0,0 +1,341 @@
+use std::collections::HashMap;
+
+use actix_web::web;
+use sea_orm::{
+    prelude::Decimal, ColumnTrait, Condition, ConnectionTrait, DbErr, EntityTrait, QueryFilter,
+};
+
+use crate::{
+    app::{
+        analytics::{
+            models::model::{
+                BranchSales, CategorySales, HeatmapCell, ItemSales, Performers, PeriodSales,
+                RankBy, SaleLine, SalesFilter,
+            },
+            services::service::{
+                branch_sales, category_sales, item_sales, performers, sales_heatmap,
+            },
+        },
+        categories::services::service::is_within,
+        orders::models::model::{ItemType, OrderStatus},
+        recipes::{dtos::dto::item_recipes, services::service::recipe_cost},
+    },
+    utils::time::{branch_timezone, day_bounds},
+    AppState,
+};
+
+/// The organization's branches the report covers: the one asked for, or all of them.
+async fn report_branches<C: ConnectionTrait>(
+    filter: &SalesFilter,
+    conn: &C,
+) -> Result<HashMap<uuid::Uuid, entity::branches::Model>, DbErr> {
+    let mut condition =
+        Condition::all().add(entity::branches::Column::OrganizationId.eq(filter.organization));
+
+    if let Some(branch) = filter.branch {
+        condition = condition.add(entity::branches::Column::Id.eq(branch));
+    }
+
+    let branches: HashMap<uuid::Uuid, entity::branches::Model> = entity::branches::Entity::find()
+        .filter(condition)
+        .all(conn)
+        .await
+        .map_err(|err| {
+            eprintln!("Database retrieval error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?
+        .into_iter()
+        .map(|branch| (branch.id, branch))
+        .collect();
+
+    if filter.branch.is_some() && branches.is_empty() {
+        return Err(DbErr::RecordNotFound("Branch not found".into()));
+    }
+
+    Ok(branches)
+}
+
+async fn find_drinks<C: ConnectionTrait>(
+    ids: impl IntoIterator<Item = uuid::Uuid>,
+    conn: &C,
+) -> Result<HashMap<uuid::Uuid, entity::stock_drinks::Model>, DbErr> {
+    Ok(entity::stock_drinks::Entity::find()
+        .filter(entity::stock_drinks::Column::Id.is_in(ids))
+        .all(conn)
+        .await
+        .map_err(|err| {
+            eprintln!("Database retrieval error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?
+        .into_iter()
+        .map(|drink| (drink.id, drink))
+        .collect())
+}
+
+/// Lines of the completed orders placed in the period, ends included and each branch's days read
+/// on its own clock, at what they sold for after promo discounts and what they cost to make
+/// when sold. Lines sold before costs were kept are costed at today's purchase prices: the
+/// recipe's components for items sold by recipe, the drink's unit price for drinks sold as
+/// they are. Other items have no cost on record.
+async fn period_sales<C: ConnectionTrait>(
+    filter: &SalesFilter,
+    conn: &C,
+) -> Result<PeriodSales, DbErr> {
+    if filter.period_end < filter.period_start {
+        return Err(DbErr::Custom(
+            "Period must end on or after its start".to_string(),
+        ));
+    }
+
+    let branches = report_branches(filter, conn).await?;
+
+    // Each branch's period runs on its own clock
+    let period = branches
+        .values()
+        .fold(Condition::any(), |condition, branch| {
+            let (from, to) = day_bounds(
+                filter.period_start,
+                filter.period_end,
+                branch_timezone(branch),
+            );
+
+            condition.add(
+                Condition::all()
+                    .add(entity::orders::Column::BranchId.eq(branch.id))
+                    .add(entity::orders::Column::CreatedAt.gte(from))
+                    .add(entity::orders::Column::CreatedAt.lt(to)),
+            )
+        });
+
+    let rows = entity::order_items::Entity::find()
+        .find_also_related(entity::orders::Entity)
+        .filter(
+            Condition::all()
+                .add(entity::orders::Column::OrganizationId.eq(filter.organization))
+                .add(entity::orders::Column::OrderStatus.eq(OrderStatus::Completed.as_str()))
+                .add(period),
+        )
+        .all(conn)
+        .await
+        .map_err(|err| {
+            eprintln!("Database retrieval error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    let drinks = find_drinks(
+        rows.iter().filter_map(|(item, _)| item.stock_drink_id),
+        conn,
+    )
+    .await?;
+    let foods: HashMap<uuid::Uuid, entity::stock_foods::Model> =
+        entity::stock_foods::Entity::find()
+            .filter(
+                entity::stock_foods::Column::Id
+                    .is_in(rows.iter().filter_map(|(item, _)| item.stock_food_id)),
+            )
+            .all(conn)
+            .await
+            .map_err(|err| {
+                eprintln!("Database retrieval error: {}", err);
+                DbErr::Custom(err.to_string())
+            })?
+            .into_iter()
+            .map(|food| (food.id, food))
+            .collect();
+    let cocktails: HashMap<uuid::Uuid, entity::custom_cocktails::Model> =
+        entity::custom_cocktails::Entity::find()
+            .filter(
+                entity::custom_cocktails::Column::Id
+                    .is_in(rows.iter().filter_map(|(item, _)| item.custom_cocktail_id)),
+            )
+            .all(conn)
+            .await
+            .map_err(|err| {
+                eprintln!("Database retrieval error: {}", err);
+                DbErr::Custom(err.to_string())
+            })?
+            .into_iter()
+            .map(|cocktail| (cocktail.id, cocktail))
+            .collect();
+
+    let keys: Vec<(ItemType, uuid::Uuid)> = drinks
+        .keys()
+        .map(|drink| (ItemType::Drink, *drink))
+        .chain(foods.keys().map(|food| (ItemType::Food, *food)))
+        .chain(
+            cocktails
+                .keys()
+                .map(|cocktail| (ItemType::Cocktail, *cocktail)),
+        )
+        .collect();
+    let recipes = item_recipes(&keys, conn).await?;
+    let components = find_drinks(
+        recipes.values().flatten().map(|line| line.component_id),
+        conn,
+    )
+    .await?;
+
+    let categories: HashMap<uuid::Uuid, entity::categories::Model> =
+        entity::categories::Entity::find()
+            .filter(
+                Condition::any()
+                    .add(entity::categories::Column::OrganizationId.is_null())
+                    .add(entity::categories::Column::OrganizationId.eq(filter.organization)),
+            )
+            .all(conn)
+            .await
+            .map_err(|err| {
+                eprintln!("Database retrieval error: {}", err);
+                DbErr::Custom(err.to_string())
+            })?
+            .into_iter()
+            .map(|category| (category.id, category))
+            .collect();
+
+    let mut lines = Vec::with_capacity(rows.len());
+
+    for (item, order) in rows {
+        let Some(order) = order else {
+            continue;
+        };
+        let Ok(item_type) = item.item_type.parse::<ItemType>() else {
+            continue;
+        };
+
+        let id = item
+            .stock_drink_id
+            .or(item.stock_food_id)
+            .or(item.custom_cocktail_id);
+
+        let (name, category, unit_cost) = match item_type {
+            ItemType::Drink => {
+                let drink = id.and_then(|id| drinks.get(&id));
+
+                (
+                    drink.map(|drink| drink.name.clone()),
+                    drink.map(|drink| drink.category_id),
+                    drink.map(|drink| drink.unit_price),
+                )
+            }
+            ItemType::Food => {
+                let food = id.and_then(|id| foods.get(&id));
+
+                (
+                    food.map(|food| food.name.clone()),
+                    food.map(|food| food.category_id),
+                    None,
+                )
+            }
+            ItemType::Cocktail => {
+                let cocktail = id.and_then(|id| cocktails.get(&id));
+
+                (
+                    cocktail.map(|cocktail| cocktail.name.clone()),
+                    cocktail.and_then(|cocktail| cocktail.category_id),
+                    None,
+                )
+            }
+        };
+
+        let recipe = id.and_then(|id| recipes.get(&id));
+        let unit_cost = match item.unit_cost {
+            Some(cost) => Some(cost),
+            None if item.has_recipe => recipe.map(|recipe| recipe_cost(recipe, &components)),
+            None => unit_cost,
+        };
+
+        if let Some(wanted) = filter.category {
+            if !category.is_some_and(|category| is_within(category, wanted, &categories)) {
+                continue;
+            }
+        }
+
+        let quantity = Decimal::from(item.quantity);
+
+        lines.push(SaleLine {
+            order: order.id,
+            branch: order.branch_id,
+            item_type,
+            item: id,
+            name: item.item_name.or(name).unwrap_or_default(),
+            category,
+            quantity: item.quantity,
+            revenue: item.price * quantity - item.discount_amount,
+            cost: unit_cost.unwrap_or_default() * quantity,
+            sold_at: branches
+                .get(&order.branch_id)
+                .map(|branch| {
+                    order
+                        .created_at
+                        .with_timezone(&branch_timezone(branch))
+                        .naive_local()
+                })
+                .unwrap_or_else(|| order.created_at.naive_utc()),
+        });
+    }
+
+    // Only drinks sold as they are have their own shelf stock
+    let on_hand = drinks
+        .values()
+        .filter(|drink| !recipes.contains_key(&drink.id))
+        .map(|drink| (drink.id, drink.quantity))
+        .collect();
+
+    Ok(PeriodSales {
+        lines,
+        on_hand,
+        categories,
+        branches,
+    })
+}
+
+pub async fn get_item_sales(
+    filter: SalesFilter,
+    state: &web::Data<AppState>,
+) -> Result<Vec<ItemSales>, DbErr> {
+    let sales = period_sales(&filter, state.pg_db.get_ref()).await?;
+
+    Ok(item_sales(&sales.lines, &sales.on_hand))
+}
+
+pub async fn get_category_sales(
+    filter: SalesFilter,
+    state: &web::Data<AppState>,
+) -> Result<Vec<CategorySales>, DbErr> {
+    let sales = period_sales(&filter, state.pg_db.get_ref()).await?;
+
+    Ok(category_sales(&sales.lines, &sales.categories))
+}
+
+pub async fn get_branch_sales(
+    filter: SalesFilter,
+    state: &web::Data<AppState>,
+) -> Result<Vec<BranchSales>, DbErr> {
+    let sales = period_sales(&filter, state.pg_db.get_ref()).await?;
+
+    Ok(branch_sales(&sales.lines, &sales.branches))
+}
+
+pub async fn get_performers(
+    filter: SalesFilter,
+    rank_by: RankBy,
+    limit: usize,
+    state: &web::Data<AppState>,
+) -> Result<Performers, DbErr> {
+    let sales = period_sales(&filter, state.pg_db.get_ref()).await?;
+
+    Ok(performers(
+        item_sales(&sales.lines, &sales.on_hand),
+        rank_by,
+        limit,
+    ))
+}
+
+pub async fn get_sales_heatmap(
+    filter: SalesFilter,
+    state: &web::Data<AppState>,
+) -> Result<Vec<HeatmapCell>, DbErr> {
+    let sales = period_sales(&filter, state.pg_db.get_ref()).await?;
+
+    Ok(sales_heatmap(&sales.lines))
+}
@@ -0,0 +1,157 @@
+use std::{collections::HashMap, str::FromStr};
+
+use chrono::{NaiveDate, NaiveDateTime};
+use sea_orm::prelude::Decimal;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::{
+    app::orders::models::model::ItemType,
+    libs::validator::{validate_naive_date_rest, validate_rank_by},
+};
+
+/// What performers are ranked by.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum RankBy {
+    Revenue,
+    Margin,
+    Units,
+}
+
+impl FromStr for RankBy {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_uppercase().as_str() {
+            "REVENUE" => Ok(RankBy::Revenue),
+            "MARGIN" => Ok(RankBy::Margin),
+            "UNITS" => Ok(RankBy::Units),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SalesFilter {
+    pub organization: uuid::Uuid,
+    pub branch: Option<uuid::Uuid>,
+    pub category: Option<uuid::Uuid>,
+    pub period_start: NaiveDate,
+    pub period_end: NaiveDate,
+}
+
+/// Sales of completed orders in the period, ends included. Without a `branch` every branch of
+/// the organization is covered, and a `category` covers its subcategories too.
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct SalesQuery {
+    #[validate(custom(function = "validate_naive_date_rest"))]
+    pub period_start: NaiveDate,
+    #[validate(custom(function = "validate_naive_date_rest"))]
+    pub period_end: NaiveDate,
+    pub branch: Option<uuid::Uuid>,
+    pub category: Option<uuid::Uuid>,
+    #[validate(custom(function = "validate_rank_by"))]
+    pub rank_by: Option<String>,
+    #[validate(range(min = 1, max = 100, message = "Limit is invalid"))]
+    pub limit: Option<usize>,
+}
+
+impl SalesQuery {
+    pub fn filter(&self, organization: uuid::Uuid) -> SalesFilter {
+        SalesFilter {
+            organization,
+            branch: self.branch,
+            category: self.category,
+            period_start: self.period_start,
+            period_end: self.period_end,
+        }
+    }
+}
+
+/// One line of a completed order, with what it sold for after promo discounts and what it cost
+/// to make. Items since deleted have no `item` and are told apart by name.
+#[derive(Debug, Clone)]
+pub struct SaleLine {
+    pub order: uuid::Uuid,
+    pub branch: uuid::Uuid,
+    pub item_type: ItemType,
+    pub item: Option<uuid::Uuid>,
+    pub name: String,
+    pub category: Option<uuid::Uuid>,
+    pub quantity: i32,
+    pub revenue: Decimal,
+    pub cost: Decimal,
+    /// When the order was placed, on the branch's clock.
+    pub sold_at: NaiveDateTime,
+}
+
+/// Sales totals. Revenue is what items sold for after promo discounts, before taxes and tips,
+/// cost of goods what they cost to make when sold.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SalesTotals {
+    pub units_sold: i64,
+    pub revenue: Decimal,
+    pub cost_of_goods: Decimal,
+    pub gross_margin: Decimal,
+    pub margin_percent: Decimal,
+}
+
+/// An item's sales. `sell_through` is the share of the drink's stock sold in the period, out
+/// of what was sold and what is left on the shelf. Items made by recipe have no stock of their
+/// own and no sell-through.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ItemSales {
+    pub branch: uuid::Uuid,
+    pub item_type: ItemType,
+    pub item: Option<uuid::Uuid>,
+    pub name: String,
+    pub category: Option<uuid::Uuid>,
+    #[serde(flatten)]
+    pub totals: SalesTotals,
+    pub units_on_hand: Option<i32>,
+    pub sell_through: Option<Decimal>,
+}
+
+/// The period's sale lines with what the reports need to name and weigh them.
+#[derive(Debug, Clone)]
+pub struct PeriodSales {
+    pub lines: Vec<SaleLine>,
+    pub on_hand: HashMap<uuid::Uuid, i32>,
+    pub categories: HashMap<uuid::Uuid, entity::categories::Model>,
+    pub branches: HashMap<uuid::Uuid, entity::branches::Model>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CategorySales {
+    pub category: Option<uuid::Uuid>,
+    pub name: String,
+    #[serde(flatten)]
+    pub totals: SalesTotals,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BranchSales {
+    pub branch: uuid::Uuid,
+    pub name: String,
+    #[serde(flatten)]
+    pub totals: SalesTotals,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Performers {
+    pub rank_by: RankBy,
+    pub top: Vec<ItemSales>,
+    pub bottom: Vec<ItemSales>,
+}
+
+/// Sales in one hour of one weekday, on the venue's clock. Weekdays run from 1 for Monday.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct HeatmapCell {
+    pub weekday: u32,
+    pub hour: u32,
+    pub orders: i64,
+    pub units_sold: i64,
+    pub revenue: Decimal,
+}
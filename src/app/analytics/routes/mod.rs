@@ -0,0 +1 @@
+pub mod route;
\ No newline at end of file
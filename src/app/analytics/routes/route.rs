@@ -0,0 +1,79 @@
+use actix_web::web;
+
+use crate::{
+    app::analytics::controllers::controller::{
+        branch_report, category_report, export_branch_report, export_category_report,
+        export_heatmap_report, export_item_report, heatmap_report, item_report, performer_report,
+    },
+    middlewares::{auth::JwtAuthMiddleware, checker::CheckUserMiddleware},
+    AppState,
+};
+
+pub fn routes(cfg: &mut web::ServiceConfig, state: web::Data<AppState>) {
+    cfg.service(
+        web::scope("/api/v1/analytics")
+            .route(
+                "/items",
+                web::get()
+                    .to(item_report)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/items/export",
+                web::get()
+                    .to(export_item_report)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/categories",
+                web::get()
+                    .to(category_report)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/categories/export",
+                web::get()
+                    .to(export_category_report)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/branches",
+                web::get()
+                    .to(branch_report)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/branches/export",
+                web::get()
+                    .to(export_branch_report)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/performers",
+                web::get()
+                    .to(performer_report)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/heatmap",
+                web::get()
+                    .to(heatmap_report)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/heatmap/export",
+                web::get()
+                    .to(export_heatmap_report)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            ),
+    );
+}
@@ -0,0 +1,418 @@
+use std::collections::{HashMap, HashSet};
+
+use chrono::{Datelike, Timelike};
+use sea_orm::prelude::Decimal;
+
+use crate::{
+    app::analytics::models::model::{
+        BranchSales, CategorySales, HeatmapCell, ItemSales, Performers, RankBy, SaleLine,
+        SalesTotals,
+    },
+    utils::csv::csv_document,
+};
+
+impl SalesTotals {
+    fn add(&mut self, line: &SaleLine) {
+        self.units_sold += i64::from(line.quantity);
+        self.revenue += line.revenue;
+        self.cost_of_goods += line.cost;
+    }
+
+    /// Works out the margin once every line is added.
+    fn finish(mut self) -> Self {
+        self.gross_margin = self.revenue - self.cost_of_goods;
+        self.margin_percent = if self.revenue > Decimal::ZERO {
+            (self.gross_margin / self.revenue * Decimal::from(100)).round_dp(2)
+        } else {
+            Decimal::ZERO
+        };
+
+        self
+    }
+}
+
+/// Sales of each item, best selling by revenue first. `on_hand` is the shelf stock of drinks
+/// sold as they are.
+pub fn item_sales(lines: &[SaleLine], on_hand: &HashMap<uuid::Uuid, i32>) -> Vec<ItemSales> {
+    let mut items: HashMap<(uuid::Uuid, Option<uuid::Uuid>, &str), ItemSales> = HashMap::new();
+
+    for line in lines {
+        // Deleted items are kept apart by name
+        let name = if line.item.is_some() { "" } else { &line.name };
+
+        items
+            .entry((line.branch, line.item, name))
+            .or_insert_with(|| ItemSales {
+                branch: line.branch,
+                item_type: line.item_type,
+                item: line.item,
+                name: line.name.clone(),
+                category: line.category,
+                totals: SalesTotals::default(),
+                units_on_hand: line.item.and_then(|item| on_hand.get(&item).copied()),
+                sell_through: None,
+            })
+            .totals
+            .add(line);
+    }
+
+    let mut items: Vec<ItemSales> = items
+        .into_values()
+        .map(|mut item| {
+            item.totals = item.totals.finish();
+            item.sell_through = item.units_on_hand.and_then(|left| {
+                let stocked = item.totals.units_sold + i64::from(left.max(0));
+
+                (stocked > 0).then(|| {
+                    (Decimal::from(item.totals.units_sold) / Decimal::from(stocked)
+                        * Decimal::from(100))
+                    .round_dp(2)
+                })
+            });
+            item
+        })
+        .collect();
+
+    items.sort_by(|a, b| {
+        b.totals
+            .revenue
+            .cmp(&a.totals.revenue)
+            .then_with(|| a.name.cmp(&b.name))
+    });
+
+    items
+}
+
+/// Sales of each category items were filed under, best selling by revenue first.
+pub fn category_sales(
+    lines: &[SaleLine],
+    categories: &HashMap<uuid::Uuid, entity::categories::Model>,
+) -> Vec<CategorySales> {
+    let mut totals: HashMap<Option<uuid::Uuid>, SalesTotals> = HashMap::new();
+
+    for line in lines {
+        totals.entry(line.category).or_default().add(line);
+    }
+
+    let mut rows: Vec<CategorySales> = totals
+        .into_iter()
+        .map(|(category, totals)| CategorySales {
+            category,
+            name: category
+                .and_then(|category| categories.get(&category))
+                .map(|category| category.name.clone())
+                .unwrap_or_else(|| "Uncategorised".to_string()),
+            totals: totals.finish(),
+        })
+        .collect();
+
+    rows.sort_by(|a, b| {
+        b.totals
+            .revenue
+            .cmp(&a.totals.revenue)
+            .then_with(|| a.name.cmp(&b.name))
+    });
+
+    rows
+}
+
+/// Sales of each branch, best selling by revenue first.
+pub fn branch_sales(
+    lines: &[SaleLine],
+    branches: &HashMap<uuid::Uuid, entity::branches::Model>,
+) -> Vec<BranchSales> {
+    let mut totals: HashMap<uuid::Uuid, SalesTotals> = HashMap::new();
+
+    for line in lines {
+        totals.entry(line.branch).or_default().add(line);
+    }
+
+    let mut rows: Vec<BranchSales> = totals
+        .into_iter()
+        .map(|(branch, totals)| BranchSales {
+            branch,
+            name: branches
+                .get(&branch)
+                .map(|branch| branch.name.clone())
+                .unwrap_or_default(),
+            totals: totals.finish(),
+        })
+        .collect();
+
+    rows.sort_by(|a, b| {
+        b.totals
+            .revenue
+            .cmp(&a.totals.revenue)
+            .then_with(|| a.name.cmp(&b.name))
+    });
+
+    rows
+}
+
+/// The `limit` best and worst items by `rank_by`. An item sold in the period is never among
+/// both unless fewer than twice `limit` items sold.
+pub fn performers(mut items: Vec<ItemSales>, rank_by: RankBy, limit: usize) -> Performers {
+    items.sort_by(|a, b| {
+        let order = match rank_by {
+            RankBy::Revenue => b.totals.revenue.cmp(&a.totals.revenue),
+            RankBy::Margin => b.totals.gross_margin.cmp(&a.totals.gross_margin),
+            RankBy::Units => b.totals.units_sold.cmp(&a.totals.units_sold),
+        };
+
+        order.then_with(|| a.name.cmp(&b.name))
+    });
+
+    let top: Vec<ItemSales> = items.iter().take(limit).cloned().collect();
+    let bottom: Vec<ItemSales> = items.iter().rev().take(limit).cloned().collect();
+
+    Performers {
+        rank_by,
+        top,
+        bottom,
+    }
+}
+
+/// Sales by weekday and hour on the selling branch's clock, every hour of the week included.
+pub fn sales_heatmap(lines: &[SaleLine]) -> Vec<HeatmapCell> {
+    let mut cells: Vec<HeatmapCell> = (1..=7)
+        .flat_map(|weekday| {
+            (0..24).map(move |hour| HeatmapCell {
+                weekday,
+                hour,
+                ..Default::default()
+            })
+        })
+        .collect();
+    let mut orders: HashSet<(usize, uuid::Uuid)> = HashSet::new();
+
+    for line in lines {
+        let at = line.sold_at;
+        let index = (at.weekday().number_from_monday() as usize - 1) * 24 + at.hour() as usize;
+        let cell = &mut cells[index];
+
+        cell.units_sold += i64::from(line.quantity);
+        cell.revenue += line.revenue;
+
+        if orders.insert((index, line.order)) {
+            cell.orders += 1;
+        }
+    }
+
+    cells
+}
+
+fn totals_fields(totals: &SalesTotals) -> Vec<String> {
+    vec![
+        totals.units_sold.to_string(),
+        totals.revenue.to_string(),
+        totals.cost_of_goods.to_string(),
+        totals.gross_margin.to_string(),
+        totals.margin_percent.to_string(),
+    ]
+}
+
+const TOTALS_HEADER: [&str; 5] = [
+    "units_sold",
+    "revenue",
+    "cost_of_goods",
+    "gross_margin",
+    "margin_percent",
+];
+
+pub fn item_sales_csv(items: &[ItemSales]) -> String {
+    let header: Vec<&str> = ["branch", "item_type", "item", "name", "category"]
+        .into_iter()
+        .chain(TOTALS_HEADER)
+        .chain(["units_on_hand", "sell_through"])
+        .collect();
+
+    csv_document(
+        &header,
+        items.iter().map(|item| {
+            let mut row = vec![
+                item.branch.to_string(),
+                item.item_type.as_str().to_string(),
+                item.item.map(|i| i.to_string()).unwrap_or_default(),
+                item.name.clone(),
+                item.category.map(|c| c.to_string()).unwrap_or_default(),
+            ];
+
+            row.extend(totals_fields(&item.totals));
+            row.push(
+                item.units_on_hand
+                    .map(|n| n.to_string())
+                    .unwrap_or_default(),
+            );
+            row.push(item.sell_through.map(|n| n.to_string()).unwrap_or_default());
+            row
+        }),
+    )
+}
+
+pub fn category_sales_csv(categories: &[CategorySales]) -> String {
+    let header: Vec<&str> = ["category", "name"]
+        .into_iter()
+        .chain(TOTALS_HEADER)
+        .collect();
+
+    csv_document(
+        &header,
+        categories.iter().map(|category| {
+            let mut row = vec![
+                category.category.map(|c| c.to_string()).unwrap_or_default(),
+                category.name.clone(),
+            ];
+
+            row.extend(totals_fields(&category.totals));
+            row
+        }),
+    )
+}
+
+pub fn branch_sales_csv(branches: &[BranchSales]) -> String {
+    let header: Vec<&str> = ["branch", "name"]
+        .into_iter()
+        .chain(TOTALS_HEADER)
+        .collect();
+
+    csv_document(
+        &header,
+        branches.iter().map(|branch| {
+            let mut row = vec![branch.branch.to_string(), branch.name.clone()];
+
+            row.extend(totals_fields(&branch.totals));
+            row
+        }),
+    )
+}
+
+pub fn heatmap_csv(cells: &[HeatmapCell]) -> String {
+    csv_document(
+        &["weekday", "hour", "orders", "units_sold", "revenue"],
+        cells.iter().map(|cell| {
+            vec![
+                cell.weekday.to_string(),
+                cell.hour.to_string(),
+                cell.orders.to_string(),
+                cell.units_sold.to_string(),
+                cell.revenue.to_string(),
+            ]
+        }),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveDate, NaiveDateTime};
+
+    use super::*;
+    use crate::{app::orders::models::model::ItemType, utils::fixtures::dec};
+
+    /// A line sold on `day` of January 2024, which started on a Monday.
+    fn sold(
+        item: Option<uuid::Uuid>,
+        name: &str,
+        quantity: i32,
+        revenue: &str,
+        cost: &str,
+        day: u32,
+        hour: u32,
+    ) -> SaleLine {
+        SaleLine {
+            order: uuid::Uuid::new_v4(),
+            branch: uuid::Uuid::nil(),
+            item_type: ItemType::Drink,
+            item,
+            name: name.to_string(),
+            category: None,
+            quantity,
+            revenue: dec(revenue),
+            cost: dec(cost),
+            sold_at: at(day, hour),
+        }
+    }
+
+    fn at(day: u32, hour: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2024, 1, day)
+            .and_then(|date| date.and_hms_opt(hour, 15, 0))
+            .unwrap()
+    }
+
+    #[test]
+    fn totals_each_item_best_selling_first() {
+        let (beer, wine) = (uuid::Uuid::new_v4(), uuid::Uuid::new_v4());
+        let lines = [
+            sold(Some(beer), "Beer", 2, "10", "4", 1, 20),
+            sold(Some(wine), "Wine", 1, "30", "12", 1, 21),
+            sold(Some(beer), "Beer", 3, "15", "6", 2, 20),
+        ];
+
+        let items = item_sales(&lines, &HashMap::from([(beer, 15)]));
+
+        assert_eq!(items[0].name, "Wine");
+        assert_eq!(items[0].sell_through, None);
+        assert_eq!(items[1].totals.units_sold, 5);
+        assert_eq!(items[1].totals.revenue, dec("25"));
+        assert_eq!(items[1].totals.gross_margin, dec("15"));
+        assert_eq!(items[1].totals.margin_percent, dec("60"));
+        assert_eq!(items[1].units_on_hand, Some(15));
+        assert_eq!(items[1].sell_through, Some(dec("25")));
+    }
+
+    #[test]
+    fn keeps_deleted_items_apart_by_name() {
+        let lines = [
+            sold(None, "Old Beer", 1, "5", "2", 1, 20),
+            sold(None, "Old Wine", 1, "8", "3", 1, 20),
+            sold(None, "Old Beer", 1, "5", "2", 1, 20),
+        ];
+
+        let items = item_sales(&lines, &HashMap::new());
+
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].name, "Old Beer");
+        assert_eq!(items[0].totals.units_sold, 2);
+    }
+
+    #[test]
+    fn has_no_margin_without_revenue() {
+        let lines = [sold(None, "Comp", 1, "0", "2", 1, 20)];
+
+        let items = item_sales(&lines, &HashMap::new());
+
+        assert_eq!(items[0].totals.gross_margin, dec("-2"));
+        assert_eq!(items[0].totals.margin_percent, Decimal::ZERO);
+    }
+
+    #[test]
+    fn fills_every_hour_of_the_week() {
+        let cells = sales_heatmap(&[]);
+
+        assert_eq!(cells.len(), 7 * 24);
+        assert_eq!((cells[0].weekday, cells[0].hour), (1, 0));
+        assert_eq!((cells[167].weekday, cells[167].hour), (7, 23));
+    }
+
+    #[test]
+    fn buckets_sales_by_weekday_and_hour() {
+        let mut lines = vec![
+            sold(None, "Beer", 2, "10", "4", 3, 21),
+            sold(None, "Wine", 1, "30", "12", 7, 0),
+        ];
+        lines.push(SaleLine {
+            name: "Wine".to_string(),
+            ..lines[0].clone()
+        });
+
+        let cells = sales_heatmap(&lines);
+        let wednesday = &cells[2 * 24 + 21];
+        let sunday = &cells[6 * 24];
+
+        assert_eq!((wednesday.weekday, wednesday.hour), (3, 21));
+        assert_eq!(wednesday.orders, 1);
+        assert_eq!(wednesday.units_sold, 4);
+        assert_eq!(wednesday.revenue, dec("20"));
+        assert_eq!(sunday.orders, 1);
+        assert_eq!(sunday.revenue, dec("30"));
+    }
+}
@@ -0,0 +1,80 @@
+use std::sync::Arc;
+
+use actix_web::{web, HttpMessage, HttpRequest, HttpResponse};
+use serde_json::json;
+
+use crate::{
+    app::{
+        approvals::{
+            dtos::dto::{get_organization_transitions, get_transitions},
+            models::model::ApprovalLogQuery,
+        },
+        employees::models::model::EmployeeResponse,
+    },
+    libs::error::Error,
+    utils::{
+        json_validator::{ValidatedPath, ValidatedQuery},
+        models::{HttpClientResponse, PathParamsModel, ResponseCode},
+    },
+    AppState,
+};
+
+pub async fn approval_history(
+    req: HttpRequest,
+    params: ValidatedPath<PathParamsModel>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    match get_transitions(params.0.id, model.organization_id, &state).await {
+        Ok(logs) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Approval History Fetched Successfully".to_string(),
+            json!(logs),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Approval history not found: {}", e),
+            json!({}),
+        ))),
+    }
+}
+
+pub async fn approval_log(
+    req: HttpRequest,
+    query: ValidatedQuery<ApprovalLogQuery>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    let status = query.0.status.map(|status| status.to_uppercase());
+
+    match get_organization_transitions(model.organization_id, status, &state).await {
+        Ok(logs) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Approval Log Fetched Successfully".to_string(),
+            json!(logs),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Approval log not found: {}", e),
+            json!({}),
+        ))),
+    }
+}
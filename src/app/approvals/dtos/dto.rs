@@ -0,0 +1,79 @@
+use actix_web::web;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, Condition, ConnectionTrait, DbErr, EntityTrait, QueryFilter,
+    QueryOrder, Set,
+};
+
+use crate::{app::approvals::models::model::ApprovalTransitionDto, AppState};
+
+/// Records one step of the approval workflow. Pass the caller's open transaction so the log
+/// entry lands together with the status change it describes.
+pub async fn log_transition<C: ConnectionTrait>(
+    data: ApprovalTransitionDto,
+    conn: &C,
+) -> Result<(), DbErr> {
+    let log = entity::approval_logs::ActiveModel {
+        organization_id: Set(data.organization),
+        entity_type: Set(data.entity.as_str().to_string()),
+        entity_id: Set(data.entity_id),
+        from_status: Set(data.from.map(|status| status.as_str().to_string())),
+        to_status: Set(data.to.as_str().to_string()),
+        comment: Set(data.comment),
+        actor_id: Set(Some(data.actor)),
+        ..Default::default()
+    };
+
+    ActiveModelTrait::insert(log, conn).await.map_err(|err| {
+        eprintln!("Database insert error: {}", err);
+        DbErr::Custom(err.to_string())
+    })?;
+
+    Ok(())
+}
+
+pub async fn get_transitions(
+    entity_id: uuid::Uuid,
+    organization: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<Vec<entity::approval_logs::Model>, DbErr> {
+    let logs = entity::approval_logs::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::approval_logs::Column::EntityId.eq(entity_id))
+                .add(entity::approval_logs::Column::OrganizationId.eq(organization)),
+        )
+        .order_by_asc(entity::approval_logs::Column::CreatedAt)
+        .all(state.pg_db.get_ref())
+        .await
+        .map_err(|err| {
+            eprintln!("Database retrieval error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    Ok(logs)
+}
+
+pub async fn get_organization_transitions(
+    organization: uuid::Uuid,
+    status: Option<String>,
+    state: &web::Data<AppState>,
+) -> Result<Vec<entity::approval_logs::Model>, DbErr> {
+    let mut condition =
+        Condition::all().add(entity::approval_logs::Column::OrganizationId.eq(organization));
+
+    if let Some(status) = status {
+        condition = condition.add(entity::approval_logs::Column::ToStatus.eq(status));
+    }
+
+    let logs = entity::approval_logs::Entity::find()
+        .filter(condition)
+        .order_by_desc(entity::approval_logs::Column::CreatedAt)
+        .all(state.pg_db.get_ref())
+        .await
+        .map_err(|err| {
+            eprintln!("Database retrieval error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    Ok(logs)
+}
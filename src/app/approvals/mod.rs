@@ -0,0 +1,4 @@
+pub mod models;
+pub mod dtos;
+pub mod controllers;
+pub mod routes;
\ No newline at end of file
@@ -0,0 +1,88 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::libs::validator::{validate_approval_decision, validate_approval_status};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum ApprovalStatus {
+    Pending,
+    Approved,
+    Rejected,
+}
+
+impl ApprovalStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ApprovalStatus::Pending => "PENDING",
+            ApprovalStatus::Approved => "APPROVED",
+            ApprovalStatus::Rejected => "REJECTED",
+        }
+    }
+}
+
+impl FromStr for ApprovalStatus {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_uppercase().as_str() {
+            "PENDING" => Ok(ApprovalStatus::Pending),
+            "APPROVED" => Ok(ApprovalStatus::Approved),
+            "REJECTED" => Ok(ApprovalStatus::Rejected),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ApprovalEntity {
+    Ticket,
+    TicketEvent,
+}
+
+impl ApprovalEntity {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ApprovalEntity::Ticket => "TICKET",
+            ApprovalEntity::TicketEvent => "TICKET_EVENT",
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ApprovalTransitionDto {
+    pub organization: uuid::Uuid,
+    pub entity: ApprovalEntity,
+    pub entity_id: uuid::Uuid,
+    pub from: Option<ApprovalStatus>,
+    pub to: ApprovalStatus,
+    pub actor: uuid::Uuid,
+    pub comment: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ReviewDto {
+    pub organization: uuid::Uuid,
+    pub reviewer: uuid::Uuid,
+    pub decision: ApprovalStatus,
+    pub comment: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct ReviewParams {
+    #[validate(custom(function = "validate_approval_decision"))]
+    pub decision: String,
+    #[validate(length(min = 3, max = 300, message = "Comment is invalid"))]
+    pub comment: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct ApprovalLogQuery {
+    #[validate(custom(function = "validate_approval_status"))]
+    pub status: Option<String>,
+}
@@ -0,0 +1,27 @@
+use actix_web::web;
+
+use crate::{
+    app::approvals::controllers::controller::{approval_history, approval_log},
+    middlewares::{auth::JwtAuthMiddleware, checker::CheckUserMiddleware},
+    AppState,
+};
+
+pub fn routes(cfg: &mut web::ServiceConfig, state: web::Data<AppState>) {
+    cfg.service(
+        web::scope("/api/v1/approvals")
+            .route(
+                "/history/{id}",
+                web::get()
+                    .to(approval_history)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/log",
+                web::get()
+                    .to(approval_log)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            ),
+    );
+}
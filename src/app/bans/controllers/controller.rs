@@ -0,0 +1,278 @@
+use std::{str::FromStr, sync::Arc};
+
+use actix_web::{web, HttpMessage, HttpRequest, HttpResponse};
+use serde_json::json;
+
+use crate::{
+    app::{
+        bans::{
+            dtos::dto::{
+                get_customer_ban_history, get_organization_customer_bans, get_shared_bans,
+                lift_ban, save_ban,
+            },
+            models::model::{
+                AddBanDto, AddBanParams, BanDurationType, BanScope, LiftBanDto, LiftBanParams,
+            },
+        },
+        customers::{
+            dtos::dto::{get_customer_by_id, get_customer_full},
+            models::model::CustomerResponse,
+        },
+        employees::models::model::EmployeeResponse,
+    },
+    libs::error::Error,
+    utils::{
+        json_validator::{ValidatedJson, ValidatedPath},
+        models::{HttpClientResponse, PathParamsModel, ResponseCode},
+    },
+    AppState,
+};
+
+pub async fn create_ban(
+    req: HttpRequest,
+    payload: ValidatedJson<AddBanParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    let data = payload.0;
+
+    let duration_type =
+        BanDurationType::from_str(&data.duration_type).unwrap_or(BanDurationType::Permanent);
+
+    if duration_type != BanDurationType::Permanent && data.duration.is_none() {
+        return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            "Duration is required for a temporary ban".to_string(),
+            json!({}),
+        )));
+    }
+
+    if let Err(e) = get_customer_by_id(data.customer, &state).await {
+        return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Could not find customer: {}", e),
+            json!({}),
+        )));
+    }
+
+    let branch = match BanScope::from_str(&data.scope) {
+        Ok(BanScope::Branch) => Some(model.branch_id),
+        _ => None,
+    };
+
+    let ban = AddBanDto {
+        customer: data.customer,
+        organization: model.organization_id,
+        branch,
+        banned_by: model.id,
+        reason: data.reason,
+        duration: match duration_type {
+            BanDurationType::Permanent => None,
+            _ => data.duration,
+        },
+        duration_type,
+        is_shared: data.is_shared.unwrap_or(false),
+    };
+
+    match save_ban(ban, &state).await {
+        Ok(res) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Customer Banned Successfully".to_string(),
+            json!(res),
+        ))),
+        Err(e) => Ok(
+            HttpResponse::InternalServerError().json(HttpClientResponse::new(
+                ResponseCode::Failed,
+                format!("Error Banning Customer: {}", e),
+                json!({}),
+            )),
+        ),
+    }
+}
+
+pub async fn remove_ban(
+    req: HttpRequest,
+    params: ValidatedPath<PathParamsModel>,
+    payload: ValidatedJson<LiftBanParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    let data = LiftBanDto {
+        organization: model.organization_id,
+        lifted_by: model.id,
+        reason: payload.0.reason,
+    };
+
+    match lift_ban(params.0.id, data, &state).await {
+        Ok(_) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Ban Lifted Successfully".to_string(),
+            json!({}),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Could not lift ban: {}", e),
+            json!({}),
+        ))),
+    }
+}
+
+pub async fn customer_bans(
+    req: HttpRequest,
+    params: ValidatedPath<PathParamsModel>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    let results = get_organization_customer_bans(params.0.id, model.organization_id, &state).await;
+
+    if let Err(e) = results {
+        return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Bans not found: {}", e),
+            json!({}),
+        )));
+    };
+
+    let bans = results.unwrap();
+
+    if bans.is_empty() {
+        return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "No Bans Found".to_string(),
+            json!([]),
+        )));
+    }
+
+    Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+        ResponseCode::Success,
+        "Bans Fetched Successfully".to_string(),
+        json!(bans),
+    )))
+}
+
+pub async fn shared_bans(
+    req: HttpRequest,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    req.extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    let results = get_shared_bans(&state).await;
+
+    if let Err(e) = results {
+        return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Bans not found: {}", e),
+            json!({}),
+        )));
+    };
+
+    let bans = results.unwrap();
+
+    if bans.is_empty() {
+        return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "No Bans Found".to_string(),
+            json!([]),
+        )));
+    }
+
+    Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+        ResponseCode::Success,
+        "Bans Fetched Successfully".to_string(),
+        json!(bans),
+    )))
+}
+
+pub async fn ban_history(
+    req: HttpRequest,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<CustomerResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Customer not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    let mut session_id = uuid::Uuid::nil();
+
+    if let Some(session_uuid) = &model.session {
+        if let Ok(s_uuid) = uuid::Uuid::parse_str(session_uuid) {
+            session_id = s_uuid
+        }
+    }
+
+    let customer = match get_customer_full(session_id, &state).await {
+        Ok(customer) => customer,
+        Err(e) => {
+            return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+                ResponseCode::Failed,
+                format!("Could not find customer: {}", e),
+                json!({}),
+            )))
+        }
+    };
+
+    let results = get_customer_ban_history(customer.id, &state).await;
+
+    if let Err(e) = results {
+        return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Bans not found: {}", e),
+            json!({}),
+        )));
+    };
+
+    let bans = results.unwrap();
+
+    if bans.is_empty() {
+        return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "No Bans Found".to_string(),
+            json!([]),
+        )));
+    }
+
+    Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+        ResponseCode::Success,
+        "Bans Fetched Successfully".to_string(),
+        json!(bans),
+    )))
+}
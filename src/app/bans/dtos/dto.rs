@@ -0,0 +1,275 @@
+use actix_web::web;
+use migration::Expr;
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, Condition, ConnectionTrait, DbErr, EntityTrait,
+    PaginatorTrait, QueryFilter, QueryOrder, Set, TransactionTrait,
+};
+
+use crate::{
+    app::bans::models::model::{AddBanDto, LiftBanDto},
+    AppState,
+};
+
+/// Keeps `customers.is_banned` in line with whether any ban record is still active.
+async fn refresh_customer_ban_flag<C: ConnectionTrait>(
+    customer: uuid::Uuid,
+    conn: &C,
+) -> Result<(), DbErr> {
+    let active = entity::ban_records::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::ban_records::Column::CustomerId.eq(customer))
+                .add(entity::ban_records::Column::IsActive.eq(true)),
+        )
+        .count(conn)
+        .await?;
+
+    entity::customers::Entity::update_many()
+        .filter(entity::customers::Column::Id.eq(customer))
+        .col_expr(entity::customers::Column::IsBanned, Expr::value(active > 0))
+        .col_expr(
+            entity::customers::Column::UpdatedAt,
+            Expr::value(chrono::Utc::now()),
+        )
+        .exec(conn)
+        .await
+        .map_err(|err| {
+            eprintln!("Database update error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    Ok(())
+}
+
+pub async fn save_ban(
+    data: AddBanDto,
+    state: &web::Data<AppState>,
+) -> Result<entity::ban_records::Model, DbErr> {
+    let now = chrono::Utc::now();
+
+    let banned_until = data
+        .duration_type
+        .banned_until(now, data.duration.unwrap_or(0).max(0) as u32);
+
+    let ban = entity::ban_records::ActiveModel {
+        customer_id: Set(Some(data.customer)),
+        organization_id: Set(Some(data.organization)),
+        branch_id: Set(data.branch),
+        banned_by: Set(Some(data.banned_by)),
+        banned_reason: Set(Some(data.reason)),
+        banned_duration: Set(data.duration),
+        banned_duration_type: Set(Some(data.duration_type.as_str().to_string())),
+        banned_at: Set(Some(now.into())),
+        banned_until: Set(banned_until.map(|dt| dt.into())),
+        is_shared: Set(data.is_shared),
+        is_active: Set(true),
+        ..Default::default()
+    };
+
+    let txn = state.pg_db.get_ref().begin().await?;
+
+    let result = ActiveModelTrait::insert(ban, &txn).await.map_err(|err| {
+        eprintln!("Database insert error: {}", err);
+        DbErr::Custom(err.to_string())
+    })?;
+
+    refresh_customer_ban_flag(data.customer, &txn).await?;
+
+    txn.commit().await?;
+
+    Ok(result)
+}
+
+pub async fn lift_ban(
+    id: uuid::Uuid,
+    data: LiftBanDto,
+    state: &web::Data<AppState>,
+) -> Result<(), DbErr> {
+    let ban = entity::ban_records::Entity::find_by_id(id)
+        .filter(
+            Condition::all()
+                .add(entity::ban_records::Column::OrganizationId.eq(data.organization))
+                .add(entity::ban_records::Column::IsActive.eq(true)),
+        )
+        .one(state.pg_db.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Ban not found".into()))?;
+
+    let customer = ban.customer_id;
+    let now = chrono::Utc::now();
+
+    let mut model: entity::ban_records::ActiveModel = ban.into();
+
+    model.is_active = ActiveValue::Set(false);
+    model.lifted_at = ActiveValue::Set(Some(now.into()));
+    model.lifted_by = ActiveValue::Set(Some(data.lifted_by));
+    model.lift_reason = ActiveValue::Set(Some(data.reason));
+    model.updated_at = ActiveValue::Set(now.into());
+
+    let txn = state.pg_db.get_ref().begin().await?;
+
+    ActiveModelTrait::update(model, &txn).await.map_err(|err| {
+        eprintln!("Database update error: {}", err);
+        DbErr::Custom(err.to_string())
+    })?;
+
+    if let Some(customer) = customer {
+        refresh_customer_ban_flag(customer, &txn).await?;
+    }
+
+    txn.commit().await?;
+
+    Ok(())
+}
+
+/// Bans that stop a customer from using a given branch: the branch's own bans, organization-wide
+/// bans, and any ban another organization has put on the shared list.
+pub async fn get_active_bans(
+    customer: uuid::Uuid,
+    organization: uuid::Uuid,
+    branch: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<Vec<entity::ban_records::Model>, DbErr> {
+    let bans = entity::ban_records::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::ban_records::Column::CustomerId.eq(customer))
+                .add(entity::ban_records::Column::IsActive.eq(true))
+                .add(
+                    Condition::any()
+                        .add(entity::ban_records::Column::BannedUntil.is_null())
+                        .add(entity::ban_records::Column::BannedUntil.gt(chrono::Utc::now())),
+                )
+                .add(
+                    Condition::any()
+                        .add(entity::ban_records::Column::IsShared.eq(true))
+                        .add(
+                            Condition::all()
+                                .add(entity::ban_records::Column::OrganizationId.eq(organization))
+                                .add(
+                                    Condition::any()
+                                        .add(entity::ban_records::Column::BranchId.is_null())
+                                        .add(entity::ban_records::Column::BranchId.eq(branch)),
+                                ),
+                        ),
+                ),
+        )
+        .all(state.pg_db.get_ref())
+        .await
+        .map_err(|err| {
+            eprintln!("Database retrieval error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    Ok(bans)
+}
+
+pub async fn get_customer_ban_history(
+    customer: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<Vec<entity::ban_records::Model>, DbErr> {
+    let bans = entity::ban_records::Entity::find()
+        .filter(entity::ban_records::Column::CustomerId.eq(customer))
+        .order_by_desc(entity::ban_records::Column::CreatedAt)
+        .all(state.pg_db.get_ref())
+        .await
+        .map_err(|err| {
+            eprintln!("Database retrieval error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    Ok(bans)
+}
+
+pub async fn get_organization_customer_bans(
+    customer: uuid::Uuid,
+    organization: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<Vec<entity::ban_records::Model>, DbErr> {
+    let bans = entity::ban_records::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::ban_records::Column::CustomerId.eq(customer))
+                .add(
+                    Condition::any()
+                        .add(entity::ban_records::Column::OrganizationId.eq(organization))
+                        .add(entity::ban_records::Column::IsShared.eq(true)),
+                ),
+        )
+        .order_by_desc(entity::ban_records::Column::CreatedAt)
+        .all(state.pg_db.get_ref())
+        .await
+        .map_err(|err| {
+            eprintln!("Database retrieval error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    Ok(bans)
+}
+
+pub async fn get_shared_bans(
+    state: &web::Data<AppState>,
+) -> Result<Vec<entity::ban_records::Model>, DbErr> {
+    let bans = entity::ban_records::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::ban_records::Column::IsShared.eq(true))
+                .add(entity::ban_records::Column::IsActive.eq(true)),
+        )
+        .order_by_desc(entity::ban_records::Column::CreatedAt)
+        .all(state.pg_db.get_ref())
+        .await
+        .map_err(|err| {
+            eprintln!("Database retrieval error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    Ok(bans)
+}
+
+pub async fn lift_expired_bans(state: &web::Data<AppState>) -> Result<u64, DbErr> {
+    let now = chrono::Utc::now();
+
+    let expired = entity::ban_records::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::ban_records::Column::IsActive.eq(true))
+                .add(entity::ban_records::Column::BannedUntil.lte(now)),
+        )
+        .all(state.pg_db.get_ref())
+        .await?;
+
+    if expired.is_empty() {
+        return Ok(0);
+    }
+
+    let txn = state.pg_db.get_ref().begin().await?;
+
+    let result = entity::ban_records::Entity::update_many()
+        .filter(entity::ban_records::Column::Id.is_in(expired.iter().map(|ban| ban.id)))
+        .col_expr(entity::ban_records::Column::IsActive, Expr::value(false))
+        .col_expr(entity::ban_records::Column::LiftedAt, Expr::value(now))
+        .col_expr(
+            entity::ban_records::Column::LiftReason,
+            Expr::value("Ban period elapsed"),
+        )
+        .col_expr(entity::ban_records::Column::UpdatedAt, Expr::value(now))
+        .exec(&txn)
+        .await
+        .map_err(|err| {
+            eprintln!("Database update error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    let mut customers: Vec<uuid::Uuid> = expired.iter().filter_map(|ban| ban.customer_id).collect();
+    customers.sort();
+    customers.dedup();
+
+    for customer in customers {
+        refresh_customer_ban_flag(customer, &txn).await?;
+    }
+
+    txn.commit().await?;
+
+    Ok(result.rows_affected)
+}
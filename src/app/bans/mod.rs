@@ -0,0 +1,4 @@
+pub mod controllers;
+pub mod dtos;
+pub mod models;
+pub mod routes;
\ No newline at end of file
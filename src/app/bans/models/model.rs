@@ -0,0 +1,115 @@
+use std::str::FromStr;
+
+use chrono::{DateTime, Months, TimeDelta, Utc};
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::libs::validator::{validate_ban_duration_type, validate_ban_scope};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum BanDurationType {
+    Hours,
+    Days,
+    Weeks,
+    Months,
+    Permanent,
+}
+
+impl BanDurationType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BanDurationType::Hours => "HOURS",
+            BanDurationType::Days => "DAYS",
+            BanDurationType::Weeks => "WEEKS",
+            BanDurationType::Months => "MONTHS",
+            BanDurationType::Permanent => "PERMANENT",
+        }
+    }
+
+    /// When a ban of `duration` units starting at `from` ends. Permanent bans never end.
+    pub fn banned_until(&self, from: DateTime<Utc>, duration: u32) -> Option<DateTime<Utc>> {
+        match self {
+            BanDurationType::Hours => from.checked_add_signed(TimeDelta::hours(duration.into())),
+            BanDurationType::Days => from.checked_add_signed(TimeDelta::days(duration.into())),
+            BanDurationType::Weeks => from.checked_add_signed(TimeDelta::weeks(duration.into())),
+            BanDurationType::Months => from.checked_add_months(Months::new(duration)),
+            BanDurationType::Permanent => None,
+        }
+    }
+}
+
+impl FromStr for BanDurationType {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_uppercase().as_str() {
+            "HOURS" => Ok(BanDurationType::Hours),
+            "DAYS" => Ok(BanDurationType::Days),
+            "WEEKS" => Ok(BanDurationType::Weeks),
+            "MONTHS" => Ok(BanDurationType::Months),
+            "PERMANENT" => Ok(BanDurationType::Permanent),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum BanScope {
+    Branch,
+    Organization,
+}
+
+impl FromStr for BanScope {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_uppercase().as_str() {
+            "BRANCH" => Ok(BanScope::Branch),
+            "ORGANIZATION" => Ok(BanScope::Organization),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AddBanDto {
+    pub customer: uuid::Uuid,
+    pub organization: uuid::Uuid,
+    pub branch: Option<uuid::Uuid>,
+    pub banned_by: uuid::Uuid,
+    pub reason: String,
+    pub duration: Option<i32>,
+    pub duration_type: BanDurationType,
+    pub is_shared: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LiftBanDto {
+    pub organization: uuid::Uuid,
+    pub lifted_by: uuid::Uuid,
+    pub reason: String,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct AddBanParams {
+    pub customer: uuid::Uuid,
+    #[validate(length(min = 3, max = 200, message = "Reason is invalid"))]
+    pub reason: String,
+    #[validate(range(min = 1, max = 1000, message = "Duration is invalid"))]
+    pub duration: Option<i32>,
+    #[validate(custom(function = "validate_ban_duration_type"))]
+    pub duration_type: String,
+    #[validate(custom(function = "validate_ban_scope"))]
+    pub scope: String,
+    pub is_shared: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct LiftBanParams {
+    #[validate(length(min = 3, max = 200, message = "Reason is invalid"))]
+    pub reason: String,
+}
@@ -0,0 +1,50 @@
+use actix_web::web;
+
+use crate::{
+    app::bans::controllers::controller::{
+        ban_history, create_ban, customer_bans, remove_ban, shared_bans,
+    },
+    middlewares::{auth::JwtAuthMiddleware, checker::CheckUserMiddleware},
+    AppState,
+};
+
+pub fn routes(cfg: &mut web::ServiceConfig, state: web::Data<AppState>) {
+    cfg.service(
+        web::scope("/api/v1/bans")
+            .route(
+                "/add",
+                web::post()
+                    .to(create_ban)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/lift/{id}",
+                web::put()
+                    .to(remove_ban)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/customer/{id}",
+                web::get()
+                    .to(customer_bans)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/shared",
+                web::get()
+                    .to(shared_bans)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/history",
+                web::get()
+                    .to(ban_history)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Customer"))
+                    .wrap(JwtAuthMiddleware),
+            ),
+    );
+}
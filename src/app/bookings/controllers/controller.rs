@@ -0,0 +1,457 @@
+use std::{str::FromStr, sync::Arc};
+
+use actix_web::{web, HttpMessage, HttpRequest, HttpResponse};
+use sea_orm::prelude::Decimal;
+use serde_json::json;
+
+use crate::{
+    app::{
+        bookings::{
+            dtos::dto::{
+                cancel_booking, check_in_booking, get_booking_by_reference, get_customer_booking,
+                get_customer_bookings, get_customer_event_booking, save_booking,
+            },
+            models::model::{
+                AddBookingDto, AddBookingParams, BookingParty, BookingReferenceParams,
+            },
+            services::service::{check_event_eligibility, within_refund_window},
+        },
+        branch::dtos::dto::get_branch_timezone,
+        customers::{
+            dtos::dto::{get_customer_by_id, get_customer_full},
+            models::model::CustomerResponse,
+        },
+        employees::models::model::EmployeeResponse,
+        events::dtos::dto::get_event_by_id,
+        schedules::dtos::dto::get_schedule,
+    },
+    libs::error::Error,
+    utils::{
+        json_validator::{ValidatedJson, ValidatedPath},
+        models::{HttpClientResponse, PathParamsModel, ResponseCode},
+    },
+    AppState,
+};
+
+pub async fn create_booking(
+    req: HttpRequest,
+    payload: ValidatedJson<AddBookingParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<CustomerResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Customer not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    let mut session_id = uuid::Uuid::nil();
+
+    if let Some(session_uuid) = &model.session {
+        if let Ok(s_uuid) = uuid::Uuid::parse_str(session_uuid) {
+            session_id = s_uuid
+        }
+    }
+
+    let customer = match get_customer_full(session_id, &state).await {
+        Ok(customer) => customer,
+        Err(e) => {
+            return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+                ResponseCode::Failed,
+                format!("Could not find customer: {}", e),
+                json!({}),
+            )))
+        }
+    };
+
+    let data = payload.0;
+
+    let eligibility = match check_event_eligibility(&customer, data.event, &state).await {
+        Ok(eligibility) => eligibility,
+        Err(e) => {
+            return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+                ResponseCode::Failed,
+                format!("Event not found: {}", e),
+                json!({}),
+            )))
+        }
+    };
+
+    if !eligibility.rejections.is_empty() {
+        return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            "Customer Not Eligible For Event".to_string(),
+            json!({ "reasons": eligibility.rejections }),
+        )));
+    }
+
+    if let Ok(true) = get_customer_event_booking(customer.id, data.event, &state).await {
+        return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            "Event Already Booked".to_string(),
+            json!({}),
+        )));
+    }
+
+    let party = data
+        .party
+        .as_deref()
+        .and_then(|s| BookingParty::from_str(s).ok())
+        .unwrap_or(BookingParty::Individual);
+
+    let attendees = match party {
+        BookingParty::Individual => 1,
+        BookingParty::Group => data.attendees.unwrap_or(1),
+    };
+
+    let schedule = eligibility.schedule;
+
+    let unit_amount = if schedule.is_discounted {
+        schedule.fee - (schedule.fee * schedule.discount_rate / Decimal::from(100))
+    } else {
+        schedule.fee
+    };
+
+    let booking = AddBookingDto {
+        organization: eligibility.event.organization_id,
+        branch: eligibility.event.branch_id,
+        customer: customer.id,
+        customer_name: customer.username.clone().unwrap_or_default(),
+        event: eligibility.event.id,
+        schedule: schedule.id,
+        party,
+        unit_amount,
+        total_amount: unit_amount * Decimal::from(attendees),
+        promo_code: data.promo_code.map(|code| code.trim().to_uppercase()),
+    };
+
+    match save_booking(booking, &state).await {
+        Ok(res) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Booking Created Successful".to_string(),
+            json!({
+                "id": res.id,
+                "booking_number": res.booking_number,
+                "booking_reference": res.booking_reference,
+            }),
+        ))),
+        Err(e) => Ok(
+            HttpResponse::InternalServerError().json(HttpClientResponse::new(
+                ResponseCode::Failed,
+                format!("Error Creating Booking: {}", e),
+                json!({}),
+            )),
+        ),
+    }
+}
+
+pub async fn get_eligibility(
+    req: HttpRequest,
+    params: ValidatedPath<PathParamsModel>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<CustomerResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Customer not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    let mut session_id = uuid::Uuid::nil();
+
+    if let Some(session_uuid) = &model.session {
+        if let Ok(s_uuid) = uuid::Uuid::parse_str(session_uuid) {
+            session_id = s_uuid
+        }
+    }
+
+    let customer = match get_customer_full(session_id, &state).await {
+        Ok(customer) => customer,
+        Err(e) => {
+            return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+                ResponseCode::Failed,
+                format!("Could not find customer: {}", e),
+                json!({}),
+            )))
+        }
+    };
+
+    let data = params.0;
+
+    let result = check_event_eligibility(&customer, data.id, &state).await;
+
+    if let Err(e) = result {
+        return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Event not found: {}", e),
+            json!({}),
+        )));
+    }
+
+    let eligibility = result.unwrap();
+
+    Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+        ResponseCode::Success,
+        "Eligibility Checked Successfully".to_string(),
+        json!({
+            "eligible": eligibility.rejections.is_empty(),
+            "reasons": eligibility.rejections,
+        }),
+    )))
+}
+
+pub async fn booking_history(
+    req: HttpRequest,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<CustomerResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Customer not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    let mut session_id = uuid::Uuid::nil();
+
+    if let Some(session_uuid) = &model.session {
+        if let Ok(s_uuid) = uuid::Uuid::parse_str(session_uuid) {
+            session_id = s_uuid
+        }
+    }
+
+    let customer = match get_customer_full(session_id, &state).await {
+        Ok(customer) => customer,
+        Err(e) => {
+            return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+                ResponseCode::Failed,
+                format!("Could not find customer: {}", e),
+                json!({}),
+            )))
+        }
+    };
+
+    let results = get_customer_bookings(customer.id, &state).await;
+
+    if let Err(e) = results {
+        return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Bookings not found: {}", e),
+            json!({}),
+        )));
+    };
+
+    let bookings = results.unwrap();
+
+    if bookings.is_empty() {
+        return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "No Bookings Found".to_string(),
+            json!([]),
+        )));
+    }
+
+    Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+        ResponseCode::Success,
+        "Bookings Fetched Successfully".to_string(),
+        json!(bookings),
+    )))
+}
+
+pub async fn checkin_booking(
+    req: HttpRequest,
+    params: ValidatedPath<BookingReferenceParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    let data = params.0;
+
+    let booking = match get_booking_by_reference(
+        data.reference,
+        model.organization_id,
+        model.branch_id,
+        &state,
+    )
+    .await
+    {
+        Ok(booking) => booking,
+        Err(e) => {
+            return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+                ResponseCode::Failed,
+                format!("Booking not found: {}", e),
+                json!({}),
+            )))
+        }
+    };
+
+    let customer = match get_customer_by_id(booking.customer_id, &state).await {
+        Ok(customer) => customer,
+        Err(e) => {
+            return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+                ResponseCode::Failed,
+                format!("Could not find customer: {}", e),
+                json!({}),
+            )))
+        }
+    };
+
+    let eligibility = match check_event_eligibility(&customer, booking.event_id, &state).await {
+        Ok(eligibility) => eligibility,
+        Err(e) => {
+            return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+                ResponseCode::Failed,
+                format!("Event not found: {}", e),
+                json!({}),
+            )))
+        }
+    };
+
+    if !eligibility.rejections.is_empty() {
+        return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            "Customer Not Eligible For Entry".to_string(),
+            json!({ "reasons": eligibility.rejections }),
+        )));
+    }
+
+    match check_in_booking(booking.id, &state).await {
+        Ok(res) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Check In Successful".to_string(),
+            json!(res),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Could not check in booking: {}", e),
+            json!({}),
+        ))),
+    }
+}
+
+pub async fn cancel_my_booking(
+    req: HttpRequest,
+    params: ValidatedPath<PathParamsModel>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<CustomerResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Customer not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    let mut session_id = uuid::Uuid::nil();
+
+    if let Some(session_uuid) = &model.session {
+        if let Ok(s_uuid) = uuid::Uuid::parse_str(session_uuid) {
+            session_id = s_uuid
+        }
+    }
+
+    let customer = match get_customer_full(session_id, &state).await {
+        Ok(customer) => customer,
+        Err(e) => {
+            return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+                ResponseCode::Failed,
+                format!("Could not find customer: {}", e),
+                json!({}),
+            )))
+        }
+    };
+
+    let booking = match get_customer_booking(params.0.id, customer.id, &state).await {
+        Ok(booking) => booking,
+        Err(e) => {
+            return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+                ResponseCode::Failed,
+                format!("Booking not found: {}", e),
+                json!({}),
+            )))
+        }
+    };
+
+    if booking.booked_on_time.is_some() {
+        return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            "Booking already checked in".to_string(),
+            json!({}),
+        )));
+    }
+
+    let event = match get_event_by_id(booking.event_id, &state).await {
+        Ok(event) => event,
+        Err(e) => {
+            return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+                ResponseCode::Failed,
+                format!("Event not found: {}", e),
+                json!({}),
+            )))
+        }
+    };
+
+    let schedule = match get_schedule(event.schedule_id, &state).await {
+        Ok(schedule) => schedule,
+        Err(e) => {
+            return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+                ResponseCode::Failed,
+                format!("Schedule not found: {}", e),
+                json!({}),
+            )))
+        }
+    };
+
+    let notice_hours = state
+        .config
+        .get::<i64>("bookings.preorder_refund_hours")
+        .unwrap_or(24);
+
+    let timezone = match get_branch_timezone(event.branch_id, state.pg_db.get_ref()).await {
+        Ok(timezone) => timezone,
+        Err(e) => {
+            return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+                ResponseCode::Failed,
+                format!("Branch not found: {}", e),
+                json!({}),
+            )))
+        }
+    };
+
+    let refund = within_refund_window(&event, &schedule, notice_hours, timezone);
+
+    match cancel_booking(booking.id, refund, &state).await {
+        Ok((booking, pre_orders)) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Booking Cancelled Successfully".to_string(),
+            json!({
+                "booking": booking,
+                "pre_orders": pre_orders,
+                "pre_orders_refunded": refund,
+            }),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Could not cancel booking: {}", e),
+            json!({}),
+        ))),
+    }
+}
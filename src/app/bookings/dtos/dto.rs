@@ -0,0 +1,300 @@
+use actix_web::web;
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, Condition, DbErr, EntityTrait, QueryFilter,
+    QueryOrder, QuerySelect, Set, TransactionTrait,
+};
+
+use crate::{
+    app::{
+        bookings::models::model::AddBookingDto,
+        order_queue::dtos::dto::publish_order_events,
+        orders::dtos::dto::{cancel_pre_orders, release_pre_orders},
+        promos::{
+            dtos::dto::{record_redemptions, redeemable_promos},
+            models::model::{PromoContext, PromoLine, PromoLookupDto, PromoTarget, RedemptionDto},
+            services::service::apply_promos,
+        },
+        receipts::dtos::dto::{issue_booking_receipt, send_receipt_email},
+        taxes::{
+            dtos::dto::{applicable_tax_rules, record_tax_lines},
+            models::model::{TaxTarget, TaxableLine},
+            services::service::compute_taxes,
+        },
+        wallets::{dtos::dto::debit_customer_wallet, models::model::WalletDebitDto},
+    },
+    libs::jwt::gen_string,
+    utils::{shared::gen_num, time::branch_timezone},
+    AppState,
+};
+
+/// Books the customer in and charges their wallet. Promos for the schedule come off the booking
+/// amount first, then the organization's taxes and service charge are added.
+pub async fn save_booking(
+    data: AddBookingDto,
+    state: &web::Data<AppState>,
+) -> Result<entity::bookings::Model, DbErr> {
+    let booking_number = gen_num("BKN", data.customer_name, state).await?;
+
+    let txn = state.pg_db.get_ref().begin().await?;
+
+    let branch = entity::branches::Entity::find_by_id(data.branch)
+        .one(&txn)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Branch not found".into()))?;
+
+    let promos = redeemable_promos(
+        &PromoLookupDto {
+            organization: data.organization,
+            customer: data.customer,
+            target: PromoTarget::Bookings,
+            code: data.promo_code.clone(),
+        },
+        &txn,
+    )
+    .await?;
+
+    let context = PromoContext {
+        branch: data.branch,
+        schedule: Some(data.schedule),
+        ticket: None,
+        at: chrono::Utc::now(),
+        timezone: branch_timezone(&branch),
+    };
+
+    let discount = apply_promos(
+        &promos,
+        &context,
+        &[PromoLine {
+            category: None,
+            amount: data.total_amount,
+        }],
+        data.promo_code.as_deref(),
+    )
+    .map_err(DbErr::Custom)?;
+
+    let amount = data.total_amount - discount.discount_amount;
+
+    let rules = applicable_tax_rules(data.organization, TaxTarget::Bookings, &txn).await?;
+
+    let taxes = compute_taxes(
+        &[TaxableLine {
+            category: None,
+            amount,
+        }],
+        &rules,
+    );
+
+    let total_amount = amount + taxes.tax_amount + taxes.service_charge;
+
+    let payment = debit_customer_wallet(
+        WalletDebitDto {
+            customer: data.customer,
+            organization: data.organization,
+            branch: data.branch,
+            amount: total_amount,
+            description: format!("Booking {}", booking_number),
+        },
+        &txn,
+    )
+    .await?;
+
+    let booking = entity::bookings::ActiveModel {
+        organization_id: Set(data.organization),
+        branch_id: Set(data.branch),
+        customer_id: Set(data.customer),
+        event_id: Set(data.event),
+        payment_id: Set(payment.id),
+        booking_number: Set(booking_number),
+        booking_reference: Set(gen_string(10).to_uppercase()),
+        booking_party: Set(data.party.to_string()),
+        qr_code_id: Set(Some(uuid::Uuid::new_v4().to_string())),
+        unit_amount: Set(data.unit_amount),
+        total_amount: Set(total_amount),
+        tax_amount: Set(taxes.tax_amount),
+        included_tax_amount: Set(taxes.included_tax_amount),
+        service_charge: Set(taxes.service_charge),
+        discount_amount: Set(discount.discount_amount),
+        ..Default::default()
+    };
+
+    let result = ActiveModelTrait::insert(booking, &txn)
+        .await
+        .map_err(|err| {
+            eprintln!("Database insert error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    record_tax_lines(&taxes.lines, None, Some(result.id), &txn).await?;
+    record_redemptions(
+        &discount,
+        RedemptionDto {
+            customer: data.customer,
+            order: None,
+            booking: Some(result.id),
+            ticket_purchase: None,
+        },
+        &txn,
+    )
+    .await?;
+
+    let receipt = issue_booking_receipt(&result, &txn).await?;
+
+    txn.commit().await?;
+
+    send_receipt_email(receipt, state.clone());
+
+    Ok(result)
+}
+
+pub async fn get_customer_event_booking(
+    customer: uuid::Uuid,
+    event: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<bool, DbErr> {
+    let result = entity::bookings::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::bookings::Column::CustomerId.eq(customer))
+                .add(entity::bookings::Column::EventId.eq(event))
+                .add(entity::bookings::Column::IsCancelled.eq(false)),
+        )
+        .one(state.pg_db.get_ref())
+        .await?
+        .is_some();
+
+    Ok(result)
+}
+
+pub async fn get_customer_bookings(
+    customer: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<Vec<entity::bookings::Model>, DbErr> {
+    let bookings = entity::bookings::Entity::find()
+        .filter(entity::bookings::Column::CustomerId.eq(customer))
+        .order_by_desc(entity::bookings::Column::CreatedAt)
+        .all(state.pg_db.get_ref())
+        .await
+        .map_err(|err| {
+            eprintln!("Database retrieval error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    Ok(bookings)
+}
+
+pub async fn get_booking_by_reference(
+    reference: String,
+    organization: uuid::Uuid,
+    branch: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<entity::bookings::Model, DbErr> {
+    let booking = entity::bookings::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::bookings::Column::BookingReference.eq(reference.to_uppercase()))
+                .add(entity::bookings::Column::OrganizationId.eq(organization))
+                .add(entity::bookings::Column::BranchId.eq(branch))
+                .add(entity::bookings::Column::IsCancelled.eq(false)),
+        )
+        .one(state.pg_db.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Booking not found".into()))?;
+
+    Ok(booking)
+}
+
+pub async fn get_customer_booking(
+    id: uuid::Uuid,
+    customer: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<entity::bookings::Model, DbErr> {
+    let booking = entity::bookings::Entity::find_by_id(id)
+        .filter(
+            Condition::all()
+                .add(entity::bookings::Column::CustomerId.eq(customer))
+                .add(entity::bookings::Column::IsCancelled.eq(false)),
+        )
+        .one(state.pg_db.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Booking not found".into()))?;
+
+    Ok(booking)
+}
+
+/// Checks the guests in and sends any bottles they pre-ordered to the bar queue.
+pub async fn check_in_booking(
+    id: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<entity::bookings::Model, DbErr> {
+    let txn = state.pg_db.get_ref().begin().await?;
+
+    // Cancelling the booking or placing a pre-order waits for the check-in, and vice versa
+    let booking = entity::bookings::Entity::find_by_id(id)
+        .filter(
+            Condition::all()
+                .add(entity::bookings::Column::IsCancelled.eq(false))
+                .add(entity::bookings::Column::BookedOnTime.is_null()),
+        )
+        .lock_exclusive()
+        .one(&txn)
+        .await?
+        .ok_or_else(|| DbErr::Custom("Booking is cancelled or already checked in".to_string()))?;
+
+    let now = chrono::Utc::now();
+
+    let mut model: entity::bookings::ActiveModel = booking.into();
+
+    model.is_book_on = ActiveValue::Set(Some(now.into()));
+    model.booked_on_time = ActiveValue::Set(Some(now.into()));
+    model.updated_at = ActiveValue::Set(now.into());
+
+    let updated = ActiveModelTrait::update(model, &txn).await.map_err(|err| {
+        eprintln!("Database update error: {}", err);
+        DbErr::Custom(err.to_string())
+    })?;
+
+    let events = release_pre_orders(updated.id, &txn).await?;
+
+    txn.commit().await?;
+
+    publish_order_events(events, state);
+
+    Ok(updated)
+}
+
+/// Cancels a booking that has not been checked in along with its pre-orders, which are
+/// refunded to the wallet only when `refund` is set by the cancellation policy.
+pub async fn cancel_booking(
+    id: uuid::Uuid,
+    refund: bool,
+    state: &web::Data<AppState>,
+) -> Result<(entity::bookings::Model, Vec<entity::orders::Model>), DbErr> {
+    let txn = state.pg_db.get_ref().begin().await?;
+
+    let booking = entity::bookings::Entity::find_by_id(id)
+        .filter(
+            Condition::all()
+                .add(entity::bookings::Column::IsCancelled.eq(false))
+                .add(entity::bookings::Column::BookedOnTime.is_null()),
+        )
+        .lock_exclusive()
+        .one(&txn)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Booking not found or already checked in".into()))?;
+
+    let mut model: entity::bookings::ActiveModel = booking.into();
+
+    model.is_cancelled = ActiveValue::Set(true);
+    model.updated_at = ActiveValue::Set(chrono::Utc::now().into());
+
+    let updated = ActiveModelTrait::update(model, &txn).await.map_err(|err| {
+        eprintln!("Database update error: {}", err);
+        DbErr::Custom(err.to_string())
+    })?;
+
+    let orders = cancel_pre_orders(updated.id, refund, &txn).await?;
+
+    txn.commit().await?;
+
+    Ok((updated, orders))
+}
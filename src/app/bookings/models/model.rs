@@ -0,0 +1,68 @@
+use std::str::FromStr;
+
+use sea_orm::prelude::Decimal;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::libs::validator::validate_booking_party;
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum BookingParty {
+    Individual,
+    Group,
+}
+
+impl std::fmt::Display for BookingParty {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            BookingParty::Individual => "INDIVIDUAL",
+            BookingParty::Group => "GROUP",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+impl FromStr for BookingParty {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_uppercase().as_str() {
+            "INDIVIDUAL" => Ok(BookingParty::Individual),
+            "GROUP" => Ok(BookingParty::Group),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AddBookingDto {
+    pub organization: uuid::Uuid,
+    pub branch: uuid::Uuid,
+    pub customer: uuid::Uuid,
+    pub customer_name: String,
+    pub event: uuid::Uuid,
+    pub schedule: uuid::Uuid,
+    pub party: BookingParty,
+    pub unit_amount: Decimal,
+    pub total_amount: Decimal,
+    pub promo_code: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct AddBookingParams {
+    pub event: uuid::Uuid,
+    #[validate(custom(function = "validate_booking_party"))]
+    pub party: Option<String>,
+    #[validate(range(min = 1, max = 20, message = "Number of attendees is invalid"))]
+    pub attendees: Option<i32>,
+    #[validate(length(min = 3, max = 30, message = "Promo code is invalid"))]
+    pub promo_code: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct BookingReferenceParams {
+    #[validate(length(min = 6, max = 20, message = "Booking reference is invalid"))]
+    pub reference: String,
+}
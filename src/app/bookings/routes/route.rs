@@ -0,0 +1,50 @@
+use actix_web::web;
+
+use crate::{
+    app::bookings::controllers::controller::{
+        booking_history, cancel_my_booking, checkin_booking, create_booking, get_eligibility,
+    },
+    middlewares::{auth::JwtAuthMiddleware, checker::CheckUserMiddleware},
+    AppState,
+};
+
+pub fn routes(cfg: &mut web::ServiceConfig, state: web::Data<AppState>) {
+    cfg.service(
+        web::scope("/api/v1/bookings")
+            .route(
+                "/add",
+                web::post()
+                    .to(create_booking)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Customer"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/eligibility/{id}",
+                web::get()
+                    .to(get_eligibility)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Customer"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/history",
+                web::get()
+                    .to(booking_history)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Customer"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/cancel/{id}",
+                web::put()
+                    .to(cancel_my_booking)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Customer"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/checkin/{reference}",
+                web::put()
+                    .to(checkin_booking)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            ),
+    );
+}
@@ -0,0 +1,79 @@
+use actix_web::web;
+use chrono_tz::Tz;
+use sea_orm::DbErr;
+
+use crate::{
+    app::{
+        bans::dtos::dto::get_active_bans,
+        customers::{
+            models::model::{EligibilityRejection, EligibilityRules, RejectionReason},
+            services::service::evaluate_eligibility,
+        },
+        events::dtos::dto::get_event_by_id,
+        schedules::dtos::dto::get_schedule,
+    },
+    utils::time::venue_instant,
+    AppState,
+};
+
+pub struct EventEligibility {
+    pub event: entity::events::Model,
+    pub schedule: entity::schedules::Model,
+    pub rejections: Vec<EligibilityRejection>,
+}
+
+/// Loads an event with its schedule and runs the customer through the schedule's entry rules.
+/// Booking and door check-in both go through here so they always agree on who may attend.
+pub async fn check_event_eligibility(
+    customer: &entity::customers::Model,
+    event: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<EventEligibility, DbErr> {
+    let event = get_event_by_id(event, state).await?;
+
+    if event.is_deleted.unwrap_or(false) || !event.is_active.unwrap_or(false) {
+        return Err(DbErr::RecordNotFound("Event not found".into()));
+    }
+
+    let schedule = get_schedule(event.schedule_id, state).await?;
+
+    let today = chrono::Utc::now().naive_utc().date();
+    let event_date = event.active_date.unwrap_or(today);
+
+    let mut rejections =
+        evaluate_eligibility(customer, &EligibilityRules::from(&schedule), event_date);
+
+    let bans = get_active_bans(customer.id, event.organization_id, event.branch_id, state).await?;
+
+    if !bans.is_empty() {
+        rejections.push(RejectionReason::Banned.into());
+    }
+
+    Ok(EventEligibility {
+        event,
+        schedule,
+        rejections,
+    })
+}
+
+/// Whether a booking cancelled now still gets its pre-orders refunded: the event must be at
+/// least `notice_hours` away. Events are dated on the branch's clock in `timezone`, starting at
+/// midnight if the schedule sets no start time.
+pub fn within_refund_window(
+    event: &entity::events::Model,
+    schedule: &entity::schedules::Model,
+    notice_hours: i64,
+    timezone: Tz,
+) -> bool {
+    let now = chrono::Utc::now();
+
+    let starts_at = venue_instant(
+        event
+            .active_date
+            .unwrap_or(now.with_timezone(&timezone).date_naive())
+            .and_time(schedule.start_time.unwrap_or_default()),
+        timezone,
+    );
+
+    now + chrono::Duration::hours(notice_hours) <= starts_at
+}
@@ -6,15 +6,15 @@ use serde_json::json;
 use crate::{
     app::{
         branch::{
-            dtos::dto::{get_organization_branches, save_branch},
-            models::model::{AddBranchDto, AddBranchParams},
+            dtos::dto::{get_organization_branches, save_branch, set_branch_timezone},
+            models::model::{AddBranchDto, AddBranchParams, SetTimezoneParams},
         },
         users::models::model::UserResponse,
     },
     libs::error,
     utils::{
-        json_validator::ValidatedJson,
-        models::{HttpClientResponse, ResponseCode},
+        json_validator::{ValidatedJson, ValidatedPath},
+        models::{HttpClientResponse, PathParamsModel, ResponseCode},
     },
     AppState,
 };
@@ -44,6 +44,7 @@ pub async fn add_branch(
         email: data.email,
         organization: model.organization_id,
         country: data.country,
+        timezone: data.timezone,
     };
 
     let result = save_branch(branch, &state).await;
@@ -104,3 +105,40 @@ pub async fn get_branches_for_organization(
         json!(res),
     )))
 }
+
+pub async fn set_timezone(
+    req: HttpRequest,
+    params: ValidatedPath<PathParamsModel>,
+    payload: ValidatedJson<SetTimezoneParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, error::Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<UserResponse>>()
+        .cloned()
+        .ok_or(error::Error {
+            message: "User not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    match set_branch_timezone(
+        params.0.id,
+        model.organization_id,
+        payload.0.timezone,
+        &state,
+    )
+    .await
+    {
+        Ok(res) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Timezone Updated Successfully".to_string(),
+            json!(res),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Error Updating Timezone: {}", e),
+            json!({}),
+        ))),
+    }
+}
@@ -1,7 +1,11 @@
 use actix_web::web;
-use sea_orm::{ColumnTrait, Condition, DbErr, EntityTrait, InsertResult, QueryFilter, Set};
+use chrono_tz::Tz;
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, Condition, ConnectionTrait, DbErr, EntityTrait,
+    InsertResult, QueryFilter, Set,
+};
 
-use crate::{app::branch::models::model::AddBranchDto, AppState};
+use crate::{app::branch::models::model::AddBranchDto, utils::time::branch_timezone, AppState};
 
 pub async fn save_branch(
     data: AddBranchDto,
@@ -15,6 +19,7 @@ pub async fn save_branch(
         country_id: Set(data.country),
         contact: Set(Some(data.contact)),
         email: Set(data.email),
+        timezone: Set(data.timezone.unwrap_or_else(|| "UTC".to_string())),
         ..Default::default()
     };
 
@@ -85,3 +90,46 @@ pub async fn get_organization_branches(
 
     Ok(branches)
 }
+
+/// Sets the timezone the branch keeps its hours in.
+pub async fn set_branch_timezone(
+    id: uuid::Uuid,
+    organization: uuid::Uuid,
+    timezone: String,
+    state: &web::Data<AppState>,
+) -> Result<entity::branches::Model, DbErr> {
+    let branch = entity::branches::Entity::find_by_id(id)
+        .filter(
+            Condition::all()
+                .add(entity::branches::Column::OrganizationId.eq(organization))
+                .add(entity::branches::Column::IsDeleted.eq(false)),
+        )
+        .one(state.pg_db.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Branch not found".into()))?;
+
+    let mut model: entity::branches::ActiveModel = branch.into();
+
+    model.timezone = ActiveValue::Set(timezone);
+    model.updated_at = ActiveValue::Set(chrono::Utc::now().into());
+
+    ActiveModelTrait::update(model, state.pg_db.get_ref())
+        .await
+        .map_err(|err| {
+            eprintln!("Database update error: {}", err);
+            DbErr::Custom(err.to_string())
+        })
+}
+
+/// The timezone the branch keeps its hours in, for reading times on the venue's clock.
+pub async fn get_branch_timezone<C: ConnectionTrait>(
+    branch: uuid::Uuid,
+    conn: &C,
+) -> Result<Tz, DbErr> {
+    let branch = entity::branches::Entity::find_by_id(branch)
+        .one(conn)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Branch not found".into()))?;
+
+    Ok(branch_timezone(&branch))
+}
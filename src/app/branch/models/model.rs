@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 use validator::Validate;
 
+use crate::libs::validator::validate_timezone;
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct AddBranchDto {
     pub name: String,
@@ -9,7 +11,8 @@ pub struct AddBranchDto {
     pub gps: String,
     pub contact: String,
     pub email: Option<String>,
-    pub country: uuid::Uuid
+    pub country: uuid::Uuid,
+    pub timezone: Option<String>
 }
 
 #[derive(Debug, Deserialize, Validate)]
@@ -25,5 +28,15 @@ pub struct AddBranchParams {
     pub contact: String,
     #[validate(email)]
     pub email: Option<String>,
-    pub country: uuid::Uuid
+    pub country: uuid::Uuid,
+    /// IANA timezone of the venue, e.g. `Africa/Accra`. UTC when left out.
+    #[validate(custom(function = "validate_timezone"))]
+    pub timezone: Option<String>
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct SetTimezoneParams {
+    #[validate(custom(function = "validate_timezone"))]
+    pub timezone: String
 }
\ No newline at end of file
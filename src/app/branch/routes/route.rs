@@ -1,7 +1,9 @@
 use actix_web::web;
 
 use crate::{
-    app::branch::controllers::controller::{add_branch, get_branches_for_organization},
+    app::branch::controllers::controller::{
+        add_branch, get_branches_for_organization, set_timezone,
+    },
     middlewares::{auth::JwtAuthMiddleware, checker::CheckUserMiddleware},
     AppState,
 };
@@ -22,6 +24,13 @@ pub fn routes(cfg: &mut web::ServiceConfig, state: web::Data<AppState>) {
                     .to(get_branches_for_organization)
                     .wrap(CheckUserMiddleware::new(state.clone(), "User"))
                     .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/timezone/{id}",
+                web::put()
+                    .to(set_timezone)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "User"))
+                    .wrap(JwtAuthMiddleware),
             ),
     );
 }
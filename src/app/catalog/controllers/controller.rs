@@ -0,0 +1,395 @@
+use std::sync::Arc;
+
+use actix_web::{http::header, web, HttpMessage, HttpRequest, HttpResponse};
+use serde_json::json;
+
+use crate::{
+    app::{
+        catalog::{
+            dtos::dto::{export_catalog, import_catalog},
+            models::model::{CatalogKind, ImportParams},
+        },
+        employees::models::model::EmployeeResponse,
+        permissions::{dtos::dto::has_permission, models::model::ModelAction},
+    },
+    libs::error::Error,
+    utils::{
+        json_validator::ValidatedJson,
+        models::{HttpClientResponse, ResponseCode},
+    },
+    AppState,
+};
+
+/// An export as a CSV download, named after what it holds.
+fn csv_attachment(kind: CatalogKind, body: String) -> HttpResponse {
+    HttpResponse::Ok()
+        .content_type("text/csv; charset=utf-8")
+        .insert_header((
+            header::CONTENT_DISPOSITION,
+            format!(
+                "attachment; filename=\"{}.csv\"",
+                kind.as_str().to_lowercase()
+            ),
+        ))
+        .body(body)
+}
+
+pub async fn import_drinks(
+    req: HttpRequest,
+    payload: ValidatedJson<ImportParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    if !has_permission(model.role_permissions, ModelAction::ManageInventory, &state)
+        .await
+        .unwrap_or(false)
+    {
+        return Ok(HttpResponse::Forbidden().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            "Not Permitted To Manage Inventory".to_string(),
+            json!({}),
+        )));
+    }
+
+    match import_catalog(
+        CatalogKind::Drinks,
+        model.organization_id,
+        model.branch_id,
+        payload.0,
+        &state,
+    )
+    .await
+    {
+        Ok(res) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Drinks Import Processed Successfully".to_string(),
+            json!(res),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Error Importing: {}", e),
+            json!({}),
+        ))),
+    }
+}
+
+pub async fn import_foods(
+    req: HttpRequest,
+    payload: ValidatedJson<ImportParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    if !has_permission(model.role_permissions, ModelAction::ManageInventory, &state)
+        .await
+        .unwrap_or(false)
+    {
+        return Ok(HttpResponse::Forbidden().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            "Not Permitted To Manage Inventory".to_string(),
+            json!({}),
+        )));
+    }
+
+    match import_catalog(
+        CatalogKind::Foods,
+        model.organization_id,
+        model.branch_id,
+        payload.0,
+        &state,
+    )
+    .await
+    {
+        Ok(res) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Foods Import Processed Successfully".to_string(),
+            json!(res),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Error Importing: {}", e),
+            json!({}),
+        ))),
+    }
+}
+
+pub async fn import_categories(
+    req: HttpRequest,
+    payload: ValidatedJson<ImportParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    if !has_permission(model.role_permissions, ModelAction::ManageInventory, &state)
+        .await
+        .unwrap_or(false)
+    {
+        return Ok(HttpResponse::Forbidden().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            "Not Permitted To Manage Inventory".to_string(),
+            json!({}),
+        )));
+    }
+
+    match import_catalog(
+        CatalogKind::Categories,
+        model.organization_id,
+        model.branch_id,
+        payload.0,
+        &state,
+    )
+    .await
+    {
+        Ok(res) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Categories Import Processed Successfully".to_string(),
+            json!(res),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Error Importing: {}", e),
+            json!({}),
+        ))),
+    }
+}
+
+pub async fn import_suppliers(
+    req: HttpRequest,
+    payload: ValidatedJson<ImportParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    if !has_permission(model.role_permissions, ModelAction::ManageInventory, &state)
+        .await
+        .unwrap_or(false)
+    {
+        return Ok(HttpResponse::Forbidden().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            "Not Permitted To Manage Inventory".to_string(),
+            json!({}),
+        )));
+    }
+
+    match import_catalog(
+        CatalogKind::Suppliers,
+        model.organization_id,
+        model.branch_id,
+        payload.0,
+        &state,
+    )
+    .await
+    {
+        Ok(res) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Suppliers Import Processed Successfully".to_string(),
+            json!(res),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Error Importing: {}", e),
+            json!({}),
+        ))),
+    }
+}
+
+pub async fn export_drinks(
+    req: HttpRequest,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    if !has_permission(model.role_permissions, ModelAction::ManageInventory, &state)
+        .await
+        .unwrap_or(false)
+    {
+        return Ok(HttpResponse::Forbidden().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            "Not Permitted To Manage Inventory".to_string(),
+            json!({}),
+        )));
+    }
+
+    match export_catalog(
+        CatalogKind::Drinks,
+        model.organization_id,
+        model.branch_id,
+        &state,
+    )
+    .await
+    {
+        Ok(res) => Ok(csv_attachment(CatalogKind::Drinks, res)),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Error Exporting: {}", e),
+            json!({}),
+        ))),
+    }
+}
+
+pub async fn export_foods(
+    req: HttpRequest,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    if !has_permission(model.role_permissions, ModelAction::ManageInventory, &state)
+        .await
+        .unwrap_or(false)
+    {
+        return Ok(HttpResponse::Forbidden().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            "Not Permitted To Manage Inventory".to_string(),
+            json!({}),
+        )));
+    }
+
+    match export_catalog(
+        CatalogKind::Foods,
+        model.organization_id,
+        model.branch_id,
+        &state,
+    )
+    .await
+    {
+        Ok(res) => Ok(csv_attachment(CatalogKind::Foods, res)),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Error Exporting: {}", e),
+            json!({}),
+        ))),
+    }
+}
+
+pub async fn export_categories(
+    req: HttpRequest,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    if !has_permission(model.role_permissions, ModelAction::ManageInventory, &state)
+        .await
+        .unwrap_or(false)
+    {
+        return Ok(HttpResponse::Forbidden().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            "Not Permitted To Manage Inventory".to_string(),
+            json!({}),
+        )));
+    }
+
+    match export_catalog(
+        CatalogKind::Categories,
+        model.organization_id,
+        model.branch_id,
+        &state,
+    )
+    .await
+    {
+        Ok(res) => Ok(csv_attachment(CatalogKind::Categories, res)),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Error Exporting: {}", e),
+            json!({}),
+        ))),
+    }
+}
+
+pub async fn export_suppliers(
+    req: HttpRequest,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    if !has_permission(model.role_permissions, ModelAction::ManageInventory, &state)
+        .await
+        .unwrap_or(false)
+    {
+        return Ok(HttpResponse::Forbidden().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            "Not Permitted To Manage Inventory".to_string(),
+            json!({}),
+        )));
+    }
+
+    match export_catalog(
+        CatalogKind::Suppliers,
+        model.organization_id,
+        model.branch_id,
+        &state,
+    )
+    .await
+    {
+        Ok(res) => Ok(csv_attachment(CatalogKind::Suppliers, res)),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Error Exporting: {}", e),
+            json!({}),
+        ))),
+    }
+}
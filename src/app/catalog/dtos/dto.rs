@@ -0,0 +1,419 @@
+use std::collections::HashMap;
+
+use actix_web::web;
+use sea_orm::{
+    sea_query::{Expr, Func},
+    ColumnTrait, ConnectionTrait, DbErr, EntityTrait, QueryFilter, QueryOrder, TransactionTrait,
+};
+
+use crate::{
+    app::{
+        catalog::{
+            models::model::{
+                CatalogKind, ImportAction, ImportParams, ImportRecord, ImportReport, ImportRow,
+            },
+            services::service::{
+                categories_csv, category_params, drink_params, drinks_csv, food_params, foods_csv,
+                import_records, supplier_params, suppliers_csv,
+            },
+        },
+        categories::dtos::dto::{
+            branch_categories, find_branch_category, find_branch_category_named,
+            find_own_category_named, insert_category, rewrite_category,
+        },
+        inventory::dtos::dto::{
+            get_branch_drinks, get_branch_foods, insert_drink, insert_food, rewrite_drink,
+            rewrite_food,
+        },
+        purchasing::dtos::dto::{insert_supplier, rewrite_supplier},
+    },
+    AppState,
+};
+
+/// A database error as the row's report shows it.
+fn row_error(err: DbErr) -> String {
+    match err {
+        DbErr::Custom(message) | DbErr::RecordNotFound(message) => message,
+        err => err.to_string(),
+    }
+}
+
+/// The category a record's column names, by id or by name.
+async fn category_column<C: ConnectionTrait>(
+    record: &ImportRecord,
+    column: &str,
+    organization: uuid::Uuid,
+    branch: uuid::Uuid,
+    conn: &C,
+) -> Result<Option<uuid::Uuid>, String> {
+    let Some(reference) = record.text(column) else {
+        return Ok(None);
+    };
+
+    let category = match reference.parse() {
+        Ok(id) => find_branch_category(id, organization, branch, conn).await,
+        Err(_) => find_branch_category_named(&reference, organization, branch, conn).await,
+    };
+
+    category
+        .map(|category| Some(category.id))
+        .map_err(|err| format!("{}: {}", column, row_error(err)))
+}
+
+async fn find_supplier_named<C: ConnectionTrait>(
+    name: &str,
+    branch: uuid::Uuid,
+    conn: &C,
+) -> Result<Option<entity::suppliers::Model>, DbErr> {
+    entity::suppliers::Entity::find()
+        .filter(entity::suppliers::Column::BranchId.eq(branch))
+        .filter(
+            Expr::expr(Func::lower(Expr::col(entity::suppliers::Column::Name)))
+                .eq(name.to_lowercase()),
+        )
+        .one(conn)
+        .await
+}
+
+/// The branch's supplier a record's column names, by id or by name.
+async fn supplier_column<C: ConnectionTrait>(
+    record: &ImportRecord,
+    column: &str,
+    branch: uuid::Uuid,
+    conn: &C,
+) -> Result<Option<uuid::Uuid>, String> {
+    let Some(reference) = record.text(column) else {
+        return Ok(None);
+    };
+
+    let supplier = match reference.parse::<uuid::Uuid>() {
+        Ok(id) => {
+            entity::suppliers::Entity::find_by_id(id)
+                .filter(entity::suppliers::Column::BranchId.eq(branch))
+                .one(conn)
+                .await
+        }
+        Err(_) => find_supplier_named(&reference, branch, conn).await,
+    };
+
+    match supplier {
+        Ok(Some(supplier)) => Ok(Some(supplier.id)),
+        Ok(None) => Err(format!("{}: Supplier not found", column)),
+        Err(err) => Err(format!("{}: {}", column, row_error(err))),
+    }
+}
+
+fn required_column(
+    reference: Result<Option<uuid::Uuid>, String>,
+    column: &str,
+) -> Result<uuid::Uuid, String> {
+    reference?.ok_or_else(|| format!("{}: is required", column))
+}
+
+/// The branch's drink a row is about: the one with its SKU, or else the one of its name,
+/// ignoring case.
+async fn match_drink<C: ConnectionTrait>(
+    branch: uuid::Uuid,
+    sku: Option<&str>,
+    name: &str,
+    conn: &C,
+) -> Result<Option<entity::stock_drinks::Model>, DbErr> {
+    if let Some(sku) = sku {
+        let drink = entity::stock_drinks::Entity::find()
+            .filter(entity::stock_drinks::Column::BranchId.eq(branch))
+            .filter(entity::stock_drinks::Column::Sku.eq(sku))
+            .one(conn)
+            .await?;
+
+        if drink.is_some() {
+            return Ok(drink);
+        }
+    }
+
+    entity::stock_drinks::Entity::find()
+        .filter(entity::stock_drinks::Column::BranchId.eq(branch))
+        .filter(
+            Expr::expr(Func::lower(Expr::col(entity::stock_drinks::Column::Name)))
+                .eq(name.to_lowercase()),
+        )
+        .one(conn)
+        .await
+}
+
+/// Same as `match_drink`, for the branch's dishes.
+async fn match_food<C: ConnectionTrait>(
+    branch: uuid::Uuid,
+    sku: Option<&str>,
+    name: &str,
+    conn: &C,
+) -> Result<Option<entity::stock_foods::Model>, DbErr> {
+    if let Some(sku) = sku {
+        let food = entity::stock_foods::Entity::find()
+            .filter(entity::stock_foods::Column::BranchId.eq(branch))
+            .filter(entity::stock_foods::Column::Sku.eq(sku))
+            .one(conn)
+            .await?;
+
+        if food.is_some() {
+            return Ok(food);
+        }
+    }
+
+    entity::stock_foods::Entity::find()
+        .filter(entity::stock_foods::Column::BranchId.eq(branch))
+        .filter(
+            Expr::expr(Func::lower(Expr::col(entity::stock_foods::Column::Name)))
+                .eq(name.to_lowercase()),
+        )
+        .one(conn)
+        .await
+}
+
+async fn import_drink<C: ConnectionTrait>(
+    record: &ImportRecord,
+    organization: uuid::Uuid,
+    branch: uuid::Uuid,
+    conn: &C,
+) -> Result<(ImportAction, uuid::Uuid), Vec<String>> {
+    let category = required_column(
+        category_column(record, "category", organization, branch, conn).await,
+        "category",
+    );
+    let supplier = required_column(
+        supplier_column(record, "supplier", branch, conn).await,
+        "supplier",
+    );
+    let mut data = drink_params(record, category, supplier)?.into_dto(organization, branch);
+
+    let drink = match match_drink(branch, data.sku.as_deref(), &data.name, conn)
+        .await
+        .map_err(|err| vec![row_error(err)])?
+    {
+        Some(drink) => {
+            // Stock on hand only moves through adjustments and counts, so a re-imported
+            // quantity is left as it is
+            data.quantity = drink.quantity;
+
+            rewrite_drink(drink, data, conn)
+                .await
+                .map(|drink| (ImportAction::Update, drink.id))
+        }
+        None => insert_drink(data, conn)
+            .await
+            .map(|drink| (ImportAction::Create, drink.id)),
+    };
+
+    drink.map_err(|err| vec![row_error(err)])
+}
+
+async fn import_food<C: ConnectionTrait>(
+    record: &ImportRecord,
+    organization: uuid::Uuid,
+    branch: uuid::Uuid,
+    conn: &C,
+) -> Result<(ImportAction, uuid::Uuid), Vec<String>> {
+    let category = required_column(
+        category_column(record, "category", organization, branch, conn).await,
+        "category",
+    );
+    let data = food_params(record, category)?.into_dto(organization, branch);
+
+    let food = match match_food(branch, data.sku.as_deref(), &data.name, conn)
+        .await
+        .map_err(|err| vec![row_error(err)])?
+    {
+        Some(food) => rewrite_food(food, data, conn)
+            .await
+            .map(|food| (ImportAction::Update, food.id)),
+        None => insert_food(data, conn)
+            .await
+            .map(|food| (ImportAction::Create, food.id)),
+    };
+
+    food.map_err(|err| vec![row_error(err)])
+}
+
+/// Imports a category, updating the organization's own category of the same name.
+async fn import_category<C: ConnectionTrait>(
+    record: &ImportRecord,
+    organization: uuid::Uuid,
+    branch: uuid::Uuid,
+    conn: &C,
+) -> Result<(ImportAction, uuid::Uuid), Vec<String>> {
+    let parent = category_column(record, "parent", organization, branch, conn).await;
+    let data = category_params(record, parent)?.into_dto(organization, branch);
+
+    let category = match find_own_category_named(&data.name, organization, branch, conn)
+        .await
+        .map_err(|err| vec![row_error(err)])?
+    {
+        Some(category) => rewrite_category(category, data, branch, conn)
+            .await
+            .map(|category| (ImportAction::Update, category.id)),
+        None => insert_category(data, branch, conn)
+            .await
+            .map(|category| (ImportAction::Create, category.id)),
+    };
+
+    category.map_err(|err| vec![row_error(err)])
+}
+
+/// Imports a supplier, updating the branch's supplier of the same name.
+async fn import_supplier<C: ConnectionTrait>(
+    record: &ImportRecord,
+    organization: uuid::Uuid,
+    branch: uuid::Uuid,
+    conn: &C,
+) -> Result<(ImportAction, uuid::Uuid), Vec<String>> {
+    let data = supplier_params(record)?.into_dto(organization, branch);
+
+    let supplier = match find_supplier_named(&data.name, branch, conn)
+        .await
+        .map_err(|err| vec![row_error(err)])?
+    {
+        Some(supplier) => rewrite_supplier(supplier, data, conn)
+            .await
+            .map(|supplier| (ImportAction::Update, supplier.id)),
+        None => insert_supplier(data, conn)
+            .await
+            .map(|supplier| (ImportAction::Create, supplier.id)),
+    };
+
+    supplier.map_err(|err| vec![row_error(err)])
+}
+
+/// Imports a CSV of `kind` into the branch. Each row is checked with the rules of the endpoint
+/// that saves one, then creates a row or updates the one it matches. A drink's quantity only
+/// stocks a drink the import creates. Rows are kept all
+/// together or not at all: on a dry run, or when any row fails, nothing is written and the
+/// report says what each row would have done.
+pub async fn import_catalog(
+    kind: CatalogKind,
+    organization: uuid::Uuid,
+    branch: uuid::Uuid,
+    params: ImportParams,
+    state: &web::Data<AppState>,
+) -> Result<ImportReport, DbErr> {
+    let records = import_records(&params.csv, kind).map_err(DbErr::Custom)?;
+
+    let mut report = ImportReport {
+        dry_run: params.dry_run.unwrap_or(false),
+        ..Default::default()
+    };
+
+    let txn = state.pg_db.get_ref().begin().await?;
+
+    for record in records {
+        // Later rows may refer to earlier ones, so each is written as it goes and undone on
+        // its own when it fails
+        let savepoint = txn.begin().await?;
+
+        let result = match kind {
+            CatalogKind::Drinks => import_drink(&record, organization, branch, &savepoint).await,
+            CatalogKind::Foods => import_food(&record, organization, branch, &savepoint).await,
+            CatalogKind::Categories => {
+                import_category(&record, organization, branch, &savepoint).await
+            }
+            CatalogKind::Suppliers => {
+                import_supplier(&record, organization, branch, &savepoint).await
+            }
+        };
+
+        let row = match result {
+            Ok((action, id)) => {
+                savepoint.commit().await?;
+
+                ImportRow {
+                    line: record.line,
+                    name: record.text("name").unwrap_or_default(),
+                    action: Some(action),
+                    id: Some(id),
+                    errors: Vec::new(),
+                }
+            }
+            Err(errors) => {
+                savepoint.rollback().await?;
+
+                ImportRow {
+                    line: record.line,
+                    name: record.text("name").unwrap_or_default(),
+                    action: None,
+                    id: None,
+                    errors,
+                }
+            }
+        };
+
+        report.add(row);
+    }
+
+    if report.dry_run || report.failed > 0 {
+        txn.rollback().await?;
+
+        // Rows that would have been created have no id to speak of
+        for row in &mut report.rows {
+            if row.action == Some(ImportAction::Create) {
+                row.id = None;
+            }
+        }
+    } else {
+        txn.commit().await?;
+        report.applied = true;
+    }
+
+    Ok(report)
+}
+
+/// The branch's rows of `kind` as a CSV its import takes back.
+pub async fn export_catalog(
+    kind: CatalogKind,
+    organization: uuid::Uuid,
+    branch: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<String, DbErr> {
+    let categories = match kind {
+        CatalogKind::Suppliers => HashMap::new(),
+        _ => branch_categories(organization, branch, state.pg_db.get_ref()).await?,
+    };
+    let category_names: HashMap<uuid::Uuid, String> = categories
+        .values()
+        .map(|category| (category.id, category.name.clone()))
+        .collect();
+
+    match kind {
+        CatalogKind::Drinks => {
+            let suppliers: HashMap<uuid::Uuid, String> = branch_suppliers(branch, state)
+                .await?
+                .into_iter()
+                .map(|supplier| (supplier.id, supplier.name))
+                .collect();
+
+            Ok(drinks_csv(
+                &get_branch_drinks(branch, state).await?,
+                &category_names,
+                &suppliers,
+            ))
+        }
+        CatalogKind::Foods => Ok(foods_csv(
+            &get_branch_foods(branch, state).await?,
+            &category_names,
+        )),
+        CatalogKind::Categories => Ok(categories_csv(&categories)),
+        CatalogKind::Suppliers => Ok(suppliers_csv(&branch_suppliers(branch, state).await?)),
+    }
+}
+
+async fn branch_suppliers(
+    branch: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<Vec<entity::suppliers::Model>, DbErr> {
+    entity::suppliers::Entity::find()
+        .filter(entity::suppliers::Column::BranchId.eq(branch))
+        .order_by_asc(entity::suppliers::Column::Name)
+        .all(state.pg_db.get_ref())
+        .await
+        .map_err(|err| {
+            eprintln!("Database retrieval error: {}", err);
+            DbErr::Custom(err.to_string())
+        })
+}
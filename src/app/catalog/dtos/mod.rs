@@ -0,0 +1 @@
+pub mod dto;
\ No newline at end of file
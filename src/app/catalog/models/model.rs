@@ -0,0 +1,70 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+/// A CSV document to import, its first line naming the columns. With `dry_run` every row is
+/// checked and the report says what would change, but nothing is written.
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct ImportParams {
+    #[validate(length(min = 1, max = 1000000, message = "CSV is invalid"))]
+    pub csv: String,
+    pub dry_run: Option<bool>,
+}
+
+/// What a catalog CSV holds.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum CatalogKind {
+    Drinks,
+    Foods,
+    Categories,
+    Suppliers,
+}
+
+impl CatalogKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CatalogKind::Drinks => "DRINKS",
+            CatalogKind::Foods => "FOODS",
+            CatalogKind::Categories => "CATEGORIES",
+            CatalogKind::Suppliers => "SUPPLIERS",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum ImportAction {
+    Create,
+    Update,
+}
+
+/// A record of the document by column name. Empty cells are left out.
+#[derive(Debug, Clone)]
+pub struct ImportRecord {
+    pub line: usize,
+    pub values: HashMap<String, String>,
+}
+
+/// What importing one row did, or would do on a dry run. A row with errors changes nothing.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportRow {
+    pub line: usize,
+    pub name: String,
+    pub action: Option<ImportAction>,
+    pub id: Option<uuid::Uuid>,
+    pub errors: Vec<String>,
+}
+
+/// The outcome of an import. Rows are only `applied` when none failed and it wasn't a dry run.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ImportReport {
+    pub dry_run: bool,
+    pub applied: bool,
+    pub created: usize,
+    pub updated: usize,
+    pub failed: usize,
+    pub rows: Vec<ImportRow>,
+}
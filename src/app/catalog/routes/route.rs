@@ -0,0 +1,72 @@
+use actix_web::web;
+
+use crate::{
+    app::catalog::controllers::controller::{
+        export_categories, export_drinks, export_foods, export_suppliers, import_categories,
+        import_drinks, import_foods, import_suppliers,
+    },
+    middlewares::{auth::JwtAuthMiddleware, checker::CheckUserMiddleware},
+    AppState,
+};
+
+pub fn routes(cfg: &mut web::ServiceConfig, state: web::Data<AppState>) {
+    cfg.service(
+        web::scope("/api/v1/catalog")
+            .route(
+                "/import/drinks",
+                web::post()
+                    .to(import_drinks)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/export/drinks",
+                web::get()
+                    .to(export_drinks)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/import/foods",
+                web::post()
+                    .to(import_foods)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/export/foods",
+                web::get()
+                    .to(export_foods)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/import/categories",
+                web::post()
+                    .to(import_categories)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/export/categories",
+                web::get()
+                    .to(export_categories)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/import/suppliers",
+                web::post()
+                    .to(import_suppliers)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/export/suppliers",
+                web::get()
+                    .to(export_suppliers)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            ),
+    );
+}
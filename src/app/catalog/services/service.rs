@@ -0,0 +1,508 @@
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+};
+
+use chrono::NaiveTime;
+use sea_orm::prelude::Decimal;
+use validator::{Validate, ValidationErrors};
+
+use crate::{
+    app::{
+        catalog::models::model::{
+            CatalogKind, ImportAction, ImportRecord, ImportReport, ImportRow,
+        },
+        categories::models::model::CategoryParams,
+        inventory::models::model::{DrinkParams, FoodParams},
+        purchasing::models::model::SupplierParams,
+    },
+    utils::csv::{csv_document, csv_value, parse_csv},
+};
+
+const DRINK_COLUMNS: [&str; 13] = [
+    "sku",
+    "name",
+    "category",
+    "supplier",
+    "unit_price",
+    "sale_price",
+    "is_alcoholic",
+    "quantity",
+    "reorder_threshold",
+    "markup_rate",
+    "reduction_rate",
+    "measure_unit",
+    "unit_size",
+];
+
+const FOOD_COLUMNS: [&str; 5] = ["sku", "name", "category", "price", "description"];
+
+const CATEGORY_COLUMNS: [&str; 9] = [
+    "name",
+    "description",
+    "category_type",
+    "parent",
+    "branch_only",
+    "sort_order",
+    "is_active",
+    "available_from",
+    "available_until",
+];
+
+const SUPPLIER_COLUMNS: [&str; 4] = ["name", "contact", "email", "delivery_weekdays"];
+
+impl CatalogKind {
+    /// The columns a CSV of this kind may have, in export order.
+    pub fn columns(&self) -> &'static [&'static str] {
+        match self {
+            CatalogKind::Drinks => &DRINK_COLUMNS,
+            CatalogKind::Foods => &FOOD_COLUMNS,
+            CatalogKind::Categories => &CATEGORY_COLUMNS,
+            CatalogKind::Suppliers => &SUPPLIER_COLUMNS,
+        }
+    }
+
+    /// The columns an imported CSV of this kind must have.
+    fn required(&self) -> &'static [&'static str] {
+        match self {
+            CatalogKind::Drinks => &["name", "category", "supplier", "unit_price", "sale_price"],
+            CatalogKind::Foods => &["name", "category", "price"],
+            CatalogKind::Categories => &["name"],
+            CatalogKind::Suppliers => &["name", "contact", "email"],
+        }
+    }
+}
+
+impl ImportRecord {
+    pub fn text(&self, column: &str) -> Option<String> {
+        self.values.get(column).cloned()
+    }
+
+    fn parse<T: FromStr>(&self, column: &str, errors: &mut Vec<String>) -> Option<T> {
+        let value = self.values.get(column)?;
+
+        value
+            .parse()
+            .map_err(|_| errors.push(format!("{}: {} is invalid", column, value)))
+            .ok()
+    }
+
+    fn required<T: FromStr>(&self, column: &str, errors: &mut Vec<String>) -> Option<T> {
+        if !self.values.contains_key(column) {
+            errors.push(format!("{}: is required", column));
+        }
+
+        self.parse(column, errors)
+    }
+
+    fn flag(&self, column: &str, errors: &mut Vec<String>) -> Option<bool> {
+        let value = self.values.get(column)?;
+
+        match value.to_lowercase().as_str() {
+            "true" | "yes" | "1" => Some(true),
+            "false" | "no" | "0" => Some(false),
+            _ => {
+                errors.push(format!("{}: {} is invalid", column, value));
+                None
+            }
+        }
+    }
+
+    fn time(&self, column: &str, errors: &mut Vec<String>) -> Option<NaiveTime> {
+        let value = self.values.get(column)?;
+
+        NaiveTime::parse_from_str(value, "%H:%M:%S")
+            .or_else(|_| NaiveTime::parse_from_str(value, "%H:%M"))
+            .map_err(|_| errors.push(format!("{}: {} is invalid", column, value)))
+            .ok()
+    }
+
+    /// Weekdays separated by semicolons, e.g. `1;3;5`.
+    fn weekdays(&self, column: &str, errors: &mut Vec<String>) -> Option<Vec<i32>> {
+        let value = self.values.get(column)?;
+
+        value
+            .split(';')
+            .map(|day| day.trim().parse())
+            .collect::<Result<Vec<i32>, _>>()
+            .map_err(|_| errors.push(format!("{}: {} is invalid", column, value)))
+            .ok()
+    }
+}
+
+impl ImportReport {
+    pub fn add(&mut self, row: ImportRow) {
+        match row.action {
+            _ if !row.errors.is_empty() => self.failed += 1,
+            Some(ImportAction::Create) => self.created += 1,
+            Some(ImportAction::Update) => self.updated += 1,
+            None => {}
+        }
+
+        self.rows.push(row);
+    }
+}
+
+/// The records of an imported CSV of `kind`. Columns are found by the header's names, in any
+/// order, and a file with unknown, repeated or missing required columns is refused whole.
+pub fn import_records(document: &str, kind: CatalogKind) -> Result<Vec<ImportRecord>, String> {
+    let mut records = parse_csv(document)?.into_iter();
+
+    let Some((_, header)) = records.next() else {
+        return Err("CSV is empty".to_string());
+    };
+
+    let header: Vec<String> = header
+        .iter()
+        .map(|column| column.trim().to_lowercase())
+        .collect();
+    let mut seen = HashSet::new();
+
+    for column in &header {
+        if !kind.columns().contains(&column.as_str()) {
+            return Err(format!("Unknown column {}", column));
+        }
+
+        if !seen.insert(column.as_str()) {
+            return Err(format!("Column {} is repeated", column));
+        }
+    }
+
+    if let Some(missing) = kind
+        .required()
+        .iter()
+        .find(|column| !seen.contains(**column))
+    {
+        return Err(format!("Missing column {}", missing));
+    }
+
+    records
+        .map(|(line, fields)| {
+            if fields.len() > header.len() {
+                return Err(format!(
+                    "Line {}: has {} fields, the header {}",
+                    line,
+                    fields.len(),
+                    header.len()
+                ));
+            }
+
+            let values = header
+                .iter()
+                .zip(fields)
+                .map(|(column, value)| (column.clone(), csv_value(value.trim()).to_string()))
+                .filter(|(_, value)| !value.is_empty())
+                .collect();
+
+            Ok(ImportRecord { line, values })
+        })
+        .collect()
+}
+
+/// Validation errors as `field: message` lines, sorted by field.
+fn validation_messages(errors: &ValidationErrors) -> Vec<String> {
+    let mut messages: Vec<String> = errors
+        .field_errors()
+        .into_iter()
+        .flat_map(|(field, errors)| {
+            errors.iter().map(move |error| {
+                format!(
+                    "{}: {}",
+                    field,
+                    error.message.as_ref().unwrap_or(&error.code)
+                )
+            })
+        })
+        .collect();
+
+    messages.sort();
+    messages
+}
+
+/// Fails with the row's errors so far, or with what the params' own rules reject.
+fn checked<T: Validate>(params: T, errors: Vec<String>) -> Result<T, Vec<String>> {
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    params
+        .validate()
+        .map_err(|errors| validation_messages(&errors))?;
+
+    Ok(params)
+}
+
+/// A drink row as the params the drink endpoints take. The category and supplier are resolved
+/// by the caller. A drink created without a quantity starts with none in stock.
+pub fn drink_params(
+    record: &ImportRecord,
+    category: Result<uuid::Uuid, String>,
+    supplier: Result<uuid::Uuid, String>,
+) -> Result<DrinkParams, Vec<String>> {
+    let mut errors: Vec<String> = [&category, &supplier]
+        .into_iter()
+        .filter_map(|reference| reference.clone().err())
+        .collect();
+
+    let name = record.required("name", &mut errors);
+    let unit_price = record.required("unit_price", &mut errors);
+    let sale_price = record.required("sale_price", &mut errors);
+    let quantity = record.parse("quantity", &mut errors);
+    let is_alcoholic = record.flag("is_alcoholic", &mut errors);
+    let reorder_threshold = record.parse("reorder_threshold", &mut errors);
+    let markup_rate = record.parse::<Decimal>("markup_rate", &mut errors);
+    let reduction_rate = record.parse::<Decimal>("reduction_rate", &mut errors);
+    let unit_size = record.parse::<Decimal>("unit_size", &mut errors);
+
+    let (Some(name), Some(unit_price), Some(sale_price), Ok(category), Ok(supplier)) =
+        (name, unit_price, sale_price, category, supplier)
+    else {
+        return Err(errors);
+    };
+
+    checked(
+        DrinkParams {
+            name,
+            unit_price,
+            sale_price,
+            is_alcoholic,
+            quantity: quantity.unwrap_or(0),
+            reorder_threshold,
+            markup_rate,
+            reduction_rate,
+            category,
+            supplier,
+            measure_unit: record.text("measure_unit").map(|unit| unit.to_uppercase()),
+            unit_size,
+            sku: record.text("sku"),
+        },
+        errors,
+    )
+}
+
+pub fn food_params(
+    record: &ImportRecord,
+    category: Result<uuid::Uuid, String>,
+) -> Result<FoodParams, Vec<String>> {
+    let mut errors: Vec<String> = category.clone().err().into_iter().collect();
+
+    let name = record.required("name", &mut errors);
+    let price = record.required("price", &mut errors);
+
+    let (Some(name), Some(price), Ok(category)) = (name, price, category) else {
+        return Err(errors);
+    };
+
+    checked(
+        FoodParams {
+            name,
+            price,
+            description: record.text("description"),
+            category,
+            sku: record.text("sku"),
+        },
+        errors,
+    )
+}
+
+/// A category row as the params the category endpoints take. The parent, when there is one,
+/// is resolved by the caller.
+pub fn category_params(
+    record: &ImportRecord,
+    parent: Result<Option<uuid::Uuid>, String>,
+) -> Result<CategoryParams, Vec<String>> {
+    let mut errors: Vec<String> = parent.clone().err().into_iter().collect();
+
+    let name = record.required("name", &mut errors);
+    let branch_only = record.flag("branch_only", &mut errors);
+    let sort_order = record.parse("sort_order", &mut errors);
+    let is_active = record.flag("is_active", &mut errors);
+    let available_from = record.time("available_from", &mut errors);
+    let available_until = record.time("available_until", &mut errors);
+
+    let (Some(name), Ok(parent)) = (name, parent) else {
+        return Err(errors);
+    };
+
+    checked(
+        CategoryParams {
+            name,
+            description: record.text("description"),
+            category_type: record.text("category_type"),
+            parent,
+            branch_only,
+            sort_order,
+            is_active,
+            available_from,
+            available_until,
+        },
+        errors,
+    )
+}
+
+pub fn supplier_params(record: &ImportRecord) -> Result<SupplierParams, Vec<String>> {
+    let mut errors = Vec::new();
+
+    let name = record.required("name", &mut errors);
+    let contact = record.required("contact", &mut errors);
+    let email = record.required("email", &mut errors);
+    let delivery_weekdays = record.weekdays("delivery_weekdays", &mut errors);
+
+    let (Some(name), Some(contact), Some(email)) = (name, contact, email) else {
+        return Err(errors);
+    };
+
+    checked(
+        SupplierParams {
+            name,
+            contact,
+            email,
+            delivery_weekdays,
+        },
+        errors,
+    )
+}
+
+fn name_of(names: &HashMap<uuid::Uuid, String>, id: Option<uuid::Uuid>) -> String {
+    id.and_then(|id| names.get(&id).cloned())
+        .unwrap_or_default()
+}
+
+/// The branch's drinks as a CSV the drink import takes back. Categories and suppliers are
+/// named rather than given by id.
+pub fn drinks_csv(
+    drinks: &[entity::stock_drinks::Model],
+    categories: &HashMap<uuid::Uuid, String>,
+    suppliers: &HashMap<uuid::Uuid, String>,
+) -> String {
+    csv_document(
+        &DRINK_COLUMNS,
+        drinks.iter().map(|drink| {
+            vec![
+                drink.sku.clone().unwrap_or_default(),
+                drink.name.clone(),
+                name_of(categories, Some(drink.category_id)),
+                name_of(suppliers, Some(drink.supplier_id)),
+                drink.unit_price.to_string(),
+                drink.sale_price.to_string(),
+                drink.is_alcholic.to_string(),
+                drink.quantity.to_string(),
+                drink.reorder_threshold.to_string(),
+                if drink.is_markup_applied {
+                    drink.markup_rate.to_string()
+                } else {
+                    String::new()
+                },
+                if drink.is_reduced {
+                    drink.reduction_rate.to_string()
+                } else {
+                    String::new()
+                },
+                drink.measure_unit.clone(),
+                drink.unit_size.to_string(),
+            ]
+        }),
+    )
+}
+
+pub fn foods_csv(
+    foods: &[entity::stock_foods::Model],
+    categories: &HashMap<uuid::Uuid, String>,
+) -> String {
+    csv_document(
+        &FOOD_COLUMNS,
+        foods.iter().map(|food| {
+            vec![
+                food.sku.clone().unwrap_or_default(),
+                food.name.clone(),
+                name_of(categories, Some(food.category_id)),
+                food.price.to_string(),
+                food.description.clone().unwrap_or_default(),
+            ]
+        }),
+    )
+}
+
+/// The organization's own categories the branch sees, as a CSV the category import takes
+/// back. Parents come before their subcategories so the import can find them by name.
+pub fn categories_csv(categories: &HashMap<uuid::Uuid, entity::categories::Model>) -> String {
+    let depth = |category: &entity::categories::Model| {
+        let mut depth = 0;
+        let mut parent = category.parent_id;
+
+        while let Some(id) = parent {
+            depth += 1;
+            parent = categories
+                .get(&id)
+                .and_then(|parent| parent.parent_id)
+                .filter(|_| depth < categories.len());
+        }
+
+        depth
+    };
+
+    let mut own: Vec<(usize, &entity::categories::Model)> = categories
+        .values()
+        .filter(|category| category.organization_id.is_some())
+        .map(|category| (depth(category), category))
+        .collect();
+
+    own.sort_by(|(a_depth, a), (b_depth, b)| {
+        a_depth
+            .cmp(b_depth)
+            .then_with(|| a.sort_order.cmp(&b.sort_order))
+            .then_with(|| a.name.cmp(&b.name))
+    });
+
+    let names: HashMap<uuid::Uuid, String> = categories
+        .values()
+        .map(|category| (category.id, category.name.clone()))
+        .collect();
+
+    csv_document(
+        &CATEGORY_COLUMNS,
+        own.into_iter().map(|(_, category)| {
+            vec![
+                category.name.clone(),
+                category.description.clone().unwrap_or_default(),
+                category.category_type.clone().unwrap_or_default(),
+                name_of(&names, category.parent_id),
+                category.branch_id.is_some().to_string(),
+                category.sort_order.to_string(),
+                category.is_active.to_string(),
+                category
+                    .available_from
+                    .map(|time| time.to_string())
+                    .unwrap_or_default(),
+                category
+                    .available_until
+                    .map(|time| time.to_string())
+                    .unwrap_or_default(),
+            ]
+        }),
+    )
+}
+
+pub fn suppliers_csv(suppliers: &[entity::suppliers::Model]) -> String {
+    csv_document(
+        &SUPPLIER_COLUMNS,
+        suppliers.iter().map(|supplier| {
+            vec![
+                supplier.name.clone(),
+                supplier.contact.clone(),
+                supplier.email.clone(),
+                supplier
+                    .delivery_weekdays
+                    .as_ref()
+                    .map(|weekdays| {
+                        weekdays
+                            .iter()
+                            .map(|day| day.to_string())
+                            .collect::<Vec<String>>()
+                            .join(";")
+                    })
+                    .unwrap_or_default(),
+            ]
+        }),
+    )
+}
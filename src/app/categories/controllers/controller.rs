@@ -0,0 +1,272 @@
+use std::sync::Arc;
+
+use actix_web::{http::header, web, HttpMessage, HttpRequest, HttpResponse};
+use serde_json::json;
+
+use crate::{
+    app::{
+        categories::{
+            dtos::dto::{
+                delete_category, get_branch_categories, get_branch_menu, reorder_categories,
+                save_category, update_category,
+            },
+            models::model::{CategoryParams, ReorderCategoriesParams},
+            services::service::{etag_matches, menu_etag},
+        },
+        employees::models::model::EmployeeResponse,
+        permissions::{dtos::dto::has_permission, models::model::ModelAction},
+    },
+    libs::error::Error,
+    utils::{
+        json_validator::{ValidatedJson, ValidatedPath},
+        models::{HttpClientResponse, PathParamsModel, ResponseCode},
+    },
+    AppState,
+};
+
+/// How long clients and proxies may reuse a menu before checking its tag again. Prices and
+/// serving windows change by the clock, so it is kept short.
+const MENU_CACHE_CONTROL: &str = "public, max-age=60";
+
+pub async fn add_category(
+    req: HttpRequest,
+    payload: ValidatedJson<CategoryParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    if !has_permission(model.role_permissions, ModelAction::ManageInventory, &state)
+        .await
+        .unwrap_or(false)
+    {
+        return Ok(HttpResponse::Forbidden().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            "Not Permitted To Manage Inventory".to_string(),
+            json!({}),
+        )));
+    }
+
+    let category = payload.0.into_dto(model.organization_id, model.branch_id);
+
+    match save_category(category, model.branch_id, &state).await {
+        Ok(res) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Category Added Successfully".to_string(),
+            json!(res),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Error Adding Category: {}", e),
+            json!({}),
+        ))),
+    }
+}
+
+pub async fn edit_category(
+    req: HttpRequest,
+    params: ValidatedPath<PathParamsModel>,
+    payload: ValidatedJson<CategoryParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    if !has_permission(model.role_permissions, ModelAction::ManageInventory, &state)
+        .await
+        .unwrap_or(false)
+    {
+        return Ok(HttpResponse::Forbidden().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            "Not Permitted To Manage Inventory".to_string(),
+            json!({}),
+        )));
+    }
+
+    let category = payload.0.into_dto(model.organization_id, model.branch_id);
+
+    match update_category(params.0.id, category, model.branch_id, &state).await {
+        Ok(res) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Category Updated Successfully".to_string(),
+            json!(res),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Error Updating Category: {}", e),
+            json!({}),
+        ))),
+    }
+}
+
+pub async fn reorder_branch_categories(
+    req: HttpRequest,
+    payload: ValidatedJson<ReorderCategoriesParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    if !has_permission(model.role_permissions, ModelAction::ManageInventory, &state)
+        .await
+        .unwrap_or(false)
+    {
+        return Ok(HttpResponse::Forbidden().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            "Not Permitted To Manage Inventory".to_string(),
+            json!({}),
+        )));
+    }
+
+    match reorder_categories(
+        model.organization_id,
+        model.branch_id,
+        payload.0.categories,
+        &state,
+    )
+    .await
+    {
+        Ok(res) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Categories Reordered Successfully".to_string(),
+            json!(res),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Error Reordering Categories: {}", e),
+            json!({}),
+        ))),
+    }
+}
+
+pub async fn remove_category(
+    req: HttpRequest,
+    params: ValidatedPath<PathParamsModel>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    if !has_permission(model.role_permissions, ModelAction::ManageInventory, &state)
+        .await
+        .unwrap_or(false)
+    {
+        return Ok(HttpResponse::Forbidden().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            "Not Permitted To Manage Inventory".to_string(),
+            json!({}),
+        )));
+    }
+
+    match delete_category(params.0.id, model.organization_id, model.branch_id, &state).await {
+        Ok(res) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Category Deleted Successfully".to_string(),
+            json!(res),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Error Deleting Category: {}", e),
+            json!({}),
+        ))),
+    }
+}
+
+pub async fn branch_categories(
+    req: HttpRequest,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    match get_branch_categories(model.organization_id, model.branch_id, &state).await {
+        Ok(res) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Categories Fetched Successfully".to_string(),
+            json!(res),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Error Retrieving: {}", e),
+            json!([]),
+        ))),
+    }
+}
+
+/// The branch's menu for customers. It carries an entity tag, and a client sending back the
+/// tag of the menu it holds gets `304 Not Modified` while nothing on the menu has changed.
+pub async fn branch_menu(
+    req: HttpRequest,
+    params: ValidatedPath<PathParamsModel>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let menu = match get_branch_menu(params.0.id, &state).await {
+        Ok(menu) => menu,
+        Err(e) => {
+            return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+                ResponseCode::Failed,
+                format!("Error Retrieving Menu: {}", e),
+                json!({}),
+            )))
+        }
+    };
+
+    let etag = menu_etag(&menu);
+
+    let unchanged = req
+        .headers()
+        .get(header::IF_NONE_MATCH)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| etag_matches(value, &etag));
+
+    if unchanged {
+        return Ok(HttpResponse::NotModified()
+            .insert_header((header::ETAG, etag))
+            .insert_header((header::CACHE_CONTROL, MENU_CACHE_CONTROL))
+            .finish());
+    }
+
+    Ok(HttpResponse::Ok()
+        .insert_header((header::ETAG, etag))
+        .insert_header((header::CACHE_CONTROL, MENU_CACHE_CONTROL))
+        .json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Menu Fetched Successfully".to_string(),
+            json!(menu),
+        )))
+}
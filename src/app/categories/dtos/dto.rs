@@ -0,0 +1,526 @@
+use std::collections::HashMap;
+
+use actix_web::web;
+use chrono_tz::Tz;
+use sea_orm::{
+    sea_query::{Expr, Func},
+    ActiveModelTrait, ActiveValue, ColumnTrait, Condition, ConnectionTrait, DbErr, EntityTrait,
+    PaginatorTrait, QueryFilter, QueryOrder, Set, TransactionTrait,
+};
+
+use crate::{
+    app::{
+        categories::{
+            models::model::{
+                BranchMenu, CategoryNode, CategoryPositionParams, MenuItem, SaveCategoryDto,
+            },
+            services::service::{branch_menu, category_tree, is_serving, is_within},
+        },
+        cocktails::models::model::CocktailStatus,
+        inventory::models::model::StockStatus,
+        orders::models::model::ItemType,
+        pricing::{dtos::dto::branch_price_rules, services::service::effective_price},
+        recipes::{dtos::dto::item_recipes, services::service::can_serve},
+    },
+    utils::time::branch_timezone,
+    AppState,
+};
+
+/// Categories a branch can use: shared ones, its organization's and its own.
+fn visible_to(organization: uuid::Uuid, branch: uuid::Uuid) -> Condition {
+    Condition::any()
+        .add(entity::categories::Column::OrganizationId.is_null())
+        .add(
+            Condition::all()
+                .add(entity::categories::Column::OrganizationId.eq(organization))
+                .add(
+                    Condition::any()
+                        .add(entity::categories::Column::BranchId.is_null())
+                        .add(entity::categories::Column::BranchId.eq(branch)),
+                ),
+        )
+}
+
+pub async fn branch_categories<C: ConnectionTrait>(
+    organization: uuid::Uuid,
+    branch: uuid::Uuid,
+    conn: &C,
+) -> Result<HashMap<uuid::Uuid, entity::categories::Model>, DbErr> {
+    Ok(entity::categories::Entity::find()
+        .filter(visible_to(organization, branch))
+        .all(conn)
+        .await
+        .map_err(|err| {
+            eprintln!("Database retrieval error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?
+        .into_iter()
+        .map(|category| (category.id, category))
+        .collect())
+}
+
+/// Looks up a category the branch can file items under.
+pub async fn find_branch_category<C: ConnectionTrait>(
+    category: uuid::Uuid,
+    organization: uuid::Uuid,
+    branch: uuid::Uuid,
+    conn: &C,
+) -> Result<entity::categories::Model, DbErr> {
+    entity::categories::Entity::find_by_id(category)
+        .filter(visible_to(organization, branch))
+        .one(conn)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Category not found".into()))
+}
+
+/// Looks up a category the branch can file items under by name, ignoring case. The branch's
+/// own categories come first, then its organization's, then shared ones.
+pub async fn find_branch_category_named<C: ConnectionTrait>(
+    name: &str,
+    organization: uuid::Uuid,
+    branch: uuid::Uuid,
+    conn: &C,
+) -> Result<entity::categories::Model, DbErr> {
+    entity::categories::Entity::find()
+        .filter(visible_to(organization, branch))
+        .filter(
+            Expr::expr(Func::lower(Expr::col(entity::categories::Column::Name)))
+                .eq(name.to_lowercase()),
+        )
+        .order_by_asc(Expr::col(entity::categories::Column::BranchId).is_null())
+        .order_by_asc(Expr::col(entity::categories::Column::OrganizationId).is_null())
+        .one(conn)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Category not found".into()))
+}
+
+/// Categories the branch's staff may change: the organization's own, either shared by its
+/// branches or kept to this one. Shared categories without an organization are read only.
+fn own_to(organization: uuid::Uuid, branch: uuid::Uuid) -> Condition {
+    Condition::all()
+        .add(entity::categories::Column::OrganizationId.eq(organization))
+        .add(
+            Condition::any()
+                .add(entity::categories::Column::BranchId.is_null())
+                .add(entity::categories::Column::BranchId.eq(branch)),
+        )
+}
+
+async fn find_own_category<C: ConnectionTrait>(
+    id: uuid::Uuid,
+    organization: uuid::Uuid,
+    branch: uuid::Uuid,
+    conn: &C,
+) -> Result<entity::categories::Model, DbErr> {
+    entity::categories::Entity::find_by_id(id)
+        .filter(own_to(organization, branch))
+        .one(conn)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Category not found".into()))
+}
+
+/// Same as `find_own_category`, by name ignoring case. The branch's own category comes before
+/// one of the same name shared by the organization's branches.
+pub async fn find_own_category_named<C: ConnectionTrait>(
+    name: &str,
+    organization: uuid::Uuid,
+    branch: uuid::Uuid,
+    conn: &C,
+) -> Result<Option<entity::categories::Model>, DbErr> {
+    entity::categories::Entity::find()
+        .filter(own_to(organization, branch))
+        .filter(
+            Expr::expr(Func::lower(Expr::col(entity::categories::Column::Name)))
+                .eq(name.to_lowercase()),
+        )
+        .order_by_asc(Expr::col(entity::categories::Column::BranchId).is_null())
+        .one(conn)
+        .await
+}
+
+/// Checks where a category is placed. Its parent must be visible wherever the category is, so
+/// a category shared by the organization's branches can't sit under one kept to a branch, and
+/// it can't sit under itself or one of its own subcategories.
+async fn check_placement<C: ConnectionTrait>(
+    id: Option<uuid::Uuid>,
+    data: &SaveCategoryDto,
+    branch: uuid::Uuid,
+    conn: &C,
+) -> Result<(), DbErr> {
+    if data.available_from.is_some() != data.available_until.is_some() {
+        return Err(DbErr::Custom(
+            "Serving window needs both a start and an end time".to_string(),
+        ));
+    }
+
+    if data.available_from.is_some() && data.available_from == data.available_until {
+        return Err(DbErr::Custom(
+            "Serving window start and end time must differ".to_string(),
+        ));
+    }
+
+    let Some(parent) = data.parent else {
+        return Ok(());
+    };
+
+    let categories = branch_categories(data.organization, branch, conn).await?;
+
+    let parent = categories
+        .get(&parent)
+        .ok_or_else(|| DbErr::RecordNotFound("Parent category not found".into()))?;
+
+    if data.branch.is_none() && parent.branch_id.is_some() {
+        return Err(DbErr::Custom(
+            "A category shared by all branches can't sit under a branch's own category".to_string(),
+        ));
+    }
+
+    if let Some(id) = id {
+        if is_within(parent.id, id, &categories) {
+            return Err(DbErr::Custom(
+                "A category can't sit under itself or one of its subcategories".to_string(),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+pub async fn insert_category<C: ConnectionTrait>(
+    data: SaveCategoryDto,
+    branch: uuid::Uuid,
+    conn: &C,
+) -> Result<entity::categories::Model, DbErr> {
+    check_placement(None, &data, branch, conn).await?;
+
+    let category = entity::categories::ActiveModel {
+        organization_id: Set(Some(data.organization)),
+        branch_id: Set(data.branch),
+        parent_id: Set(data.parent),
+        name: Set(data.name),
+        description: Set(data.description),
+        category_type: Set(data.category_type),
+        sort_order: Set(data.sort_order),
+        is_active: Set(data.is_active),
+        available_from: Set(data.available_from),
+        available_until: Set(data.available_until),
+        ..Default::default()
+    };
+
+    category.insert(conn).await.map_err(|err| {
+        eprintln!("Database insert error: {}", err);
+        DbErr::Custom(err.to_string())
+    })
+}
+
+pub async fn save_category(
+    data: SaveCategoryDto,
+    branch: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<entity::categories::Model, DbErr> {
+    insert_category(data, branch, state.pg_db.get_ref()).await
+}
+
+/// Rewrites one of the organization's categories. A category keeps to one branch only while
+/// none of its subcategories are shared by the other branches.
+pub async fn rewrite_category<C: ConnectionTrait>(
+    category: entity::categories::Model,
+    data: SaveCategoryDto,
+    branch: uuid::Uuid,
+    conn: &C,
+) -> Result<entity::categories::Model, DbErr> {
+    let id = category.id;
+
+    check_placement(Some(id), &data, branch, conn).await?;
+
+    if data.branch.is_some() {
+        let shared_children = entity::categories::Entity::find()
+            .filter(
+                Condition::all()
+                    .add(entity::categories::Column::ParentId.eq(id))
+                    .add(entity::categories::Column::BranchId.is_null()),
+            )
+            .count(conn)
+            .await?;
+
+        if shared_children > 0 {
+            return Err(DbErr::Custom(
+                "Category has subcategories shared by all branches".to_string(),
+            ));
+        }
+    }
+
+    let mut model: entity::categories::ActiveModel = category.into();
+
+    model.branch_id = ActiveValue::Set(data.branch);
+    model.parent_id = ActiveValue::Set(data.parent);
+    model.name = ActiveValue::Set(data.name);
+    model.description = ActiveValue::Set(data.description);
+    model.category_type = ActiveValue::Set(data.category_type);
+    model.sort_order = ActiveValue::Set(data.sort_order);
+    model.is_active = ActiveValue::Set(data.is_active);
+    model.available_from = ActiveValue::Set(data.available_from);
+    model.available_until = ActiveValue::Set(data.available_until);
+    model.updated_at = ActiveValue::Set(chrono::Utc::now().into());
+
+    model.update(conn).await.map_err(|err| {
+        eprintln!("Database update error: {}", err);
+        DbErr::Custom(err.to_string())
+    })
+}
+
+pub async fn update_category(
+    id: uuid::Uuid,
+    data: SaveCategoryDto,
+    branch: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<entity::categories::Model, DbErr> {
+    let txn = state.pg_db.get_ref().begin().await?;
+
+    let category = find_own_category(id, data.organization, branch, &txn).await?;
+    let category = rewrite_category(category, data, branch, &txn).await?;
+
+    txn.commit().await?;
+
+    Ok(category)
+}
+
+/// Moves categories among their siblings. Every category must be one the branch's staff may
+/// change, otherwise none are moved.
+pub async fn reorder_categories(
+    organization: uuid::Uuid,
+    branch: uuid::Uuid,
+    positions: Vec<CategoryPositionParams>,
+    state: &web::Data<AppState>,
+) -> Result<Vec<entity::categories::Model>, DbErr> {
+    let txn = state.pg_db.get_ref().begin().await?;
+
+    let mut categories = Vec::with_capacity(positions.len());
+
+    for position in positions {
+        let category = find_own_category(position.category, organization, branch, &txn).await?;
+
+        let mut model: entity::categories::ActiveModel = category.into();
+
+        model.sort_order = ActiveValue::Set(position.sort_order);
+        model.updated_at = ActiveValue::Set(chrono::Utc::now().into());
+
+        categories.push(model.update(&txn).await.map_err(|err| {
+            eprintln!("Database update error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?);
+    }
+
+    txn.commit().await?;
+
+    Ok(categories)
+}
+
+/// Deletes a category that nothing is filed under. Items would otherwise go with it.
+pub async fn delete_category(
+    id: uuid::Uuid,
+    organization: uuid::Uuid,
+    branch: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<entity::categories::Model, DbErr> {
+    let category = find_own_category(id, organization, branch, state.pg_db.get_ref()).await?;
+
+    let children = entity::categories::Entity::find()
+        .filter(entity::categories::Column::ParentId.eq(id))
+        .count(state.pg_db.get_ref())
+        .await?;
+    let drinks = entity::stock_drinks::Entity::find()
+        .filter(entity::stock_drinks::Column::CategoryId.eq(id))
+        .count(state.pg_db.get_ref())
+        .await?;
+    let foods = entity::stock_foods::Entity::find()
+        .filter(entity::stock_foods::Column::CategoryId.eq(id))
+        .count(state.pg_db.get_ref())
+        .await?;
+    let cocktails = entity::custom_cocktails::Entity::find()
+        .filter(entity::custom_cocktails::Column::CategoryId.eq(id))
+        .count(state.pg_db.get_ref())
+        .await?;
+
+    if children > 0 {
+        return Err(DbErr::Custom("Category has subcategories".to_string()));
+    }
+
+    if drinks + foods + cocktails > 0 {
+        return Err(DbErr::Custom("Category still has items".to_string()));
+    }
+
+    entity::categories::Entity::delete_by_id(id)
+        .exec(state.pg_db.get_ref())
+        .await
+        .map_err(|err| {
+            eprintln!("Database delete error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    Ok(category)
+}
+
+/// The categories the branch can use, under their parents. Inactive ones are included.
+pub async fn get_branch_categories(
+    organization: uuid::Uuid,
+    branch: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<Vec<CategoryNode>, DbErr> {
+    let categories = branch_categories(organization, branch, state.pg_db.get_ref()).await?;
+
+    Ok(category_tree(&categories))
+}
+
+/// Refuses a sale when an item's category isn't being served at `at`, on the branch's clock,
+/// e.g. food once the kitchen has closed. `items` are each item's name and category.
+pub async fn check_serving<C: ConnectionTrait>(
+    items: &[(String, uuid::Uuid)],
+    organization: uuid::Uuid,
+    branch: uuid::Uuid,
+    at: chrono::DateTime<Tz>,
+    conn: &C,
+) -> Result<(), DbErr> {
+    let categories = branch_categories(organization, branch, conn).await?;
+    let time = at.time();
+
+    for (name, category) in items {
+        if let Some(category) = categories.get(category) {
+            if !is_serving(category, &categories, time) {
+                return Err(DbErr::Custom(format!(
+                    "{} is not being served right now",
+                    name
+                )));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// The branch's public menu: its categories with the items filed under them, priced as they
+/// sell right now. Items made by recipe are sold out once a component runs short, other drinks
+/// once they are out of stock.
+pub async fn get_branch_menu(
+    branch: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<BranchMenu, DbErr> {
+    let branch = entity::branches::Entity::find_by_id(branch)
+        .filter(entity::branches::Column::IsActive.eq(true))
+        .one(state.pg_db.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Branch not found".into()))?;
+
+    let categories =
+        branch_categories(branch.organization_id, branch.id, state.pg_db.get_ref()).await?;
+
+    let drinks = entity::stock_drinks::Entity::find()
+        .filter(entity::stock_drinks::Column::BranchId.eq(branch.id))
+        .order_by_asc(entity::stock_drinks::Column::Name)
+        .all(state.pg_db.get_ref())
+        .await
+        .map_err(|err| {
+            eprintln!("Database retrieval error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+    let foods = entity::stock_foods::Entity::find()
+        .filter(entity::stock_foods::Column::BranchId.eq(branch.id))
+        .order_by_asc(entity::stock_foods::Column::Name)
+        .all(state.pg_db.get_ref())
+        .await
+        .map_err(|err| {
+            eprintln!("Database retrieval error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+    let cocktails = entity::custom_cocktails::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::custom_cocktails::Column::BranchId.eq(branch.id))
+                .add(
+                    entity::custom_cocktails::Column::CocktailStatus
+                        .eq(CocktailStatus::Approved.as_str()),
+                )
+                .add(entity::custom_cocktails::Column::IsAvailable.eq(true)),
+        )
+        .order_by_asc(entity::custom_cocktails::Column::Name)
+        .all(state.pg_db.get_ref())
+        .await
+        .map_err(|err| {
+            eprintln!("Database retrieval error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    let keys: Vec<(ItemType, uuid::Uuid)> = drinks
+        .iter()
+        .map(|drink| (ItemType::Drink, drink.id))
+        .chain(foods.iter().map(|food| (ItemType::Food, food.id)))
+        .chain(
+            cocktails
+                .iter()
+                .map(|cocktail| (ItemType::Cocktail, cocktail.id)),
+        )
+        .collect();
+    let recipes = item_recipes(&keys, state.pg_db.get_ref()).await?;
+    let rules = branch_price_rules(branch.id, state.pg_db.get_ref()).await?;
+
+    let components: HashMap<uuid::Uuid, entity::stock_drinks::Model> = drinks
+        .iter()
+        .map(|drink| (drink.id, drink.clone()))
+        .collect();
+    let sold_out = |item: uuid::Uuid| {
+        recipes
+            .get(&item)
+            .map(|recipe| !can_serve(recipe, &components))
+    };
+
+    let now = chrono::Utc::now().with_timezone(&branch_timezone(&branch));
+    let mut items: HashMap<uuid::Uuid, Vec<MenuItem>> = HashMap::new();
+
+    for drink in &drinks {
+        let price = effective_price(drink, &rules, now);
+
+        items.entry(drink.category_id).or_default().push(MenuItem {
+            item_type: ItemType::Drink,
+            id: drink.id,
+            name: drink.name.clone(),
+            description: None,
+            price: price.price,
+            base_price: price.base_price,
+            image: drink.image_id.clone(),
+            sold_out: sold_out(drink.id)
+                .unwrap_or(drink.stock_status == StockStatus::OutOfStock.as_str()),
+        });
+    }
+
+    for food in &foods {
+        items.entry(food.category_id).or_default().push(MenuItem {
+            item_type: ItemType::Food,
+            id: food.id,
+            name: food.name.clone(),
+            description: food.description.clone(),
+            price: food.price,
+            base_price: food.price,
+            image: food.image_id.clone(),
+            sold_out: sold_out(food.id).unwrap_or(false),
+        });
+    }
+
+    for cocktail in &cocktails {
+        let (Some(price), Some(category)) = (cocktail.price, cocktail.category_id) else {
+            continue;
+        };
+
+        items.entry(category).or_default().push(MenuItem {
+            item_type: ItemType::Cocktail,
+            id: cocktail.id,
+            name: cocktail.name.clone(),
+            description: cocktail.description.clone(),
+            price,
+            base_price: price,
+            image: None,
+            sold_out: sold_out(cocktail.id).unwrap_or(false),
+        });
+    }
+
+    Ok(branch_menu(&branch, &categories, &items, now.time()))
+}
@@ -0,0 +1,119 @@
+use chrono::NaiveTime;
+use sea_orm::prelude::Decimal;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::app::orders::models::model::ItemType;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SaveCategoryDto {
+    pub organization: uuid::Uuid,
+    pub branch: Option<uuid::Uuid>,
+    pub parent: Option<uuid::Uuid>,
+    pub name: String,
+    pub description: Option<String>,
+    pub category_type: Option<String>,
+    pub sort_order: i32,
+    pub is_active: bool,
+    pub available_from: Option<NaiveTime>,
+    pub available_until: Option<NaiveTime>,
+}
+
+/// A menu category of the organization. It is shared by all the organization's branches unless
+/// `branch_only` keeps it to the employee's branch. A category can sit under a `parent`, and
+/// may only be served between `available_from` and `available_until` on the venue's clock. A
+/// window whose end is before its start runs past midnight.
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct CategoryParams {
+    #[validate(length(min = 2, max = 100, message = "Name is invalid"))]
+    pub name: String,
+    #[validate(length(max = 500, message = "Description is too long"))]
+    pub description: Option<String>,
+    #[validate(length(min = 2, max = 50, message = "Category type is invalid"))]
+    pub category_type: Option<String>,
+    pub parent: Option<uuid::Uuid>,
+    pub branch_only: Option<bool>,
+    #[validate(range(min = 0, max = 10000, message = "Sort order is invalid"))]
+    pub sort_order: Option<i32>,
+    pub is_active: Option<bool>,
+    pub available_from: Option<NaiveTime>,
+    pub available_until: Option<NaiveTime>,
+}
+
+impl CategoryParams {
+    pub fn into_dto(self, organization: uuid::Uuid, branch: uuid::Uuid) -> SaveCategoryDto {
+        SaveCategoryDto {
+            organization,
+            branch: self.branch_only.unwrap_or(false).then_some(branch),
+            parent: self.parent,
+            name: self.name.trim().to_string(),
+            description: self.description,
+            category_type: self.category_type,
+            sort_order: self.sort_order.unwrap_or_default(),
+            is_active: self.is_active.unwrap_or(true),
+            available_from: self.available_from,
+            available_until: self.available_until,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct CategoryPositionParams {
+    pub category: uuid::Uuid,
+    #[validate(range(min = 0, max = 10000, message = "Sort order is invalid"))]
+    pub sort_order: i32,
+}
+
+/// New positions of categories among their siblings.
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct ReorderCategoriesParams {
+    #[validate(length(min = 1, max = 200, message = "Categories are invalid"), nested)]
+    pub categories: Vec<CategoryPositionParams>,
+}
+
+/// A category with its subcategories, in menu order.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CategoryNode {
+    #[serde(flatten)]
+    pub category: entity::categories::Model,
+    pub children: Vec<CategoryNode>,
+}
+
+/// An item as customers see it on the menu. `price` is what it sells for right now, after any
+/// price rule in force, and `base_price` what it sells for without one.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MenuItem {
+    pub item_type: ItemType,
+    pub id: uuid::Uuid,
+    pub name: String,
+    pub description: Option<String>,
+    pub price: Decimal,
+    pub base_price: Decimal,
+    pub image: Option<String>,
+    pub sold_out: bool,
+}
+
+/// A menu category with its items and subcategories. `is_open` is whether it is served right
+/// now: it and every category above it are within their serving window.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MenuCategory {
+    pub id: uuid::Uuid,
+    pub name: String,
+    pub description: Option<String>,
+    pub category_type: Option<String>,
+    pub available_from: Option<NaiveTime>,
+    pub available_until: Option<NaiveTime>,
+    pub is_open: bool,
+    pub items: Vec<MenuItem>,
+    pub children: Vec<MenuCategory>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BranchMenu {
+    pub branch: uuid::Uuid,
+    pub name: String,
+    pub categories: Vec<MenuCategory>,
+}
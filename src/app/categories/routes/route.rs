@@ -0,0 +1,52 @@
+use actix_web::web;
+
+use crate::{
+    app::categories::controllers::controller::{
+        add_category, branch_categories, branch_menu, edit_category, remove_category,
+        reorder_branch_categories,
+    },
+    middlewares::{auth::JwtAuthMiddleware, checker::CheckUserMiddleware},
+    AppState,
+};
+
+pub fn routes(cfg: &mut web::ServiceConfig, state: web::Data<AppState>) {
+    cfg.service(
+        web::scope("/api/v1/categories")
+            .route(
+                "/save",
+                web::post()
+                    .to(add_category)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/update/{id}",
+                web::put()
+                    .to(edit_category)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/reorder",
+                web::put()
+                    .to(reorder_branch_categories)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/delete/{id}",
+                web::delete()
+                    .to(remove_category)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/branch",
+                web::get()
+                    .to(branch_categories)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route("/menu/{id}", web::get().to(branch_menu)),
+    );
+}
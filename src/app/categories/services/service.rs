@@ -0,0 +1,287 @@
+use std::collections::HashMap;
+
+use chrono::NaiveTime;
+use sha2::{Digest, Sha256};
+
+use crate::app::categories::models::model::{BranchMenu, CategoryNode, MenuCategory, MenuItem};
+
+/// Whether `time` falls in a daily serving window. A window whose end is before its start runs
+/// past midnight, and a category without one is served all day.
+pub fn in_window(from: Option<NaiveTime>, until: Option<NaiveTime>, time: NaiveTime) -> bool {
+    match (from, until) {
+        (Some(from), Some(until)) if from <= until => time >= from && time < until,
+        (Some(from), Some(until)) => time >= from || time < until,
+        _ => true,
+    }
+}
+
+/// Whether a category is served at `time`: it and every category above it are active and
+/// within their window.
+pub fn is_serving(
+    category: &entity::categories::Model,
+    categories: &HashMap<uuid::Uuid, entity::categories::Model>,
+    time: NaiveTime,
+) -> bool {
+    let mut current = Some(category);
+    let mut depth = 0;
+
+    while let Some(category) = current {
+        if !category.is_active
+            || !in_window(category.available_from, category.available_until, time)
+        {
+            return false;
+        }
+
+        // Parents are checked for cycles when saved, the depth only guards against bad data
+        depth += 1;
+        if depth > categories.len() {
+            break;
+        }
+
+        current = category
+            .parent_id
+            .and_then(|parent| categories.get(&parent));
+    }
+
+    true
+}
+
+/// Whether `ancestor` is `category` or sits somewhere above it.
+pub fn is_within(
+    category: uuid::Uuid,
+    ancestor: uuid::Uuid,
+    categories: &HashMap<uuid::Uuid, entity::categories::Model>,
+) -> bool {
+    let mut current = Some(category);
+    let mut depth = 0;
+
+    while let Some(id) = current {
+        if id == ancestor {
+            return true;
+        }
+
+        depth += 1;
+        if depth > categories.len() {
+            break;
+        }
+
+        current = categories.get(&id).and_then(|category| category.parent_id);
+    }
+
+    false
+}
+
+fn menu_position(category: &entity::categories::Model) -> (i32, String) {
+    (category.sort_order, category.name.to_lowercase())
+}
+
+/// Children of each category, in menu order. Categories whose parent isn't among them are
+/// listed under `None` with the top-level ones.
+fn children_by_parent(
+    categories: &HashMap<uuid::Uuid, entity::categories::Model>,
+) -> HashMap<Option<uuid::Uuid>, Vec<&entity::categories::Model>> {
+    let mut children: HashMap<Option<uuid::Uuid>, Vec<&entity::categories::Model>> = HashMap::new();
+
+    for category in categories.values() {
+        let parent = category
+            .parent_id
+            .filter(|parent| categories.contains_key(parent));
+
+        children.entry(parent).or_default().push(category);
+    }
+
+    for siblings in children.values_mut() {
+        siblings.sort_by_key(|category| menu_position(category));
+    }
+
+    children
+}
+
+fn category_node(
+    category: &entity::categories::Model,
+    children: &HashMap<Option<uuid::Uuid>, Vec<&entity::categories::Model>>,
+) -> CategoryNode {
+    CategoryNode {
+        category: category.clone(),
+        children: children
+            .get(&Some(category.id))
+            .into_iter()
+            .flatten()
+            .map(|child| category_node(child, children))
+            .collect(),
+    }
+}
+
+/// The categories arranged under their parents.
+pub fn category_tree(
+    categories: &HashMap<uuid::Uuid, entity::categories::Model>,
+) -> Vec<CategoryNode> {
+    let children = children_by_parent(categories);
+
+    children
+        .get(&None)
+        .into_iter()
+        .flatten()
+        .map(|category| category_node(category, &children))
+        .collect()
+}
+
+fn menu_category(
+    category: &entity::categories::Model,
+    children: &HashMap<Option<uuid::Uuid>, Vec<&entity::categories::Model>>,
+    items: &HashMap<uuid::Uuid, Vec<MenuItem>>,
+    categories: &HashMap<uuid::Uuid, entity::categories::Model>,
+    time: NaiveTime,
+) -> Option<MenuCategory> {
+    if !category.is_active {
+        return None;
+    }
+
+    let entry = MenuCategory {
+        id: category.id,
+        name: category.name.clone(),
+        description: category.description.clone(),
+        category_type: category.category_type.clone(),
+        available_from: category.available_from,
+        available_until: category.available_until,
+        is_open: is_serving(category, categories, time),
+        items: items.get(&category.id).cloned().unwrap_or_default(),
+        children: children
+            .get(&Some(category.id))
+            .into_iter()
+            .flatten()
+            .filter_map(|child| menu_category(child, children, items, categories, time))
+            .collect(),
+    };
+
+    (!entry.items.is_empty() || !entry.children.is_empty()).then_some(entry)
+}
+
+/// The branch's menu: its active categories under their parents, each with its items. Empty
+/// categories are left off.
+pub fn branch_menu(
+    branch: &entity::branches::Model,
+    categories: &HashMap<uuid::Uuid, entity::categories::Model>,
+    items: &HashMap<uuid::Uuid, Vec<MenuItem>>,
+    time: NaiveTime,
+) -> BranchMenu {
+    let children = children_by_parent(categories);
+
+    BranchMenu {
+        branch: branch.id,
+        name: branch.name.clone(),
+        categories: children
+            .get(&None)
+            .into_iter()
+            .flatten()
+            .filter_map(|category| menu_category(category, &children, items, categories, time))
+            .collect(),
+    }
+}
+
+/// A strong entity tag for the menu, changing whenever anything customers see on it does.
+pub fn menu_etag(menu: &BranchMenu) -> String {
+    let body = serde_json::to_vec(menu).unwrap_or_default();
+
+    format!("\"{}\"", hex::encode(Sha256::digest(body)))
+}
+
+/// Whether an `If-None-Match` header names the tag. Weak tags compare by their value.
+pub fn etag_matches(if_none_match: &str, etag: &str) -> bool {
+    if_none_match.split(',').map(str::trim).any(|candidate| {
+        candidate == "*" || candidate.strip_prefix("W/").unwrap_or(candidate) == etag
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::fixtures::category;
+
+    fn at(hour: u32, minute: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(hour, minute, 0).unwrap()
+    }
+
+    fn by_id(
+        categories: &[&entity::categories::Model],
+    ) -> HashMap<uuid::Uuid, entity::categories::Model> {
+        categories.iter().map(|c| (c.id, (*c).clone())).collect()
+    }
+
+    #[test]
+    fn serves_within_a_daytime_window() {
+        let (from, until) = (Some(at(11, 0)), Some(at(15, 0)));
+
+        assert!(in_window(from, until, at(11, 0)));
+        assert!(in_window(from, until, at(14, 59)));
+        assert!(!in_window(from, until, at(15, 0)));
+        assert!(!in_window(from, until, at(10, 59)));
+    }
+
+    #[test]
+    fn windows_can_run_past_midnight() {
+        let (from, until) = (Some(at(22, 0)), Some(at(2, 0)));
+
+        assert!(in_window(from, until, at(23, 30)));
+        assert!(in_window(from, until, at(1, 0)));
+        assert!(!in_window(from, until, at(2, 0)));
+        assert!(!in_window(from, until, at(12, 0)));
+    }
+
+    #[test]
+    fn serves_all_day_without_a_full_window() {
+        assert!(in_window(None, None, at(3, 0)));
+        assert!(in_window(Some(at(11, 0)), None, at(3, 0)));
+    }
+
+    #[test]
+    fn a_parent_out_of_its_window_hides_its_children() {
+        let kitchen = entity::categories::Model {
+            available_from: Some(at(12, 0)),
+            available_until: Some(at(22, 0)),
+            ..category("Kitchen", None)
+        };
+        let grills = category("Grills", Some(&kitchen));
+        let categories = by_id(&[&kitchen, &grills]);
+
+        assert!(is_serving(&grills, &categories, at(13, 0)));
+        assert!(!is_serving(&grills, &categories, at(23, 0)));
+    }
+
+    #[test]
+    fn finds_ancestors_up_the_tree() {
+        let drinks = category("Drinks", None);
+        let spirits = category("Spirits", Some(&drinks));
+        let gin = category("Gin", Some(&spirits));
+        let food = category("Food", None);
+        let categories = by_id(&[&drinks, &spirits, &gin, &food]);
+
+        assert!(is_within(gin.id, gin.id, &categories));
+        assert!(is_within(gin.id, drinks.id, &categories));
+        assert!(!is_within(drinks.id, gin.id, &categories));
+        assert!(!is_within(gin.id, food.id, &categories));
+    }
+
+    #[test]
+    fn stops_on_a_parent_cycle() {
+        let mut a = category("A", None);
+        let b = category("B", Some(&a));
+        a.parent_id = Some(b.id);
+        let other = category("Other", None);
+        let categories = by_id(&[&a, &b]);
+
+        assert!(!is_within(a.id, other.id, &categories));
+    }
+
+    #[test]
+    fn matches_if_none_match_headers() {
+        let etag = "\"abc\"";
+
+        assert!(etag_matches("\"abc\"", etag));
+        assert!(etag_matches("W/\"abc\"", etag));
+        assert!(etag_matches("\"xyz\", \"abc\"", etag));
+        assert!(etag_matches("*", etag));
+        assert!(!etag_matches("\"xyz\"", etag));
+        assert!(!etag_matches("abc", etag));
+    }
+}
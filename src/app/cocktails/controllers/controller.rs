@@ -0,0 +1,363 @@
+use std::sync::Arc;
+
+use actix_web::{web, HttpMessage, HttpRequest, HttpResponse};
+use serde_json::json;
+
+use crate::{
+    app::{
+        cocktails::{
+            dtos::dto::{
+                approve_cocktail, get_branch_cocktails, get_cocktail_details, get_cocktail_menu,
+                get_customer_cocktails, reject_cocktail, set_cocktail_availability,
+                submit_cocktail,
+            },
+            models::model::{
+                ApproveCocktailParams, CocktailAvailabilityParams, CocktailQuery, CocktailStatus,
+                RejectCocktailParams, SubmitCocktailParams,
+            },
+        },
+        customers::{dtos::dto::get_customer_full, models::model::CustomerResponse},
+        employees::models::model::EmployeeResponse,
+        permissions::{dtos::dto::has_permission, models::model::ModelAction},
+    },
+    libs::error::Error,
+    utils::{
+        json_validator::{ValidatedJson, ValidatedPath, ValidatedQuery},
+        models::{HttpClientResponse, PathParamsModel, ResponseCode},
+    },
+    AppState,
+};
+
+pub async fn submit_my_cocktail(
+    req: HttpRequest,
+    payload: ValidatedJson<SubmitCocktailParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<CustomerResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Customer not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    let mut session_id = uuid::Uuid::nil();
+
+    if let Some(session_uuid) = &model.session {
+        if let Ok(s_uuid) = uuid::Uuid::parse_str(session_uuid) {
+            session_id = s_uuid
+        }
+    }
+
+    let customer = match get_customer_full(session_id, &state).await {
+        Ok(customer) => customer,
+        Err(e) => {
+            return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+                ResponseCode::Failed,
+                format!("Could not find customer: {}", e),
+                json!({}),
+            )))
+        }
+    };
+
+    match submit_cocktail(payload.0.into_dto(customer.id), &state).await {
+        Ok(res) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Cocktail Submitted Successfully".to_string(),
+            json!(res),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Error Submitting Cocktail: {}", e),
+            json!({}),
+        ))),
+    }
+}
+
+pub async fn my_cocktails(
+    req: HttpRequest,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<CustomerResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Customer not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    let mut session_id = uuid::Uuid::nil();
+
+    if let Some(session_uuid) = &model.session {
+        if let Ok(s_uuid) = uuid::Uuid::parse_str(session_uuid) {
+            session_id = s_uuid
+        }
+    }
+
+    let customer = match get_customer_full(session_id, &state).await {
+        Ok(customer) => customer,
+        Err(e) => {
+            return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+                ResponseCode::Failed,
+                format!("Could not find customer: {}", e),
+                json!({}),
+            )))
+        }
+    };
+
+    match get_customer_cocktails(customer.id, &state).await {
+        Ok(res) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Cocktails Fetched Successfully".to_string(),
+            json!(res),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Error Retrieving: {}", e),
+            json!([]),
+        ))),
+    }
+}
+
+pub async fn branch_cocktails(
+    req: HttpRequest,
+    query: ValidatedQuery<CocktailQuery>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    if !has_permission(model.role_permissions, ModelAction::ManageInventory, &state)
+        .await
+        .unwrap_or(false)
+    {
+        return Ok(HttpResponse::Forbidden().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            "Not Permitted To Manage Inventory".to_string(),
+            json!({}),
+        )));
+    }
+
+    let status = query
+        .0
+        .status
+        .and_then(|status| status.parse::<CocktailStatus>().ok());
+
+    match get_branch_cocktails(model.branch_id, status, &state).await {
+        Ok(res) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Cocktails Fetched Successfully".to_string(),
+            json!(res),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Error Retrieving: {}", e),
+            json!([]),
+        ))),
+    }
+}
+
+pub async fn cocktail_details(
+    req: HttpRequest,
+    params: ValidatedPath<PathParamsModel>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    if !has_permission(model.role_permissions, ModelAction::ManageInventory, &state)
+        .await
+        .unwrap_or(false)
+    {
+        return Ok(HttpResponse::Forbidden().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            "Not Permitted To Manage Inventory".to_string(),
+            json!({}),
+        )));
+    }
+
+    match get_cocktail_details(params.0.id, model.branch_id, &state).await {
+        Ok(res) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Cocktail Fetched Successfully".to_string(),
+            json!(res),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Cocktail not found: {}", e),
+            json!({}),
+        ))),
+    }
+}
+
+pub async fn approve(
+    req: HttpRequest,
+    params: ValidatedPath<PathParamsModel>,
+    payload: ValidatedJson<ApproveCocktailParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    if !has_permission(model.role_permissions, ModelAction::ManageInventory, &state)
+        .await
+        .unwrap_or(false)
+    {
+        return Ok(HttpResponse::Forbidden().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            "Not Permitted To Manage Inventory".to_string(),
+            json!({}),
+        )));
+    }
+
+    let approval = payload.0.into_dto(model.branch_id, model.id);
+
+    match approve_cocktail(params.0.id, approval, &state).await {
+        Ok(res) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Cocktail Approved Successfully".to_string(),
+            json!(res),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Error Approving Cocktail: {}", e),
+            json!({}),
+        ))),
+    }
+}
+
+pub async fn reject(
+    req: HttpRequest,
+    params: ValidatedPath<PathParamsModel>,
+    payload: ValidatedJson<RejectCocktailParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    if !has_permission(model.role_permissions, ModelAction::ManageInventory, &state)
+        .await
+        .unwrap_or(false)
+    {
+        return Ok(HttpResponse::Forbidden().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            "Not Permitted To Manage Inventory".to_string(),
+            json!({}),
+        )));
+    }
+
+    match reject_cocktail(
+        params.0.id,
+        model.branch_id,
+        model.id,
+        payload.0.note,
+        &state,
+    )
+    .await
+    {
+        Ok(res) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Cocktail Rejected Successfully".to_string(),
+            json!(res),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Error Rejecting Cocktail: {}", e),
+            json!({}),
+        ))),
+    }
+}
+
+pub async fn toggle_availability(
+    req: HttpRequest,
+    params: ValidatedPath<PathParamsModel>,
+    payload: ValidatedJson<CocktailAvailabilityParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    if !has_permission(model.role_permissions, ModelAction::ManageInventory, &state)
+        .await
+        .unwrap_or(false)
+    {
+        return Ok(HttpResponse::Forbidden().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            "Not Permitted To Manage Inventory".to_string(),
+            json!({}),
+        )));
+    }
+
+    match set_cocktail_availability(params.0.id, model.branch_id, payload.0.is_available, &state)
+        .await
+    {
+        Ok(res) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Cocktail Updated Successfully".to_string(),
+            json!(res),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Error Updating Cocktail: {}", e),
+            json!({}),
+        ))),
+    }
+}
+
+pub async fn cocktail_menu(
+    _req: HttpRequest,
+    params: ValidatedPath<PathParamsModel>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    match get_cocktail_menu(params.0.id, &state).await {
+        Ok(res) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Cocktails Fetched Successfully".to_string(),
+            json!(res),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Error Retrieving: {}", e),
+            json!([]),
+        ))),
+    }
+}
@@ -0,0 +1,349 @@
+use std::collections::HashMap;
+
+use actix_web::web;
+use sea_orm::{
+    prelude::Decimal, ActiveModelTrait, ActiveValue, ColumnTrait, Condition, ConnectionTrait,
+    DbErr, EntityTrait, QueryFilter, QueryOrder, QuerySelect, Set, TransactionTrait,
+};
+
+use crate::{
+    app::{
+        categories::dtos::dto::find_branch_category,
+        cocktails::{
+            models::model::{
+                ApproveCocktailDto, CocktailDetails, CocktailStatus, MenuCocktail,
+                SubmitCocktailDto,
+            },
+            services::service::menu_cocktails,
+        },
+        orders::models::model::ItemType,
+        recipes::{
+            dtos::dto::{get_item_costing, item_recipes, replace_recipe},
+            models::model::SaveRecipeDto,
+        },
+        wallets::{
+            dtos::dto::credit_customer_wallet,
+            models::model::{TransactionType, WalletCreditDto},
+        },
+    },
+    AppState,
+};
+
+async fn find_cocktail<C: ConnectionTrait>(
+    id: uuid::Uuid,
+    branch: uuid::Uuid,
+    conn: &C,
+) -> Result<entity::custom_cocktails::Model, DbErr> {
+    entity::custom_cocktails::Entity::find_by_id(id)
+        .filter(entity::custom_cocktails::Column::BranchId.eq(branch))
+        .one(conn)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Cocktail not found".into()))
+}
+
+/// Locks a cocktail of the branch still waiting for review, so two managers can't both
+/// decide on it.
+async fn lock_pending_cocktail<C: ConnectionTrait>(
+    id: uuid::Uuid,
+    branch: uuid::Uuid,
+    conn: &C,
+) -> Result<entity::custom_cocktails::Model, DbErr> {
+    entity::custom_cocktails::Entity::find_by_id(id)
+        .filter(
+            Condition::all()
+                .add(entity::custom_cocktails::Column::BranchId.eq(branch))
+                .add(
+                    entity::custom_cocktails::Column::CocktailStatus
+                        .eq(CocktailStatus::Pending.as_str()),
+                ),
+        )
+        .lock_exclusive()
+        .one(conn)
+        .await?
+        .ok_or_else(|| DbErr::Custom("Cocktail is not awaiting review".to_string()))
+}
+
+async fn branch_drinks<C: ConnectionTrait>(
+    branch: uuid::Uuid,
+    conn: &C,
+) -> Result<HashMap<uuid::Uuid, entity::stock_drinks::Model>, DbErr> {
+    Ok(entity::stock_drinks::Entity::find()
+        .filter(entity::stock_drinks::Column::BranchId.eq(branch))
+        .all(conn)
+        .await
+        .map_err(|err| {
+            eprintln!("Database retrieval error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?
+        .into_iter()
+        .map(|drink| (drink.id, drink))
+        .collect())
+}
+
+/// Sends a customer's cocktail idea to the branch's bar for review.
+pub async fn submit_cocktail(
+    data: SubmitCocktailDto,
+    state: &web::Data<AppState>,
+) -> Result<entity::custom_cocktails::Model, DbErr> {
+    let branch = entity::branches::Entity::find_by_id(data.branch)
+        .one(state.pg_db.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Branch not found".into()))?;
+
+    if data.ingredients.is_empty() {
+        return Err(DbErr::Custom("Ingredients are invalid".to_string()));
+    }
+
+    let cocktail = entity::custom_cocktails::ActiveModel {
+        organization_id: Set(branch.organization_id),
+        branch_id: Set(branch.id),
+        customer_id: Set(data.customer),
+        name: Set(data.name),
+        description: Set(data.description),
+        ingredients: Set(Some(data.ingredients)),
+        is_approved: Set(false),
+        cocktail_status: Set(CocktailStatus::Pending.as_str().to_string()),
+        is_available: Set(true),
+        credit_creator: Set(true),
+        reward_amount: Set(Decimal::ZERO),
+        ..Default::default()
+    };
+
+    ActiveModelTrait::insert(cocktail, state.pg_db.get_ref())
+        .await
+        .map_err(|err| {
+            eprintln!("Database insert error: {}", err);
+            DbErr::Custom(err.to_string())
+        })
+}
+
+pub async fn get_customer_cocktails(
+    customer: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<Vec<entity::custom_cocktails::Model>, DbErr> {
+    entity::custom_cocktails::Entity::find()
+        .filter(entity::custom_cocktails::Column::CustomerId.eq(customer))
+        .order_by_desc(entity::custom_cocktails::Column::CreatedAt)
+        .all(state.pg_db.get_ref())
+        .await
+        .map_err(|err| {
+            eprintln!("Database retrieval error: {}", err);
+            DbErr::Custom(err.to_string())
+        })
+}
+
+pub async fn get_branch_cocktails(
+    branch: uuid::Uuid,
+    status: Option<CocktailStatus>,
+    state: &web::Data<AppState>,
+) -> Result<Vec<entity::custom_cocktails::Model>, DbErr> {
+    let mut condition = Condition::all().add(entity::custom_cocktails::Column::BranchId.eq(branch));
+
+    if let Some(status) = status {
+        condition =
+            condition.add(entity::custom_cocktails::Column::CocktailStatus.eq(status.as_str()));
+    }
+
+    entity::custom_cocktails::Entity::find()
+        .filter(condition)
+        .order_by_desc(entity::custom_cocktails::Column::CreatedAt)
+        .all(state.pg_db.get_ref())
+        .await
+        .map_err(|err| {
+            eprintln!("Database retrieval error: {}", err);
+            DbErr::Custom(err.to_string())
+        })
+}
+
+pub async fn get_cocktail_details(
+    id: uuid::Uuid,
+    branch: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<CocktailDetails, DbErr> {
+    let cocktail = find_cocktail(id, branch, state.pg_db.get_ref()).await?;
+
+    let costing = if cocktail.cocktail_status == CocktailStatus::Approved.as_str() {
+        Some(get_item_costing(ItemType::Cocktail, cocktail.id, branch, state).await?)
+    } else {
+        None
+    };
+
+    Ok(CocktailDetails { cocktail, costing })
+}
+
+/// Puts a pending cocktail on the menu with its recipe of branch drinks, which are poured
+/// whenever an order of it completes. The creator's reward, if any, is paid into their wallet along with the approval.
+pub async fn approve_cocktail(
+    id: uuid::Uuid,
+    data: ApproveCocktailDto,
+    state: &web::Data<AppState>,
+) -> Result<entity::custom_cocktails::Model, DbErr> {
+    let txn = state.pg_db.get_ref().begin().await?;
+
+    let cocktail = lock_pending_cocktail(id, data.branch, &txn).await?;
+
+    find_branch_category(
+        data.category,
+        cocktail.organization_id,
+        cocktail.branch_id,
+        &txn,
+    )
+    .await?;
+
+    replace_recipe(
+        SaveRecipeDto {
+            organization: cocktail.organization_id,
+            branch: cocktail.branch_id,
+            item_type: ItemType::Cocktail,
+            item: cocktail.id,
+            lines: data.ingredients,
+        },
+        &txn,
+    )
+    .await?;
+
+    if data.reward_amount > Decimal::ZERO {
+        credit_customer_wallet(
+            WalletCreditDto {
+                customer: cocktail.customer_id,
+                organization: cocktail.organization_id,
+                branch: cocktail.branch_id,
+                amount: data.reward_amount,
+                description: format!("Reward for your cocktail {}", cocktail.name),
+                transaction_type: TransactionType::Reward,
+            },
+            &txn,
+        )
+        .await?;
+    }
+
+    let mut model: entity::custom_cocktails::ActiveModel = cocktail.into();
+
+    model.cocktail_status = ActiveValue::Set(CocktailStatus::Approved.as_str().to_string());
+    model.is_approved = ActiveValue::Set(true);
+    model.price = ActiveValue::Set(Some(data.price));
+    model.category_id = ActiveValue::Set(Some(data.category));
+    model.credit_creator = ActiveValue::Set(data.credit_creator);
+    model.reward_amount = ActiveValue::Set(data.reward_amount);
+    model.reviewed_by = ActiveValue::Set(Some(data.employee));
+    model.reviewed_at = ActiveValue::Set(Some(chrono::Utc::now().into()));
+    model.review_note = ActiveValue::Set(data.note);
+    model.updated_at = ActiveValue::Set(chrono::Utc::now().into());
+
+    let result = ActiveModelTrait::update(model, &txn).await.map_err(|err| {
+        eprintln!("Database update error: {}", err);
+        DbErr::Custom(err.to_string())
+    })?;
+
+    txn.commit().await?;
+
+    Ok(result)
+}
+
+pub async fn reject_cocktail(
+    id: uuid::Uuid,
+    branch: uuid::Uuid,
+    employee: uuid::Uuid,
+    note: String,
+    state: &web::Data<AppState>,
+) -> Result<entity::custom_cocktails::Model, DbErr> {
+    let txn = state.pg_db.get_ref().begin().await?;
+
+    let cocktail = lock_pending_cocktail(id, branch, &txn).await?;
+
+    let mut model: entity::custom_cocktails::ActiveModel = cocktail.into();
+
+    model.cocktail_status = ActiveValue::Set(CocktailStatus::Rejected.as_str().to_string());
+    model.reviewed_by = ActiveValue::Set(Some(employee));
+    model.reviewed_at = ActiveValue::Set(Some(chrono::Utc::now().into()));
+    model.review_note = ActiveValue::Set(Some(note));
+    model.updated_at = ActiveValue::Set(chrono::Utc::now().into());
+
+    let result = ActiveModelTrait::update(model, &txn).await.map_err(|err| {
+        eprintln!("Database update error: {}", err);
+        DbErr::Custom(err.to_string())
+    })?;
+
+    txn.commit().await?;
+
+    Ok(result)
+}
+
+/// Takes an approved cocktail off the menu or puts it back, e.g. while a component is out.
+pub async fn set_cocktail_availability(
+    id: uuid::Uuid,
+    branch: uuid::Uuid,
+    is_available: bool,
+    state: &web::Data<AppState>,
+) -> Result<entity::custom_cocktails::Model, DbErr> {
+    let cocktail = find_cocktail(id, branch, state.pg_db.get_ref()).await?;
+
+    if cocktail.cocktail_status != CocktailStatus::Approved.as_str() {
+        return Err(DbErr::Custom("Cocktail is not approved".to_string()));
+    }
+
+    let mut model: entity::custom_cocktails::ActiveModel = cocktail.into();
+
+    model.is_available = ActiveValue::Set(is_available);
+    model.updated_at = ActiveValue::Set(chrono::Utc::now().into());
+
+    ActiveModelTrait::update(model, state.pg_db.get_ref())
+        .await
+        .map_err(|err| {
+            eprintln!("Database update error: {}", err);
+            DbErr::Custom(err.to_string())
+        })
+}
+
+/// The branch's approved cocktails that can be ordered right now.
+pub async fn get_cocktail_menu(
+    branch: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<Vec<MenuCocktail>, DbErr> {
+    let cocktails = entity::custom_cocktails::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::custom_cocktails::Column::BranchId.eq(branch))
+                .add(
+                    entity::custom_cocktails::Column::CocktailStatus
+                        .eq(CocktailStatus::Approved.as_str()),
+                )
+                .add(entity::custom_cocktails::Column::IsAvailable.eq(true)),
+        )
+        .order_by_asc(entity::custom_cocktails::Column::Name)
+        .all(state.pg_db.get_ref())
+        .await
+        .map_err(|err| {
+            eprintln!("Database retrieval error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    let keys: Vec<(ItemType, uuid::Uuid)> = cocktails
+        .iter()
+        .map(|cocktail| (ItemType::Cocktail, cocktail.id))
+        .collect();
+    let recipes = item_recipes(&keys, state.pg_db.get_ref()).await?;
+    let drinks = branch_drinks(branch, state.pg_db.get_ref()).await?;
+
+    let customers: HashMap<uuid::Uuid, entity::customers::Model> =
+        entity::customers::Entity::find()
+            .filter(
+                entity::customers::Column::Id.is_in(
+                    cocktails
+                        .iter()
+                        .filter(|cocktail| cocktail.credit_creator)
+                        .map(|cocktail| cocktail.customer_id),
+                ),
+            )
+            .all(state.pg_db.get_ref())
+            .await
+            .map_err(|err| {
+                eprintln!("Database retrieval error: {}", err);
+                DbErr::Custom(err.to_string())
+            })?
+            .into_iter()
+            .map(|customer| (customer.id, customer))
+            .collect();
+
+    Ok(menu_cocktails(cocktails, &recipes, &drinks, &customers))
+}
@@ -0,0 +1,164 @@
+use std::str::FromStr;
+
+use sea_orm::prelude::Decimal;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::{
+    app::recipes::models::model::{ItemCosting, RecipeLine, RecipeLineParams},
+    libs::validator::{validate_approval_status, validate_cash_amount},
+};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum CocktailStatus {
+    Pending,
+    Approved,
+    Rejected,
+}
+
+impl CocktailStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CocktailStatus::Pending => "PENDING",
+            CocktailStatus::Approved => "APPROVED",
+            CocktailStatus::Rejected => "REJECTED",
+        }
+    }
+}
+
+impl FromStr for CocktailStatus {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_uppercase().as_str() {
+            "PENDING" => Ok(CocktailStatus::Pending),
+            "APPROVED" => Ok(CocktailStatus::Approved),
+            "REJECTED" => Ok(CocktailStatus::Rejected),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SubmitCocktailDto {
+    pub customer: uuid::Uuid,
+    pub branch: uuid::Uuid,
+    pub name: String,
+    pub description: Option<String>,
+    pub ingredients: Vec<String>,
+}
+
+/// A customer's cocktail idea. Ingredients are free text, the bar works out the pour when it
+/// reviews the cocktail.
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct SubmitCocktailParams {
+    pub branch: uuid::Uuid,
+    #[validate(length(min = 2, max = 100, message = "Name is invalid"))]
+    pub name: String,
+    #[validate(length(max = 500, message = "Description is too long"))]
+    pub description: Option<String>,
+    #[validate(length(min = 1, max = 20, message = "Ingredients are invalid"))]
+    pub ingredients: Vec<String>,
+}
+
+impl SubmitCocktailParams {
+    pub fn into_dto(self, customer: uuid::Uuid) -> SubmitCocktailDto {
+        SubmitCocktailDto {
+            customer,
+            branch: self.branch,
+            name: self.name.trim().to_string(),
+            description: self.description,
+            ingredients: self
+                .ingredients
+                .into_iter()
+                .map(|ingredient| ingredient.trim().to_string())
+                .filter(|ingredient| !ingredient.is_empty())
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ApproveCocktailDto {
+    pub branch: uuid::Uuid,
+    pub employee: uuid::Uuid,
+    pub price: Decimal,
+    pub category: uuid::Uuid,
+    pub ingredients: Vec<RecipeLine>,
+    pub credit_creator: bool,
+    pub reward_amount: Decimal,
+    pub note: Option<String>,
+}
+
+/// Puts a cocktail on the menu. Its ingredients are its recipe: branch drinks and how much of
+/// each goes into one cocktail. The creator is named on the menu unless `credit_creator` is
+/// off, and any `reward_amount` goes into their wallet.
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct ApproveCocktailParams {
+    #[validate(custom(function = "validate_cash_amount"))]
+    pub price: Decimal,
+    pub category: uuid::Uuid,
+    #[validate(length(min = 1, max = 20, message = "Ingredients are invalid"), nested)]
+    pub ingredients: Vec<RecipeLineParams>,
+    pub credit_creator: Option<bool>,
+    #[validate(custom(function = "validate_cash_amount"))]
+    pub reward_amount: Option<Decimal>,
+    #[validate(length(max = 500, message = "Note is too long"))]
+    pub note: Option<String>,
+}
+
+impl ApproveCocktailParams {
+    pub fn into_dto(self, branch: uuid::Uuid, employee: uuid::Uuid) -> ApproveCocktailDto {
+        ApproveCocktailDto {
+            branch,
+            employee,
+            price: self.price,
+            category: self.category,
+            ingredients: self.ingredients.into_iter().map(Into::into).collect(),
+            credit_creator: self.credit_creator.unwrap_or(true),
+            reward_amount: self.reward_amount.unwrap_or_default(),
+            note: self.note,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct RejectCocktailParams {
+    #[validate(length(min = 2, max = 500, message = "Note is invalid"))]
+    pub note: String,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct CocktailAvailabilityParams {
+    pub is_available: bool,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct CocktailQuery {
+    #[validate(custom(function = "validate_approval_status"))]
+    pub status: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CocktailDetails {
+    pub cocktail: entity::custom_cocktails::Model,
+    pub costing: Option<ItemCosting>,
+}
+
+/// An approved cocktail as customers see it on a branch's menu.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MenuCocktail {
+    pub id: uuid::Uuid,
+    pub name: String,
+    pub description: Option<String>,
+    pub price: Decimal,
+    pub category: Option<uuid::Uuid>,
+    pub ingredients: Vec<String>,
+    pub created_by: Option<String>,
+}
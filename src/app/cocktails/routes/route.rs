@@ -0,0 +1,66 @@
+use actix_web::web;
+
+use crate::{
+    app::cocktails::controllers::controller::{
+        approve, branch_cocktails, cocktail_details, cocktail_menu, my_cocktails, reject,
+        submit_my_cocktail, toggle_availability,
+    },
+    middlewares::{auth::JwtAuthMiddleware, checker::CheckUserMiddleware},
+    AppState,
+};
+
+pub fn routes(cfg: &mut web::ServiceConfig, state: web::Data<AppState>) {
+    cfg.service(
+        web::scope("/api/v1/cocktails")
+            .route(
+                "/submit",
+                web::post()
+                    .to(submit_my_cocktail)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Customer"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/mine",
+                web::get()
+                    .to(my_cocktails)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Customer"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/branch",
+                web::get()
+                    .to(branch_cocktails)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/details/{id}",
+                web::get()
+                    .to(cocktail_details)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/approve/{id}",
+                web::put()
+                    .to(approve)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/reject/{id}",
+                web::put()
+                    .to(reject)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/availability/{id}",
+                web::put()
+                    .to(toggle_availability)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route("/menu/{id}", web::get().to(cocktail_menu)),
+    );
+}
@@ -0,0 +1,54 @@
+use std::collections::HashMap;
+
+use crate::app::cocktails::models::model::MenuCocktail;
+
+/// How the creator is named on the menu: their first name and the initial of their last.
+pub fn creator_name(customer: &entity::customers::Model) -> Option<String> {
+    let first = customer.first_name.as_deref().map(str::trim).unwrap_or("");
+    let initial = customer
+        .last_name
+        .as_deref()
+        .and_then(|last| last.trim().chars().next());
+
+    match (first.is_empty(), initial) {
+        (true, _) => None,
+        (false, Some(initial)) => Some(format!("{} {}.", first, initial)),
+        (false, None) => Some(first.to_string()),
+    }
+}
+
+/// Menu entries for approved cocktails, naming the creator where they are credited. The listed
+/// ingredients are the drinks poured, not the customer's original notes.
+pub fn menu_cocktails(
+    cocktails: Vec<entity::custom_cocktails::Model>,
+    recipes: &HashMap<uuid::Uuid, Vec<entity::recipe_items::Model>>,
+    drinks: &HashMap<uuid::Uuid, entity::stock_drinks::Model>,
+    customers: &HashMap<uuid::Uuid, entity::customers::Model>,
+) -> Vec<MenuCocktail> {
+    cocktails
+        .into_iter()
+        .filter_map(|cocktail| {
+            let price = cocktail.price?;
+
+            Some(MenuCocktail {
+                id: cocktail.id,
+                name: cocktail.name,
+                description: cocktail.description,
+                price,
+                category: cocktail.category_id,
+                ingredients: recipes
+                    .get(&cocktail.id)
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|line| drinks.get(&line.component_id))
+                    .map(|drink| drink.name.clone())
+                    .collect(),
+                created_by: cocktail
+                    .credit_creator
+                    .then(|| customers.get(&cocktail.customer_id))
+                    .flatten()
+                    .and_then(creator_name),
+            })
+        })
+        .collect()
+}
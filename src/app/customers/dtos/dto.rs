@@ -223,3 +223,38 @@ pub async fn get_customer_by_contact(
 
     Ok(CustomerResponse::from(customer))
 }
+
+pub async fn get_active_customer_by_contact(
+    phone: String,
+    state: &web::Data<AppState>,
+) -> Result<entity::customers::Model, DbErr> {
+    let customer = entity::customers::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::customers::Column::Contact.eq(phone))
+                .add(entity::customers::Column::IsBlocked.eq(false))
+                .add(entity::customers::Column::IsDeleted.eq(false)),
+        )
+        .one(state.pg_db.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Customer not found".into()))?;
+
+    Ok(customer)
+}
+
+pub async fn get_customer_by_id(
+    id: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<entity::customers::Model, DbErr> {
+    let customer = entity::customers::Entity::find_by_id(id)
+        .filter(
+            Condition::all()
+                .add(entity::customers::Column::IsBlocked.eq(false))
+                .add(entity::customers::Column::IsDeleted.eq(false)),
+        )
+        .one(state.pg_db.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Customer not found".into()))?;
+
+    Ok(customer)
+}
@@ -1,4 +1,5 @@
 pub mod controllers;
 pub mod dtos;
 pub mod models;
-pub mod routes;
\ No newline at end of file
+pub mod routes;
+pub mod services;
\ No newline at end of file
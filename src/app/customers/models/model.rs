@@ -116,3 +116,76 @@ impl From<entity::customers::Model> for CustomerResponse {
         }
     }
 }
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum RejectionReason {
+    DateOfBirthMissing,
+    Underage,
+    OverAgeLimit,
+    StudentStatusRequired,
+    StudentNotVerified,
+    StudentStatusExpired,
+    IdNotVerified,
+    Banned,
+}
+
+impl RejectionReason {
+    pub fn message(&self) -> &'static str {
+        match self {
+            RejectionReason::DateOfBirthMissing => "Date of birth is required for this event",
+            RejectionReason::Underage => "Customer is below the minimum age for this event",
+            RejectionReason::OverAgeLimit => "Customer is above the maximum age for this event",
+            RejectionReason::StudentStatusRequired => "This event is for students only",
+            RejectionReason::StudentNotVerified => "Student ID has not been verified",
+            RejectionReason::StudentStatusExpired => "Student status has expired",
+            RejectionReason::IdNotVerified => "A verified ID is required for this event",
+            RejectionReason::Banned => "Customer is banned from this venue",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EligibilityRejection {
+    pub code: RejectionReason,
+    pub message: String,
+}
+
+impl From<RejectionReason> for EligibilityRejection {
+    fn from(reason: RejectionReason) -> Self {
+        Self {
+            code: reason,
+            message: reason.message().to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EligibilityRules {
+    pub min_age: i32,
+    pub max_age: i32,
+    pub is_student_event: bool,
+    pub requires_id: bool,
+}
+
+impl From<&entity::schedules::Model> for EligibilityRules {
+    fn from(schedule: &entity::schedules::Model) -> Self {
+        Self {
+            min_age: schedule.min_age_limit,
+            max_age: schedule.max_age_limit,
+            is_student_event: schedule.is_student_event,
+            requires_id: schedule.min_age_limit >= 18,
+        }
+    }
+}
+
+impl From<&entity::ticket_events::Model> for EligibilityRules {
+    fn from(event: &entity::ticket_events::Model) -> Self {
+        Self {
+            min_age: event.min_age_limit,
+            max_age: event.max_age_limit,
+            is_student_event: false,
+            requires_id: event.min_age_limit >= 18,
+        }
+    }
+}
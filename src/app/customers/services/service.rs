@@ -0,0 +1,181 @@
+use chrono::{Datelike, NaiveDate};
+
+use crate::app::customers::models::model::{
+    EligibilityRejection, EligibilityRules, RejectionReason,
+};
+
+/// Checks a customer against an event's age, student and ID rules.
+/// Returns every failed rule so clients can show all of them at once; an empty list means eligible.
+pub fn evaluate_eligibility(
+    customer: &entity::customers::Model,
+    rules: &EligibilityRules,
+    today: NaiveDate,
+) -> Vec<EligibilityRejection> {
+    let mut reasons = Vec::new();
+
+    match customer.date_of_birth.and_then(|dob| today.years_since(dob)) {
+        Some(age) => {
+            let age = age as i32;
+
+            if age < rules.min_age {
+                reasons.push(RejectionReason::Underage);
+            }
+
+            // A max age of zero means the event has no upper limit
+            if rules.max_age > 0 && age > rules.max_age {
+                reasons.push(RejectionReason::OverAgeLimit);
+            }
+        }
+        None => {
+            if rules.min_age > 0 || rules.max_age > 0 {
+                reasons.push(RejectionReason::DateOfBirthMissing);
+            }
+        }
+    }
+
+    if rules.is_student_event {
+        if !customer.is_student {
+            reasons.push(RejectionReason::StudentStatusRequired);
+        } else if !customer.is_student_id_verified {
+            reasons.push(RejectionReason::StudentNotVerified);
+        } else if customer
+            .student_end_year
+            .is_none_or(|end_year| end_year < today.year())
+        {
+            reasons.push(RejectionReason::StudentStatusExpired);
+        }
+    }
+
+    if rules.requires_id && !customer.is_id_verified {
+        reasons.push(RejectionReason::IdNotVerified);
+    }
+
+    reasons.into_iter().map(EligibilityRejection::from).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::fixtures::customer;
+
+    fn today() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 5, 1).unwrap()
+    }
+
+    fn rules(min_age: i32, max_age: i32) -> EligibilityRules {
+        EligibilityRules {
+            min_age,
+            max_age,
+            is_student_event: false,
+            requires_id: false,
+        }
+    }
+
+    fn codes(rejections: Vec<EligibilityRejection>) -> Vec<RejectionReason> {
+        rejections
+            .into_iter()
+            .map(|rejection| rejection.code)
+            .collect()
+    }
+
+    #[test]
+    fn open_events_admit_anyone() {
+        let customer = entity::customers::Model {
+            date_of_birth: None,
+            is_id_verified: false,
+            ..customer()
+        };
+
+        assert!(evaluate_eligibility(&customer, &rules(0, 0), today()).is_empty());
+    }
+
+    #[test]
+    fn checks_age_limits_on_the_day() {
+        // Turns 25 today
+        let customer = customer();
+
+        assert!(evaluate_eligibility(&customer, &rules(25, 25), today()).is_empty());
+        assert_eq!(
+            codes(evaluate_eligibility(&customer, &rules(26, 0), today())),
+            vec![RejectionReason::Underage]
+        );
+        assert_eq!(
+            codes(evaluate_eligibility(&customer, &rules(18, 24), today())),
+            vec![RejectionReason::OverAgeLimit]
+        );
+    }
+
+    #[test]
+    fn age_limits_need_a_date_of_birth() {
+        let customer = entity::customers::Model {
+            date_of_birth: None,
+            ..customer()
+        };
+
+        assert_eq!(
+            codes(evaluate_eligibility(&customer, &rules(0, 30), today())),
+            vec![RejectionReason::DateOfBirthMissing]
+        );
+    }
+
+    #[test]
+    fn student_events_need_a_current_verified_student() {
+        let student = EligibilityRules {
+            is_student_event: true,
+            ..rules(0, 0)
+        };
+        let check = |customer: entity::customers::Model| {
+            codes(evaluate_eligibility(&customer, &student, today()))
+        };
+
+        assert_eq!(
+            check(customer()),
+            vec![RejectionReason::StudentStatusRequired]
+        );
+        assert_eq!(
+            check(entity::customers::Model {
+                is_student: true,
+                ..customer()
+            }),
+            vec![RejectionReason::StudentNotVerified]
+        );
+        assert_eq!(
+            check(entity::customers::Model {
+                is_student: true,
+                is_student_id_verified: true,
+                student_end_year: Some(2024),
+                ..customer()
+            }),
+            vec![RejectionReason::StudentStatusExpired]
+        );
+        assert!(check(entity::customers::Model {
+            is_student: true,
+            is_student_id_verified: true,
+            student_end_year: Some(2025),
+            ..customer()
+        })
+        .is_empty());
+    }
+
+    #[test]
+    fn reports_every_failed_rule() {
+        let customer = entity::customers::Model {
+            is_id_verified: false,
+            ..customer()
+        };
+        let rules = EligibilityRules {
+            is_student_event: true,
+            requires_id: true,
+            ..rules(30, 0)
+        };
+
+        assert_eq!(
+            codes(evaluate_eligibility(&customer, &rules, today())),
+            vec![
+                RejectionReason::Underage,
+                RejectionReason::StudentStatusRequired,
+                RejectionReason::IdNotVerified,
+            ]
+        );
+    }
+}
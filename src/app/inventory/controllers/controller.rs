@@ -0,0 +1,674 @@
+use std::sync::Arc;
+
+use actix_web::{web, HttpMessage, HttpRequest, HttpResponse};
+use serde_json::json;
+
+use crate::{
+    app::{
+        employees::models::model::EmployeeResponse,
+        inventory::{
+            dtos::dto::{
+                delete_drink, delete_food, get_branch_drinks, get_branch_foods, get_drink,
+                get_food, get_reorder_suggestions, save_drink, save_food, set_drink_category,
+                set_drink_image, set_food_category, set_food_image, update_drink, update_food,
+            },
+            models::model::{
+                AssignCategoryParams, DrinkParams, FoodParams, MenuImageParams, ReorderQuery,
+                ReorderSettings,
+            },
+        },
+        permissions::{dtos::dto::has_permission, models::model::ModelAction},
+    },
+    libs::error::Error,
+    utils::{
+        json_validator::{ValidatedJson, ValidatedPath, ValidatedQuery},
+        models::{HttpClientResponse, PathParamsModel, ResponseCode, SaveMediaFilesDto},
+        shared::save_media_files,
+    },
+    AppState,
+};
+
+pub async fn add_drink(
+    req: HttpRequest,
+    payload: ValidatedJson<DrinkParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    if !has_permission(model.role_permissions, ModelAction::ManageInventory, &state)
+        .await
+        .unwrap_or(false)
+    {
+        return Ok(HttpResponse::Forbidden().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            "Not Permitted To Manage Inventory".to_string(),
+            json!({}),
+        )));
+    }
+
+    let drink = payload.0.into_dto(model.organization_id, model.branch_id);
+
+    match save_drink(drink, &state).await {
+        Ok(res) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Drink Added Successfully".to_string(),
+            json!(res),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Error Adding Drink: {}", e),
+            json!({}),
+        ))),
+    }
+}
+
+pub async fn branch_drinks(
+    req: HttpRequest,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    match get_branch_drinks(model.branch_id, &state).await {
+        Ok(res) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Drinks Fetched Successfully".to_string(),
+            json!(res),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Error Retrieving: {}", e),
+            json!([]),
+        ))),
+    }
+}
+
+pub async fn drink_details(
+    req: HttpRequest,
+    params: ValidatedPath<PathParamsModel>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    match get_drink(params.0.id, model.branch_id, &state).await {
+        Ok(res) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Drink Fetched Successfully".to_string(),
+            json!(res),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Drink not found: {}", e),
+            json!({}),
+        ))),
+    }
+}
+
+pub async fn edit_drink(
+    req: HttpRequest,
+    params: ValidatedPath<PathParamsModel>,
+    payload: ValidatedJson<DrinkParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    if !has_permission(model.role_permissions, ModelAction::ManageInventory, &state)
+        .await
+        .unwrap_or(false)
+    {
+        return Ok(HttpResponse::Forbidden().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            "Not Permitted To Manage Inventory".to_string(),
+            json!({}),
+        )));
+    }
+
+    let drink = payload.0.into_dto(model.organization_id, model.branch_id);
+
+    match update_drink(params.0.id, drink, &state).await {
+        Ok(res) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Drink Updated Successfully".to_string(),
+            json!(res),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Error Updating Drink: {}", e),
+            json!({}),
+        ))),
+    }
+}
+
+pub async fn drink_category(
+    req: HttpRequest,
+    params: ValidatedPath<PathParamsModel>,
+    payload: ValidatedJson<AssignCategoryParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    if !has_permission(model.role_permissions, ModelAction::ManageInventory, &state)
+        .await
+        .unwrap_or(false)
+    {
+        return Ok(HttpResponse::Forbidden().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            "Not Permitted To Manage Inventory".to_string(),
+            json!({}),
+        )));
+    }
+
+    match set_drink_category(params.0.id, model.branch_id, payload.0.category, &state).await {
+        Ok(res) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Drink Category Updated Successfully".to_string(),
+            json!(res),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Error Updating Drink: {}", e),
+            json!({}),
+        ))),
+    }
+}
+
+pub async fn drink_image(
+    req: HttpRequest,
+    params: ValidatedPath<PathParamsModel>,
+    payload: ValidatedJson<MenuImageParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    if !has_permission(model.role_permissions, ModelAction::ManageInventory, &state)
+        .await
+        .unwrap_or(false)
+    {
+        return Ok(HttpResponse::Forbidden().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            "Not Permitted To Manage Inventory".to_string(),
+            json!({}),
+        )));
+    }
+
+    if let Err(e) = get_drink(params.0.id, model.branch_id, &state).await {
+        return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Drink not found: {}", e),
+            json!({}),
+        )));
+    }
+
+    let data = payload.0;
+
+    let media = SaveMediaFilesDto {
+        id: params.0.id,
+        dir: "menu".to_string(),
+        data: data.img,
+        mime_type: data.mime_type,
+        media_type: data.media_type,
+        size: data.file_size,
+        width: data.width,
+        height: data.height,
+    };
+
+    let image = match save_media_files(media, &state).await {
+        Ok(image) => image,
+        Err(e) => {
+            return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+                ResponseCode::Failed,
+                format!("Could not save image: {}", e),
+                json!({}),
+            )))
+        }
+    };
+
+    match set_drink_image(params.0.id, model.branch_id, image, &state).await {
+        Ok(res) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Drink Image Updated Successfully".to_string(),
+            json!(res),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Error Updating Drink: {}", e),
+            json!({}),
+        ))),
+    }
+}
+
+pub async fn remove_drink(
+    req: HttpRequest,
+    params: ValidatedPath<PathParamsModel>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    if !has_permission(model.role_permissions, ModelAction::ManageInventory, &state)
+        .await
+        .unwrap_or(false)
+    {
+        return Ok(HttpResponse::Forbidden().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            "Not Permitted To Manage Inventory".to_string(),
+            json!({}),
+        )));
+    }
+
+    match delete_drink(params.0.id, model.branch_id, &state).await {
+        Ok(res) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Drink Removed Successfully".to_string(),
+            json!(res),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Error Removing Drink: {}", e),
+            json!({}),
+        ))),
+    }
+}
+
+pub async fn add_food(
+    req: HttpRequest,
+    payload: ValidatedJson<FoodParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    if !has_permission(model.role_permissions, ModelAction::ManageInventory, &state)
+        .await
+        .unwrap_or(false)
+    {
+        return Ok(HttpResponse::Forbidden().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            "Not Permitted To Manage Inventory".to_string(),
+            json!({}),
+        )));
+    }
+
+    let food = payload.0.into_dto(model.organization_id, model.branch_id);
+
+    match save_food(food, &state).await {
+        Ok(res) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Food Added Successfully".to_string(),
+            json!(res),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Error Adding Food: {}", e),
+            json!({}),
+        ))),
+    }
+}
+
+pub async fn branch_foods(
+    req: HttpRequest,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    match get_branch_foods(model.branch_id, &state).await {
+        Ok(res) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Foods Fetched Successfully".to_string(),
+            json!(res),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Error Retrieving: {}", e),
+            json!([]),
+        ))),
+    }
+}
+
+pub async fn food_details(
+    req: HttpRequest,
+    params: ValidatedPath<PathParamsModel>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    match get_food(params.0.id, model.branch_id, &state).await {
+        Ok(res) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Food Fetched Successfully".to_string(),
+            json!(res),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Food not found: {}", e),
+            json!({}),
+        ))),
+    }
+}
+
+pub async fn edit_food(
+    req: HttpRequest,
+    params: ValidatedPath<PathParamsModel>,
+    payload: ValidatedJson<FoodParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    if !has_permission(model.role_permissions, ModelAction::ManageInventory, &state)
+        .await
+        .unwrap_or(false)
+    {
+        return Ok(HttpResponse::Forbidden().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            "Not Permitted To Manage Inventory".to_string(),
+            json!({}),
+        )));
+    }
+
+    let food = payload.0.into_dto(model.organization_id, model.branch_id);
+
+    match update_food(params.0.id, food, &state).await {
+        Ok(res) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Food Updated Successfully".to_string(),
+            json!(res),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Error Updating Food: {}", e),
+            json!({}),
+        ))),
+    }
+}
+
+pub async fn food_category(
+    req: HttpRequest,
+    params: ValidatedPath<PathParamsModel>,
+    payload: ValidatedJson<AssignCategoryParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    if !has_permission(model.role_permissions, ModelAction::ManageInventory, &state)
+        .await
+        .unwrap_or(false)
+    {
+        return Ok(HttpResponse::Forbidden().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            "Not Permitted To Manage Inventory".to_string(),
+            json!({}),
+        )));
+    }
+
+    match set_food_category(params.0.id, model.branch_id, payload.0.category, &state).await {
+        Ok(res) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Food Category Updated Successfully".to_string(),
+            json!(res),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Error Updating Food: {}", e),
+            json!({}),
+        ))),
+    }
+}
+
+pub async fn food_image(
+    req: HttpRequest,
+    params: ValidatedPath<PathParamsModel>,
+    payload: ValidatedJson<MenuImageParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    if !has_permission(model.role_permissions, ModelAction::ManageInventory, &state)
+        .await
+        .unwrap_or(false)
+    {
+        return Ok(HttpResponse::Forbidden().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            "Not Permitted To Manage Inventory".to_string(),
+            json!({}),
+        )));
+    }
+
+    if let Err(e) = get_food(params.0.id, model.branch_id, &state).await {
+        return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Food not found: {}", e),
+            json!({}),
+        )));
+    }
+
+    let data = payload.0;
+
+    let media = SaveMediaFilesDto {
+        id: params.0.id,
+        dir: "menu".to_string(),
+        data: data.img,
+        mime_type: data.mime_type,
+        media_type: data.media_type,
+        size: data.file_size,
+        width: data.width,
+        height: data.height,
+    };
+
+    let image = match save_media_files(media, &state).await {
+        Ok(image) => image,
+        Err(e) => {
+            return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+                ResponseCode::Failed,
+                format!("Could not save image: {}", e),
+                json!({}),
+            )))
+        }
+    };
+
+    match set_food_image(params.0.id, model.branch_id, image, &state).await {
+        Ok(res) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Food Image Updated Successfully".to_string(),
+            json!(res),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Error Updating Food: {}", e),
+            json!({}),
+        ))),
+    }
+}
+
+pub async fn remove_food(
+    req: HttpRequest,
+    params: ValidatedPath<PathParamsModel>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    if !has_permission(model.role_permissions, ModelAction::ManageInventory, &state)
+        .await
+        .unwrap_or(false)
+    {
+        return Ok(HttpResponse::Forbidden().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            "Not Permitted To Manage Inventory".to_string(),
+            json!({}),
+        )));
+    }
+
+    match delete_food(params.0.id, model.branch_id, &state).await {
+        Ok(res) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Food Removed Successfully".to_string(),
+            json!(res),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Error Removing Food: {}", e),
+            json!({}),
+        ))),
+    }
+}
+
+pub async fn reorder_suggestions(
+    req: HttpRequest,
+    query: ValidatedQuery<ReorderQuery>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    if !has_permission(model.role_permissions, ModelAction::ManageInventory, &state)
+        .await
+        .unwrap_or(false)
+    {
+        return Ok(HttpResponse::Forbidden().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            "Not Permitted To Manage Inventory".to_string(),
+            json!({}),
+        )));
+    }
+
+    let settings = ReorderSettings {
+        days: query.0.days.unwrap_or_else(|| {
+            state
+                .config
+                .get::<i64>("inventory.reorder_days")
+                .unwrap_or(7)
+        }),
+        sales_window_days: state
+            .config
+            .get::<i64>("inventory.sales_window_days")
+            .unwrap_or(14),
+    };
+
+    match get_reorder_suggestions(model.branch_id, settings, &state).await {
+        Ok(res) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Reorder Suggestions Fetched Successfully".to_string(),
+            json!(res),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Error Retrieving: {}", e),
+            json!([]),
+        ))),
+    }
+}
@@ -0,0 +1,888 @@
+use std::collections::{BTreeMap, HashMap};
+
+use actix_web::web;
+use sea_orm::{
+    prelude::{Decimal, Expr},
+    ActiveModelTrait, ActiveValue, ColumnTrait, Condition, ConnectionTrait, DbErr, EntityTrait,
+    PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, Set,
+};
+
+use crate::{
+    app::{
+        categories::dtos::dto::find_branch_category,
+        inventory::{
+            models::model::{
+                ReorderSettings, SaveDrinkDto, SaveFoodDto, StockLine, StockStatus, SupplierReorder,
+            },
+            services::service::{
+                available_measure, low_stock_email, pour_measure, reorder_line, stock_status,
+            },
+        },
+        orders::models::model::OrderStatus,
+        permissions::{dtos::dto::branch_employees_with_permission, models::model::ModelAction},
+    },
+    mailer::{
+        mailer::send_mail,
+        model::{MailerModel, MsgType},
+    },
+    AppState,
+};
+
+async fn find_supplier<C: ConnectionTrait>(
+    supplier: uuid::Uuid,
+    branch: uuid::Uuid,
+    conn: &C,
+) -> Result<entity::suppliers::Model, DbErr> {
+    entity::suppliers::Entity::find_by_id(supplier)
+        .filter(entity::suppliers::Column::BranchId.eq(branch))
+        .one(conn)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Supplier not found".into()))
+}
+
+async fn find_drink<C: ConnectionTrait>(
+    id: uuid::Uuid,
+    branch: uuid::Uuid,
+    conn: &C,
+) -> Result<entity::stock_drinks::Model, DbErr> {
+    entity::stock_drinks::Entity::find_by_id(id)
+        .filter(entity::stock_drinks::Column::BranchId.eq(branch))
+        .one(conn)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Drink not found".into()))
+}
+
+async fn find_food<C: ConnectionTrait>(
+    id: uuid::Uuid,
+    branch: uuid::Uuid,
+    conn: &C,
+) -> Result<entity::stock_foods::Model, DbErr> {
+    entity::stock_foods::Entity::find_by_id(id)
+        .filter(entity::stock_foods::Column::BranchId.eq(branch))
+        .one(conn)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Food not found".into()))
+}
+
+/// Moves the drinks' stock by `sign` units per line, locking the rows in a fixed order so
+/// concurrent orders can't deadlock or both take the last unit.
+async fn move_stock<C: ConnectionTrait>(
+    lines: &[StockLine],
+    sign: i32,
+    conn: &C,
+) -> Result<(), DbErr> {
+    let mut totals: BTreeMap<uuid::Uuid, i32> = BTreeMap::new();
+
+    for line in lines {
+        *totals.entry(line.drink).or_default() += line.quantity;
+    }
+
+    for (drink, quantity) in totals {
+        let Some(stock) = entity::stock_drinks::Entity::find_by_id(drink)
+            .lock_exclusive()
+            .one(conn)
+            .await?
+        else {
+            // The drink was removed from the menu since, there is nothing to put back
+            continue;
+        };
+
+        let remaining = stock.quantity - sign * quantity;
+
+        if remaining < 0 {
+            return Err(DbErr::Custom(format!(
+                "Only {} of {} left in stock",
+                stock.quantity, stock.name
+            )));
+        }
+
+        let sold = (stock.quantity_sold + sign * quantity).max(0);
+        let status = stock_status(remaining, stock.reorder_threshold);
+
+        let mut model: entity::stock_drinks::ActiveModel = stock.into();
+
+        model.quantity = ActiveValue::Set(remaining);
+        model.quantity_sold = ActiveValue::Set(sold);
+        model.stock_status = ActiveValue::Set(status.as_str().to_string());
+
+        if status == StockStatus::InStock {
+            model.low_stock_alerted_at = ActiveValue::Set(None);
+        }
+        model.updated_at = ActiveValue::Set(chrono::Utc::now().into());
+
+        ActiveModelTrait::update(model, conn).await.map_err(|err| {
+            eprintln!("Database update error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+    }
+
+    Ok(())
+}
+
+/// Takes an order's drinks off the shelf. Call inside the order's transaction, it fails the
+/// order when there isn't enough left.
+pub async fn take_stock<C: ConnectionTrait>(lines: &[StockLine], conn: &C) -> Result<(), DbErr> {
+    move_stock(lines, 1, conn).await
+}
+
+/// Refuses a sale when a component it needs doesn't have enough left. `demand` is the measure
+/// of each component the sale pours.
+pub async fn check_components<C: ConnectionTrait>(
+    demand: &BTreeMap<uuid::Uuid, Decimal>,
+    conn: &C,
+) -> Result<(), DbErr> {
+    if demand.is_empty() {
+        return Ok(());
+    }
+
+    let components = entity::stock_drinks::Entity::find()
+        .filter(entity::stock_drinks::Column::Id.is_in(demand.keys().copied()))
+        .all(conn)
+        .await?;
+
+    for component in components {
+        if demand[&component.id] > available_measure(&component) {
+            return Err(DbErr::Custom(format!(
+                "Not enough {} left in stock",
+                component.name
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+/// Pours the components of a completed sale, `demand` being the measure of each. The sale has
+/// been served by now, so a component that runs dry is left at zero rather than failing it.
+pub async fn pour_components<C: ConnectionTrait>(
+    demand: &BTreeMap<uuid::Uuid, Decimal>,
+    conn: &C,
+) -> Result<(), DbErr> {
+    for (component, measure) in demand {
+        let Some(stock) = entity::stock_drinks::Entity::find_by_id(*component)
+            .lock_exclusive()
+            .one(conn)
+            .await?
+        else {
+            continue;
+        };
+
+        let (remaining, open_measure) = pour_measure(&stock, *measure);
+        let status = stock_status(remaining, stock.reorder_threshold);
+
+        let mut model: entity::stock_drinks::ActiveModel = stock.into();
+
+        model.quantity = ActiveValue::Set(remaining);
+        model.open_measure = ActiveValue::Set(open_measure);
+        model.stock_status = ActiveValue::Set(status.as_str().to_string());
+
+        if status == StockStatus::InStock {
+            model.low_stock_alerted_at = ActiveValue::Set(None);
+        }
+        model.updated_at = ActiveValue::Set(chrono::Utc::now().into());
+
+        ActiveModelTrait::update(model, conn).await.map_err(|err| {
+            eprintln!("Database update error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+    }
+
+    Ok(())
+}
+
+/// Puts the drinks of a cancelled or rejected order back on the shelf. Items sold by recipe
+/// haven't poured anything yet, their components only come off when the order completes.
+pub async fn restore_order_stock<C: ConnectionTrait>(
+    order: uuid::Uuid,
+    conn: &C,
+) -> Result<(), DbErr> {
+    let lines: Vec<StockLine> = entity::order_items::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::order_items::Column::OrderId.eq(order))
+                .add(entity::order_items::Column::HasRecipe.eq(false)),
+        )
+        .all(conn)
+        .await?
+        .into_iter()
+        .filter_map(|item| {
+            item.stock_drink_id.map(|drink| StockLine {
+                drink,
+                quantity: item.quantity,
+            })
+        })
+        .collect();
+
+    move_stock(&lines, -1, conn).await
+}
+
+/// Moves a drink's stock by `change` units outside of sales, for deliveries and stock counts.
+/// Unlike a restored order this leaves the drink's sales alone. Stock never goes below zero.
+pub async fn adjust_stock<C: ConnectionTrait>(
+    drink: uuid::Uuid,
+    change: i32,
+    conn: &C,
+) -> Result<entity::stock_drinks::Model, DbErr> {
+    let stock = entity::stock_drinks::Entity::find_by_id(drink)
+        .lock_exclusive()
+        .one(conn)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Drink not found".into()))?;
+
+    let remaining = stock.quantity.saturating_add(change).max(0);
+    let status = stock_status(remaining, stock.reorder_threshold);
+
+    let mut model: entity::stock_drinks::ActiveModel = stock.into();
+
+    model.quantity = ActiveValue::Set(remaining);
+    model.stock_status = ActiveValue::Set(status.as_str().to_string());
+
+    if status == StockStatus::InStock {
+        model.low_stock_alerted_at = ActiveValue::Set(None);
+    }
+    model.updated_at = ActiveValue::Set(chrono::Utc::now().into());
+
+    ActiveModelTrait::update(model, conn).await.map_err(|err| {
+        eprintln!("Database update error: {}", err);
+        DbErr::Custom(err.to_string())
+    })
+}
+
+pub async fn insert_drink<C: ConnectionTrait>(
+    data: SaveDrinkDto,
+    conn: &C,
+) -> Result<entity::stock_drinks::Model, DbErr> {
+    find_branch_category(data.category, data.organization, data.branch, conn).await?;
+    find_supplier(data.supplier, data.branch, conn).await?;
+
+    let drink = entity::stock_drinks::ActiveModel {
+        organization_id: Set(data.organization),
+        branch_id: Set(data.branch),
+        name: Set(data.name),
+        unit_price: Set(data.unit_price),
+        sale_price: Set(data.sale_price),
+        is_alcholic: Set(data.is_alcoholic),
+        quantity: Set(data.quantity),
+        is_markup_applied: Set(data.markup_rate.is_some()),
+        markup_rate: Set(data.markup_rate.unwrap_or(Decimal::ZERO)),
+        is_reduced: Set(data.reduction_rate.is_some()),
+        reduction_rate: Set(data.reduction_rate.unwrap_or(Decimal::ZERO)),
+        reorder_threshold: Set(data.reorder_threshold),
+        stock_status: Set(stock_status(data.quantity, data.reorder_threshold)
+            .as_str()
+            .to_string()),
+        category_id: Set(data.category),
+        supplier_id: Set(data.supplier),
+        measure_unit: Set(data.measure_unit.as_str().to_string()),
+        unit_size: Set(data.unit_size),
+        sku: Set(data.sku),
+        ..Default::default()
+    };
+
+    let result = ActiveModelTrait::insert(drink, conn).await.map_err(|err| {
+        eprintln!("Database insert error: {}", err);
+        DbErr::Custom(err.to_string())
+    })?;
+
+    Ok(result)
+}
+
+pub async fn save_drink(
+    data: SaveDrinkDto,
+    state: &web::Data<AppState>,
+) -> Result<entity::stock_drinks::Model, DbErr> {
+    insert_drink(data, state.pg_db.get_ref()).await
+}
+
+/// Rewrites a branch's drink. Setting the quantity is a stock count, the shelf status follows
+/// it and units already sold are kept. A drink restocked past its reorder level can alert again
+/// the next time it runs low. Changing what a unit holds discards the opened unit's leftover.
+pub async fn rewrite_drink<C: ConnectionTrait>(
+    drink: entity::stock_drinks::Model,
+    data: SaveDrinkDto,
+    conn: &C,
+) -> Result<entity::stock_drinks::Model, DbErr> {
+    find_branch_category(data.category, data.organization, data.branch, conn).await?;
+    find_supplier(data.supplier, data.branch, conn).await?;
+
+    if drink.measure_unit != data.measure_unit.as_str() {
+        let measured = entity::recipe_items::Entity::find()
+            .filter(
+                Condition::all()
+                    .add(entity::recipe_items::Column::ComponentId.eq(drink.id))
+                    .add(entity::recipe_items::Column::Unit.ne("UNIT")),
+            )
+            .count(conn)
+            .await?;
+
+        if measured > 0 {
+            return Err(DbErr::Custom(
+                "Recipes measure this drink, update them before changing its measure".to_string(),
+            ));
+        }
+    }
+
+    let remeasured =
+        drink.measure_unit != data.measure_unit.as_str() || drink.unit_size != data.unit_size;
+
+    let mut model: entity::stock_drinks::ActiveModel = drink.into();
+
+    model.name = ActiveValue::Set(data.name);
+    model.unit_price = ActiveValue::Set(data.unit_price);
+    model.sale_price = ActiveValue::Set(data.sale_price);
+    model.is_alcholic = ActiveValue::Set(data.is_alcoholic);
+    model.quantity = ActiveValue::Set(data.quantity);
+    model.is_markup_applied = ActiveValue::Set(data.markup_rate.is_some());
+    model.markup_rate = ActiveValue::Set(data.markup_rate.unwrap_or(Decimal::ZERO));
+    model.is_reduced = ActiveValue::Set(data.reduction_rate.is_some());
+    model.reduction_rate = ActiveValue::Set(data.reduction_rate.unwrap_or(Decimal::ZERO));
+    let status = stock_status(data.quantity, data.reorder_threshold);
+
+    model.reorder_threshold = ActiveValue::Set(data.reorder_threshold);
+    model.stock_status = ActiveValue::Set(status.as_str().to_string());
+
+    if status == StockStatus::InStock {
+        model.low_stock_alerted_at = ActiveValue::Set(None);
+    }
+
+    model.category_id = ActiveValue::Set(data.category);
+    model.supplier_id = ActiveValue::Set(data.supplier);
+    model.measure_unit = ActiveValue::Set(data.measure_unit.as_str().to_string());
+    model.unit_size = ActiveValue::Set(data.unit_size);
+    model.sku = ActiveValue::Set(data.sku);
+
+    if remeasured {
+        model.open_measure = ActiveValue::Set(Decimal::ZERO);
+    }
+
+    model.updated_at = ActiveValue::Set(chrono::Utc::now().into());
+
+    let result = ActiveModelTrait::update(model, conn).await.map_err(|err| {
+        eprintln!("Database update error: {}", err);
+        DbErr::Custom(err.to_string())
+    })?;
+
+    Ok(result)
+}
+
+pub async fn update_drink(
+    id: uuid::Uuid,
+    data: SaveDrinkDto,
+    state: &web::Data<AppState>,
+) -> Result<entity::stock_drinks::Model, DbErr> {
+    let drink = find_drink(id, data.branch, state.pg_db.get_ref()).await?;
+
+    rewrite_drink(drink, data, state.pg_db.get_ref()).await
+}
+
+pub async fn set_drink_category(
+    id: uuid::Uuid,
+    branch: uuid::Uuid,
+    category: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<entity::stock_drinks::Model, DbErr> {
+    let drink = find_drink(id, branch, state.pg_db.get_ref()).await?;
+
+    find_branch_category(
+        category,
+        drink.organization_id,
+        branch,
+        state.pg_db.get_ref(),
+    )
+    .await?;
+
+    let mut model: entity::stock_drinks::ActiveModel = drink.into();
+
+    model.category_id = ActiveValue::Set(category);
+    model.updated_at = ActiveValue::Set(chrono::Utc::now().into());
+
+    let result = ActiveModelTrait::update(model, state.pg_db.get_ref())
+        .await
+        .map_err(|err| {
+            eprintln!("Database update error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    Ok(result)
+}
+
+pub async fn set_drink_image(
+    id: uuid::Uuid,
+    branch: uuid::Uuid,
+    image: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<entity::stock_drinks::Model, DbErr> {
+    let drink = find_drink(id, branch, state.pg_db.get_ref()).await?;
+
+    let mut model: entity::stock_drinks::ActiveModel = drink.into();
+
+    model.image_id = ActiveValue::Set(Some(image.to_string()));
+    model.updated_at = ActiveValue::Set(chrono::Utc::now().into());
+
+    let result = ActiveModelTrait::update(model, state.pg_db.get_ref())
+        .await
+        .map_err(|err| {
+            eprintln!("Database update error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    Ok(result)
+}
+
+/// Removes a drink nobody has ordered yet. Drinks with orders stay so the orders keep their
+/// items, they can be run out of stock instead.
+pub async fn delete_drink(
+    id: uuid::Uuid,
+    branch: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<(), DbErr> {
+    let drink = find_drink(id, branch, state.pg_db.get_ref()).await?;
+
+    let ordered = entity::order_items::Entity::find()
+        .filter(entity::order_items::Column::StockDrinkId.eq(drink.id))
+        .count(state.pg_db.get_ref())
+        .await?;
+
+    if ordered > 0 {
+        return Err(DbErr::Custom(
+            "Drink has orders, set its quantity to zero instead".to_string(),
+        ));
+    }
+
+    let poured = entity::recipe_items::Entity::find()
+        .filter(entity::recipe_items::Column::ComponentId.eq(drink.id))
+        .count(state.pg_db.get_ref())
+        .await?;
+
+    if poured > 0 {
+        return Err(DbErr::Custom(
+            "Drink is used in recipes, set its quantity to zero instead".to_string(),
+        ));
+    }
+
+    entity::stock_drinks::Entity::delete_by_id(drink.id)
+        .exec(state.pg_db.get_ref())
+        .await
+        .map_err(|err| {
+            eprintln!("Database delete error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    Ok(())
+}
+
+pub async fn get_drink(
+    id: uuid::Uuid,
+    branch: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<entity::stock_drinks::Model, DbErr> {
+    find_drink(id, branch, state.pg_db.get_ref()).await
+}
+
+pub async fn get_branch_drinks(
+    branch: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<Vec<entity::stock_drinks::Model>, DbErr> {
+    let drinks = entity::stock_drinks::Entity::find()
+        .filter(entity::stock_drinks::Column::BranchId.eq(branch))
+        .order_by_asc(entity::stock_drinks::Column::Name)
+        .all(state.pg_db.get_ref())
+        .await
+        .map_err(|err| {
+            eprintln!("Database retrieval error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    Ok(drinks)
+}
+
+pub async fn insert_food<C: ConnectionTrait>(
+    data: SaveFoodDto,
+    conn: &C,
+) -> Result<entity::stock_foods::Model, DbErr> {
+    find_branch_category(data.category, data.organization, data.branch, conn).await?;
+
+    let food = entity::stock_foods::ActiveModel {
+        organization_id: Set(data.organization),
+        branch_id: Set(data.branch),
+        name: Set(data.name),
+        price: Set(data.price),
+        description: Set(data.description),
+        category_id: Set(data.category),
+        sku: Set(data.sku),
+        ..Default::default()
+    };
+
+    let result = ActiveModelTrait::insert(food, conn).await.map_err(|err| {
+        eprintln!("Database insert error: {}", err);
+        DbErr::Custom(err.to_string())
+    })?;
+
+    Ok(result)
+}
+
+pub async fn save_food(
+    data: SaveFoodDto,
+    state: &web::Data<AppState>,
+) -> Result<entity::stock_foods::Model, DbErr> {
+    insert_food(data, state.pg_db.get_ref()).await
+}
+
+pub async fn rewrite_food<C: ConnectionTrait>(
+    food: entity::stock_foods::Model,
+    data: SaveFoodDto,
+    conn: &C,
+) -> Result<entity::stock_foods::Model, DbErr> {
+    find_branch_category(data.category, data.organization, data.branch, conn).await?;
+
+    let mut model: entity::stock_foods::ActiveModel = food.into();
+
+    model.name = ActiveValue::Set(data.name);
+    model.price = ActiveValue::Set(data.price);
+    model.description = ActiveValue::Set(data.description);
+    model.category_id = ActiveValue::Set(data.category);
+    model.sku = ActiveValue::Set(data.sku);
+    model.updated_at = ActiveValue::Set(chrono::Utc::now().into());
+
+    let result = ActiveModelTrait::update(model, conn).await.map_err(|err| {
+        eprintln!("Database update error: {}", err);
+        DbErr::Custom(err.to_string())
+    })?;
+
+    Ok(result)
+}
+
+pub async fn update_food(
+    id: uuid::Uuid,
+    data: SaveFoodDto,
+    state: &web::Data<AppState>,
+) -> Result<entity::stock_foods::Model, DbErr> {
+    let food = find_food(id, data.branch, state.pg_db.get_ref()).await?;
+
+    rewrite_food(food, data, state.pg_db.get_ref()).await
+}
+
+pub async fn set_food_category(
+    id: uuid::Uuid,
+    branch: uuid::Uuid,
+    category: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<entity::stock_foods::Model, DbErr> {
+    let food = find_food(id, branch, state.pg_db.get_ref()).await?;
+
+    find_branch_category(
+        category,
+        food.organization_id,
+        branch,
+        state.pg_db.get_ref(),
+    )
+    .await?;
+
+    let mut model: entity::stock_foods::ActiveModel = food.into();
+
+    model.category_id = ActiveValue::Set(category);
+    model.updated_at = ActiveValue::Set(chrono::Utc::now().into());
+
+    let result = ActiveModelTrait::update(model, state.pg_db.get_ref())
+        .await
+        .map_err(|err| {
+            eprintln!("Database update error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    Ok(result)
+}
+
+pub async fn set_food_image(
+    id: uuid::Uuid,
+    branch: uuid::Uuid,
+    image: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<entity::stock_foods::Model, DbErr> {
+    let food = find_food(id, branch, state.pg_db.get_ref()).await?;
+
+    let mut model: entity::stock_foods::ActiveModel = food.into();
+
+    model.image_id = ActiveValue::Set(Some(image.to_string()));
+    model.updated_at = ActiveValue::Set(chrono::Utc::now().into());
+
+    let result = ActiveModelTrait::update(model, state.pg_db.get_ref())
+        .await
+        .map_err(|err| {
+            eprintln!("Database update error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    Ok(result)
+}
+
+/// Removes a food nobody has ordered yet, orders keep their items.
+pub async fn delete_food(
+    id: uuid::Uuid,
+    branch: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<(), DbErr> {
+    let food = find_food(id, branch, state.pg_db.get_ref()).await?;
+
+    let ordered = entity::order_items::Entity::find()
+        .filter(entity::order_items::Column::StockFoodId.eq(food.id))
+        .count(state.pg_db.get_ref())
+        .await?;
+
+    if ordered > 0 {
+        return Err(DbErr::Custom(
+            "Food has orders and can't be removed".to_string(),
+        ));
+    }
+
+    entity::stock_foods::Entity::delete_by_id(food.id)
+        .exec(state.pg_db.get_ref())
+        .await
+        .map_err(|err| {
+            eprintln!("Database delete error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    Ok(())
+}
+
+pub async fn get_food(
+    id: uuid::Uuid,
+    branch: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<entity::stock_foods::Model, DbErr> {
+    find_food(id, branch, state.pg_db.get_ref()).await
+}
+
+pub async fn get_branch_foods(
+    branch: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<Vec<entity::stock_foods::Model>, DbErr> {
+    let foods = entity::stock_foods::Entity::find()
+        .filter(entity::stock_foods::Column::BranchId.eq(branch))
+        .order_by_asc(entity::stock_foods::Column::Name)
+        .all(state.pg_db.get_ref())
+        .await
+        .map_err(|err| {
+            eprintln!("Database retrieval error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    Ok(foods)
+}
+
+/// Units of each drink the branch sold since `since`, leaving out rejected and cancelled orders.
+async fn drink_sales<C: ConnectionTrait>(
+    branch: uuid::Uuid,
+    drinks: Vec<uuid::Uuid>,
+    since: chrono::DateTime<chrono::Utc>,
+    conn: &C,
+) -> Result<HashMap<uuid::Uuid, i64>, DbErr> {
+    let items = entity::order_items::Entity::find()
+        .inner_join(entity::orders::Entity)
+        .filter(
+            Condition::all()
+                .add(entity::order_items::Column::StockDrinkId.is_in(drinks))
+                .add(entity::orders::Column::BranchId.eq(branch))
+                .add(entity::orders::Column::CreatedAt.gte(since))
+                .add(entity::orders::Column::OrderStatus.is_not_in([
+                    OrderStatus::Rejected.as_str(),
+                    OrderStatus::Cancelled.as_str(),
+                ])),
+        )
+        .all(conn)
+        .await
+        .map_err(|err| {
+            eprintln!("Database retrieval error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    let mut sales = HashMap::new();
+
+    for item in items {
+        if let Some(drink) = item.stock_drink_id {
+            *sales.entry(drink).or_default() += i64::from(item.quantity);
+        }
+    }
+
+    Ok(sales)
+}
+
+/// What to reorder of the given low drinks of a branch, one order per supplier.
+async fn supplier_reorders<C: ConnectionTrait>(
+    branch: uuid::Uuid,
+    drinks: &[entity::stock_drinks::Model],
+    settings: &ReorderSettings,
+    conn: &C,
+) -> Result<Vec<SupplierReorder>, DbErr> {
+    let since = chrono::Utc::now() - chrono::Duration::days(settings.sales_window_days);
+    let sales = drink_sales(branch, drinks.iter().map(|d| d.id).collect(), since, conn).await?;
+
+    let suppliers: HashMap<uuid::Uuid, entity::suppliers::Model> =
+        entity::suppliers::Entity::find()
+            .filter(entity::suppliers::Column::Id.is_in(drinks.iter().map(|d| d.supplier_id)))
+            .all(conn)
+            .await?
+            .into_iter()
+            .map(|supplier| (supplier.id, supplier))
+            .collect();
+
+    let mut reorders: BTreeMap<uuid::Uuid, SupplierReorder> = BTreeMap::new();
+
+    for drink in drinks {
+        let Some(supplier) = suppliers.get(&drink.supplier_id) else {
+            continue;
+        };
+
+        let sold = sales.get(&drink.id).copied().unwrap_or(0);
+
+        reorders
+            .entry(supplier.id)
+            .or_insert_with(|| SupplierReorder {
+                supplier: supplier.id,
+                name: supplier.name.clone(),
+                email: supplier.email.clone(),
+                contact: supplier.contact.clone(),
+                lines: Vec::new(),
+            })
+            .lines
+            .push(reorder_line(drink, sold, settings));
+    }
+
+    Ok(reorders.into_values().collect())
+}
+
+/// The branch's drinks at or below their reorder level, with what to order of each from its
+/// supplier to last the coming `settings.days`.
+pub async fn get_reorder_suggestions(
+    branch: uuid::Uuid,
+    settings: ReorderSettings,
+    state: &web::Data<AppState>,
+) -> Result<Vec<SupplierReorder>, DbErr> {
+    let drinks = entity::stock_drinks::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::stock_drinks::Column::BranchId.eq(branch))
+                .add(
+                    Expr::col(entity::stock_drinks::Column::Quantity)
+                        .lte(Expr::col(entity::stock_drinks::Column::ReorderThreshold)),
+                ),
+        )
+        .order_by_asc(entity::stock_drinks::Column::Name)
+        .all(state.pg_db.get_ref())
+        .await
+        .map_err(|err| {
+            eprintln!("Database retrieval error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    supplier_reorders(branch, &drinks, &settings, state.pg_db.get_ref()).await
+}
+
+/// Emails each branch with drinks newly at or below their reorder level the reorders it should
+/// place, grouped by supplier. It goes to the branch's staff who manage inventory, or to the
+/// branch's own address when there are none. Drinks are only alerted once until restocked, a
+/// branch whose email could not be sent is tried again on the next run. Returns the number of
+/// branches alerted.
+pub async fn alert_low_stock(
+    settings: ReorderSettings,
+    state: &web::Data<AppState>,
+) -> Result<usize, DbErr> {
+    let drinks = entity::stock_drinks::Entity::find()
+        .filter(
+            Condition::all()
+                .add(
+                    Expr::col(entity::stock_drinks::Column::Quantity)
+                        .lte(Expr::col(entity::stock_drinks::Column::ReorderThreshold)),
+                )
+                .add(entity::stock_drinks::Column::LowStockAlertedAt.is_null()),
+        )
+        .order_by_asc(entity::stock_drinks::Column::Name)
+        .all(state.pg_db.get_ref())
+        .await
+        .map_err(|err| {
+            eprintln!("Database retrieval error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    let mut branches: BTreeMap<uuid::Uuid, Vec<entity::stock_drinks::Model>> = BTreeMap::new();
+
+    for drink in drinks {
+        branches.entry(drink.branch_id).or_default().push(drink);
+    }
+
+    let mut alerted = 0;
+
+    for (branch_id, drinks) in branches {
+        let Some(branch) = entity::branches::Entity::find_by_id(branch_id)
+            .filter(entity::branches::Column::IsDeleted.eq(false))
+            .one(state.pg_db.get_ref())
+            .await?
+        else {
+            continue;
+        };
+
+        let mut receivers: Vec<String> = branch_employees_with_permission(
+            branch.id,
+            ModelAction::ManageInventory,
+            state.pg_db.get_ref(),
+        )
+        .await?
+        .into_iter()
+        .map(|employee| employee.email)
+        .collect();
+
+        if receivers.is_empty() {
+            receivers.extend(branch.email.clone());
+        }
+
+        let reorders =
+            supplier_reorders(branch.id, &drinks, &settings, state.pg_db.get_ref()).await?;
+
+        let body = low_stock_email(&branch, &reorders);
+        let mut sent = false;
+
+        for receiver in receivers {
+            let mail = MailerModel {
+                subject: format!("Low stock at {}", branch.name),
+                body: body.clone(),
+                receiver,
+                msg_type: MsgType::TEXT,
+            };
+
+            match send_mail(mail).await {
+                Ok(_) => sent = true,
+                Err(err) => eprintln!("Low stock email error: {}", err),
+            }
+        }
+
+        if !sent {
+            continue;
+        }
+
+        entity::stock_drinks::Entity::update_many()
+            .col_expr(
+                entity::stock_drinks::Column::LowStockAlertedAt,
+                chrono::Utc::now().into(),
+            )
+            .filter(
+                Condition::all()
+                    .add(entity::stock_drinks::Column::Id.is_in(drinks.iter().map(|d| d.id)))
+                    // Restocked while the email went out, it has nothing to be alerted about
+                    .add(
+                        Expr::col(entity::stock_drinks::Column::Quantity)
+                            .lte(Expr::col(entity::stock_drinks::Column::ReorderThreshold)),
+                    ),
+            )
+            .exec(state.pg_db.get_ref())
+            .await
+            .map_err(|err| {
+                eprintln!("Database update error: {}", err);
+                DbErr::Custom(err.to_string())
+            })?;
+
+        alerted += 1;
+    }
+
+    Ok(alerted)
+}
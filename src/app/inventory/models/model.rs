@@ -0,0 +1,254 @@
+use std::str::FromStr;
+
+use sea_orm::prelude::Decimal;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::{
+    app::inventory::services::service::DEFAULT_REORDER_THRESHOLD,
+    libs::validator::{
+        validate_base64_file_size, validate_cash_amount, validate_measure, validate_measure_unit,
+        validate_mime_type, validate_percent_range,
+    },
+};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum StockStatus {
+    InStock,
+    RunningLow,
+    OutOfStock,
+}
+
+impl StockStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StockStatus::InStock => "INSTOCK",
+            StockStatus::RunningLow => "RUNNINGLOW",
+            StockStatus::OutOfStock => "OUTOFSTOCK",
+        }
+    }
+}
+
+impl FromStr for StockStatus {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_uppercase().as_str() {
+            "INSTOCK" => Ok(StockStatus::InStock),
+            "RUNNINGLOW" => Ok(StockStatus::RunningLow),
+            "OUTOFSTOCK" => Ok(StockStatus::OutOfStock),
+            _ => Err(()),
+        }
+    }
+}
+
+/// What one unit of a drink is measured in, so recipes can pour part of it.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum MeasureUnit {
+    Piece,
+    Ml,
+    G,
+}
+
+impl MeasureUnit {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MeasureUnit::Piece => "PIECE",
+            MeasureUnit::Ml => "ML",
+            MeasureUnit::G => "G",
+        }
+    }
+}
+
+impl FromStr for MeasureUnit {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_uppercase().as_str() {
+            "PIECE" => Ok(MeasureUnit::Piece),
+            "ML" => Ok(MeasureUnit::Ml),
+            "G" => Ok(MeasureUnit::G),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Units of a drink taken off or put back on the shelf by an order.
+#[derive(Debug, Clone, Copy)]
+pub struct StockLine {
+    pub drink: uuid::Uuid,
+    pub quantity: i32,
+}
+
+/// How far ahead reorders are worked out, and over how many past days sales are averaged.
+#[derive(Debug, Clone, Copy)]
+pub struct ReorderSettings {
+    pub days: i64,
+    pub sales_window_days: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReorderLine {
+    pub drink: uuid::Uuid,
+    pub name: String,
+    pub quantity: i32,
+    pub reorder_threshold: i32,
+    pub daily_sales: Decimal,
+    pub suggested_quantity: i32,
+}
+
+/// The low drinks of a branch that come from one supplier.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SupplierReorder {
+    pub supplier: uuid::Uuid,
+    pub name: String,
+    pub email: String,
+    pub contact: String,
+    pub lines: Vec<ReorderLine>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct ReorderQuery {
+    #[validate(range(min = 1, max = 90, message = "Days are invalid"))]
+    pub days: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SaveDrinkDto {
+    pub organization: uuid::Uuid,
+    pub branch: uuid::Uuid,
+    pub name: String,
+    pub unit_price: Decimal,
+    pub sale_price: Decimal,
+    pub is_alcoholic: bool,
+    pub quantity: i32,
+    pub reorder_threshold: i32,
+    pub markup_rate: Option<Decimal>,
+    pub reduction_rate: Option<Decimal>,
+    pub category: uuid::Uuid,
+    pub supplier: uuid::Uuid,
+    pub measure_unit: MeasureUnit,
+    pub unit_size: Decimal,
+    pub sku: Option<String>,
+}
+
+/// A drink on the branch's shelf. `measure_unit` and `unit_size` say what one unit holds, e.g.
+/// a 700 ML bottle, and default to a single piece. `sku` is the branch's own code for it.
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct DrinkParams {
+    #[validate(length(min = 2, max = 100, message = "Name is invalid"))]
+    pub name: String,
+    #[validate(custom(function = "validate_cash_amount"))]
+    pub unit_price: Decimal,
+    #[validate(custom(function = "validate_cash_amount"))]
+    pub sale_price: Decimal,
+    pub is_alcoholic: Option<bool>,
+    #[validate(range(min = 0, max = 100000, message = "Quantity is invalid"))]
+    pub quantity: i32,
+    #[validate(range(min = 0, max = 100000, message = "Reorder threshold is invalid"))]
+    pub reorder_threshold: Option<i32>,
+    #[validate(custom(function = "validate_percent_range"))]
+    pub markup_rate: Option<Decimal>,
+    #[validate(custom(function = "validate_percent_range"))]
+    pub reduction_rate: Option<Decimal>,
+    pub category: uuid::Uuid,
+    pub supplier: uuid::Uuid,
+    #[validate(custom(function = "validate_measure_unit"))]
+    pub measure_unit: Option<String>,
+    #[validate(custom(function = "validate_measure"))]
+    pub unit_size: Option<Decimal>,
+    #[validate(length(min = 1, max = 50, message = "SKU is invalid"))]
+    pub sku: Option<String>,
+}
+
+impl DrinkParams {
+    pub fn into_dto(self, organization: uuid::Uuid, branch: uuid::Uuid) -> SaveDrinkDto {
+        SaveDrinkDto {
+            organization,
+            branch,
+            name: self.name,
+            unit_price: self.unit_price,
+            sale_price: self.sale_price,
+            is_alcoholic: self.is_alcoholic.unwrap_or(false),
+            quantity: self.quantity,
+            reorder_threshold: self.reorder_threshold.unwrap_or(DEFAULT_REORDER_THRESHOLD),
+            markup_rate: self.markup_rate,
+            reduction_rate: self.reduction_rate,
+            category: self.category,
+            supplier: self.supplier,
+            measure_unit: self
+                .measure_unit
+                .and_then(|unit| unit.parse().ok())
+                .unwrap_or(MeasureUnit::Piece),
+            unit_size: self.unit_size.unwrap_or(Decimal::ONE),
+            sku: self.sku,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SaveFoodDto {
+    pub organization: uuid::Uuid,
+    pub branch: uuid::Uuid,
+    pub name: String,
+    pub price: Decimal,
+    pub description: Option<String>,
+    pub category: uuid::Uuid,
+    pub sku: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct FoodParams {
+    #[validate(length(min = 2, max = 100, message = "Name is invalid"))]
+    pub name: String,
+    #[validate(custom(function = "validate_cash_amount"))]
+    pub price: Decimal,
+    #[validate(length(max = 500, message = "Description is too long"))]
+    pub description: Option<String>,
+    pub category: uuid::Uuid,
+    #[validate(length(min = 1, max = 50, message = "SKU is invalid"))]
+    pub sku: Option<String>,
+}
+
+impl FoodParams {
+    pub fn into_dto(self, organization: uuid::Uuid, branch: uuid::Uuid) -> SaveFoodDto {
+        SaveFoodDto {
+            organization,
+            branch,
+            name: self.name,
+            price: self.price,
+            description: self.description,
+            category: self.category,
+            sku: self.sku,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct AssignCategoryParams {
+    pub category: uuid::Uuid,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct MenuImageParams {
+    #[validate(custom(function = "validate_base64_file_size"))]
+    pub img: String,
+    #[validate(custom(function = "validate_mime_type"))]
+    pub mime_type: String,
+    #[serde(default)]
+    #[validate(range(min = 0, message = "File size must be a non-negative number"))]
+    pub file_size: i64,
+    #[validate(length(min = 1, message = "Media type must not be empty"))]
+    pub media_type: String,
+    #[validate(range(min = 1, message = "Width must be a positive number"))]
+    pub width: Option<i32>,
+    #[validate(range(min = 1, message = "Height must be a positive number"))]
+    pub height: Option<i32>,
+}
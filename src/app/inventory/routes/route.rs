@@ -0,0 +1,122 @@
+use actix_web::web;
+
+use crate::{
+    app::inventory::controllers::controller::{
+        add_drink, add_food, branch_drinks, branch_foods, drink_category, drink_details,
+        drink_image, edit_drink, edit_food, food_category, food_details, food_image, remove_drink,
+        remove_food, reorder_suggestions,
+    },
+    middlewares::{auth::JwtAuthMiddleware, checker::CheckUserMiddleware},
+    AppState,
+};
+
+pub fn routes(cfg: &mut web::ServiceConfig, state: web::Data<AppState>) {
+    cfg.service(
+        web::scope("/api/v1/inventory")
+            .route(
+                "/drinks",
+                web::post()
+                    .to(add_drink)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/drinks",
+                web::get()
+                    .to(branch_drinks)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/drinks/{id}",
+                web::get()
+                    .to(drink_details)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/drinks/{id}",
+                web::put()
+                    .to(edit_drink)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/drinks/category/{id}",
+                web::put()
+                    .to(drink_category)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/drinks/image/{id}",
+                web::put()
+                    .to(drink_image)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/drinks/{id}",
+                web::delete()
+                    .to(remove_drink)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/foods",
+                web::post()
+                    .to(add_food)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/foods",
+                web::get()
+                    .to(branch_foods)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/foods/{id}",
+                web::get()
+                    .to(food_details)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/foods/{id}",
+                web::put()
+                    .to(edit_food)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/foods/category/{id}",
+                web::put()
+                    .to(food_category)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/foods/image/{id}",
+                web::put()
+                    .to(food_image)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/foods/{id}",
+                web::delete()
+                    .to(remove_food)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/reorders",
+                web::get()
+                    .to(reorder_suggestions)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            ),
+    );
+}
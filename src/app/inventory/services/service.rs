@@ -0,0 +1,163 @@
+use sea_orm::prelude::Decimal;
+
+use crate::app::inventory::models::model::{
+    ReorderLine, ReorderSettings, StockStatus, SupplierReorder,
+};
+
+/// Reorder level of drinks added without one, the same as the column default.
+pub const DEFAULT_REORDER_THRESHOLD: i32 = 10;
+
+/// The shelf status a drink has with this many units left.
+pub fn stock_status(quantity: i32, reorder_threshold: i32) -> StockStatus {
+    match quantity {
+        q if q <= 0 => StockStatus::OutOfStock,
+        q if q <= reorder_threshold => StockStatus::RunningLow,
+        _ => StockStatus::InStock,
+    }
+}
+
+/// Everything left of a drink in its measure: its sealed units and the opened unit's leftover.
+pub fn available_measure(drink: &entity::stock_drinks::Model) -> Decimal {
+    Decimal::from(drink.quantity) * drink.unit_size + drink.open_measure
+}
+
+/// Sealed units and opened leftover once `measure` is poured from a drink. The opened unit is
+/// used up first, then as many sealed units are opened as the pour needs. Stock never goes
+/// below zero.
+pub fn pour_measure(drink: &entity::stock_drinks::Model, measure: Decimal) -> (i32, Decimal) {
+    if measure <= drink.open_measure {
+        return (drink.quantity, drink.open_measure - measure);
+    }
+
+    let needed = measure - drink.open_measure;
+    let opened = (needed / drink.unit_size).ceil();
+
+    match i32::try_from(opened) {
+        Ok(units) if units <= drink.quantity => {
+            (drink.quantity - units, opened * drink.unit_size - needed)
+        }
+        _ => (0, Decimal::ZERO),
+    }
+}
+
+/// What to order of a low drink: enough to cover its recent daily sales over the coming
+/// `settings.days` and still sit at the reorder level after that.
+pub fn reorder_line(
+    drink: &entity::stock_drinks::Model,
+    sold: i64,
+    settings: &ReorderSettings,
+) -> ReorderLine {
+    let daily_sales = Decimal::from(sold) / Decimal::from(settings.sales_window_days.max(1));
+    let demand = (daily_sales * Decimal::from(settings.days))
+        .ceil()
+        .try_into()
+        .unwrap_or(i32::MAX);
+
+    ReorderLine {
+        drink: drink.id,
+        name: drink.name.clone(),
+        quantity: drink.quantity,
+        reorder_threshold: drink.reorder_threshold,
+        daily_sales: daily_sales.round_dp(2),
+        suggested_quantity: demand
+            .saturating_add(drink.reorder_threshold)
+            .saturating_sub(drink.quantity)
+            .max(0),
+    }
+}
+
+pub fn low_stock_email(branch: &entity::branches::Model, orders: &[SupplierReorder]) -> String {
+    let sections: Vec<String> = orders
+        .iter()
+        .map(|order| {
+            let lines: Vec<String> = order
+                .lines
+                .iter()
+                .map(|line| {
+                    format!(
+                        "  - {}: {} left (reorder at {}), selling {} a day, order {}",
+                        line.name,
+                        line.quantity,
+                        line.reorder_threshold,
+                        line.daily_sales,
+                        line.suggested_quantity
+                    )
+                })
+                .collect();
+
+            format!(
+                "{} ({}, {})\n{}",
+                order.name,
+                order.email,
+                order.contact,
+                lines.join("\n")
+            )
+        })
+        .collect();
+
+    format!(
+        "These drinks at {} are at or below their reorder level:\n\n{}\n",
+        branch.name,
+        sections.join("\n\n")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        app::inventory::models::model::MeasureUnit,
+        utils::fixtures::{dec, stock_drink},
+    };
+
+    /// A 700 ML bottle with `quantity` sealed and `open` ML left in the opened one.
+    fn bottle(quantity: i32, open: &str) -> entity::stock_drinks::Model {
+        entity::stock_drinks::Model {
+            quantity,
+            measure_unit: MeasureUnit::Ml.as_str().to_string(),
+            unit_size: dec("700"),
+            open_measure: dec(open),
+            ..stock_drink("Gin", "10")
+        }
+    }
+
+    #[test]
+    fn stock_status_follows_the_reorder_level() {
+        assert_eq!(stock_status(0, 10), StockStatus::OutOfStock);
+        assert_eq!(stock_status(-2, 10), StockStatus::OutOfStock);
+        assert_eq!(stock_status(10, 10), StockStatus::RunningLow);
+        assert_eq!(stock_status(11, 10), StockStatus::InStock);
+        assert_eq!(stock_status(1, 0), StockStatus::InStock);
+    }
+
+    #[test]
+    fn pours_from_the_opened_unit_first() {
+        assert_eq!(pour_measure(&bottle(3, "100"), dec("50")), (3, dec("50")));
+        assert_eq!(
+            pour_measure(&bottle(3, "100"), dec("100")),
+            (3, Decimal::ZERO)
+        );
+    }
+
+    #[test]
+    fn opens_as_many_units_as_the_pour_needs() {
+        assert_eq!(pour_measure(&bottle(3, "100"), dec("150")), (2, dec("650")));
+        assert_eq!(
+            pour_measure(&bottle(3, "0"), dec("1400")),
+            (1, Decimal::ZERO)
+        );
+    }
+
+    #[test]
+    fn never_pours_below_zero() {
+        assert_eq!(
+            pour_measure(&bottle(1, "100"), dec("900")),
+            (0, Decimal::ZERO)
+        );
+    }
+
+    #[test]
+    fn counts_sealed_and_opened_measure() {
+        assert_eq!(available_measure(&bottle(2, "250")), dec("1650"));
+    }
+}
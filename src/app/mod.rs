@@ -14,10 +14,40 @@ pub fn app_routes(state: web::Data<AppState>) -> impl FnOnce(&mut ServiceConfig)
         cfg.configure(|c| employees::routes::route::routes(c, state.clone()));
         cfg.configure(|c| customers::routes::route::routes(c, state.clone()));
         cfg.configure(|c| schedules::routes::route::routes(c, state.clone()));
+        cfg.configure(|c| bookings::routes::route::routes(c, state.clone()));
+        cfg.configure(|c| bans::routes::route::routes(c, state.clone()));
+        cfg.configure(|c| ticket_events::routes::route::routes(c, state.clone()));
+        cfg.configure(|c| tickets::routes::route::routes(c, state.clone()));
+        cfg.configure(|c| analytics::routes::route::routes(c, state.clone()));
+        cfg.configure(|c| approvals::routes::route::routes(c, state.clone()));
+        cfg.configure(|c| catalog::routes::route::routes(c, state.clone()));
+        cfg.configure(|c| categories::routes::route::routes(c, state.clone()));
+        cfg.configure(|c| cocktails::routes::route::routes(c, state.clone()));
+        cfg.configure(|c| inventory::routes::route::routes(c, state.clone()));
+        cfg.configure(|c| orders::routes::route::routes(c, state.clone()));
+        cfg.configure(|c| order_queue::routes::route::routes(c, state.clone()));
+        cfg.configure(|c| pricing::routes::route::routes(c, state.clone()));
+        cfg.configure(|c| promos::routes::route::routes(c, state.clone()));
+        cfg.configure(|c| purchasing::routes::route::routes(c, state.clone()));
+        cfg.configure(|c| receipts::routes::route::routes(c, state.clone()));
+        cfg.configure(|c| recipes::routes::route::routes(c, state.clone()));
+        cfg.configure(|c| stock_takes::routes::route::routes(c, state.clone()));
+        cfg.configure(|c| stock_transfers::routes::route::routes(c, state.clone()));
+        cfg.configure(|c| tabs::routes::route::routes(c, state.clone()));
+        cfg.configure(|c| taxes::routes::route::routes(c, state.clone()));
+        cfg.configure(|c| tills::routes::route::routes(c, state.clone()));
+        cfg.configure(|c| tips::routes::route::routes(c, state.clone()));
     }
 }
 
+pub mod analytics;
+pub mod approvals;
+pub mod bans;
+pub mod bookings;
 pub mod branch;
+pub mod catalog;
+pub mod categories;
+pub mod cocktails;
 pub mod country;
 pub mod customers;
 pub mod departments;
@@ -25,6 +55,24 @@ pub mod employees;
 pub mod events;
 pub mod files_manager;
 pub mod health;
+pub mod inventory;
+pub mod order_queue;
+pub mod orders;
 pub mod organization;
+pub mod permissions;
+pub mod pricing;
+pub mod promos;
+pub mod purchasing;
+pub mod receipts;
+pub mod recipes;
 pub mod schedules;
+pub mod stock_takes;
+pub mod stock_transfers;
+pub mod taxes;
+pub mod ticket_events;
+pub mod tickets;
+pub mod tabs;
+pub mod tills;
+pub mod tips;
 pub mod users;
+pub mod wallets;
@@ -0,0 +1,154 @@
+use std::{collections::BTreeSet, sync::Arc, time::Duration};
+
+use actix_web::{web, HttpMessage, HttpRequest, HttpResponse, Responder};
+use actix_web_lab::sse;
+use serde_json::json;
+use tokio::sync::{broadcast::error::RecvError, mpsc};
+
+use crate::{
+    app::{
+        employees::models::model::EmployeeResponse,
+        order_queue::{
+            dtos::dto::get_events_since,
+            models::model::{OrderStreamQuery, Station},
+        },
+    },
+    libs::error::Error,
+    utils::{
+        json_validator::ValidatedQuery,
+        models::{HttpClientResponse, ResponseCode},
+    },
+    AppState,
+};
+
+fn to_sse(event: &entity::order_events::Model) -> sse::Event {
+    sse::Data::new(event.payload.to_string())
+        .id(event.id.to_string())
+        .event(event.event_type.to_lowercase())
+        .into()
+}
+
+/// Tells the screen its replay was cut short and it should reload its orders.
+fn replay_truncated(last_event_id: Option<i64>) -> sse::Event {
+    sse::Data::new(json!({ "last_event_id": last_event_id }).to_string())
+        .event("replay_truncated")
+        .into()
+}
+
+/// How many streamed event ids a connection remembers to skip repeats.
+const SENT_MEMORY: usize = 2048;
+
+/// Live queue for one station of the employee's branch. A screen reconnecting with the
+/// `Last-Event-ID` header first receives everything it missed, then live events. Replay may
+/// repeat events from just before that id, so screens should key on the event id. When more
+/// was missed than one replay holds, a `replay_truncated` event follows it.
+pub async fn order_stream(
+    req: HttpRequest,
+    query: ValidatedQuery<OrderStreamQuery>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    let station = match query.0.station.parse::<Station>() {
+        Ok(station) => station,
+        Err(_) => {
+            return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+                ResponseCode::Failed,
+                "Invalid station".to_string(),
+                json!({}),
+            )))
+        }
+    };
+
+    let mut last_event_id = req
+        .headers()
+        .get("Last-Event-ID")
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse::<i64>().ok());
+
+    let branch = model.branch_id;
+    let (tx, rx) = mpsc::channel(64);
+
+    // Subscribe before replaying so nothing published in between is lost
+    let mut feed = state.order_feed.subscribe();
+
+    actix_web::rt::spawn(async move {
+        let mut replay = last_event_id.is_some();
+        let mut sent = BTreeSet::new();
+
+        loop {
+            if replay {
+                if let Some(after) = last_event_id {
+                    match get_events_since(branch, station, after, &state).await {
+                        Ok(missed) => {
+                            for event in missed.events {
+                                if !sent.insert(event.id) {
+                                    continue;
+                                }
+
+                                last_event_id = last_event_id.max(Some(event.id));
+
+                                if tx.send(to_sse(&event)).await.is_err() {
+                                    return;
+                                }
+                            }
+
+                            if missed.truncated
+                                && tx.send(replay_truncated(last_event_id)).await.is_err()
+                            {
+                                return;
+                            }
+                        }
+                        Err(e) => eprintln!("Order queue replay failed: {e:?}"),
+                    }
+                }
+
+                replay = false;
+            }
+
+            while sent.len() > SENT_MEMORY {
+                sent.pop_first();
+            }
+
+            let event = tokio::select! {
+                _ = tx.closed() => return,
+                event = feed.recv() => event,
+            };
+
+            match event {
+                Ok(event) => {
+                    // Events can commit out of id order, so skip only those already sent
+                    if event.branch_id != branch
+                        || event.station != station.as_str()
+                        || !sent.insert(event.id)
+                    {
+                        continue;
+                    }
+
+                    last_event_id = last_event_id.max(Some(event.id));
+
+                    if tx.send(to_sse(&event)).await.is_err() {
+                        return;
+                    }
+                }
+                // Fell behind the live feed, catch up from the database instead
+                Err(RecvError::Lagged(_)) => replay = true,
+                Err(RecvError::Closed) => return,
+            }
+        }
+    });
+
+    Ok(sse::Sse::from_infallible_receiver(rx)
+        .with_keep_alive(Duration::from_secs(15))
+        .with_retry_duration(Duration::from_secs(3))
+        .respond_to(&req)
+        .map_into_boxed_body())
+}
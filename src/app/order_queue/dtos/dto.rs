@@ -0,0 +1,161 @@
+use actix_web::web;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, Condition, ConnectionTrait, DbErr, EntityTrait, QueryFilter,
+    QueryOrder, QuerySelect, Set,
+};
+use serde_json::json;
+
+use crate::{
+    app::{
+        order_queue::models::model::{EventReplay, OrderEventType, Station},
+        orders::models::model::ItemType,
+    },
+    AppState,
+};
+
+/// Most events a reconnecting screen is sent in one go.
+const REPLAY_LIMIT: u64 = 500;
+
+/// How long a transaction may hold an event before committing it. Ids are handed out on
+/// insert, so an event committed late can carry a lower id than one already streamed.
+const COMMIT_GRACE_SECONDS: i64 = 60;
+
+/// Writes one queue event per station that has items on the order: drinks go to the bar and
+/// food to the kitchen. Pass the caller's open transaction so events only exist for changes
+/// that were committed, then hand the result to `publish_order_events` after commit.
+/// Pre-orders stay off the queue until their booking checks in and releases them.
+pub async fn record_order_event<C: ConnectionTrait>(
+    order: &entity::orders::Model,
+    event: OrderEventType,
+    conn: &C,
+) -> Result<Vec<entity::order_events::Model>, DbErr> {
+    if order.booking_id.is_some() && order.released_at.is_none() {
+        return Ok(Vec::new());
+    }
+
+    let items = entity::order_items::Entity::find()
+        .filter(entity::order_items::Column::OrderId.eq(order.id))
+        .order_by_asc(entity::order_items::Column::CreatedAt)
+        .all(conn)
+        .await?;
+
+    let mut events = Vec::new();
+
+    for station in [Station::Bar, Station::Kitchen] {
+        let station_items: Vec<_> = items
+            .iter()
+            .filter(|item| {
+                item.item_type
+                    .parse::<ItemType>()
+                    .is_ok_and(|item_type| Station::from(item_type) == station)
+            })
+            .map(|item| {
+                json!({
+                    "id": item.id,
+                    "item_type": item.item_type,
+                    "item_name": item.item_name,
+                    "quantity": item.quantity,
+                })
+            })
+            .collect();
+
+        if station_items.is_empty() {
+            continue;
+        }
+
+        let model = entity::order_events::ActiveModel {
+            organization_id: Set(order.organization_id),
+            branch_id: Set(order.branch_id),
+            order_id: Set(order.id),
+            station: Set(station.as_str().to_string()),
+            event_type: Set(event.as_str().to_string()),
+            payload: Set(json!({
+                "order_id": order.id,
+                "order_number": order.order_number,
+                "order_status": order.order_status,
+                "order_state": order.order_state,
+                "status_reason": order.status_reason,
+                "created_at": order.created_at,
+                "items": station_items,
+            })),
+            ..Default::default()
+        };
+
+        let result = ActiveModelTrait::insert(model, conn).await.map_err(|err| {
+            eprintln!("Database insert error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+        events.push(result);
+    }
+
+    Ok(events)
+}
+
+/// Pushes committed events to every open queue stream. Having no listeners is not an error.
+pub fn publish_order_events(events: Vec<entity::order_events::Model>, state: &web::Data<AppState>) {
+    for event in events {
+        let _ = state.order_feed.send(event);
+    }
+}
+
+/// Events of the station after `last_event_id`, along with those written shortly before it
+/// that may have committed after it was sent. Some of these may have been seen already, so
+/// callers skip the ids they sent.
+pub async fn get_events_since(
+    branch: uuid::Uuid,
+    station: Station,
+    last_event_id: i64,
+    state: &web::Data<AppState>,
+) -> Result<EventReplay, DbErr> {
+    let last = entity::order_events::Entity::find_by_id(last_event_id)
+        .one(state.pg_db.get_ref())
+        .await?;
+
+    let mut missed = Condition::any().add(entity::order_events::Column::Id.gt(last_event_id));
+
+    if let Some(last) = last {
+        missed = missed.add(
+            entity::order_events::Column::CreatedAt
+                .gte(last.created_at - chrono::Duration::seconds(COMMIT_GRACE_SECONDS)),
+        );
+    }
+
+    let mut events = entity::order_events::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::order_events::Column::BranchId.eq(branch))
+                .add(entity::order_events::Column::Station.eq(station.as_str()))
+                .add(missed),
+        )
+        .order_by_asc(entity::order_events::Column::Id)
+        .limit(REPLAY_LIMIT + 1)
+        .all(state.pg_db.get_ref())
+        .await
+        .map_err(|err| {
+            eprintln!("Database retrieval error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    let truncated = events.len() as u64 > REPLAY_LIMIT;
+
+    events.truncate(REPLAY_LIMIT as usize);
+
+    Ok(EventReplay { events, truncated })
+}
+
+/// Drops queue events older than two days; screens never need to replay further back.
+pub async fn prune_order_events(state: &web::Data<AppState>) -> Result<u64, DbErr> {
+    let cutoff = chrono::Utc::now() - chrono::Duration::days(2);
+
+    let result = entity::order_events::Entity::delete_many()
+        .filter(entity::order_events::Column::CreatedAt.lt(cutoff))
+        .exec(state.pg_db.get_ref())
+        .await
+        .map_err(|err| {
+            eprintln!("Database delete error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    Ok(result.rows_affected)
+}
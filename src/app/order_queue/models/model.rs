@@ -0,0 +1,76 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::{app::orders::models::model::ItemType, libs::validator::validate_station};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum Station {
+    Bar,
+    Kitchen,
+}
+
+impl Station {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Station::Bar => "BAR",
+            Station::Kitchen => "KITCHEN",
+        }
+    }
+}
+
+impl From<ItemType> for Station {
+    fn from(item_type: ItemType) -> Self {
+        match item_type {
+            ItemType::Drink | ItemType::Cocktail => Station::Bar,
+            ItemType::Food => Station::Kitchen,
+        }
+    }
+}
+
+impl FromStr for Station {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_uppercase().as_str() {
+            "BAR" => Ok(Station::Bar),
+            "KITCHEN" => Ok(Station::Kitchen),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum OrderEventType {
+    OrderCreated,
+    StatusChanged,
+    OrderCancelled,
+}
+
+impl OrderEventType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OrderEventType::OrderCreated => "ORDER_CREATED",
+            OrderEventType::StatusChanged => "STATUS_CHANGED",
+            OrderEventType::OrderCancelled => "ORDER_CANCELLED",
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct OrderStreamQuery {
+    #[validate(custom(function = "validate_station"))]
+    pub station: String,
+}
+
+/// Events a reconnecting screen may have missed, oldest first. `truncated` is set when there
+/// were more than one replay sends, so the screen should reload its orders.
+#[derive(Debug, Clone)]
+pub struct EventReplay {
+    pub events: Vec<entity::order_events::Model>,
+    pub truncated: bool,
+}
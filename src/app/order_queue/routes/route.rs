@@ -0,0 +1,19 @@
+use actix_web::web;
+
+use crate::{
+    app::order_queue::controllers::controller::order_stream,
+    middlewares::{auth::JwtAuthMiddleware, checker::CheckUserMiddleware},
+    AppState,
+};
+
+pub fn routes(cfg: &mut web::ServiceConfig, state: web::Data<AppState>) {
+    cfg.service(
+        web::scope("/api/v1/order-queue").route(
+            "/stream",
+            web::get()
+                .to(order_stream)
+                .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                .wrap(JwtAuthMiddleware),
+        ),
+    );
+}
@@ -0,0 +1,633 @@
+use std::sync::Arc;
+
+use actix_web::{web, HttpMessage, HttpRequest, HttpResponse};
+use serde_json::json;
+
+use crate::{
+    app::{
+        bans::dtos::dto::get_active_bans,
+        bookings::dtos::dto::get_customer_booking,
+        branch::dtos::dto::get_branch,
+        customers::{
+            dtos::dto::{get_active_customer_by_contact, get_customer_full},
+            models::model::CustomerResponse,
+        },
+        employees::models::model::EmployeeResponse,
+        orders::{
+            dtos::dto::{
+                cancel_customer_order, get_branch_orders, get_customer_order_details,
+                get_customer_orders, get_order_details, save_order, update_order_status,
+            },
+            models::model::{
+                AddOrderDto, AddOrderParams, OrderListQuery, OrderStatus, PlaceOrderParams,
+                PreOrderParams, UpdateOrderStatusDto, UpdateOrderStatusParams,
+            },
+        },
+        tills::dtos::dto::get_open_till,
+        wallets::models::model::PaymentChannel,
+    },
+    libs::error::Error,
+    utils::{
+        json_validator::{ValidatedJson, ValidatedPath, ValidatedQuery},
+        models::{HttpClientResponse, PathParamsModel, ResponseCode},
+    },
+    AppState,
+};
+
+pub async fn place_order(
+    req: HttpRequest,
+    payload: ValidatedJson<PlaceOrderParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<CustomerResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Customer not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    let mut session_id = uuid::Uuid::nil();
+
+    if let Some(session_uuid) = &model.session {
+        if let Ok(s_uuid) = uuid::Uuid::parse_str(session_uuid) {
+            session_id = s_uuid
+        }
+    }
+
+    let customer = match get_customer_full(session_id, &state).await {
+        Ok(customer) => customer,
+        Err(e) => {
+            return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+                ResponseCode::Failed,
+                format!("Could not find customer: {}", e),
+                json!({}),
+            )))
+        }
+    };
+
+    let data = payload.0;
+
+    let branch = match get_branch(data.branch, &state).await {
+        Ok(branch) => branch,
+        Err(e) => {
+            return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+                ResponseCode::Failed,
+                format!("Branch not found: {}", e),
+                json!({}),
+            )))
+        }
+    };
+
+    match get_active_bans(customer.id, branch.organization_id, branch.id, &state).await {
+        Ok(bans) if bans.is_empty() => {}
+        Ok(_) => {
+            return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+                ResponseCode::Failed,
+                "Customer is banned from this venue".to_string(),
+                json!({}),
+            )));
+        }
+        Err(e) => {
+            return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+                ResponseCode::Failed,
+                format!("Could not check bans: {}", e),
+                json!({}),
+            )))
+        }
+    }
+
+    let order = AddOrderDto {
+        customer: customer.id,
+        organization: branch.organization_id,
+        branch: branch.id,
+        served_by: None,
+        booking: None,
+        till_session: None,
+        tab: None,
+        payment_channel: PaymentChannel::Wallet,
+        items: data.items.into_iter().map(Into::into).collect(),
+        tip_amount: data.tip_amount,
+        promo_code: data.promo_code.map(|code| code.trim().to_uppercase()),
+    };
+
+    match save_order(order, &state).await {
+        Ok(res) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Order Placed Successfully".to_string(),
+            json!({
+                "id": res.id,
+                "order_number": res.order_number,
+                "total_amount": res.total_amount,
+                "order_status": res.order_status,
+            }),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Could not place order: {}", e),
+            json!({}),
+        ))),
+    }
+}
+
+/// Pre-orders are paid now and reach the bar when the booking checks in.
+pub async fn pre_order(
+    req: HttpRequest,
+    payload: ValidatedJson<PreOrderParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<CustomerResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Customer not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    let mut session_id = uuid::Uuid::nil();
+
+    if let Some(session_uuid) = &model.session {
+        if let Ok(s_uuid) = uuid::Uuid::parse_str(session_uuid) {
+            session_id = s_uuid
+        }
+    }
+
+    let customer = match get_customer_full(session_id, &state).await {
+        Ok(customer) => customer,
+        Err(e) => {
+            return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+                ResponseCode::Failed,
+                format!("Could not find customer: {}", e),
+                json!({}),
+            )))
+        }
+    };
+
+    let data = payload.0;
+
+    let booking = match get_customer_booking(data.booking, customer.id, &state).await {
+        Ok(booking) => booking,
+        Err(e) => {
+            return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+                ResponseCode::Failed,
+                format!("Booking not found: {}", e),
+                json!({}),
+            )))
+        }
+    };
+
+    if booking.booked_on_time.is_some() {
+        return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            "Booking already checked in, place a regular order instead".to_string(),
+            json!({}),
+        )));
+    }
+
+    match get_active_bans(
+        customer.id,
+        booking.organization_id,
+        booking.branch_id,
+        &state,
+    )
+    .await
+    {
+        Ok(bans) if bans.is_empty() => {}
+        Ok(_) => {
+            return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+                ResponseCode::Failed,
+                "Customer is banned from this venue".to_string(),
+                json!({}),
+            )));
+        }
+        Err(e) => {
+            return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+                ResponseCode::Failed,
+                format!("Could not check bans: {}", e),
+                json!({}),
+            )))
+        }
+    }
+
+    let order = AddOrderDto {
+        customer: customer.id,
+        organization: booking.organization_id,
+        branch: booking.branch_id,
+        served_by: None,
+        booking: Some(booking.id),
+        till_session: None,
+        tab: None,
+        payment_channel: PaymentChannel::Wallet,
+        items: data.items.into_iter().map(Into::into).collect(),
+        tip_amount: data.tip_amount,
+        promo_code: data.promo_code.map(|code| code.trim().to_uppercase()),
+    };
+
+    match save_order(order, &state).await {
+        Ok(res) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Pre-Order Placed Successfully".to_string(),
+            json!({
+                "id": res.id,
+                "order_number": res.order_number,
+                "booking_id": res.booking_id,
+                "total_amount": res.total_amount,
+                "order_status": res.order_status,
+            }),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Could not place pre-order: {}", e),
+            json!({}),
+        ))),
+    }
+}
+
+pub async fn my_orders(
+    req: HttpRequest,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<CustomerResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Customer not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    let mut session_id = uuid::Uuid::nil();
+
+    if let Some(session_uuid) = &model.session {
+        if let Ok(s_uuid) = uuid::Uuid::parse_str(session_uuid) {
+            session_id = s_uuid
+        }
+    }
+
+    let customer = match get_customer_full(session_id, &state).await {
+        Ok(customer) => customer,
+        Err(e) => {
+            return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+                ResponseCode::Failed,
+                format!("Could not find customer: {}", e),
+                json!({}),
+            )))
+        }
+    };
+
+    let results = get_customer_orders(customer.id, &state).await;
+
+    if let Err(e) = results {
+        return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Orders not found: {}", e),
+            json!({}),
+        )));
+    };
+
+    let orders = results.unwrap();
+
+    if orders.is_empty() {
+        return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "No Orders Found".to_string(),
+            json!([]),
+        )));
+    }
+
+    Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+        ResponseCode::Success,
+        "Orders Fetched Successfully".to_string(),
+        json!(orders),
+    )))
+}
+
+pub async fn my_order_details(
+    req: HttpRequest,
+    params: ValidatedPath<PathParamsModel>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<CustomerResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Customer not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    let mut session_id = uuid::Uuid::nil();
+
+    if let Some(session_uuid) = &model.session {
+        if let Ok(s_uuid) = uuid::Uuid::parse_str(session_uuid) {
+            session_id = s_uuid
+        }
+    }
+
+    let customer = match get_customer_full(session_id, &state).await {
+        Ok(customer) => customer,
+        Err(e) => {
+            return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+                ResponseCode::Failed,
+                format!("Could not find customer: {}", e),
+                json!({}),
+            )))
+        }
+    };
+
+    match get_customer_order_details(params.0.id, customer.id, &state).await {
+        Ok(order) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Order Fetched Successfully".to_string(),
+            json!(order),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Order not found: {}", e),
+            json!({}),
+        ))),
+    }
+}
+
+pub async fn cancel_my_order(
+    req: HttpRequest,
+    params: ValidatedPath<PathParamsModel>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<CustomerResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Customer not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    let mut session_id = uuid::Uuid::nil();
+
+    if let Some(session_uuid) = &model.session {
+        if let Ok(s_uuid) = uuid::Uuid::parse_str(session_uuid) {
+            session_id = s_uuid
+        }
+    }
+
+    let customer = match get_customer_full(session_id, &state).await {
+        Ok(customer) => customer,
+        Err(e) => {
+            return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+                ResponseCode::Failed,
+                format!("Could not find customer: {}", e),
+                json!({}),
+            )))
+        }
+    };
+
+    match cancel_customer_order(params.0.id, customer.id, &state).await {
+        Ok(_) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Order Cancelled Successfully".to_string(),
+            json!({}),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Could not cancel order: {}", e),
+            json!({}),
+        ))),
+    }
+}
+
+pub async fn create_order(
+    req: HttpRequest,
+    payload: ValidatedJson<AddOrderParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    let till = match get_open_till(model.id, &state).await {
+        Ok(Some(till)) => till,
+        Ok(None) => {
+            return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+                ResponseCode::Failed,
+                "No open till, open a till before taking orders".to_string(),
+                json!({}),
+            )))
+        }
+        Err(e) => {
+            return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+                ResponseCode::Failed,
+                format!("Could not find till: {}", e),
+                json!({}),
+            )))
+        }
+    };
+
+    let data = payload.0;
+
+    let customer = match get_active_customer_by_contact(data.phone, &state).await {
+        Ok(customer) => customer,
+        Err(e) => {
+            return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+                ResponseCode::Failed,
+                format!("Could not find customer: {}", e),
+                json!({}),
+            )))
+        }
+    };
+
+    match get_active_bans(customer.id, model.organization_id, model.branch_id, &state).await {
+        Ok(bans) if bans.is_empty() => {}
+        Ok(_) => {
+            return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+                ResponseCode::Failed,
+                "Customer is banned from this venue".to_string(),
+                json!({}),
+            )));
+        }
+        Err(e) => {
+            return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+                ResponseCode::Failed,
+                format!("Could not check bans: {}", e),
+                json!({}),
+            )))
+        }
+    }
+
+    let order = AddOrderDto {
+        customer: customer.id,
+        organization: model.organization_id,
+        branch: model.branch_id,
+        served_by: Some(model.id),
+        booking: None,
+        till_session: Some(till.id),
+        tab: None,
+        payment_channel: data
+            .payment_channel
+            .as_deref()
+            .and_then(|channel| channel.parse().ok())
+            .unwrap_or(PaymentChannel::Wallet),
+        items: data.items.into_iter().map(Into::into).collect(),
+        tip_amount: data.tip_amount,
+        promo_code: data.promo_code.map(|code| code.trim().to_uppercase()),
+    };
+
+    match save_order(order, &state).await {
+        Ok(res) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Order Created Successfully".to_string(),
+            json!({
+                "id": res.id,
+                "order_number": res.order_number,
+                "total_amount": res.total_amount,
+                "order_status": res.order_status,
+            }),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Could not create order: {}", e),
+            json!({}),
+        ))),
+    }
+}
+
+pub async fn branch_orders(
+    req: HttpRequest,
+    query: ValidatedQuery<OrderListQuery>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    let results = get_branch_orders(
+        model.organization_id,
+        model.branch_id,
+        query.0.status,
+        &state,
+    )
+    .await;
+
+    if let Err(e) = results {
+        return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Orders not found: {}", e),
+            json!({}),
+        )));
+    };
+
+    let orders = results.unwrap();
+
+    if orders.is_empty() {
+        return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "No Orders Found".to_string(),
+            json!([]),
+        )));
+    }
+
+    Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+        ResponseCode::Success,
+        "Orders Fetched Successfully".to_string(),
+        json!(orders),
+    )))
+}
+
+pub async fn order_details(
+    req: HttpRequest,
+    params: ValidatedPath<PathParamsModel>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    match get_order_details(params.0.id, model.organization_id, model.branch_id, &state).await {
+        Ok(order) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Order Fetched Successfully".to_string(),
+            json!(order),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Order not found: {}", e),
+            json!({}),
+        ))),
+    }
+}
+
+pub async fn change_order_status(
+    req: HttpRequest,
+    params: ValidatedPath<PathParamsModel>,
+    payload: ValidatedJson<UpdateOrderStatusParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    let data = payload.0;
+
+    let status = match data.status.parse::<OrderStatus>() {
+        Ok(status) => status,
+        Err(_) => {
+            return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+                ResponseCode::Failed,
+                "Invalid order status".to_string(),
+                json!({}),
+            )))
+        }
+    };
+
+    let update = UpdateOrderStatusDto {
+        organization: model.organization_id,
+        branch: model.branch_id,
+        served_by: model.id,
+        status,
+        reason: data.reason,
+    };
+
+    match update_order_status(params.0.id, update, &state).await {
+        Ok(res) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Order Updated Successfully".to_string(),
+            json!({ "order_status": res.order_status }),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Could not update order: {}", e),
+            json!({}),
+        ))),
+    }
+}
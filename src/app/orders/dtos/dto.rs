@@ -0,0 +1,796 @@
+use actix_web::web;
+use chrono_tz::Tz;
+use sea_orm::{
+    prelude::Decimal, ActiveModelTrait, ActiveValue, ColumnTrait, Condition, ConnectionTrait,
+    DbErr, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, Set,
+    TransactionTrait,
+};
+
+use crate::{
+    app::{
+        categories::dtos::dto::check_serving,
+        inventory::{
+            dtos::dto::{restore_order_stock, take_stock},
+            models::model::StockLine,
+        },
+        order_queue::{
+            dtos::dto::{publish_order_events, record_order_event},
+            models::model::OrderEventType,
+        },
+        orders::models::model::{
+            AddOrderDto, ItemType, OrderDetailsResponse, OrderItemDto, OrderState, OrderStatus,
+            UpdateOrderStatusDto,
+        },
+        pricing::{dtos::dto::branch_price_rules, services::service::effective_price},
+        promos::{
+            dtos::dto::{record_redemptions, redeemable_promos, void_redemptions},
+            models::model::{PromoContext, PromoLine, PromoLookupDto, PromoTarget, RedemptionDto},
+            services::service::apply_promos,
+        },
+        receipts::dtos::dto::{issue_order_receipt, send_receipt_email},
+        recipes::dtos::dto::{
+            check_recipe_stock, deplete_order_components, item_recipes, recipe_costs,
+        },
+        tabs::models::model::TabStatus,
+        taxes::{
+            dtos::dto::{applicable_tax_rules, record_tax_lines},
+            models::model::{TaxTarget, TaxableLine},
+            services::service::compute_taxes,
+        },
+        tills::models::model::TillStatus,
+        tips::dtos::dto::{record_order_tip, void_order_tip},
+        wallets::{
+            dtos::dto::{
+                credit_customer_wallet, debit_customer_wallet, record_counter_transaction,
+            },
+            models::model::{
+                CounterTransactionDto, PaymentChannel, TransactionType, WalletCreditDto,
+                WalletDebitDto,
+            },
+        },
+    },
+    utils::time::{branch_timezone, day_start},
+    AppState,
+};
+
+/// Short order number that staff can call out, e.g. `250424-0007`: the branch's date followed
+/// by its running count for the day. The caller must hold the branch row lock.
+async fn next_order_number<C: ConnectionTrait>(
+    branch: uuid::Uuid,
+    timezone: Tz,
+    conn: &C,
+) -> Result<String, DbErr> {
+    let today = chrono::Utc::now().with_timezone(&timezone).date_naive();
+
+    let count = entity::orders::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::orders::Column::BranchId.eq(branch))
+                .add(entity::orders::Column::CreatedAt.gte(day_start(today, timezone))),
+        )
+        .count(conn)
+        .await?;
+
+    Ok(format!("{}-{:04}", today.format("%y%m%d"), count + 1))
+}
+
+/// Snapshots a branch menu item's name, price at `at` and tax category, and for drinks the
+/// unit cost. Drinks made by recipe sell while their components last, whatever their stock.
+async fn price_item<C: ConnectionTrait>(
+    item: &OrderItemDto,
+    by_recipe: bool,
+    branch: uuid::Uuid,
+    rules: &[entity::price_rules::Model],
+    at: chrono::DateTime<Tz>,
+    conn: &C,
+) -> Result<(String, Decimal, uuid::Uuid, Option<Decimal>), DbErr> {
+    match item.item_type {
+        ItemType::Drink => {
+            let mut condition =
+                Condition::all().add(entity::stock_drinks::Column::BranchId.eq(branch));
+
+            if !by_recipe {
+                condition =
+                    condition.add(entity::stock_drinks::Column::StockStatus.ne("OUTOFSTOCK"));
+            }
+
+            let drink = entity::stock_drinks::Entity::find_by_id(item.item)
+                .filter(condition)
+                .one(conn)
+                .await?
+                .ok_or_else(|| DbErr::RecordNotFound("Drink not available".into()))?;
+
+            let price = effective_price(&drink, rules, at).price;
+
+            Ok((drink.name, price, drink.category_id, Some(drink.unit_price)))
+        }
+        ItemType::Food => {
+            let food = entity::stock_foods::Entity::find_by_id(item.item)
+                .filter(entity::stock_foods::Column::BranchId.eq(branch))
+                .one(conn)
+                .await?
+                .ok_or_else(|| DbErr::RecordNotFound("Food not available".into()))?;
+
+            Ok((food.name, food.price, food.category_id, None))
+        }
+        ItemType::Cocktail => {
+            let cocktail = entity::custom_cocktails::Entity::find_by_id(item.item)
+                .filter(
+                    Condition::all()
+                        .add(entity::custom_cocktails::Column::BranchId.eq(branch))
+                        .add(entity::custom_cocktails::Column::CocktailStatus.eq("APPROVED"))
+                        .add(entity::custom_cocktails::Column::IsAvailable.eq(true)),
+                )
+                .one(conn)
+                .await?
+                .ok_or_else(|| DbErr::RecordNotFound("Cocktail not available".into()))?;
+
+            match (cocktail.price, cocktail.category_id) {
+                (Some(price), Some(category)) => Ok((cocktail.name, price, category, None)),
+                _ => Err(DbErr::RecordNotFound("Cocktail not available".into())),
+            }
+        }
+    }
+}
+
+/// Orders staff can work on: everything except pre-orders still waiting for their booking.
+fn on_queue() -> Condition {
+    Condition::any()
+        .add(entity::orders::Column::BookingId.is_null())
+        .add(entity::orders::Column::ReleasedAt.is_not_null())
+}
+
+/// Prices and saves an order with its items, promo discounts and tax lines, then charges it
+/// unless it goes on a tab. Pre-orders stay off the queue until their booking checks in.
+pub async fn save_order(
+    data: AddOrderDto,
+    state: &web::Data<AppState>,
+) -> Result<entity::orders::Model, DbErr> {
+    let txn = state.pg_db.get_ref().begin().await?;
+
+    // Serializes order creation per branch so order numbers stay unique
+    let branch = entity::branches::Entity::find_by_id(data.branch)
+        .lock_exclusive()
+        .one(&txn)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Branch not found".into()))?;
+
+    // Holds the till open until the order is in, closing waits for it
+    if let Some(till) = data.till_session {
+        entity::till_sessions::Entity::find_by_id(till)
+            .filter(entity::till_sessions::Column::SessionStatus.eq(TillStatus::Open.as_str()))
+            .lock_exclusive()
+            .one(&txn)
+            .await?
+            .ok_or_else(|| DbErr::Custom("Till is closed".to_string()))?;
+    }
+
+    // Splitting a tab waits for orders being added to it, and vice versa
+    if let Some(tab) = data.tab {
+        entity::tabs::Entity::find_by_id(tab)
+            .filter(
+                Condition::all()
+                    .add(entity::tabs::Column::BranchId.eq(data.branch))
+                    .add(entity::tabs::Column::TabStatus.eq(TabStatus::Open.as_str())),
+            )
+            .lock_exclusive()
+            .one(&txn)
+            .await?
+            .ok_or_else(|| DbErr::Custom("Tab is not open".to_string()))?;
+    }
+
+    // Cancelling or checking in the booking waits for its pre-order, and vice versa
+    if let Some(booking) = data.booking {
+        entity::bookings::Entity::find_by_id(booking)
+            .filter(
+                Condition::all()
+                    .add(entity::bookings::Column::IsCancelled.eq(false))
+                    .add(entity::bookings::Column::BookedOnTime.is_null()),
+            )
+            .lock_exclusive()
+            .one(&txn)
+            .await?
+            .ok_or_else(|| {
+                DbErr::Custom("Booking is cancelled or already checked in".to_string())
+            })?;
+    }
+
+    let now = chrono::Utc::now();
+    let timezone = branch_timezone(&branch);
+    let local = now.with_timezone(&timezone);
+    let price_rules = branch_price_rules(data.branch, &txn).await?;
+
+    let keys: Vec<(ItemType, uuid::Uuid)> = data
+        .items
+        .iter()
+        .map(|item| (item.item_type, item.item))
+        .collect();
+    let recipes = item_recipes(&keys, &txn).await?;
+    let recipe_costs = recipe_costs(&recipes, &txn).await?;
+
+    let mut items = Vec::with_capacity(data.items.len());
+    let mut lines = Vec::with_capacity(data.items.len());
+    let mut served = Vec::with_capacity(data.items.len());
+    let mut subtotal = Decimal::ZERO;
+
+    for item in &data.items {
+        let (name, price, category, unit_cost) = price_item(
+            item,
+            recipes.contains_key(&item.item),
+            data.branch,
+            &price_rules,
+            local,
+            &txn,
+        )
+        .await?;
+        let amount = price * Decimal::from(item.quantity);
+
+        subtotal += amount;
+        lines.push(PromoLine {
+            category: Some(category),
+            amount,
+        });
+        served.push((name.clone(), category));
+        // Items made by recipe cost what goes into them
+        let unit_cost = recipe_costs.get(&item.item).copied().or(unit_cost);
+
+        items.push((item, name, price, unit_cost));
+    }
+
+    check_serving(&served, data.organization, data.branch, local, &txn).await?;
+
+    let promos = redeemable_promos(
+        &PromoLookupDto {
+            organization: data.organization,
+            customer: data.customer,
+            target: PromoTarget::Orders,
+            code: data.promo_code.clone(),
+        },
+        &txn,
+    )
+    .await?;
+
+    let context = PromoContext {
+        branch: data.branch,
+        schedule: None,
+        ticket: None,
+        at: now,
+        timezone,
+    };
+
+    let discount = apply_promos(&promos, &context, &lines, data.promo_code.as_deref())
+        .map_err(DbErr::Custom)?;
+
+    // Taxes are worked out on what the customer pays after the discount
+    let taxable: Vec<_> = lines
+        .iter()
+        .zip(&discount.line_discounts)
+        .map(|(line, off)| TaxableLine {
+            category: line.category,
+            amount: line.amount - *off,
+        })
+        .collect();
+
+    let rules = applicable_tax_rules(data.organization, TaxTarget::Orders, &txn).await?;
+    let taxes = compute_taxes(&taxable, &rules);
+
+    let tip_amount = data.tip_amount.unwrap_or_default();
+    let total_amount =
+        subtotal - discount.discount_amount + taxes.tax_amount + taxes.service_charge + tip_amount;
+    let order_number = next_order_number(data.branch, timezone, &txn).await?;
+
+    let description = format!("Payment for order {}", order_number);
+
+    let payment = match (data.tab, data.payment_channel) {
+        (Some(_), _) => None,
+        (None, PaymentChannel::Wallet) => Some(
+            debit_customer_wallet(
+                WalletDebitDto {
+                    customer: data.customer,
+                    organization: data.organization,
+                    branch: data.branch,
+                    amount: total_amount,
+                    description,
+                },
+                &txn,
+            )
+            .await?,
+        ),
+        (None, channel) => Some(
+            record_counter_transaction(
+                CounterTransactionDto {
+                    customer: data.customer,
+                    organization: data.organization,
+                    branch: data.branch,
+                    amount: total_amount,
+                    channel,
+                    description,
+                    transaction_type: TransactionType::Payment,
+                },
+                &txn,
+            )
+            .await?,
+        ),
+    };
+
+    let order = entity::orders::ActiveModel {
+        customer_id: Set(data.customer),
+        organization_id: Set(data.organization),
+        branch_id: Set(data.branch),
+        order_number: Set(order_number),
+        payment_id: Set(payment.map(|payment| payment.id)),
+        total_amount: Set(total_amount),
+        is_discount_applied: Set(discount.discount_amount > Decimal::ZERO),
+        discount_amount: Set(discount.discount_amount),
+        promo_reference: Set(discount.reference()),
+        is_cancelled: Set(false),
+        served_by: Set(data.served_by),
+        order_status: Set(OrderStatus::Pending.as_str().to_string()),
+        is_tip_applied: Set(tip_amount > Decimal::ZERO),
+        tip_amount: Set(Some(tip_amount)),
+        order_state: Set(match data.booking {
+            Some(_) => OrderState::PreOrdered.as_str().to_string(),
+            None => OrderState::InstantOrder.as_str().to_string(),
+        }),
+        booking_id: Set(data.booking),
+        till_session_id: Set(data.till_session),
+        tab_id: Set(data.tab),
+        tax_amount: Set(taxes.tax_amount),
+        included_tax_amount: Set(taxes.included_tax_amount),
+        service_charge: Set(taxes.service_charge),
+        ..Default::default()
+    };
+
+    let result = ActiveModelTrait::insert(order, &txn).await.map_err(|err| {
+        eprintln!("Database insert error: {}", err);
+        DbErr::Custom(err.to_string())
+    })?;
+
+    let mut stock = Vec::with_capacity(items.len());
+    let mut servings = Vec::new();
+
+    for (item, _, _, _) in &items {
+        match recipes.get(&item.item) {
+            Some(recipe) => servings.push((recipe.as_slice(), item.quantity)),
+            None if item.item_type == ItemType::Drink => stock.push(StockLine {
+                drink: item.item,
+                quantity: item.quantity,
+            }),
+            None => {}
+        }
+    }
+
+    take_stock(&stock, &txn).await?;
+    check_recipe_stock(&servings, data.branch, &txn).await?;
+
+    let items = items.into_iter().zip(&discount.line_discounts).map(
+        |((item, name, price, unit_cost), off)| entity::order_items::ActiveModel {
+            order_id: Set(result.id),
+            stock_drink_id: Set((item.item_type == ItemType::Drink).then_some(item.item)),
+            stock_food_id: Set((item.item_type == ItemType::Food).then_some(item.item)),
+            custom_cocktail_id: Set((item.item_type == ItemType::Cocktail).then_some(item.item)),
+            has_recipe: Set(recipes.contains_key(&item.item)),
+            item_type: Set(item.item_type.as_str().to_string()),
+            item_name: Set(Some(name)),
+            price: Set(price),
+            quantity: Set(item.quantity),
+            discount_amount: Set(*off),
+            unit_cost: Set(unit_cost),
+            ..Default::default()
+        },
+    );
+
+    entity::order_items::Entity::insert_many(items)
+        .exec(&txn)
+        .await
+        .map_err(|err| {
+            eprintln!("Database insert error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    record_tax_lines(&taxes.lines, Some(result.id), None, &txn).await?;
+    record_redemptions(
+        &discount,
+        RedemptionDto {
+            customer: data.customer,
+            order: Some(result.id),
+            booking: None,
+            ticket_purchase: None,
+        },
+        &txn,
+    )
+    .await?;
+    record_order_tip(&result, &txn).await?;
+
+    let receipt = match result.payment_id {
+        Some(_) => Some(issue_order_receipt(&result, &txn).await?),
+        None => None,
+    };
+
+    let events = record_order_event(&result, OrderEventType::OrderCreated, &txn).await?;
+
+    txn.commit().await?;
+
+    publish_order_events(events, state);
+
+    if let Some(receipt) = receipt {
+        send_receipt_email(receipt, state.clone());
+    }
+
+    Ok(result)
+}
+
+/// Moves an order along its lifecycle, refunding the wallet payment when it is rejected or
+/// cancelled unless `refund` is off. Orders on a tab have nothing to refund and can only be
+/// voided while the tab is still open. Drinks of a rejected or cancelled order go back into
+/// stock whether or not it is refunded, and a completed order pours the components of items
+/// made by recipe. Transitions not allowed by
+/// `OrderStatus::can_transition_to` are refused.
+async fn transition_order<C: ConnectionTrait>(
+    order: entity::orders::Model,
+    next: OrderStatus,
+    reason: Option<String>,
+    served_by: Option<uuid::Uuid>,
+    refund: bool,
+    conn: &C,
+) -> Result<(entity::orders::Model, Vec<entity::order_events::Model>), DbErr> {
+    let current = order
+        .order_status
+        .parse::<OrderStatus>()
+        .map_err(|_| DbErr::Custom("Order has an unknown status".to_string()))?;
+
+    if !current.can_transition_to(next) {
+        return Err(DbErr::Custom(format!(
+            "Order cannot move from {} to {}",
+            current.as_str(),
+            next.as_str()
+        )));
+    }
+
+    // Voiding an order would change what the tab's shares were worked out from
+    if let (Some(tab), true) = (order.tab_id, next.is_refundable()) {
+        entity::tabs::Entity::find_by_id(tab)
+            .filter(entity::tabs::Column::TabStatus.eq(TabStatus::Open.as_str()))
+            .lock_exclusive()
+            .one(conn)
+            .await?
+            .ok_or_else(|| {
+                DbErr::Custom("Tab is being settled, cancel its shares first".to_string())
+            })?;
+    }
+
+    let mut refund_id = None;
+
+    if let (Some(payment), true) = (order.payment_id, refund && next.is_refundable()) {
+        // Money goes back the way it came in
+        let channel = entity::transactions::Entity::find_by_id(payment)
+            .one(conn)
+            .await?
+            .and_then(|payment| payment.debit_channel)
+            .and_then(|channel| channel.parse::<PaymentChannel>().ok())
+            .unwrap_or(PaymentChannel::Wallet);
+
+        let description = format!("Refund for order {}", order.order_number);
+
+        let transaction = match channel {
+            PaymentChannel::Wallet => {
+                credit_customer_wallet(
+                    WalletCreditDto {
+                        customer: order.customer_id,
+                        organization: order.organization_id,
+                        branch: order.branch_id,
+                        amount: order.total_amount,
+                        description,
+                        transaction_type: TransactionType::Refund,
+                    },
+                    conn,
+                )
+                .await?
+            }
+            channel => {
+                record_counter_transaction(
+                    CounterTransactionDto {
+                        customer: order.customer_id,
+                        organization: order.organization_id,
+                        branch: order.branch_id,
+                        amount: order.total_amount,
+                        channel,
+                        description,
+                        transaction_type: TransactionType::Refund,
+                    },
+                    conn,
+                )
+                .await?
+            }
+        };
+
+        refund_id = Some(transaction.id);
+
+        void_order_tip(order.id, conn).await?;
+    }
+
+    if next.is_refundable() {
+        void_redemptions(PromoTarget::Orders, order.id, conn).await?;
+        restore_order_stock(order.id, conn).await?;
+    }
+
+    if next == OrderStatus::Completed {
+        deplete_order_components(order.id, conn).await?;
+    }
+
+    let mut model: entity::orders::ActiveModel = order.into();
+
+    model.order_status = ActiveValue::Set(next.as_str().to_string());
+    model.status_reason = ActiveValue::Set(reason);
+
+    if next == OrderStatus::Cancelled {
+        model.is_cancelled = ActiveValue::Set(true);
+    }
+
+    if refund_id.is_some() {
+        model.refund_transaction_id = ActiveValue::Set(refund_id);
+    }
+
+    if let Some(served_by) = served_by {
+        model.served_by = ActiveValue::Set(Some(served_by));
+    }
+
+    model.updated_at = ActiveValue::Set(chrono::Utc::now().into());
+
+    let result = ActiveModelTrait::update(model, conn).await.map_err(|err| {
+        eprintln!("Database update error: {}", err);
+        DbErr::Custom(err.to_string())
+    })?;
+
+    let event = match next {
+        OrderStatus::Cancelled => OrderEventType::OrderCancelled,
+        _ => OrderEventType::StatusChanged,
+    };
+
+    let events = record_order_event(&result, event, conn).await?;
+
+    Ok((result, events))
+}
+
+pub async fn update_order_status(
+    id: uuid::Uuid,
+    data: UpdateOrderStatusDto,
+    state: &web::Data<AppState>,
+) -> Result<entity::orders::Model, DbErr> {
+    let txn = state.pg_db.get_ref().begin().await?;
+
+    let order = entity::orders::Entity::find_by_id(id)
+        .filter(
+            Condition::all()
+                .add(entity::orders::Column::OrganizationId.eq(data.organization))
+                .add(entity::orders::Column::BranchId.eq(data.branch))
+                .add(on_queue()),
+        )
+        .lock_exclusive()
+        .one(&txn)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Order not found".into()))?;
+
+    let (result, events) = transition_order(
+        order,
+        data.status,
+        data.reason,
+        Some(data.served_by),
+        true,
+        &txn,
+    )
+    .await?;
+
+    txn.commit().await?;
+
+    publish_order_events(events, state);
+
+    Ok(result)
+}
+
+/// Customers can only call off an order the bar has not started on yet.
+pub async fn cancel_customer_order(
+    id: uuid::Uuid,
+    customer: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<entity::orders::Model, DbErr> {
+    let txn = state.pg_db.get_ref().begin().await?;
+
+    let order = entity::orders::Entity::find_by_id(id)
+        .filter(
+            Condition::all()
+                .add(entity::orders::Column::CustomerId.eq(customer))
+                .add(entity::orders::Column::OrderStatus.eq(OrderStatus::Pending.as_str()))
+                .add(on_queue()),
+        )
+        .lock_exclusive()
+        .one(&txn)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Pending order not found".into()))?;
+
+    let (result, events) = transition_order(
+        order,
+        OrderStatus::Cancelled,
+        Some("Cancelled by customer".to_string()),
+        None,
+        true,
+        &txn,
+    )
+    .await?;
+
+    txn.commit().await?;
+
+    publish_order_events(events, state);
+
+    Ok(result)
+}
+
+/// Pending pre-orders of a booking that has not been released yet, locked for update.
+async fn held_pre_orders<C: ConnectionTrait>(
+    booking: uuid::Uuid,
+    conn: &C,
+) -> Result<Vec<entity::orders::Model>, DbErr> {
+    entity::orders::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::orders::Column::BookingId.eq(booking))
+                .add(entity::orders::Column::ReleasedAt.is_null())
+                .add(entity::orders::Column::OrderStatus.eq(OrderStatus::Pending.as_str())),
+        )
+        .lock_exclusive()
+        .all(conn)
+        .await
+}
+
+/// Puts a booking's pre-orders on the bar queue once the guests have checked in. Run inside
+/// the check-in transaction and publish the returned events after commit.
+pub async fn release_pre_orders<C: ConnectionTrait>(
+    booking: uuid::Uuid,
+    conn: &C,
+) -> Result<Vec<entity::order_events::Model>, DbErr> {
+    let now = chrono::Utc::now();
+    let mut events = Vec::new();
+
+    for order in held_pre_orders(booking, conn).await? {
+        let mut model: entity::orders::ActiveModel = order.into();
+
+        model.released_at = ActiveValue::Set(Some(now.into()));
+        model.updated_at = ActiveValue::Set(now.into());
+
+        let released = ActiveModelTrait::update(model, conn).await.map_err(|err| {
+            eprintln!("Database update error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+        events.extend(record_order_event(&released, OrderEventType::OrderCreated, conn).await?);
+    }
+
+    Ok(events)
+}
+
+/// Cancels a booking's held pre-orders, refunding them only when the booking was called off
+/// within the cancellation policy.
+pub async fn cancel_pre_orders<C: ConnectionTrait>(
+    booking: uuid::Uuid,
+    refund: bool,
+    conn: &C,
+) -> Result<Vec<entity::orders::Model>, DbErr> {
+    let reason = if refund {
+        "Booking cancelled"
+    } else {
+        "Booking cancelled outside the refund window"
+    };
+
+    let mut cancelled = Vec::new();
+
+    for order in held_pre_orders(booking, conn).await? {
+        let (result, _) = transition_order(
+            order,
+            OrderStatus::Cancelled,
+            Some(reason.to_string()),
+            None,
+            refund,
+            conn,
+        )
+        .await?;
+
+        cancelled.push(result);
+    }
+
+    Ok(cancelled)
+}
+
+async fn with_items(
+    order: entity::orders::Model,
+    state: &web::Data<AppState>,
+) -> Result<OrderDetailsResponse, DbErr> {
+    let items = entity::order_items::Entity::find()
+        .filter(entity::order_items::Column::OrderId.eq(order.id))
+        .order_by_asc(entity::order_items::Column::CreatedAt)
+        .all(state.pg_db.get_ref())
+        .await
+        .map_err(|err| {
+            eprintln!("Database retrieval error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    Ok(OrderDetailsResponse { order, items })
+}
+
+pub async fn get_order_details(
+    id: uuid::Uuid,
+    organization: uuid::Uuid,
+    branch: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<OrderDetailsResponse, DbErr> {
+    let order = entity::orders::Entity::find_by_id(id)
+        .filter(
+            Condition::all()
+                .add(entity::orders::Column::OrganizationId.eq(organization))
+                .add(entity::orders::Column::BranchId.eq(branch)),
+        )
+        .one(state.pg_db.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Order not found".into()))?;
+
+    with_items(order, state).await
+}
+
+pub async fn get_customer_order_details(
+    id: uuid::Uuid,
+    customer: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<OrderDetailsResponse, DbErr> {
+    let order = entity::orders::Entity::find_by_id(id)
+        .filter(entity::orders::Column::CustomerId.eq(customer))
+        .one(state.pg_db.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Order not found".into()))?;
+
+    with_items(order, state).await
+}
+
+pub async fn get_customer_orders(
+    customer: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<Vec<entity::orders::Model>, DbErr> {
+    let orders = entity::orders::Entity::find()
+        .filter(entity::orders::Column::CustomerId.eq(customer))
+        .order_by_desc(entity::orders::Column::CreatedAt)
+        .all(state.pg_db.get_ref())
+        .await
+        .map_err(|err| {
+            eprintln!("Database retrieval error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    Ok(orders)
+}
+
+pub async fn get_branch_orders(
+    organization: uuid::Uuid,
+    branch: uuid::Uuid,
+    status: Option<String>,
+    state: &web::Data<AppState>,
+) -> Result<Vec<entity::orders::Model>, DbErr> {
+    let mut condition = Condition::all()
+        .add(entity::orders::Column::OrganizationId.eq(organization))
+        .add(entity::orders::Column::BranchId.eq(branch))
+        .add(on_queue());
+
+    if let Some(status) = status {
+        condition = condition.add(entity::orders::Column::OrderStatus.eq(status));
+    }
+
+    let orders = entity::orders::Entity::find()
+        .filter(condition)
+        .order_by_asc(entity::orders::Column::CreatedAt)
+        .all(state.pg_db.get_ref())
+        .await
+        .map_err(|err| {
+            eprintln!("Database retrieval error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    Ok(orders)
+}
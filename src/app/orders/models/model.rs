@@ -0,0 +1,292 @@
+use std::str::FromStr;
+
+use sea_orm::prelude::Decimal;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::{
+    app::wallets::models::model::PaymentChannel,
+    libs::validator::{
+        validate_contact, validate_item_type, validate_order_status, validate_payment_channel,
+        validate_val_range,
+    },
+};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum OrderStatus {
+    Pending,
+    Processing,
+    Completed,
+    Rejected,
+    Cancelled,
+}
+
+impl OrderStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OrderStatus::Pending => "PENDING",
+            OrderStatus::Processing => "PROCESSING",
+            OrderStatus::Completed => "COMPLETED",
+            OrderStatus::Rejected => "REJECTED",
+            OrderStatus::Cancelled => "CANCELLED",
+        }
+    }
+
+    /// The order lifecycle: a pending order is either taken up, rejected or cancelled, and an
+    /// order being prepared is either completed, rejected when it can't be fulfilled, or
+    /// cancelled. Every other status is final.
+    pub fn can_transition_to(&self, next: OrderStatus) -> bool {
+        matches!(
+            (self, next),
+            (
+                OrderStatus::Pending,
+                OrderStatus::Processing | OrderStatus::Rejected | OrderStatus::Cancelled
+            ) | (
+                OrderStatus::Processing,
+                OrderStatus::Completed | OrderStatus::Rejected | OrderStatus::Cancelled
+            )
+        )
+    }
+
+    /// Whether moving into this status hands the customer's money back.
+    pub fn is_refundable(&self) -> bool {
+        matches!(self, OrderStatus::Rejected | OrderStatus::Cancelled)
+    }
+}
+
+impl FromStr for OrderStatus {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_uppercase().as_str() {
+            "PENDING" => Ok(OrderStatus::Pending),
+            "PROCESSING" => Ok(OrderStatus::Processing),
+            "COMPLETED" => Ok(OrderStatus::Completed),
+            "REJECTED" => Ok(OrderStatus::Rejected),
+            "CANCELLED" => Ok(OrderStatus::Cancelled),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum OrderState {
+    PreOrdered,
+    InstantOrder,
+}
+
+impl OrderState {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OrderState::PreOrdered => "PREORDERED",
+            OrderState::InstantOrder => "INSTANTORDER",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum ItemType {
+    Drink,
+    Food,
+    Cocktail,
+}
+
+impl ItemType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ItemType::Drink => "DRINK",
+            ItemType::Food => "FOOD",
+            ItemType::Cocktail => "COCKTAIL",
+        }
+    }
+}
+
+impl FromStr for ItemType {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_uppercase().as_str() {
+            "DRINK" => Ok(ItemType::Drink),
+            "FOOD" => Ok(ItemType::Food),
+            "COCKTAIL" => Ok(ItemType::Cocktail),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct OrderItemDto {
+    pub item_type: ItemType,
+    pub item: uuid::Uuid,
+    pub quantity: i32,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AddOrderDto {
+    pub customer: uuid::Uuid,
+    pub organization: uuid::Uuid,
+    pub branch: uuid::Uuid,
+    pub served_by: Option<uuid::Uuid>,
+    pub booking: Option<uuid::Uuid>,
+    pub till_session: Option<uuid::Uuid>,
+    pub tab: Option<uuid::Uuid>,
+    pub payment_channel: PaymentChannel,
+    pub items: Vec<OrderItemDto>,
+    pub tip_amount: Option<Decimal>,
+    pub promo_code: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UpdateOrderStatusDto {
+    pub organization: uuid::Uuid,
+    pub branch: uuid::Uuid,
+    pub served_by: uuid::Uuid,
+    pub status: OrderStatus,
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct OrderDetailsResponse {
+    pub order: entity::orders::Model,
+    pub items: Vec<entity::order_items::Model>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct OrderItemParams {
+    #[validate(custom(function = "validate_item_type"))]
+    pub item_type: String,
+    pub item: uuid::Uuid,
+    #[validate(range(min = 1, max = 50, message = "Quantity is invalid"))]
+    pub quantity: i32,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct PlaceOrderParams {
+    pub branch: uuid::Uuid,
+    #[validate(length(min = 1, max = 30, message = "Items are invalid"), nested)]
+    pub items: Vec<OrderItemParams>,
+    #[validate(custom(function = "validate_val_range"))]
+    pub tip_amount: Option<Decimal>,
+    #[validate(length(min = 3, max = 30, message = "Promo code is invalid"))]
+    pub promo_code: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct PreOrderParams {
+    pub booking: uuid::Uuid,
+    #[validate(length(min = 1, max = 30, message = "Items are invalid"), nested)]
+    pub items: Vec<OrderItemParams>,
+    #[validate(custom(function = "validate_val_range"))]
+    pub tip_amount: Option<Decimal>,
+    #[validate(length(min = 3, max = 30, message = "Promo code is invalid"))]
+    pub promo_code: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct AddOrderParams {
+    #[validate(custom(function = "validate_contact"))]
+    pub phone: String,
+    #[validate(custom(function = "validate_payment_channel"))]
+    pub payment_channel: Option<String>,
+    #[validate(length(min = 1, max = 30, message = "Items are invalid"), nested)]
+    pub items: Vec<OrderItemParams>,
+    #[validate(custom(function = "validate_val_range"))]
+    pub tip_amount: Option<Decimal>,
+    #[validate(length(min = 3, max = 30, message = "Promo code is invalid"))]
+    pub promo_code: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct UpdateOrderStatusParams {
+    #[validate(custom(function = "validate_order_status"))]
+    pub status: String,
+    #[validate(length(min = 3, max = 200, message = "Reason is invalid"))]
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct OrderListQuery {
+    #[validate(custom(function = "validate_order_status"))]
+    pub status: Option<String>,
+}
+
+impl From<OrderItemParams> for OrderItemDto {
+    fn from(item: OrderItemParams) -> Self {
+        Self {
+            item_type: item.item_type.parse().unwrap_or(ItemType::Drink),
+            item: item.item,
+            quantity: item.quantity,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STATUSES: [OrderStatus; 5] = [
+        OrderStatus::Pending,
+        OrderStatus::Processing,
+        OrderStatus::Completed,
+        OrderStatus::Rejected,
+        OrderStatus::Cancelled,
+    ];
+
+    fn next_statuses(status: OrderStatus) -> Vec<OrderStatus> {
+        STATUSES
+            .into_iter()
+            .filter(|next| status.can_transition_to(*next))
+            .collect()
+    }
+
+    #[test]
+    fn pending_orders_are_taken_up_rejected_or_cancelled() {
+        assert_eq!(
+            next_statuses(OrderStatus::Pending),
+            vec![
+                OrderStatus::Processing,
+                OrderStatus::Rejected,
+                OrderStatus::Cancelled
+            ]
+        );
+    }
+
+    #[test]
+    fn orders_being_prepared_can_still_be_rejected() {
+        assert_eq!(
+            next_statuses(OrderStatus::Processing),
+            vec![
+                OrderStatus::Completed,
+                OrderStatus::Rejected,
+                OrderStatus::Cancelled
+            ]
+        );
+    }
+
+    #[test]
+    fn final_statuses_stay_put() {
+        for status in [
+            OrderStatus::Completed,
+            OrderStatus::Rejected,
+            OrderStatus::Cancelled,
+        ] {
+            assert!(next_statuses(status).is_empty(), "{:?}", status);
+        }
+    }
+
+    #[test]
+    fn statuses_read_back_from_their_names() {
+        for status in STATUSES {
+            assert_eq!(status.as_str().parse::<OrderStatus>(), Ok(status));
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use actix_web::web;
+
+use crate::{
+    app::orders::controllers::controller::{
+        branch_orders, cancel_my_order, change_order_status, create_order, my_order_details,
+        my_orders, order_details, place_order, pre_order,
+    },
+    middlewares::{auth::JwtAuthMiddleware, checker::CheckUserMiddleware},
+    AppState,
+};
+
+pub fn routes(cfg: &mut web::ServiceConfig, state: web::Data<AppState>) {
+    cfg.service(
+        web::scope("/api/v1/orders")
+            .route(
+                "/place",
+                web::post()
+                    .to(place_order)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Customer"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/pre-order",
+                web::post()
+                    .to(pre_order)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Customer"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/history",
+                web::get()
+                    .to(my_orders)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Customer"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/mine/{id}",
+                web::get()
+                    .to(my_order_details)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Customer"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/cancel/{id}",
+                web::put()
+                    .to(cancel_my_order)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Customer"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/add",
+                web::post()
+                    .to(create_order)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/branch",
+                web::get()
+                    .to(branch_orders)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/details/{id}",
+                web::get()
+                    .to(order_details)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/status/{id}",
+                web::put()
+                    .to(change_order_status)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            ),
+    );
+}
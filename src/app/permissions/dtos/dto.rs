@@ -0,0 +1,89 @@
+use actix_web::web;
+use sea_orm::{ColumnTrait, Condition, ConnectionTrait, DbErr, EntityTrait, QueryFilter};
+
+use crate::{app::permissions::models::model::ModelAction, AppState};
+
+/// Whether the role-permission assigned to an employee or user grants `action`.
+pub async fn has_permission(
+    role_permission: Option<uuid::Uuid>,
+    action: ModelAction,
+    state: &web::Data<AppState>,
+) -> Result<bool, DbErr> {
+    let Some(role_permission) = role_permission else {
+        return Ok(false);
+    };
+
+    let role_permission = entity::role_permissions::Entity::find_by_id(role_permission)
+        .filter(entity::role_permissions::Column::IsDeleted.eq(false))
+        .one(state.pg_db.get_ref())
+        .await?;
+
+    let Some(role_permission) = role_permission else {
+        return Ok(false);
+    };
+
+    let permission = entity::permissions::Entity::find_by_id(role_permission.permission_id)
+        .filter(entity::permissions::Column::IsDeleted.eq(false))
+        .one(state.pg_db.get_ref())
+        .await?;
+
+    Ok(permission
+        .and_then(|permission| permission.model_action)
+        .is_some_and(|actions| actions.iter().any(|a| a == action.as_str())))
+}
+
+/// Active employees of the branch whose role-permission grants `action`.
+pub async fn branch_employees_with_permission<C: ConnectionTrait>(
+    branch: uuid::Uuid,
+    action: ModelAction,
+    conn: &C,
+) -> Result<Vec<entity::employees::Model>, DbErr> {
+    let permissions: Vec<uuid::Uuid> = entity::permissions::Entity::find()
+        .filter(entity::permissions::Column::IsDeleted.eq(false))
+        .all(conn)
+        .await?
+        .into_iter()
+        .filter(|permission| {
+            permission
+                .model_action
+                .as_ref()
+                .is_some_and(|actions| actions.iter().any(|a| a == action.as_str()))
+        })
+        .map(|permission| permission.id)
+        .collect();
+
+    if permissions.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let role_permissions: Vec<uuid::Uuid> = entity::role_permissions::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::role_permissions::Column::PermissionId.is_in(permissions))
+                .add(entity::role_permissions::Column::IsDeleted.eq(false)),
+        )
+        .all(conn)
+        .await?
+        .into_iter()
+        .map(|role_permission| role_permission.id)
+        .collect();
+
+    if role_permissions.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    entity::employees::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::employees::Column::BranchId.eq(branch))
+                .add(entity::employees::Column::RolePermissions.is_in(role_permissions))
+                .add(entity::employees::Column::IsActive.eq(true))
+                .add(entity::employees::Column::IsDeleted.eq(false)),
+        )
+        .all(conn)
+        .await
+        .map_err(|err| {
+            eprintln!("Database retrieval error: {}", err);
+            DbErr::Custom(err.to_string())
+        })
+}
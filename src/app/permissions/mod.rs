@@ -0,0 +1,2 @@
+pub mod models;
+pub mod dtos;
\ No newline at end of file
@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+
+/// Entries stored in `permissions.model_action`, written as `Model::Action`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum ModelAction {
+    ApproveTickets,
+    ApproveTicketEvents,
+    ManageTips,
+    ManagePricing,
+    ManageInventory,
+    ViewReports,
+}
+
+impl ModelAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ModelAction::ApproveTickets => "Tickets::Approve",
+            ModelAction::ApproveTicketEvents => "TicketEvents::Approve",
+            ModelAction::ManageTips => "Tips::Manage",
+            ModelAction::ManagePricing => "Pricing::Manage",
+            ModelAction::ManageInventory => "Inventory::Manage",
+            ModelAction::ViewReports => "Reports::View",
+        }
+    }
+}
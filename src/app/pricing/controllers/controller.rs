@@ -0,0 +1,215 @@
+use std::sync::Arc;
+
+use actix_web::{web, HttpMessage, HttpRequest, HttpResponse};
+use serde_json::json;
+
+use crate::{
+    app::{
+        employees::models::model::EmployeeResponse,
+        permissions::{dtos::dto::has_permission, models::model::ModelAction},
+        pricing::{
+            dtos::dto::{
+                get_branch_price_rules, preview_branch_prices, save_price_rule, toggle_price_rule,
+                update_price_rule,
+            },
+            models::model::{PricePreviewQuery, PriceRuleParams},
+        },
+    },
+    libs::error::Error,
+    utils::{
+        json_validator::{ValidatedJson, ValidatedPath, ValidatedQuery},
+        models::{HttpClientResponse, PathParamsModel, ResponseCode},
+    },
+    AppState,
+};
+
+pub async fn add_rule(
+    req: HttpRequest,
+    payload: ValidatedJson<PriceRuleParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    if !has_permission(model.role_permissions, ModelAction::ManagePricing, &state)
+        .await
+        .unwrap_or(false)
+    {
+        return Ok(HttpResponse::Forbidden().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            "Not Permitted To Manage Pricing".to_string(),
+            json!({}),
+        )));
+    }
+
+    if payload.0.start_time == payload.0.end_time {
+        return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            "Start and end time must differ".to_string(),
+            json!({}),
+        )));
+    }
+
+    let rule = payload.0.into_dto(model.organization_id, model.branch_id);
+
+    match save_price_rule(rule, &state).await {
+        Ok(res) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Price Rule Added Successfully".to_string(),
+            json!(res),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Error Adding Price Rule: {}", e),
+            json!({}),
+        ))),
+    }
+}
+
+pub async fn branch_rules(
+    req: HttpRequest,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    match get_branch_price_rules(model.branch_id, &state).await {
+        Ok(res) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Price Rules Fetched Successfully".to_string(),
+            json!(res),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Error Retrieving: {}", e),
+            json!([]),
+        ))),
+    }
+}
+
+pub async fn edit_rule(
+    req: HttpRequest,
+    params: ValidatedPath<PathParamsModel>,
+    payload: ValidatedJson<PriceRuleParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    if !has_permission(model.role_permissions, ModelAction::ManagePricing, &state)
+        .await
+        .unwrap_or(false)
+    {
+        return Ok(HttpResponse::Forbidden().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            "Not Permitted To Manage Pricing".to_string(),
+            json!({}),
+        )));
+    }
+
+    if payload.0.start_time == payload.0.end_time {
+        return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            "Start and end time must differ".to_string(),
+            json!({}),
+        )));
+    }
+
+    let rule = payload.0.into_dto(model.organization_id, model.branch_id);
+
+    match update_price_rule(params.0.id, rule, &state).await {
+        Ok(res) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Price Rule Updated Successfully".to_string(),
+            json!(res),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Error Updating Price Rule: {}", e),
+            json!({}),
+        ))),
+    }
+}
+
+pub async fn toggle_rule(
+    req: HttpRequest,
+    params: ValidatedPath<PathParamsModel>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    if !has_permission(model.role_permissions, ModelAction::ManagePricing, &state)
+        .await
+        .unwrap_or(false)
+    {
+        return Ok(HttpResponse::Forbidden().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            "Not Permitted To Manage Pricing".to_string(),
+            json!({}),
+        )));
+    }
+
+    match toggle_price_rule(params.0.id, model.branch_id, &state).await {
+        Ok(res) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Price Rule Updated Successfully".to_string(),
+            json!(res),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Error Updating Price Rule: {}", e),
+            json!({}),
+        ))),
+    }
+}
+
+pub async fn preview_prices(
+    _req: HttpRequest,
+    params: ValidatedPath<PathParamsModel>,
+    query: ValidatedQuery<PricePreviewQuery>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let at = query.0.at.unwrap_or_else(chrono::Utc::now);
+
+    match preview_branch_prices(params.0.id, at, &state).await {
+        Ok(res) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Prices Fetched Successfully".to_string(),
+            json!(res),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Error Retrieving: {}", e),
+            json!([]),
+        ))),
+    }
+}
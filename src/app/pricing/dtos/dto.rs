@@ -0,0 +1,175 @@
+use actix_web::web;
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, Condition, ConnectionTrait, DbErr, EntityTrait,
+    QueryFilter, QueryOrder, Set,
+};
+
+use crate::{
+    app::{
+        branch::dtos::dto::get_branch_timezone,
+        pricing::{
+            models::model::{EffectivePrice, SavePriceRuleDto},
+            services::service::effective_price,
+        },
+    },
+    AppState,
+};
+
+/// The branch's live price rules, for pricing drinks as they are ordered.
+pub async fn branch_price_rules<C: ConnectionTrait>(
+    branch: uuid::Uuid,
+    conn: &C,
+) -> Result<Vec<entity::price_rules::Model>, DbErr> {
+    entity::price_rules::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::price_rules::Column::BranchId.eq(branch))
+                .add(entity::price_rules::Column::IsActive.eq(true)),
+        )
+        .all(conn)
+        .await
+        .map_err(|err| {
+            eprintln!("Database retrieval error: {}", err);
+            DbErr::Custom(err.to_string())
+        })
+}
+
+pub async fn save_price_rule(
+    data: SavePriceRuleDto,
+    state: &web::Data<AppState>,
+) -> Result<entity::price_rules::Model, DbErr> {
+    let rule = entity::price_rules::ActiveModel {
+        organization_id: Set(data.organization),
+        branch_id: Set(data.branch),
+        name: Set(data.name),
+        adjustment_type: Set(data.adjustment_type.as_str().to_string()),
+        rate: Set(data.rate),
+        category_ids: Set(data.categories),
+        drink_ids: Set(data.drinks),
+        weekdays: Set(data.weekdays),
+        start_time: Set(data.start_time),
+        end_time: Set(data.end_time),
+        priority: Set(data.priority),
+        ..Default::default()
+    };
+
+    let result = ActiveModelTrait::insert(rule, state.pg_db.get_ref())
+        .await
+        .map_err(|err| {
+            eprintln!("Database insert error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    Ok(result)
+}
+
+/// Rewrites a branch's price rule. Orders already placed keep the prices they were charged.
+pub async fn update_price_rule(
+    id: uuid::Uuid,
+    data: SavePriceRuleDto,
+    state: &web::Data<AppState>,
+) -> Result<entity::price_rules::Model, DbErr> {
+    let rule = entity::price_rules::Entity::find_by_id(id)
+        .filter(entity::price_rules::Column::BranchId.eq(data.branch))
+        .one(state.pg_db.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Price rule not found".into()))?;
+
+    let mut model: entity::price_rules::ActiveModel = rule.into();
+
+    model.name = ActiveValue::Set(data.name);
+    model.adjustment_type = ActiveValue::Set(data.adjustment_type.as_str().to_string());
+    model.rate = ActiveValue::Set(data.rate);
+    model.category_ids = ActiveValue::Set(data.categories);
+    model.drink_ids = ActiveValue::Set(data.drinks);
+    model.weekdays = ActiveValue::Set(data.weekdays);
+    model.start_time = ActiveValue::Set(data.start_time);
+    model.end_time = ActiveValue::Set(data.end_time);
+    model.priority = ActiveValue::Set(data.priority);
+    model.updated_at = ActiveValue::Set(chrono::Utc::now().into());
+
+    let result = ActiveModelTrait::update(model, state.pg_db.get_ref())
+        .await
+        .map_err(|err| {
+            eprintln!("Database update error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    Ok(result)
+}
+
+/// Switches a branch's price rule on or off.
+pub async fn toggle_price_rule(
+    id: uuid::Uuid,
+    branch: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<entity::price_rules::Model, DbErr> {
+    let rule = entity::price_rules::Entity::find_by_id(id)
+        .filter(entity::price_rules::Column::BranchId.eq(branch))
+        .one(state.pg_db.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Price rule not found".into()))?;
+
+    let is_active = rule.is_active;
+
+    let mut model: entity::price_rules::ActiveModel = rule.into();
+
+    model.is_active = ActiveValue::Set(!is_active);
+    model.updated_at = ActiveValue::Set(chrono::Utc::now().into());
+
+    let result = ActiveModelTrait::update(model, state.pg_db.get_ref())
+        .await
+        .map_err(|err| {
+            eprintln!("Database update error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    Ok(result)
+}
+
+pub async fn get_branch_price_rules(
+    branch: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<Vec<entity::price_rules::Model>, DbErr> {
+    let rules = entity::price_rules::Entity::find()
+        .filter(entity::price_rules::Column::BranchId.eq(branch))
+        .order_by_desc(entity::price_rules::Column::Priority)
+        .order_by_asc(entity::price_rules::Column::CreatedAt)
+        .all(state.pg_db.get_ref())
+        .await
+        .map_err(|err| {
+            eprintln!("Database retrieval error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    Ok(rules)
+}
+
+/// What each drink on the branch's menu costs at `at`, the price an order placed then pays.
+pub async fn preview_branch_prices(
+    branch: uuid::Uuid,
+    at: chrono::DateTime<chrono::Utc>,
+    state: &web::Data<AppState>,
+) -> Result<Vec<EffectivePrice>, DbErr> {
+    let rules = branch_price_rules(branch, state.pg_db.get_ref()).await?;
+    let at = at.with_timezone(&get_branch_timezone(branch, state.pg_db.get_ref()).await?);
+
+    let drinks = entity::stock_drinks::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::stock_drinks::Column::BranchId.eq(branch))
+                .add(entity::stock_drinks::Column::StockStatus.ne("OUTOFSTOCK")),
+        )
+        .order_by_asc(entity::stock_drinks::Column::Name)
+        .all(state.pg_db.get_ref())
+        .await
+        .map_err(|err| {
+            eprintln!("Database retrieval error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    Ok(drinks
+        .iter()
+        .map(|drink| effective_price(drink, &rules, at))
+        .collect())
+}
@@ -0,0 +1,111 @@
+use std::str::FromStr;
+
+use chrono::{DateTime, NaiveTime, Utc};
+use sea_orm::prelude::Decimal;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::libs::validator::{validate_adjustment_type, validate_percent_range, validate_weekdays};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum AdjustmentType {
+    Discount,
+    Markup,
+}
+
+impl AdjustmentType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AdjustmentType::Discount => "DISCOUNT",
+            AdjustmentType::Markup => "MARKUP",
+        }
+    }
+}
+
+impl FromStr for AdjustmentType {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_uppercase().as_str() {
+            "DISCOUNT" => Ok(AdjustmentType::Discount),
+            "MARKUP" => Ok(AdjustmentType::Markup),
+            _ => Err(()),
+        }
+    }
+}
+
+/// A drink's price at a given moment, with the rule that set it if any.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EffectivePrice {
+    pub drink: uuid::Uuid,
+    pub name: String,
+    pub category: uuid::Uuid,
+    pub base_price: Decimal,
+    pub price: Decimal,
+    pub rule: Option<uuid::Uuid>,
+    pub rule_name: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SavePriceRuleDto {
+    pub organization: uuid::Uuid,
+    pub branch: uuid::Uuid,
+    pub name: String,
+    pub adjustment_type: AdjustmentType,
+    pub rate: Decimal,
+    pub categories: Option<Vec<uuid::Uuid>>,
+    pub drinks: Option<Vec<uuid::Uuid>>,
+    pub weekdays: Option<Vec<i32>>,
+    pub start_time: NaiveTime,
+    pub end_time: NaiveTime,
+    pub priority: i32,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct PriceRuleParams {
+    #[validate(length(min = 3, max = 100, message = "Name is invalid"))]
+    pub name: String,
+    #[validate(custom(function = "validate_adjustment_type"))]
+    pub adjustment_type: String,
+    #[validate(custom(function = "validate_percent_range"))]
+    pub rate: Decimal,
+    #[validate(length(min = 1, max = 50, message = "Categories are invalid"))]
+    pub categories: Option<Vec<uuid::Uuid>>,
+    #[validate(length(min = 1, max = 100, message = "Drinks are invalid"))]
+    pub drinks: Option<Vec<uuid::Uuid>>,
+    #[validate(custom(function = "validate_weekdays"))]
+    pub weekdays: Option<Vec<i32>>,
+    pub start_time: NaiveTime,
+    pub end_time: NaiveTime,
+    #[validate(range(min = 0, max = 100, message = "Priority is invalid"))]
+    pub priority: Option<i32>,
+}
+
+impl PriceRuleParams {
+    pub fn into_dto(self, organization: uuid::Uuid, branch: uuid::Uuid) -> SavePriceRuleDto {
+        SavePriceRuleDto {
+            organization,
+            branch,
+            name: self.name,
+            adjustment_type: self
+                .adjustment_type
+                .parse()
+                .unwrap_or(AdjustmentType::Discount),
+            rate: self.rate,
+            categories: self.categories,
+            drinks: self.drinks,
+            weekdays: self.weekdays,
+            start_time: self.start_time,
+            end_time: self.end_time,
+            priority: self.priority.unwrap_or(0),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct PricePreviewQuery {
+    pub at: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,44 @@
+use actix_web::web;
+
+use crate::{
+    app::pricing::controllers::controller::{
+        add_rule, branch_rules, edit_rule, preview_prices, toggle_rule,
+    },
+    middlewares::{auth::JwtAuthMiddleware, checker::CheckUserMiddleware},
+    AppState,
+};
+
+pub fn routes(cfg: &mut web::ServiceConfig, state: web::Data<AppState>) {
+    cfg.service(
+        web::scope("/api/v1/pricing")
+            .route(
+                "/rules",
+                web::post()
+                    .to(add_rule)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/rules",
+                web::get()
+                    .to(branch_rules)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/rules/{id}",
+                web::put()
+                    .to(edit_rule)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/rules/toggle/{id}",
+                web::put()
+                    .to(toggle_rule)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route("/preview/{id}", web::get().to(preview_prices)),
+    );
+}
@@ -0,0 +1,279 @@
+use chrono::{DateTime, Datelike};
+use chrono_tz::Tz;
+use sea_orm::prelude::Decimal;
+
+use crate::app::pricing::models::model::{AdjustmentType, EffectivePrice};
+
+/// How closely a rule targets the drink: named outright, through its category, or not at all
+/// because the rule covers every drink.
+fn specificity(rule: &entity::price_rules::Model, drink: &entity::stock_drinks::Model) -> u8 {
+    if rule
+        .drink_ids
+        .as_ref()
+        .is_some_and(|drinks| drinks.contains(&drink.id))
+    {
+        2
+    } else if rule
+        .category_ids
+        .as_ref()
+        .is_some_and(|categories| categories.contains(&drink.category_id))
+    {
+        1
+    } else {
+        0
+    }
+}
+
+fn applies(
+    rule: &entity::price_rules::Model,
+    drink: &entity::stock_drinks::Model,
+    at: DateTime<Tz>,
+) -> bool {
+    let scoped = rule.drink_ids.is_some() || rule.category_ids.is_some();
+
+    if scoped && specificity(rule, drink) == 0 {
+        return false;
+    }
+
+    let weekday = at.weekday().number_from_monday() as i32;
+
+    if let Some(weekdays) = &rule.weekdays {
+        if !weekdays.contains(&weekday) {
+            return false;
+        }
+    }
+
+    let time = at.time();
+
+    match rule.start_time <= rule.end_time {
+        true => time >= rule.start_time && time < rule.end_time,
+        // The window runs past midnight
+        false => time >= rule.start_time || time < rule.end_time,
+    }
+}
+
+/// The one rule pricing the drink at `at`, on the branch's clock: the highest priority wins,
+/// then the rule aimed most closely at the drink, then the oldest.
+pub fn matching_rule<'a>(
+    rules: &'a [entity::price_rules::Model],
+    drink: &entity::stock_drinks::Model,
+    at: DateTime<Tz>,
+) -> Option<&'a entity::price_rules::Model> {
+    rules
+        .iter()
+        .filter(|rule| applies(rule, drink, at))
+        .max_by(|a, b| {
+            a.priority
+                .cmp(&b.priority)
+                .then(specificity(a, drink).cmp(&specificity(b, drink)))
+                .then(b.created_at.cmp(&a.created_at))
+                .then(b.id.cmp(&a.id))
+        })
+}
+
+/// The drink's sale price after the rule in force at `at`, rounded to the cent.
+pub fn effective_price(
+    drink: &entity::stock_drinks::Model,
+    rules: &[entity::price_rules::Model],
+    at: DateTime<Tz>,
+) -> EffectivePrice {
+    let rule = matching_rule(rules, drink, at);
+
+    let price = match rule {
+        Some(rule) => {
+            let change = drink.sale_price * rule.rate / Decimal::from(100);
+
+            match rule.adjustment_type.parse() {
+                Ok(AdjustmentType::Markup) => drink.sale_price + change,
+                _ => drink.sale_price - change,
+            }
+        }
+        None => drink.sale_price,
+    };
+
+    EffectivePrice {
+        drink: drink.id,
+        name: drink.name.clone(),
+        category: drink.category_id,
+        base_price: drink.sale_price,
+        price: price.max(Decimal::ZERO).round_dp(2),
+        rule: rule.map(|rule| rule.id),
+        rule_name: rule.map(|rule| rule.name.clone()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveTime, TimeZone};
+
+    use super::*;
+    use crate::utils::fixtures::{dec, price_rule, stock_drink};
+
+    fn hours(start: u32, end: u32) -> (NaiveTime, NaiveTime) {
+        (
+            NaiveTime::from_hms_opt(start, 0, 0).unwrap(),
+            NaiveTime::from_hms_opt(end, 0, 0).unwrap(),
+        )
+    }
+
+    /// Wednesday 14 May 2025 at `hour` in UTC.
+    fn at(hour: u32) -> DateTime<Tz> {
+        Tz::UTC.with_ymd_and_hms(2025, 5, 14, hour, 0, 0).unwrap()
+    }
+
+    fn happy_hour() -> entity::price_rules::Model {
+        let (start_time, end_time) = hours(17, 19);
+
+        entity::price_rules::Model {
+            start_time,
+            end_time,
+            ..price_rule("Happy Hour", AdjustmentType::Discount, "20")
+        }
+    }
+
+    #[test]
+    fn prices_by_the_rule_in_force() {
+        let drink = stock_drink("Gin", "10");
+        let rules = [happy_hour()];
+
+        let price = effective_price(&drink, &rules, at(18));
+
+        assert_eq!(price.price, dec("8"));
+        assert_eq!(price.base_price, dec("10"));
+        assert_eq!(price.rule_name.as_deref(), Some("Happy Hour"));
+        assert_eq!(effective_price(&drink, &rules, at(19)).price, dec("10"));
+    }
+
+    #[test]
+    fn marks_up_and_rounds_to_the_cent() {
+        let drink = stock_drink("Gin", "9.99");
+        let (start_time, end_time) = hours(0, 6);
+        let late = entity::price_rules::Model {
+            start_time,
+            end_time,
+            ..price_rule("Late", AdjustmentType::Markup, "15")
+        };
+
+        assert_eq!(effective_price(&drink, &[late], at(2)).price, dec("11.49"));
+    }
+
+    #[test]
+    fn never_prices_below_zero() {
+        let drink = stock_drink("Gin", "10");
+        let free = entity::price_rules::Model {
+            rate: dec("150"),
+            ..happy_hour()
+        };
+
+        assert_eq!(
+            effective_price(&drink, &[free], at(18)).price,
+            Decimal::ZERO
+        );
+    }
+
+    #[test]
+    fn reads_the_window_on_the_branch_clock() {
+        let drink = stock_drink("Gin", "10");
+        let dubai_evening = "Asia/Dubai"
+            .parse::<Tz>()
+            .unwrap()
+            .with_ymd_and_hms(2025, 5, 14, 18, 0, 0)
+            .unwrap();
+
+        // 18:00 in Dubai is 14:00 UTC
+        assert!(matching_rule(&[happy_hour()], &drink, dubai_evening).is_some());
+        assert!(matching_rule(&[happy_hour()], &drink, at(14)).is_none());
+    }
+
+    #[test]
+    fn windows_can_run_past_midnight() {
+        let drink = stock_drink("Gin", "10");
+        let (start_time, end_time) = hours(22, 2);
+        let late = entity::price_rules::Model {
+            start_time,
+            end_time,
+            ..price_rule("Late", AdjustmentType::Markup, "10")
+        };
+        let rules = [late];
+
+        assert!(matching_rule(&rules, &drink, at(23)).is_some());
+        assert!(matching_rule(&rules, &drink, at(1)).is_some());
+        assert!(matching_rule(&rules, &drink, at(2)).is_none());
+    }
+
+    #[test]
+    fn keeps_to_its_weekdays() {
+        let drink = stock_drink("Gin", "10");
+        let weekend = entity::price_rules::Model {
+            weekdays: Some(vec![6, 7]),
+            ..happy_hour()
+        };
+
+        assert!(matching_rule(&[weekend], &drink, at(18)).is_none());
+    }
+
+    #[test]
+    fn skips_rules_aimed_at_other_drinks() {
+        let drink = stock_drink("Gin", "10");
+        let other = entity::price_rules::Model {
+            drink_ids: Some(vec![uuid::Uuid::new_v4()]),
+            category_ids: Some(vec![uuid::Uuid::new_v4()]),
+            ..happy_hour()
+        };
+
+        assert!(matching_rule(&[other], &drink, at(18)).is_none());
+    }
+
+    #[test]
+    fn the_highest_priority_wins() {
+        let drink = stock_drink("Gin", "10");
+        let rules = [
+            entity::price_rules::Model {
+                name: "Low".to_string(),
+                drink_ids: Some(vec![drink.id]),
+                ..happy_hour()
+            },
+            entity::price_rules::Model {
+                name: "High".to_string(),
+                priority: 1,
+                ..happy_hour()
+            },
+        ];
+
+        let rule = matching_rule(&rules, &drink, at(18)).unwrap();
+
+        assert_eq!(rule.name, "High");
+    }
+
+    #[test]
+    fn then_the_closest_aim_then_the_oldest() {
+        let drink = stock_drink("Gin", "10");
+        let older = happy_hour();
+        let newer = entity::price_rules::Model {
+            created_at: (chrono::Utc::now() + chrono::Duration::hours(1)).into(),
+            ..happy_hour()
+        };
+        let by_category = entity::price_rules::Model {
+            name: "Category".to_string(),
+            category_ids: Some(vec![drink.category_id]),
+            ..newer.clone()
+        };
+        let by_drink = entity::price_rules::Model {
+            name: "Drink".to_string(),
+            drink_ids: Some(vec![drink.id]),
+            ..newer.clone()
+        };
+
+        let rules = [older.clone(), by_category.clone(), by_drink];
+        assert_eq!(matching_rule(&rules, &drink, at(18)).unwrap().name, "Drink");
+
+        let rules = [older.clone(), by_category];
+        assert_eq!(
+            matching_rule(&rules, &drink, at(18)).unwrap().name,
+            "Category"
+        );
+
+        let rules = [newer, older.clone()];
+        assert_eq!(matching_rule(&rules, &drink, at(18)).unwrap().id, older.id);
+    }
+}
@@ -0,0 +1,221 @@
+use std::sync::Arc;
+
+use actix_web::{web, HttpMessage, HttpRequest, HttpResponse};
+use serde_json::json;
+
+use crate::{
+    app::{
+        promos::{
+            dtos::dto::{
+                get_organization_promos, get_promo_redemptions, save_promo, toggle_promo,
+                update_promo,
+            },
+            models::model::{PromoParams, PromoTarget, PromoType, SavePromoDto},
+        },
+        users::models::model::UserResponse,
+    },
+    libs::error,
+    utils::{
+        json_validator::{ValidatedJson, ValidatedPath},
+        models::{HttpClientResponse, PathParamsModel, ResponseCode},
+    },
+    AppState,
+};
+
+/// Checks that only make sense across fields: codes go with code promos, time windows need
+/// both ends, and each scope belongs to one kind of purchase.
+fn check_promo(promo: &SavePromoDto) -> Option<String> {
+    if (promo.promo_type == PromoType::Code) != promo.code.is_some() {
+        return Some("Code promos need a code, automatic promos cannot have one".to_string());
+    }
+
+    if promo.valid_to <= promo.valid_from {
+        return Some("Promo must end after it starts".to_string());
+    }
+
+    if promo.start_time.is_some() != promo.end_time.is_some() {
+        return Some("Time window needs both a start and an end time".to_string());
+    }
+
+    let misplaced = match promo.applies_to {
+        PromoTarget::Orders => promo.schedules.is_some() || promo.tickets.is_some(),
+        PromoTarget::Bookings => promo.categories.is_some() || promo.tickets.is_some(),
+        PromoTarget::Tickets => promo.categories.is_some() || promo.schedules.is_some(),
+    };
+
+    if misplaced {
+        return Some(format!(
+            "Scope does not fit a promo for {}",
+            promo.applies_to.as_str().to_lowercase()
+        ));
+    }
+
+    None
+}
+
+pub async fn add_promo(
+    req: HttpRequest,
+    payload: ValidatedJson<PromoParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, error::Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<UserResponse>>()
+        .cloned()
+        .ok_or(error::Error {
+            message: "User not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    let promo = payload.0.into_dto(model.organization_id);
+
+    if let Some(message) = check_promo(&promo) {
+        return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            message,
+            json!({}),
+        )));
+    }
+
+    match save_promo(promo, &state).await {
+        Ok(res) => Ok(HttpResponse::Created().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Promo Saved Successfully".to_string(),
+            json!(res),
+        ))),
+        Err(e) => Ok(
+            HttpResponse::InternalServerError().json(HttpClientResponse::new(
+                ResponseCode::Failed,
+                format!("Error Adding Promo: {}", e),
+                json!({}),
+            )),
+        ),
+    }
+}
+
+pub async fn organization_promos(
+    req: HttpRequest,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, error::Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<UserResponse>>()
+        .cloned()
+        .ok_or(error::Error {
+            message: "User not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    match get_organization_promos(model.organization_id, &state).await {
+        Ok(res) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Promos Fetched Successfully".to_string(),
+            json!(res),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Error Retrieving: {}", e),
+            json!([]),
+        ))),
+    }
+}
+
+pub async fn edit_promo(
+    req: HttpRequest,
+    params: ValidatedPath<PathParamsModel>,
+    payload: ValidatedJson<PromoParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, error::Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<UserResponse>>()
+        .cloned()
+        .ok_or(error::Error {
+            message: "User not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    let promo = payload.0.into_dto(model.organization_id);
+
+    if let Some(message) = check_promo(&promo) {
+        return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            message,
+            json!({}),
+        )));
+    }
+
+    match update_promo(params.0.id, promo, &state).await {
+        Ok(res) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Promo Updated Successfully".to_string(),
+            json!(res),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Error Updating Promo: {}", e),
+            json!({}),
+        ))),
+    }
+}
+
+pub async fn switch_promo(
+    req: HttpRequest,
+    params: ValidatedPath<PathParamsModel>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, error::Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<UserResponse>>()
+        .cloned()
+        .ok_or(error::Error {
+            message: "User not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    match toggle_promo(params.0.id, model.organization_id, &state).await {
+        Ok(res) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Promo Updated Successfully".to_string(),
+            json!(res),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Error Updating Promo: {}", e),
+            json!({}),
+        ))),
+    }
+}
+
+pub async fn promo_redemptions(
+    req: HttpRequest,
+    params: ValidatedPath<PathParamsModel>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, error::Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<UserResponse>>()
+        .cloned()
+        .ok_or(error::Error {
+            message: "User not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    match get_promo_redemptions(params.0.id, model.organization_id, &state).await {
+        Ok(res) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Redemptions Fetched Successfully".to_string(),
+            json!(res),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Error Retrieving: {}", e),
+            json!([]),
+        ))),
+    }
+}
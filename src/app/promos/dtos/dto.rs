@@ -0,0 +1,352 @@
+use actix_web::web;
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, Condition, ConnectionTrait, DbErr, EntityTrait,
+    PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, Set,
+};
+
+use crate::{
+    app::promos::models::model::{
+        PromoLookupDto, PromoOutcome, PromoTarget, PromoType, RedemptionDto, RedemptionStatus,
+        SavePromoDto,
+    },
+    AppState,
+};
+
+async fn count_redemptions<C: ConnectionTrait>(
+    promo: uuid::Uuid,
+    customer: Option<uuid::Uuid>,
+    conn: &C,
+) -> Result<u64, DbErr> {
+    let mut condition = Condition::all()
+        .add(entity::promo_redemptions::Column::PromoId.eq(promo))
+        .add(
+            entity::promo_redemptions::Column::RedemptionStatus
+                .eq(RedemptionStatus::Active.as_str()),
+        );
+
+    if let Some(customer) = customer {
+        condition = condition.add(entity::promo_redemptions::Column::CustomerId.eq(customer));
+    }
+
+    entity::promo_redemptions::Entity::find()
+        .filter(condition)
+        .count(conn)
+        .await
+}
+
+/// Live promos of the organization for this kind of purchase that the customer can still use:
+/// every automatic one plus the one matching `code`. Promos with a usage limit stay locked
+/// until commit so two purchases can't both take the last use, unlimited ones are left free
+/// so ordering across branches doesn't queue on them. A code that doesn't exist or is used up
+/// is an error, used up automatic promos are just left out.
+pub async fn redeemable_promos<C: ConnectionTrait>(
+    lookup: &PromoLookupDto,
+    conn: &C,
+) -> Result<Vec<entity::promos::Model>, DbErr> {
+    let now = chrono::Utc::now();
+
+    let mut kinds =
+        Condition::any().add(entity::promos::Column::PromoType.eq(PromoType::Automatic.as_str()));
+
+    if let Some(code) = &lookup.code {
+        kinds = kinds.add(
+            Condition::all()
+                .add(entity::promos::Column::PromoType.eq(PromoType::Code.as_str()))
+                .add(entity::promos::Column::Code.eq(code.as_str())),
+        );
+    }
+
+    let promos = entity::promos::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::promos::Column::OrganizationId.eq(lookup.organization))
+                .add(entity::promos::Column::AppliesTo.eq(lookup.target.as_str()))
+                .add(entity::promos::Column::IsActive.eq(true))
+                .add(entity::promos::Column::ValidFrom.lte(now))
+                .add(entity::promos::Column::ValidTo.gte(now))
+                .add(kinds),
+        )
+        .order_by_asc(entity::promos::Column::CreatedAt)
+        .all(conn)
+        .await?;
+
+    // Only promos with a limit need their redemptions counted one purchase at a time
+    let limited: Vec<uuid::Uuid> = promos
+        .iter()
+        .filter(|promo| promo.usage_limit.is_some() || promo.per_customer_limit.is_some())
+        .map(|promo| promo.id)
+        .collect();
+
+    if !limited.is_empty() {
+        entity::promos::Entity::find()
+            .filter(entity::promos::Column::Id.is_in(limited))
+            .order_by_asc(entity::promos::Column::Id)
+            .lock_exclusive()
+            .all(conn)
+            .await?;
+    }
+
+    if let Some(code) = &lookup.code {
+        if !promos
+            .iter()
+            .any(|promo| promo.code.as_deref() == Some(code.as_str()))
+        {
+            return Err(DbErr::Custom(format!("Promo code {} is not valid", code)));
+        }
+    }
+
+    let mut redeemable = Vec::with_capacity(promos.len());
+
+    for promo in promos {
+        let used_up = match promo.usage_limit {
+            Some(limit) => count_redemptions(promo.id, None, conn).await? >= limit as u64,
+            None => false,
+        } || match promo.per_customer_limit {
+            Some(limit) => {
+                count_redemptions(promo.id, Some(lookup.customer), conn).await? >= limit as u64
+            }
+            None => false,
+        };
+
+        match (used_up, promo.promo_type == PromoType::Code.as_str()) {
+            (false, _) => redeemable.push(promo),
+            (true, true) => {
+                return Err(DbErr::Custom(format!(
+                    "Promo code {} has already been used up",
+                    promo.code.unwrap_or_default()
+                )))
+            }
+            (true, false) => {}
+        }
+    }
+
+    Ok(redeemable)
+}
+
+/// Records a redemption for each applied promo. Call inside the purchase's transaction.
+pub async fn record_redemptions<C: ConnectionTrait>(
+    outcome: &PromoOutcome,
+    data: RedemptionDto,
+    conn: &C,
+) -> Result<(), DbErr> {
+    if outcome.applied.is_empty() {
+        return Ok(());
+    }
+
+    let models = outcome
+        .applied
+        .iter()
+        .map(|promo| entity::promo_redemptions::ActiveModel {
+            promo_id: Set(promo.promo),
+            customer_id: Set(data.customer),
+            order_id: Set(data.order),
+            booking_id: Set(data.booking),
+            ticket_purchase_id: Set(data.ticket_purchase),
+            code: Set(promo.code.clone()),
+            discount_amount: Set(promo.amount),
+            redemption_status: Set(RedemptionStatus::Active.as_str().to_string()),
+            ..Default::default()
+        });
+
+    entity::promo_redemptions::Entity::insert_many(models)
+        .exec(conn)
+        .await
+        .map_err(|err| {
+            eprintln!("Database insert error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    Ok(())
+}
+
+/// Gives back the promo uses of a cancelled or refunded purchase.
+pub async fn void_redemptions<C: ConnectionTrait>(
+    target: PromoTarget,
+    id: uuid::Uuid,
+    conn: &C,
+) -> Result<(), DbErr> {
+    let column = match target {
+        PromoTarget::Orders => entity::promo_redemptions::Column::OrderId,
+        PromoTarget::Bookings => entity::promo_redemptions::Column::BookingId,
+        PromoTarget::Tickets => entity::promo_redemptions::Column::TicketPurchaseId,
+    };
+
+    entity::promo_redemptions::Entity::update_many()
+        .col_expr(
+            entity::promo_redemptions::Column::RedemptionStatus,
+            RedemptionStatus::Void.as_str().into(),
+        )
+        .col_expr(
+            entity::promo_redemptions::Column::UpdatedAt,
+            chrono::Utc::now().into(),
+        )
+        .filter(
+            Condition::all().add(column.eq(id)).add(
+                entity::promo_redemptions::Column::RedemptionStatus
+                    .eq(RedemptionStatus::Active.as_str()),
+            ),
+        )
+        .exec(conn)
+        .await
+        .map_err(|err| {
+            eprintln!("Database update error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    Ok(())
+}
+
+pub async fn save_promo(
+    data: SavePromoDto,
+    state: &web::Data<AppState>,
+) -> Result<entity::promos::Model, DbErr> {
+    let promo = entity::promos::ActiveModel {
+        organization_id: Set(Some(data.organization)),
+        title: Set(data.title),
+        description: Set(data.description),
+        code: Set(data.code),
+        promo_type: Set(data.promo_type.as_str().to_string()),
+        applies_to: Set(data.applies_to.as_str().to_string()),
+        discount_rate: Set(data.discount_rate),
+        max_discount: Set(data.max_discount),
+        min_spend: Set(data.min_spend),
+        valid_from: Set(data.valid_from.into()),
+        valid_to: Set(data.valid_to.into()),
+        branch_ids: Set(data.branches),
+        schedule_ids: Set(data.schedules),
+        category_ids: Set(data.categories),
+        ticket_ids: Set(data.tickets),
+        start_time: Set(data.start_time),
+        end_time: Set(data.end_time),
+        weekdays: Set(data.weekdays),
+        usage_limit: Set(data.usage_limit),
+        per_customer_limit: Set(data.per_customer_limit),
+        is_stackable: Set(data.is_stackable),
+        ..Default::default()
+    };
+
+    let result = ActiveModelTrait::insert(promo, state.pg_db.get_ref())
+        .await
+        .map_err(|err| {
+            eprintln!("Database insert error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    Ok(result)
+}
+
+/// Rewrites an organization's promo. Redemptions already made keep the discount they got.
+pub async fn update_promo(
+    id: uuid::Uuid,
+    data: SavePromoDto,
+    state: &web::Data<AppState>,
+) -> Result<entity::promos::Model, DbErr> {
+    let promo = entity::promos::Entity::find_by_id(id)
+        .filter(entity::promos::Column::OrganizationId.eq(data.organization))
+        .one(state.pg_db.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Promo not found".into()))?;
+
+    let mut model: entity::promos::ActiveModel = promo.into();
+
+    model.title = ActiveValue::Set(data.title);
+    model.description = ActiveValue::Set(data.description);
+    model.code = ActiveValue::Set(data.code);
+    model.promo_type = ActiveValue::Set(data.promo_type.as_str().to_string());
+    model.applies_to = ActiveValue::Set(data.applies_to.as_str().to_string());
+    model.discount_rate = ActiveValue::Set(data.discount_rate);
+    model.max_discount = ActiveValue::Set(data.max_discount);
+    model.min_spend = ActiveValue::Set(data.min_spend);
+    model.valid_from = ActiveValue::Set(data.valid_from.into());
+    model.valid_to = ActiveValue::Set(data.valid_to.into());
+    model.branch_ids = ActiveValue::Set(data.branches);
+    model.schedule_ids = ActiveValue::Set(data.schedules);
+    model.category_ids = ActiveValue::Set(data.categories);
+    model.ticket_ids = ActiveValue::Set(data.tickets);
+    model.start_time = ActiveValue::Set(data.start_time);
+    model.end_time = ActiveValue::Set(data.end_time);
+    model.weekdays = ActiveValue::Set(data.weekdays);
+    model.usage_limit = ActiveValue::Set(data.usage_limit);
+    model.per_customer_limit = ActiveValue::Set(data.per_customer_limit);
+    model.is_stackable = ActiveValue::Set(data.is_stackable);
+    model.updated_at = ActiveValue::Set(chrono::Utc::now().into());
+
+    let result = ActiveModelTrait::update(model, state.pg_db.get_ref())
+        .await
+        .map_err(|err| {
+            eprintln!("Database update error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    Ok(result)
+}
+
+/// Switches an organization's promo on or off.
+pub async fn toggle_promo(
+    id: uuid::Uuid,
+    organization: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<entity::promos::Model, DbErr> {
+    let promo = entity::promos::Entity::find_by_id(id)
+        .filter(entity::promos::Column::OrganizationId.eq(organization))
+        .one(state.pg_db.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Promo not found".into()))?;
+
+    let is_active = promo.is_active;
+
+    let mut model: entity::promos::ActiveModel = promo.into();
+
+    model.is_active = ActiveValue::Set(!is_active);
+    model.updated_at = ActiveValue::Set(chrono::Utc::now().into());
+
+    let result = ActiveModelTrait::update(model, state.pg_db.get_ref())
+        .await
+        .map_err(|err| {
+            eprintln!("Database update error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    Ok(result)
+}
+
+pub async fn get_organization_promos(
+    organization: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<Vec<entity::promos::Model>, DbErr> {
+    let promos = entity::promos::Entity::find()
+        .filter(entity::promos::Column::OrganizationId.eq(organization))
+        .order_by_desc(entity::promos::Column::CreatedAt)
+        .all(state.pg_db.get_ref())
+        .await
+        .map_err(|err| {
+            eprintln!("Database retrieval error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    Ok(promos)
+}
+
+pub async fn get_promo_redemptions(
+    id: uuid::Uuid,
+    organization: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<Vec<entity::promo_redemptions::Model>, DbErr> {
+    let promo = entity::promos::Entity::find_by_id(id)
+        .filter(entity::promos::Column::OrganizationId.eq(organization))
+        .one(state.pg_db.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Promo not found".into()))?;
+
+    let redemptions = entity::promo_redemptions::Entity::find()
+        .filter(entity::promo_redemptions::Column::PromoId.eq(promo.id))
+        .order_by_desc(entity::promo_redemptions::Column::CreatedAt)
+        .all(state.pg_db.get_ref())
+        .await
+        .map_err(|err| {
+            eprintln!("Database retrieval error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    Ok(redemptions)
+}
@@ -0,0 +1,249 @@
+use std::str::FromStr;
+
+use chrono::{DateTime, NaiveTime, Utc};
+use chrono_tz::Tz;
+use sea_orm::prelude::Decimal;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::libs::validator::{
+    validate_percent_range, validate_promo_target, validate_promo_type, validate_val_range,
+    validate_weekdays,
+};
+
+/// Code promos are redeemed by entering their code, automatic ones apply on their own.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum PromoType {
+    Code,
+    Automatic,
+}
+
+impl PromoType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PromoType::Code => "CODE",
+            PromoType::Automatic => "AUTOMATIC",
+        }
+    }
+}
+
+impl FromStr for PromoType {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_uppercase().as_str() {
+            "CODE" => Ok(PromoType::Code),
+            "AUTOMATIC" => Ok(PromoType::Automatic),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum PromoTarget {
+    Orders,
+    Bookings,
+    Tickets,
+}
+
+impl PromoTarget {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PromoTarget::Orders => "ORDERS",
+            PromoTarget::Bookings => "BOOKINGS",
+            PromoTarget::Tickets => "TICKETS",
+        }
+    }
+}
+
+impl FromStr for PromoTarget {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_uppercase().as_str() {
+            "ORDERS" => Ok(PromoTarget::Orders),
+            "BOOKINGS" => Ok(PromoTarget::Bookings),
+            "TICKETS" => Ok(PromoTarget::Tickets),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Void redemptions belong to cancelled or refunded purchases and don't count towards limits.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum RedemptionStatus {
+    Active,
+    Void,
+}
+
+impl RedemptionStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RedemptionStatus::Active => "ACTIVE",
+            RedemptionStatus::Void => "VOID",
+        }
+    }
+}
+
+/// Where and when a purchase is made, matched against a promo's scope and time window.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PromoContext {
+    pub branch: uuid::Uuid,
+    pub schedule: Option<uuid::Uuid>,
+    pub ticket: Option<uuid::Uuid>,
+    pub at: DateTime<Utc>,
+    /// The branch's timezone, which the promo's time window is read in.
+    pub timezone: Tz,
+}
+
+/// A priced line of the purchase. Only order lines have a category.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PromoLine {
+    pub category: Option<uuid::Uuid>,
+    pub amount: Decimal,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AppliedPromo {
+    pub promo: uuid::Uuid,
+    pub code: Option<String>,
+    pub title: String,
+    pub amount: Decimal,
+}
+
+/// The promos chosen for a purchase, what each took off and the discount left on every line.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PromoOutcome {
+    pub applied: Vec<AppliedPromo>,
+    pub line_discounts: Vec<Decimal>,
+    pub discount_amount: Decimal,
+}
+
+impl PromoOutcome {
+    /// The applied promos' codes, or titles for automatic ones, e.g. `SUMMER10+Happy Hour`.
+    pub fn reference(&self) -> Option<String> {
+        if self.applied.is_empty() {
+            return None;
+        }
+
+        Some(
+            self.applied
+                .iter()
+                .map(|promo| promo.code.clone().unwrap_or_else(|| promo.title.clone()))
+                .collect::<Vec<_>>()
+                .join("+"),
+        )
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PromoLookupDto {
+    pub organization: uuid::Uuid,
+    pub customer: uuid::Uuid,
+    pub target: PromoTarget,
+    pub code: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RedemptionDto {
+    pub customer: uuid::Uuid,
+    pub order: Option<uuid::Uuid>,
+    pub booking: Option<uuid::Uuid>,
+    pub ticket_purchase: Option<uuid::Uuid>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SavePromoDto {
+    pub organization: uuid::Uuid,
+    pub title: String,
+    pub description: String,
+    pub code: Option<String>,
+    pub promo_type: PromoType,
+    pub applies_to: PromoTarget,
+    pub discount_rate: Decimal,
+    pub max_discount: Option<Decimal>,
+    pub min_spend: Option<Decimal>,
+    pub valid_from: DateTime<Utc>,
+    pub valid_to: DateTime<Utc>,
+    pub branches: Option<Vec<uuid::Uuid>>,
+    pub schedules: Option<Vec<uuid::Uuid>>,
+    pub categories: Option<Vec<uuid::Uuid>>,
+    pub tickets: Option<Vec<uuid::Uuid>>,
+    pub start_time: Option<NaiveTime>,
+    pub end_time: Option<NaiveTime>,
+    pub weekdays: Option<Vec<i32>>,
+    pub usage_limit: Option<i32>,
+    pub per_customer_limit: Option<i32>,
+    pub is_stackable: bool,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct PromoParams {
+    #[validate(length(min = 3, max = 100, message = "Title is invalid"))]
+    pub title: String,
+    #[validate(length(min = 3, max = 500, message = "Description is invalid"))]
+    pub description: String,
+    #[validate(length(min = 3, max = 30, message = "Code is invalid"))]
+    pub code: Option<String>,
+    #[validate(custom(function = "validate_promo_type"))]
+    pub promo_type: String,
+    #[validate(custom(function = "validate_promo_target"))]
+    pub applies_to: String,
+    #[validate(custom(function = "validate_percent_range"))]
+    pub discount_rate: Decimal,
+    #[validate(custom(function = "validate_val_range"))]
+    pub max_discount: Option<Decimal>,
+    #[validate(custom(function = "validate_val_range"))]
+    pub min_spend: Option<Decimal>,
+    pub valid_from: DateTime<Utc>,
+    pub valid_to: DateTime<Utc>,
+    #[validate(length(min = 1, max = 50, message = "Branches are invalid"))]
+    pub branches: Option<Vec<uuid::Uuid>>,
+    #[validate(length(min = 1, max = 50, message = "Schedules are invalid"))]
+    pub schedules: Option<Vec<uuid::Uuid>>,
+    #[validate(length(min = 1, max = 50, message = "Categories are invalid"))]
+    pub categories: Option<Vec<uuid::Uuid>>,
+    #[validate(length(min = 1, max = 50, message = "Tickets are invalid"))]
+    pub tickets: Option<Vec<uuid::Uuid>>,
+    pub start_time: Option<NaiveTime>,
+    pub end_time: Option<NaiveTime>,
+    #[validate(custom(function = "validate_weekdays"))]
+    pub weekdays: Option<Vec<i32>>,
+    #[validate(range(min = 1, message = "Usage limit is invalid"))]
+    pub usage_limit: Option<i32>,
+    #[validate(range(min = 1, message = "Per customer limit is invalid"))]
+    pub per_customer_limit: Option<i32>,
+    pub is_stackable: Option<bool>,
+}
+
+impl PromoParams {
+    pub fn into_dto(self, organization: uuid::Uuid) -> SavePromoDto {
+        SavePromoDto {
+            organization,
+            title: self.title,
+            description: self.description,
+            code: self.code.map(|code| code.trim().to_uppercase()),
+            promo_type: self.promo_type.parse().unwrap_or(PromoType::Code),
+            applies_to: self.applies_to.parse().unwrap_or(PromoTarget::Orders),
+            discount_rate: self.discount_rate,
+            max_discount: self.max_discount,
+            min_spend: self.min_spend,
+            valid_from: self.valid_from,
+            valid_to: self.valid_to,
+            branches: self.branches,
+            schedules: self.schedules,
+            categories: self.categories,
+            tickets: self.tickets,
+            start_time: self.start_time,
+            end_time: self.end_time,
+            weekdays: self.weekdays,
+            usage_limit: self.usage_limit,
+            per_customer_limit: self.per_customer_limit,
+            is_stackable: self.is_stackable.unwrap_or(false),
+        }
+    }
+}
@@ -0,0 +1,50 @@
+use actix_web::web;
+
+use crate::{
+    app::promos::controllers::controller::{
+        add_promo, edit_promo, organization_promos, promo_redemptions, switch_promo,
+    },
+    middlewares::{auth::JwtAuthMiddleware, checker::CheckUserMiddleware},
+    AppState,
+};
+
+pub fn routes(cfg: &mut web::ServiceConfig, state: web::Data<AppState>) {
+    cfg.service(
+        web::scope("/api/v1/promos")
+            .route(
+                "/add",
+                web::post()
+                    .to(add_promo)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "User"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/organization",
+                web::get()
+                    .to(organization_promos)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "User"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/update/{id}",
+                web::put()
+                    .to(edit_promo)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "User"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/toggle/{id}",
+                web::put()
+                    .to(switch_promo)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "User"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/redemptions/{id}",
+                web::get()
+                    .to(promo_redemptions)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "User"))
+                    .wrap(JwtAuthMiddleware),
+            ),
+    );
+}
@@ -0,0 +1,391 @@
+use chrono::Datelike;
+use sea_orm::prelude::Decimal;
+
+use crate::app::promos::models::model::{AppliedPromo, PromoContext, PromoLine, PromoOutcome};
+
+/// Whether `at` falls in the promo's weekdays and daily time window, read on the branch's
+/// clock. A window whose end is before its start runs past midnight.
+fn in_window(promo: &entity::promos::Model, ctx: &PromoContext) -> bool {
+    let at = ctx.at.with_timezone(&ctx.timezone);
+    let weekday = at.weekday().number_from_monday() as i32;
+
+    if let Some(weekdays) = &promo.weekdays {
+        if !weekdays.contains(&weekday) {
+            return false;
+        }
+    }
+
+    let time = at.time();
+
+    match (promo.start_time, promo.end_time) {
+        (Some(start), Some(end)) if start <= end => time >= start && time < end,
+        (Some(start), Some(end)) => time >= start || time < end,
+        _ => true,
+    }
+}
+
+fn in_scope(promo: &entity::promos::Model, ctx: &PromoContext) -> bool {
+    let matches = |scope: &Option<Vec<uuid::Uuid>>, value: Option<uuid::Uuid>| match scope {
+        Some(scope) => value.is_some_and(|value| scope.contains(&value)),
+        None => true,
+    };
+
+    matches(&promo.branch_ids, Some(ctx.branch))
+        && matches(&promo.schedule_ids, ctx.schedule)
+        && matches(&promo.ticket_ids, ctx.ticket)
+}
+
+/// What the promo takes off each line, or `None` when it doesn't apply to this purchase.
+fn promo_discounts(
+    promo: &entity::promos::Model,
+    ctx: &PromoContext,
+    lines: &[PromoLine],
+) -> Option<Vec<Decimal>> {
+    if !in_window(promo, ctx) || !in_scope(promo, ctx) {
+        return None;
+    }
+
+    let eligible: Vec<bool> = lines
+        .iter()
+        .map(|line| match &promo.category_ids {
+            Some(categories) => line
+                .category
+                .is_some_and(|category| categories.contains(&category)),
+            None => true,
+        })
+        .collect();
+
+    let spend: Decimal = lines
+        .iter()
+        .zip(&eligible)
+        .filter(|(_, eligible)| **eligible)
+        .map(|(line, _)| line.amount)
+        .sum();
+
+    if spend <= Decimal::ZERO || promo.min_spend.is_some_and(|min| spend < min) {
+        return None;
+    }
+
+    let mut discounts: Vec<Decimal> = lines
+        .iter()
+        .zip(&eligible)
+        .map(|(line, eligible)| match eligible {
+            true => line.amount * promo.discount_rate / Decimal::from(100),
+            false => Decimal::ZERO,
+        })
+        .collect();
+
+    let total: Decimal = discounts.iter().sum();
+
+    if let Some(max) = promo.max_discount.filter(|max| total > *max) {
+        for discount in discounts.iter_mut() {
+            *discount = *discount * max / total;
+        }
+    }
+
+    Some(discounts)
+}
+
+/// Combines the chosen promos line by line, never taking a line below zero.
+fn combine(chosen: &[(&entity::promos::Model, Vec<Decimal>)], lines: &[PromoLine]) -> PromoOutcome {
+    let mut line_discounts = vec![Decimal::ZERO; lines.len()];
+    let mut amounts = vec![Decimal::ZERO; chosen.len()];
+
+    for (index, line) in lines.iter().enumerate() {
+        let wanted: Decimal = chosen.iter().map(|(_, discounts)| discounts[index]).sum();
+
+        if wanted <= Decimal::ZERO {
+            continue;
+        }
+
+        let scale = match wanted > line.amount {
+            true => line.amount / wanted,
+            false => Decimal::ONE,
+        };
+
+        for (position, (_, discounts)) in chosen.iter().enumerate() {
+            amounts[position] += discounts[index] * scale;
+        }
+
+        line_discounts[index] = wanted.min(line.amount);
+    }
+
+    let applied: Vec<AppliedPromo> = chosen
+        .iter()
+        .zip(amounts)
+        .map(|((promo, _), amount)| AppliedPromo {
+            promo: promo.id,
+            code: promo.code.clone(),
+            title: promo.title.clone(),
+            amount: amount.round_dp(2),
+        })
+        .filter(|promo| promo.amount > Decimal::ZERO)
+        .collect();
+
+    PromoOutcome {
+        discount_amount: applied.iter().map(|promo| promo.amount).sum(),
+        applied,
+        line_discounts,
+    }
+}
+
+/// Picks the promos for a purchase from those the customer may still use.
+///
+/// Stacking policy: all stackable promos that apply are combined, and each promo that doesn't
+/// stack is taken on its own. Whichever of these gives the larger discount wins, the stack on
+/// a tie, then the earlier promo. A code the customer entered must apply to the purchase, but
+/// it can still lose out to a better automatic promotion.
+pub fn apply_promos(
+    promos: &[entity::promos::Model],
+    ctx: &PromoContext,
+    lines: &[PromoLine],
+    code: Option<&str>,
+) -> Result<PromoOutcome, String> {
+    let applicable: Vec<_> = promos
+        .iter()
+        .filter_map(|promo| promo_discounts(promo, ctx, lines).map(|discounts| (promo, discounts)))
+        .collect();
+
+    if let Some(code) = code {
+        if !applicable
+            .iter()
+            .any(|(promo, _)| promo.code.as_deref() == Some(code))
+        {
+            return Err(format!("Promo code {} does not apply here", code));
+        }
+    }
+
+    let (stack, single): (Vec<_>, Vec<_>) = applicable
+        .into_iter()
+        .partition(|(promo, _)| promo.is_stackable);
+
+    let mut best = combine(&stack, lines);
+
+    for promo in single {
+        let outcome = combine(&[promo], lines);
+
+        if outcome.discount_amount > best.discount_amount {
+            best = outcome;
+        }
+    }
+
+    Ok(best)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveTime, TimeZone, Utc};
+    use chrono_tz::Tz;
+
+    use super::*;
+    use crate::utils::fixtures::{dec, promo};
+
+    fn stackable(title: &str, rate: &str) -> entity::promos::Model {
+        entity::promos::Model {
+            is_stackable: true,
+            ..promo(title, rate)
+        }
+    }
+
+    fn context() -> PromoContext {
+        PromoContext {
+            branch: uuid::Uuid::new_v4(),
+            schedule: None,
+            ticket: None,
+            // A Wednesday
+            at: Utc.with_ymd_and_hms(2025, 5, 14, 12, 0, 0).unwrap(),
+            timezone: Tz::UTC,
+        }
+    }
+
+    fn line(amount: &str) -> PromoLine {
+        PromoLine {
+            category: None,
+            amount: dec(amount),
+        }
+    }
+
+    fn titles(outcome: &PromoOutcome) -> Vec<&str> {
+        outcome
+            .applied
+            .iter()
+            .map(|promo| promo.title.as_str())
+            .collect()
+    }
+
+    fn time(hour: u32) -> Option<NaiveTime> {
+        NaiveTime::from_hms_opt(hour, 0, 0)
+    }
+
+    #[test]
+    fn combines_stackable_promos() {
+        let promos = [stackable("A", "10"), stackable("B", "5")];
+
+        let outcome = apply_promos(&promos, &context(), &[line("100")], None).unwrap();
+
+        assert_eq!(outcome.discount_amount, dec("15"));
+        assert_eq!(titles(&outcome), vec!["A", "B"]);
+    }
+
+    #[test]
+    fn a_better_single_promo_beats_the_stack() {
+        let promos = [stackable("A", "10"), stackable("B", "5"), promo("C", "20")];
+
+        let outcome = apply_promos(&promos, &context(), &[line("100")], None).unwrap();
+
+        assert_eq!(outcome.discount_amount, dec("20"));
+        assert_eq!(titles(&outcome), vec!["C"]);
+    }
+
+    #[test]
+    fn the_stack_wins_a_tie() {
+        let promos = [promo("C", "15"), stackable("A", "10"), stackable("B", "5")];
+
+        let outcome = apply_promos(&promos, &context(), &[line("100")], None).unwrap();
+
+        assert_eq!(titles(&outcome), vec!["A", "B"]);
+    }
+
+    #[test]
+    fn never_takes_a_line_below_zero() {
+        let promos = [stackable("A", "60"), stackable("B", "60")];
+
+        let outcome = apply_promos(&promos, &context(), &[line("100")], None).unwrap();
+
+        assert_eq!(outcome.line_discounts, vec![dec("100")]);
+        assert_eq!(outcome.applied[0].amount, dec("50"));
+        assert_eq!(outcome.applied[1].amount, dec("50"));
+    }
+
+    #[test]
+    fn combine_keeps_each_lines_discount() {
+        let a = stackable("A", "10");
+        let b = stackable("B", "10");
+        let lines = [line("40"), line("60")];
+
+        let outcome = combine(
+            &[
+                (&a, vec![dec("4"), dec("6")]),
+                (&b, vec![Decimal::ZERO, dec("6")]),
+            ],
+            &lines,
+        );
+
+        assert_eq!(outcome.line_discounts, vec![dec("4"), dec("12")]);
+        assert_eq!(outcome.discount_amount, dec("16"));
+    }
+
+    #[test]
+    fn caps_the_discount_across_lines() {
+        let capped = entity::promos::Model {
+            max_discount: Some(dec("10")),
+            ..promo("A", "50")
+        };
+
+        let outcome = apply_promos(&[capped], &context(), &[line("20"), line("60")], None).unwrap();
+
+        assert_eq!(outcome.discount_amount, dec("10"));
+        assert_eq!(outcome.line_discounts, vec![dec("2.5"), dec("7.5")]);
+    }
+
+    #[test]
+    fn only_discounts_its_categories_and_counts_their_spend() {
+        let food = uuid::Uuid::new_v4();
+        let scoped = entity::promos::Model {
+            category_ids: Some(vec![food]),
+            min_spend: Some(dec("50")),
+            ..promo("Food", "10")
+        };
+        let lines = [
+            PromoLine {
+                category: Some(food),
+                amount: dec("40"),
+            },
+            line("100"),
+        ];
+
+        let outcome =
+            apply_promos(std::slice::from_ref(&scoped), &context(), &lines, None).unwrap();
+
+        assert!(outcome.applied.is_empty());
+
+        let lines = [
+            PromoLine {
+                category: Some(food),
+                amount: dec("60"),
+            },
+            line("100"),
+        ];
+
+        let outcome = apply_promos(&[scoped], &context(), &lines, None).unwrap();
+
+        assert_eq!(outcome.line_discounts, vec![dec("6"), Decimal::ZERO]);
+    }
+
+    #[test]
+    fn refuses_a_code_that_does_not_apply() {
+        let coded = entity::promos::Model {
+            code: Some("SUMMER".to_string()),
+            min_spend: Some(dec("500")),
+            ..promo("Summer", "10")
+        };
+
+        assert_eq!(
+            apply_promos(&[coded], &context(), &[line("100")], Some("SUMMER")).unwrap_err(),
+            "Promo code SUMMER does not apply here"
+        );
+    }
+
+    #[test]
+    fn reads_the_window_on_the_branch_clock() {
+        let happy_hour = entity::promos::Model {
+            start_time: time(12),
+            end_time: time(13),
+            ..promo("Happy Hour", "10")
+        };
+
+        assert!(in_window(&happy_hour, &context()));
+        assert!(!in_window(
+            &happy_hour,
+            &PromoContext {
+                timezone: "Africa/Lagos".parse().unwrap(),
+                ..context()
+            }
+        ));
+    }
+
+    #[test]
+    fn windows_can_run_past_midnight() {
+        let late = entity::promos::Model {
+            start_time: time(22),
+            end_time: time(2),
+            ..promo("Late", "10")
+        };
+        let at = |hour| PromoContext {
+            at: Utc.with_ymd_and_hms(2025, 5, 14, hour, 0, 0).unwrap(),
+            ..context()
+        };
+
+        assert!(in_window(&late, &at(23)));
+        assert!(in_window(&late, &at(1)));
+        assert!(!in_window(&late, &at(2)));
+        assert!(!in_window(&late, &at(12)));
+    }
+
+    #[test]
+    fn keeps_to_its_weekdays() {
+        let weekend = entity::promos::Model {
+            weekdays: Some(vec![6, 7]),
+            ..promo("Weekend", "10")
+        };
+
+        assert!(!in_window(&weekend, &context()));
+        assert!(in_window(
+            &weekend,
+            &PromoContext {
+                at: Utc.with_ymd_and_hms(2025, 5, 17, 12, 0, 0).unwrap(),
+                ..context()
+            }
+        ));
+    }
+}
@@ -0,0 +1,692 @@
+use std::collections::HashMap;
+
+use actix_web::web;
+use sea_orm::{
+    prelude::Decimal, ActiveModelTrait, ActiveValue, ColumnTrait, Condition, ConnectionTrait,
+    DbErr, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, Set,
+    TransactionTrait,
+};
+
+use crate::{
+    app::{
+        branch::dtos::dto::get_branch_timezone,
+        inventory::dtos::dto::adjust_stock,
+        purchasing::{
+            models::model::{
+                DeliveryCalendarEntry, PoStatus, PurchaseLineDto, PurchaseOrderDetails,
+                ReceiveGoodsDto, SavePurchaseOrderDto, SaveSupplierDto, SupplierPerformance,
+            },
+            services::service::{delivery_calendar, purchase_order_email, supplier_performance},
+        },
+    },
+    mailer::{
+        mailer::send_mail,
+        model::{MailerModel, MsgType},
+    },
+    AppState,
+};
+
+async fn find_supplier<C: ConnectionTrait>(
+    supplier: uuid::Uuid,
+    branch: uuid::Uuid,
+    conn: &C,
+) -> Result<entity::suppliers::Model, DbErr> {
+    entity::suppliers::Entity::find_by_id(supplier)
+        .filter(entity::suppliers::Column::BranchId.eq(branch))
+        .one(conn)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Supplier not found".into()))
+}
+
+async fn find_purchase_order<C: ConnectionTrait>(
+    id: uuid::Uuid,
+    branch: uuid::Uuid,
+    conn: &C,
+) -> Result<entity::purchase_orders::Model, DbErr> {
+    entity::purchase_orders::Entity::find_by_id(id)
+        .filter(entity::purchase_orders::Column::BranchId.eq(branch))
+        .one(conn)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Purchase order not found".into()))
+}
+
+/// Same as `find_purchase_order`, holding the order's row until the transaction ends so
+/// receipts and status changes on it run one at a time.
+async fn lock_purchase_order<C: ConnectionTrait>(
+    id: uuid::Uuid,
+    branch: uuid::Uuid,
+    conn: &C,
+) -> Result<entity::purchase_orders::Model, DbErr> {
+    entity::purchase_orders::Entity::find_by_id(id)
+        .filter(entity::purchase_orders::Column::BranchId.eq(branch))
+        .lock_exclusive()
+        .one(conn)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Purchase order not found".into()))
+}
+
+fn order_status(order: &entity::purchase_orders::Model) -> Result<PoStatus, DbErr> {
+    order
+        .po_status
+        .parse::<PoStatus>()
+        .map_err(|_| DbErr::Custom(format!("Unknown purchase order status {}", order.po_status)))
+}
+
+/// PO number for the supplier, e.g. `PO-250506-0003`: the date followed by the branch's running
+/// count of purchase orders for the day. The caller must hold the branch row lock.
+async fn next_po_number<C: ConnectionTrait>(branch: uuid::Uuid, conn: &C) -> Result<String, DbErr> {
+    let now = chrono::Utc::now();
+    let midnight = now.date_naive().and_hms_opt(0, 0, 0).unwrap_or_default();
+
+    let count = entity::purchase_orders::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::purchase_orders::Column::BranchId.eq(branch))
+                .add(entity::purchase_orders::Column::CreatedAt.gte(midnight.and_utc())),
+        )
+        .count(conn)
+        .await?;
+
+    Ok(format!("PO-{}-{:04}", now.format("%y%m%d"), count + 1))
+}
+
+/// Checks every line is a drink the supplier stocks the branch with, and writes the lines of
+/// the order. Returns the order's total.
+async fn insert_lines<C: ConnectionTrait>(
+    order: uuid::Uuid,
+    branch: uuid::Uuid,
+    supplier: uuid::Uuid,
+    lines: Vec<PurchaseLineDto>,
+    conn: &C,
+) -> Result<Decimal, DbErr> {
+    let drinks: HashMap<uuid::Uuid, entity::stock_drinks::Model> =
+        entity::stock_drinks::Entity::find()
+            .filter(
+                Condition::all()
+                    .add(entity::stock_drinks::Column::Id.is_in(lines.iter().map(|l| l.drink)))
+                    .add(entity::stock_drinks::Column::BranchId.eq(branch)),
+            )
+            .all(conn)
+            .await?
+            .into_iter()
+            .map(|drink| (drink.id, drink))
+            .collect();
+
+    let mut total = Decimal::ZERO;
+    let mut models = Vec::with_capacity(lines.len());
+
+    for line in lines {
+        let drink = drinks
+            .get(&line.drink)
+            .ok_or_else(|| DbErr::RecordNotFound("Drink not found".into()))?;
+
+        if drink.supplier_id != supplier {
+            return Err(DbErr::Custom(format!(
+                "{} is not stocked from this supplier",
+                drink.name
+            )));
+        }
+
+        total += line.unit_cost * Decimal::from(line.quantity);
+
+        models.push(entity::purchase_order_lines::ActiveModel {
+            purchase_order_id: Set(order),
+            stock_drink_id: Set(line.drink),
+            quantity_ordered: Set(line.quantity),
+            unit_cost: Set(line.unit_cost),
+            ..Default::default()
+        });
+    }
+
+    entity::purchase_order_lines::Entity::insert_many(models)
+        .exec(conn)
+        .await
+        .map_err(|err| {
+            eprintln!("Database insert error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    Ok(total)
+}
+
+pub async fn save_purchase_order(
+    data: SavePurchaseOrderDto,
+    state: &web::Data<AppState>,
+) -> Result<entity::purchase_orders::Model, DbErr> {
+    let txn = state.pg_db.get_ref().begin().await?;
+
+    // Serializes purchase order creation per branch so PO numbers stay unique
+    entity::branches::Entity::find_by_id(data.branch)
+        .lock_exclusive()
+        .one(&txn)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Branch not found".into()))?;
+
+    find_supplier(data.supplier, data.branch, &txn).await?;
+
+    let po_number = next_po_number(data.branch, &txn).await?;
+
+    let order = entity::purchase_orders::ActiveModel {
+        organization_id: Set(data.organization),
+        branch_id: Set(data.branch),
+        supplier_id: Set(data.supplier),
+        po_number: Set(po_number),
+        po_status: Set(PoStatus::Draft.as_str().to_string()),
+        expected_on: Set(data.expected_on),
+        notes: Set(data.notes),
+        created_by: Set(data.employee),
+        ..Default::default()
+    };
+
+    let order = ActiveModelTrait::insert(order, &txn).await.map_err(|err| {
+        eprintln!("Database insert error: {}", err);
+        DbErr::Custom(err.to_string())
+    })?;
+
+    let total = insert_lines(order.id, data.branch, data.supplier, data.lines, &txn).await?;
+
+    let mut model: entity::purchase_orders::ActiveModel = order.into();
+
+    model.total_amount = ActiveValue::Set(total);
+
+    let result = ActiveModelTrait::update(model, &txn).await.map_err(|err| {
+        eprintln!("Database update error: {}", err);
+        DbErr::Custom(err.to_string())
+    })?;
+
+    txn.commit().await?;
+
+    Ok(result)
+}
+
+/// Rewrites a draft purchase order and its lines. Orders already sent to the supplier can only
+/// be received or cancelled.
+pub async fn update_purchase_order(
+    id: uuid::Uuid,
+    data: SavePurchaseOrderDto,
+    state: &web::Data<AppState>,
+) -> Result<entity::purchase_orders::Model, DbErr> {
+    let txn = state.pg_db.get_ref().begin().await?;
+
+    let order = lock_purchase_order(id, data.branch, &txn).await?;
+
+    if order_status(&order)? != PoStatus::Draft {
+        return Err(DbErr::Custom(
+            "Only draft purchase orders can be edited".to_string(),
+        ));
+    }
+
+    find_supplier(data.supplier, data.branch, &txn).await?;
+
+    entity::purchase_order_lines::Entity::delete_many()
+        .filter(entity::purchase_order_lines::Column::PurchaseOrderId.eq(order.id))
+        .exec(&txn)
+        .await
+        .map_err(|err| {
+            eprintln!("Database delete error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    let total = insert_lines(order.id, data.branch, data.supplier, data.lines, &txn).await?;
+
+    let mut model: entity::purchase_orders::ActiveModel = order.into();
+
+    model.supplier_id = ActiveValue::Set(data.supplier);
+    model.expected_on = ActiveValue::Set(data.expected_on);
+    model.notes = ActiveValue::Set(data.notes);
+    model.total_amount = ActiveValue::Set(total);
+    model.updated_at = ActiveValue::Set(chrono::Utc::now().into());
+
+    let result = ActiveModelTrait::update(model, &txn).await.map_err(|err| {
+        eprintln!("Database update error: {}", err);
+        DbErr::Custom(err.to_string())
+    })?;
+
+    txn.commit().await?;
+
+    Ok(result)
+}
+
+/// Emails a draft purchase order to its supplier and marks it sent. The order stays a draft
+/// when the email could not be sent.
+pub async fn send_purchase_order(
+    id: uuid::Uuid,
+    branch: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<entity::purchase_orders::Model, DbErr> {
+    let txn = state.pg_db.get_ref().begin().await?;
+
+    let order = lock_purchase_order(id, branch, &txn).await?;
+
+    if order_status(&order)? != PoStatus::Draft {
+        return Err(DbErr::Custom(
+            "Purchase order has already been sent".to_string(),
+        ));
+    }
+
+    let branch = entity::branches::Entity::find_by_id(branch)
+        .one(&txn)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Branch not found".into()))?;
+
+    let supplier = find_supplier(order.supplier_id, branch.id, &txn).await?;
+
+    let lines = entity::purchase_order_lines::Entity::find()
+        .filter(entity::purchase_order_lines::Column::PurchaseOrderId.eq(order.id))
+        .find_also_related(entity::stock_drinks::Entity)
+        .all(&txn)
+        .await
+        .map_err(|err| {
+            eprintln!("Database retrieval error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?
+        .into_iter()
+        .map(|(line, drink)| (line, drink.map(|d| d.name).unwrap_or_default()))
+        .collect::<Vec<_>>();
+
+    let mail = MailerModel {
+        subject: format!("Purchase order {} from {}", order.po_number, branch.name),
+        body: purchase_order_email(&branch, &supplier, &order, &lines),
+        receiver: supplier.email.clone(),
+        msg_type: MsgType::TEXT,
+    };
+
+    send_mail(mail).await.map_err(|err| {
+        eprintln!("Purchase order email error: {}", err);
+        DbErr::Custom(format!("Could not email the supplier: {}", err))
+    })?;
+
+    let now = chrono::Utc::now();
+    let mut model: entity::purchase_orders::ActiveModel = order.into();
+
+    model.po_status = ActiveValue::Set(PoStatus::Sent.as_str().to_string());
+    model.sent_at = ActiveValue::Set(Some(now.into()));
+    model.updated_at = ActiveValue::Set(now.into());
+
+    let result = ActiveModelTrait::update(model, &txn).await.map_err(|err| {
+        eprintln!("Database update error: {}", err);
+        DbErr::Custom(err.to_string())
+    })?;
+
+    txn.commit().await?;
+
+    Ok(result)
+}
+
+/// Cancels a purchase order that hasn't been fully received. Goods already received against
+/// it stay in stock.
+pub async fn cancel_purchase_order(
+    id: uuid::Uuid,
+    branch: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<entity::purchase_orders::Model, DbErr> {
+    let txn = state.pg_db.get_ref().begin().await?;
+
+    let order = lock_purchase_order(id, branch, &txn).await?;
+
+    if matches!(
+        order_status(&order)?,
+        PoStatus::Received | PoStatus::Cancelled
+    ) {
+        return Err(DbErr::Custom(format!(
+            "Purchase order is already {}",
+            order.po_status.to_lowercase()
+        )));
+    }
+
+    let mut model: entity::purchase_orders::ActiveModel = order.into();
+
+    model.po_status = ActiveValue::Set(PoStatus::Cancelled.as_str().to_string());
+    model.updated_at = ActiveValue::Set(chrono::Utc::now().into());
+
+    let result = ActiveModelTrait::update(model, &txn).await.map_err(|err| {
+        eprintln!("Database update error: {}", err);
+        DbErr::Custom(err.to_string())
+    })?;
+
+    txn.commit().await?;
+
+    Ok(result)
+}
+
+/// Books goods delivered against a sent purchase order: each line's units go on the shelf and
+/// a receipt records what was paid for them, with the variance from the PO's unit cost. The
+/// order is received once every line is, partially received until then.
+pub async fn receive_purchase_order(
+    id: uuid::Uuid,
+    data: ReceiveGoodsDto,
+    state: &web::Data<AppState>,
+) -> Result<PurchaseOrderDetails, DbErr> {
+    let txn = state.pg_db.get_ref().begin().await?;
+
+    let order = lock_purchase_order(id, data.branch, &txn).await?;
+
+    if !order_status(&order)?.is_receivable() {
+        return Err(DbErr::Custom(format!(
+            "Goods can't be received against a {} purchase order",
+            order.po_status.to_lowercase()
+        )));
+    }
+
+    let mut lines: HashMap<uuid::Uuid, entity::purchase_order_lines::Model> =
+        entity::purchase_order_lines::Entity::find()
+            .filter(entity::purchase_order_lines::Column::PurchaseOrderId.eq(order.id))
+            .all(&txn)
+            .await?
+            .into_iter()
+            .map(|line| (line.id, line))
+            .collect();
+
+    let mut receipts = Vec::with_capacity(data.lines.len());
+
+    for received in data.lines {
+        let line = lines
+            .get_mut(&received.line)
+            .ok_or_else(|| DbErr::RecordNotFound("Purchase order line not found".into()))?;
+
+        let remaining = line.quantity_ordered - line.quantity_received;
+
+        if received.quantity > remaining {
+            return Err(DbErr::Custom(format!(
+                "Only {} left to receive on a line",
+                remaining
+            )));
+        }
+
+        let unit_cost = received.unit_cost.unwrap_or(line.unit_cost);
+
+        adjust_stock(line.stock_drink_id, received.quantity, &txn).await?;
+
+        line.quantity_received += received.quantity;
+
+        let mut model: entity::purchase_order_lines::ActiveModel = line.clone().into();
+
+        model.quantity_received = ActiveValue::Set(line.quantity_received);
+        model.updated_at = ActiveValue::Set(chrono::Utc::now().into());
+
+        ActiveModelTrait::update(model, &txn).await.map_err(|err| {
+            eprintln!("Database update error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+        receipts.push(entity::goods_receipts::ActiveModel {
+            purchase_order_id: Set(order.id),
+            purchase_order_line_id: Set(line.id),
+            quantity: Set(received.quantity),
+            unit_cost: Set(unit_cost),
+            price_variance: Set((unit_cost - line.unit_cost) * Decimal::from(received.quantity)),
+            received_by: Set(data.employee),
+            ..Default::default()
+        });
+    }
+
+    entity::goods_receipts::Entity::insert_many(receipts)
+        .exec(&txn)
+        .await
+        .map_err(|err| {
+            eprintln!("Database insert error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    let complete = lines
+        .values()
+        .all(|line| line.quantity_received >= line.quantity_ordered);
+
+    let now = chrono::Utc::now();
+    let mut model: entity::purchase_orders::ActiveModel = order.into();
+
+    if complete {
+        model.po_status = ActiveValue::Set(PoStatus::Received.as_str().to_string());
+        model.received_at = ActiveValue::Set(Some(now.into()));
+    } else {
+        model.po_status = ActiveValue::Set(PoStatus::Partial.as_str().to_string());
+    }
+    model.updated_at = ActiveValue::Set(now.into());
+
+    let order = ActiveModelTrait::update(model, &txn).await.map_err(|err| {
+        eprintln!("Database update error: {}", err);
+        DbErr::Custom(err.to_string())
+    })?;
+
+    let details = purchase_order_details(order, &txn).await?;
+
+    txn.commit().await?;
+
+    Ok(details)
+}
+
+async fn purchase_order_details<C: ConnectionTrait>(
+    order: entity::purchase_orders::Model,
+    conn: &C,
+) -> Result<PurchaseOrderDetails, DbErr> {
+    let lines = entity::purchase_order_lines::Entity::find()
+        .filter(entity::purchase_order_lines::Column::PurchaseOrderId.eq(order.id))
+        .order_by_asc(entity::purchase_order_lines::Column::CreatedAt)
+        .all(conn)
+        .await
+        .map_err(|err| {
+            eprintln!("Database retrieval error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    let receipts = entity::goods_receipts::Entity::find()
+        .filter(entity::goods_receipts::Column::PurchaseOrderId.eq(order.id))
+        .order_by_asc(entity::goods_receipts::Column::CreatedAt)
+        .all(conn)
+        .await
+        .map_err(|err| {
+            eprintln!("Database retrieval error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    let price_variance = receipts.iter().map(|receipt| receipt.price_variance).sum();
+
+    Ok(PurchaseOrderDetails {
+        order,
+        lines,
+        receipts,
+        price_variance,
+    })
+}
+
+pub async fn get_purchase_order(
+    id: uuid::Uuid,
+    branch: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<PurchaseOrderDetails, DbErr> {
+    let order = find_purchase_order(id, branch, state.pg_db.get_ref()).await?;
+
+    purchase_order_details(order, state.pg_db.get_ref()).await
+}
+
+pub async fn get_branch_purchase_orders(
+    branch: uuid::Uuid,
+    status: Option<PoStatus>,
+    state: &web::Data<AppState>,
+) -> Result<Vec<entity::purchase_orders::Model>, DbErr> {
+    let mut condition = Condition::all().add(entity::purchase_orders::Column::BranchId.eq(branch));
+
+    if let Some(status) = status {
+        condition = condition.add(entity::purchase_orders::Column::PoStatus.eq(status.as_str()));
+    }
+
+    let orders = entity::purchase_orders::Entity::find()
+        .filter(condition)
+        .order_by_desc(entity::purchase_orders::Column::CreatedAt)
+        .all(state.pg_db.get_ref())
+        .await
+        .map_err(|err| {
+            eprintln!("Database retrieval error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    Ok(orders)
+}
+
+/// Sets the weekdays, Monday being 1, a supplier usually delivers to the branch on. `None`
+/// clears them.
+pub async fn set_delivery_days(
+    supplier: uuid::Uuid,
+    branch: uuid::Uuid,
+    weekdays: Option<Vec<i32>>,
+    state: &web::Data<AppState>,
+) -> Result<entity::suppliers::Model, DbErr> {
+    let supplier = find_supplier(supplier, branch, state.pg_db.get_ref()).await?;
+
+    let weekdays = weekdays.map(|mut weekdays| {
+        weekdays.sort_unstable();
+        weekdays.dedup();
+        weekdays
+    });
+
+    let mut model: entity::suppliers::ActiveModel = supplier.into();
+
+    model.delivery_weekdays = ActiveValue::Set(weekdays);
+    model.updated_at = ActiveValue::Set(chrono::Utc::now().into());
+
+    let result = ActiveModelTrait::update(model, state.pg_db.get_ref())
+        .await
+        .map_err(|err| {
+            eprintln!("Database update error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    Ok(result)
+}
+
+pub async fn insert_supplier<C: ConnectionTrait>(
+    data: SaveSupplierDto,
+    conn: &C,
+) -> Result<entity::suppliers::Model, DbErr> {
+    let supplier = entity::suppliers::ActiveModel {
+        organization_id: Set(data.organization),
+        branch_id: Set(data.branch),
+        name: Set(data.name),
+        contact: Set(data.contact),
+        email: Set(data.email),
+        // Unused since delivery weekdays, but required and checked to be in the past
+        delivery_dates: Set((chrono::Utc::now() - chrono::Duration::days(1)).into()),
+        delivery_weekdays: Set(data.delivery_weekdays),
+        ..Default::default()
+    };
+
+    ActiveModelTrait::insert(supplier, conn)
+        .await
+        .map_err(|err| {
+            eprintln!("Database insert error: {}", err);
+            DbErr::Custom(err.to_string())
+        })
+}
+
+pub async fn rewrite_supplier<C: ConnectionTrait>(
+    supplier: entity::suppliers::Model,
+    data: SaveSupplierDto,
+    conn: &C,
+) -> Result<entity::suppliers::Model, DbErr> {
+    let mut model: entity::suppliers::ActiveModel = supplier.into();
+
+    model.name = ActiveValue::Set(data.name);
+    model.contact = ActiveValue::Set(data.contact);
+    model.email = ActiveValue::Set(data.email);
+    model.delivery_weekdays = ActiveValue::Set(data.delivery_weekdays);
+    model.updated_at = ActiveValue::Set(chrono::Utc::now().into());
+
+    ActiveModelTrait::update(model, conn).await.map_err(|err| {
+        eprintln!("Database update error: {}", err);
+        DbErr::Custom(err.to_string())
+    })
+}
+
+/// The branch's deliveries due over the next `days` days, starting today on the venue's clock.
+pub async fn get_delivery_calendar(
+    branch: uuid::Uuid,
+    days: i64,
+    state: &web::Data<AppState>,
+) -> Result<Vec<DeliveryCalendarEntry>, DbErr> {
+    let suppliers = entity::suppliers::Entity::find()
+        .filter(entity::suppliers::Column::BranchId.eq(branch))
+        .order_by_asc(entity::suppliers::Column::Name)
+        .all(state.pg_db.get_ref())
+        .await
+        .map_err(|err| {
+            eprintln!("Database retrieval error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    let orders = entity::purchase_orders::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::purchase_orders::Column::BranchId.eq(branch))
+                .add(
+                    entity::purchase_orders::Column::PoStatus
+                        .is_in([PoStatus::Sent.as_str(), PoStatus::Partial.as_str()]),
+                ),
+        )
+        .order_by_asc(entity::purchase_orders::Column::PoNumber)
+        .all(state.pg_db.get_ref())
+        .await
+        .map_err(|err| {
+            eprintln!("Database retrieval error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    let timezone = get_branch_timezone(branch, state.pg_db.get_ref()).await?;
+    let today = chrono::Utc::now().with_timezone(&timezone).date_naive();
+
+    Ok(delivery_calendar(&suppliers, &orders, today, days))
+}
+
+/// Lead time, fill rate and price variance of each of the branch's suppliers, over every
+/// purchase order sent to them.
+pub async fn get_supplier_performance(
+    branch: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<Vec<SupplierPerformance>, DbErr> {
+    let suppliers = entity::suppliers::Entity::find()
+        .filter(entity::suppliers::Column::BranchId.eq(branch))
+        .order_by_asc(entity::suppliers::Column::Name)
+        .all(state.pg_db.get_ref())
+        .await
+        .map_err(|err| {
+            eprintln!("Database retrieval error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    let orders = entity::purchase_orders::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::purchase_orders::Column::BranchId.eq(branch))
+                .add(entity::purchase_orders::Column::SentAt.is_not_null()),
+        )
+        .all(state.pg_db.get_ref())
+        .await
+        .map_err(|err| {
+            eprintln!("Database retrieval error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    let ids: Vec<uuid::Uuid> = orders.iter().map(|order| order.id).collect();
+
+    let lines = entity::purchase_order_lines::Entity::find()
+        .filter(entity::purchase_order_lines::Column::PurchaseOrderId.is_in(ids.clone()))
+        .all(state.pg_db.get_ref())
+        .await
+        .map_err(|err| {
+            eprintln!("Database retrieval error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    let receipts = entity::goods_receipts::Entity::find()
+        .filter(entity::goods_receipts::Column::PurchaseOrderId.is_in(ids))
+        .all(state.pg_db.get_ref())
+        .await
+        .map_err(|err| {
+            eprintln!("Database retrieval error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    Ok(suppliers
+        .iter()
+        .map(|supplier| supplier_performance(supplier, &orders, &lines, &receipts))
+        .collect())
+}
@@ -0,0 +1,5 @@
+pub mod models;
+pub mod dtos;
+pub mod controllers;
+pub mod routes;
+pub mod services;
\ No newline at end of file
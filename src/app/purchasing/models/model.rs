@@ -0,0 +1,259 @@
+use std::str::FromStr;
+
+use chrono::NaiveDate;
+use sea_orm::prelude::Decimal;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::libs::validator::{
+    validate_cash_amount, validate_contact, validate_po_status, validate_weekdays,
+};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum PoStatus {
+    Draft,
+    Sent,
+    Partial,
+    Received,
+    Cancelled,
+}
+
+impl PoStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PoStatus::Draft => "DRAFT",
+            PoStatus::Sent => "SENT",
+            PoStatus::Partial => "PARTIAL",
+            PoStatus::Received => "RECEIVED",
+            PoStatus::Cancelled => "CANCELLED",
+        }
+    }
+
+    /// Whether goods can still be received against the order.
+    pub fn is_receivable(&self) -> bool {
+        matches!(self, PoStatus::Sent | PoStatus::Partial)
+    }
+}
+
+impl FromStr for PoStatus {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_uppercase().as_str() {
+            "DRAFT" => Ok(PoStatus::Draft),
+            "SENT" => Ok(PoStatus::Sent),
+            "PARTIAL" => Ok(PoStatus::Partial),
+            "RECEIVED" => Ok(PoStatus::Received),
+            "CANCELLED" => Ok(PoStatus::Cancelled),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PurchaseLineDto {
+    pub drink: uuid::Uuid,
+    pub quantity: i32,
+    pub unit_cost: Decimal,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SavePurchaseOrderDto {
+    pub organization: uuid::Uuid,
+    pub branch: uuid::Uuid,
+    pub employee: uuid::Uuid,
+    pub supplier: uuid::Uuid,
+    pub expected_on: Option<NaiveDate>,
+    pub notes: Option<String>,
+    pub lines: Vec<PurchaseLineDto>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct PurchaseLineParams {
+    pub drink: uuid::Uuid,
+    #[validate(range(min = 1, max = 100000, message = "Quantity is invalid"))]
+    pub quantity: i32,
+    #[validate(custom(function = "validate_cash_amount"))]
+    pub unit_cost: Decimal,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct PurchaseOrderParams {
+    pub supplier: uuid::Uuid,
+    pub expected_on: Option<NaiveDate>,
+    #[validate(length(max = 500, message = "Notes are too long"))]
+    pub notes: Option<String>,
+    #[validate(length(min = 1, max = 100, message = "Lines are invalid"), nested)]
+    pub lines: Vec<PurchaseLineParams>,
+}
+
+impl PurchaseOrderParams {
+    pub fn into_dto(
+        self,
+        organization: uuid::Uuid,
+        branch: uuid::Uuid,
+        employee: uuid::Uuid,
+    ) -> SavePurchaseOrderDto {
+        SavePurchaseOrderDto {
+            organization,
+            branch,
+            employee,
+            supplier: self.supplier,
+            expected_on: self.expected_on,
+            notes: self.notes,
+            lines: self
+                .lines
+                .into_iter()
+                .map(|line| PurchaseLineDto {
+                    drink: line.drink,
+                    quantity: line.quantity,
+                    unit_cost: line.unit_cost,
+                })
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ReceiveLineDto {
+    pub line: uuid::Uuid,
+    pub quantity: i32,
+    pub unit_cost: Option<Decimal>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ReceiveGoodsDto {
+    pub branch: uuid::Uuid,
+    pub employee: uuid::Uuid,
+    pub lines: Vec<ReceiveLineDto>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct ReceiveLineParams {
+    pub line: uuid::Uuid,
+    #[validate(range(min = 1, max = 100000, message = "Quantity is invalid"))]
+    pub quantity: i32,
+    #[validate(custom(function = "validate_cash_amount"))]
+    pub unit_cost: Option<Decimal>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct ReceiveGoodsParams {
+    #[validate(length(min = 1, max = 100, message = "Lines are invalid"), nested)]
+    pub lines: Vec<ReceiveLineParams>,
+}
+
+impl ReceiveGoodsParams {
+    pub fn into_dto(self, branch: uuid::Uuid, employee: uuid::Uuid) -> ReceiveGoodsDto {
+        ReceiveGoodsDto {
+            branch,
+            employee,
+            lines: self
+                .lines
+                .into_iter()
+                .map(|line| ReceiveLineDto {
+                    line: line.line,
+                    quantity: line.quantity,
+                    unit_cost: line.unit_cost,
+                })
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct PurchaseOrderQuery {
+    #[validate(custom(function = "validate_po_status"))]
+    pub status: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SaveSupplierDto {
+    pub organization: uuid::Uuid,
+    pub branch: uuid::Uuid,
+    pub name: String,
+    pub contact: String,
+    pub email: String,
+    pub delivery_weekdays: Option<Vec<i32>>,
+}
+
+/// A supplier of the branch. `delivery_weekdays` are the days it delivers on, from 1 for Monday.
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct SupplierParams {
+    #[validate(length(min = 2, max = 100, message = "Name is invalid"))]
+    pub name: String,
+    #[validate(custom(function = "validate_contact"))]
+    pub contact: String,
+    #[validate(email(message = "Email is invalid"))]
+    pub email: String,
+    #[validate(custom(function = "validate_weekdays"))]
+    pub delivery_weekdays: Option<Vec<i32>>,
+}
+
+impl SupplierParams {
+    pub fn into_dto(self, organization: uuid::Uuid, branch: uuid::Uuid) -> SaveSupplierDto {
+        SaveSupplierDto {
+            organization,
+            branch,
+            name: self.name.trim().to_string(),
+            contact: self.contact,
+            email: self.email,
+            delivery_weekdays: self.delivery_weekdays.map(|mut weekdays| {
+                weekdays.sort_unstable();
+                weekdays.dedup();
+                weekdays
+            }),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct DeliveryDaysParams {
+    #[validate(custom(function = "validate_weekdays"))]
+    pub weekdays: Option<Vec<i32>>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct DeliveryCalendarQuery {
+    #[validate(range(min = 1, max = 60, message = "Days are invalid"))]
+    pub days: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PurchaseOrderDetails {
+    pub order: entity::purchase_orders::Model,
+    pub lines: Vec<entity::purchase_order_lines::Model>,
+    pub receipts: Vec<entity::goods_receipts::Model>,
+    pub price_variance: Decimal,
+}
+
+/// A day a supplier is due at the branch, on its usual delivery day or because an open
+/// purchase order is expected then.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeliveryCalendarEntry {
+    pub date: NaiveDate,
+    pub supplier: uuid::Uuid,
+    pub supplier_name: String,
+    pub is_delivery_day: bool,
+    pub purchase_orders: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SupplierPerformance {
+    pub supplier: uuid::Uuid,
+    pub name: String,
+    pub orders_sent: usize,
+    pub orders_received: usize,
+    pub average_lead_time_days: Option<Decimal>,
+    pub fill_rate: Option<Decimal>,
+    pub price_variance: Decimal,
+}
@@ -0,0 +1,87 @@
+use actix_web::web;
+
+use crate::{
+    app::purchasing::controllers::controller::{
+        add_purchase_order, branch_purchase_orders, cancel_order, delivery_calendar, delivery_days,
+        edit_purchase_order, purchase_order_details, receive_goods, send_order,
+        supplier_performance,
+    },
+    middlewares::{auth::JwtAuthMiddleware, checker::CheckUserMiddleware},
+    AppState,
+};
+
+pub fn routes(cfg: &mut web::ServiceConfig, state: web::Data<AppState>) {
+    cfg.service(
+        web::scope("/api/v1/purchasing")
+            .route(
+                "/orders",
+                web::post()
+                    .to(add_purchase_order)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/orders",
+                web::get()
+                    .to(branch_purchase_orders)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/orders/{id}",
+                web::get()
+                    .to(purchase_order_details)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/orders/{id}",
+                web::put()
+                    .to(edit_purchase_order)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/orders/send/{id}",
+                web::post()
+                    .to(send_order)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/orders/cancel/{id}",
+                web::post()
+                    .to(cancel_order)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/orders/receive/{id}",
+                web::post()
+                    .to(receive_goods)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/suppliers/delivery-days/{id}",
+                web::put()
+                    .to(delivery_days)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/suppliers/calendar",
+                web::get()
+                    .to(delivery_calendar)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/suppliers/performance",
+                web::get()
+                    .to(supplier_performance)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            ),
+    );
+}
@@ -0,0 +1,171 @@
+use std::collections::BTreeMap;
+
+use chrono::{Datelike, NaiveDate};
+use sea_orm::prelude::Decimal;
+
+use crate::app::purchasing::models::model::{DeliveryCalendarEntry, PoStatus, SupplierPerformance};
+
+pub fn purchase_order_email(
+    branch: &entity::branches::Model,
+    supplier: &entity::suppliers::Model,
+    order: &entity::purchase_orders::Model,
+    lines: &[(entity::purchase_order_lines::Model, String)],
+) -> String {
+    let items: Vec<String> = lines
+        .iter()
+        .map(|(line, name)| {
+            format!(
+                "  - {} x {} at {:.2} = {:.2}",
+                line.quantity_ordered,
+                name,
+                line.unit_cost,
+                line.unit_cost * Decimal::from(line.quantity_ordered)
+            )
+        })
+        .collect();
+
+    let expected = match order.expected_on {
+        Some(date) => format!("Please deliver on {}.\n", date),
+        None => String::new(),
+    };
+
+    let notes = match &order.notes {
+        Some(notes) => format!("\nNotes: {}\n", notes),
+        None => String::new(),
+    };
+
+    format!(
+        "Dear {},\n\n{} would like to order the following under purchase order {}:\n\n{}\n\nTotal: {:.2}\n{}{}\nDeliver to: {}{}\n",
+        supplier.name,
+        branch.name,
+        order.po_number,
+        items.join("\n"),
+        order.total_amount,
+        expected,
+        notes,
+        branch.name,
+        branch
+            .location
+            .as_ref()
+            .map(|location| format!(", {}", location))
+            .unwrap_or_default()
+    )
+}
+
+/// Deliveries due from `from` for the next `days` days: each supplier's usual delivery days,
+/// merged with the dates open purchase orders are expected on.
+pub fn delivery_calendar(
+    suppliers: &[entity::suppliers::Model],
+    orders: &[entity::purchase_orders::Model],
+    from: NaiveDate,
+    days: i64,
+) -> Vec<DeliveryCalendarEntry> {
+    let until = from + chrono::Duration::days(days);
+    let mut calendar: BTreeMap<(NaiveDate, uuid::Uuid), DeliveryCalendarEntry> = BTreeMap::new();
+
+    for supplier in suppliers {
+        let Some(weekdays) = &supplier.delivery_weekdays else {
+            continue;
+        };
+
+        for date in from.iter_days().take_while(|date| *date < until) {
+            if weekdays.contains(&(date.weekday().number_from_monday() as i32)) {
+                calendar.insert(
+                    (date, supplier.id),
+                    DeliveryCalendarEntry {
+                        date,
+                        supplier: supplier.id,
+                        supplier_name: supplier.name.clone(),
+                        is_delivery_day: true,
+                        purchase_orders: Vec::new(),
+                    },
+                );
+            }
+        }
+    }
+
+    for order in orders {
+        let Some(date) = order
+            .expected_on
+            .filter(|date| *date >= from && *date < until)
+        else {
+            continue;
+        };
+
+        let Some(supplier) = suppliers.iter().find(|s| s.id == order.supplier_id) else {
+            continue;
+        };
+
+        calendar
+            .entry((date, supplier.id))
+            .or_insert_with(|| DeliveryCalendarEntry {
+                date,
+                supplier: supplier.id,
+                supplier_name: supplier.name.clone(),
+                is_delivery_day: false,
+                purchase_orders: Vec::new(),
+            })
+            .purchase_orders
+            .push(order.po_number.clone());
+    }
+
+    calendar.into_values().collect()
+}
+
+/// How a supplier has done on the orders sent to it. Lead time runs from sending an order to
+/// receiving the last of it, over fully received orders. Fill rate is the share of units
+/// ordered that were delivered, over every order sent, so orders cancelled before they were
+/// filled count against it.
+pub fn supplier_performance(
+    supplier: &entity::suppliers::Model,
+    orders: &[entity::purchase_orders::Model],
+    lines: &[entity::purchase_order_lines::Model],
+    receipts: &[entity::goods_receipts::Model],
+) -> SupplierPerformance {
+    let sent: Vec<_> = orders
+        .iter()
+        .filter(|order| order.supplier_id == supplier.id && order.sent_at.is_some())
+        .collect();
+
+    let lead_times: Vec<Decimal> = sent
+        .iter()
+        .filter(|order| order.po_status == PoStatus::Received.as_str())
+        .filter_map(|order| {
+            let hours = (order.received_at? - order.sent_at?).num_hours();
+            Some(Decimal::from(hours) / Decimal::from(24))
+        })
+        .collect();
+
+    let (ordered, received) = lines
+        .iter()
+        .filter(|line| sent.iter().any(|order| order.id == line.purchase_order_id))
+        .fold((0i64, 0i64), |(ordered, received), line| {
+            (
+                ordered + i64::from(line.quantity_ordered),
+                received + i64::from(line.quantity_received),
+            )
+        });
+
+    let price_variance = receipts
+        .iter()
+        .filter(|receipt| {
+            sent.iter()
+                .any(|order| order.id == receipt.purchase_order_id)
+        })
+        .map(|receipt| receipt.price_variance)
+        .sum();
+
+    SupplierPerformance {
+        supplier: supplier.id,
+        name: supplier.name.clone(),
+        orders_sent: sent.len(),
+        orders_received: lead_times.len(),
+        average_lead_time_days: (!lead_times.is_empty()).then(|| {
+            (lead_times.iter().sum::<Decimal>() / Decimal::from(lead_times.len())).round_dp(1)
+        }),
+        fill_rate: (ordered > 0).then(|| {
+            (Decimal::from(received) * Decimal::from(100) / Decimal::from(ordered)).round_dp(1)
+        }),
+        price_variance,
+    }
+}
@@ -0,0 +1,370 @@
+use std::sync::Arc;
+
+use actix_web::{http::header, web, HttpMessage, HttpRequest, HttpResponse};
+use serde_json::json;
+
+use crate::{
+    app::{
+        customers::{dtos::dto::get_customer_full, models::model::CustomerResponse},
+        receipts::{
+            dtos::dto::{
+                email_receipt, get_booking_receipt, get_customer_receipt, get_customer_receipts,
+                get_order_receipt, get_receipt_document,
+            },
+            services::service::render_receipt_pdf,
+        },
+    },
+    libs::error::Error,
+    utils::{
+        json_validator::ValidatedPath,
+        models::{HttpClientResponse, PathParamsModel, ResponseCode},
+    },
+    AppState,
+};
+
+pub async fn my_receipts(
+    req: HttpRequest,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<CustomerResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Customer not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    let mut session_id = uuid::Uuid::nil();
+
+    if let Some(session_uuid) = &model.session {
+        if let Ok(s_uuid) = uuid::Uuid::parse_str(session_uuid) {
+            session_id = s_uuid
+        }
+    }
+
+    let customer = match get_customer_full(session_id, &state).await {
+        Ok(customer) => customer,
+        Err(e) => {
+            return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+                ResponseCode::Failed,
+                format!("Could not find customer: {}", e),
+                json!({}),
+            )))
+        }
+    };
+
+    match get_customer_receipts(customer.id, &state).await {
+        Ok(res) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Receipts Fetched Successfully".to_string(),
+            json!(res),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Receipts not found: {}", e),
+            json!({}),
+        ))),
+    }
+}
+
+pub async fn receipt_details(
+    req: HttpRequest,
+    params: ValidatedPath<PathParamsModel>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<CustomerResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Customer not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    let mut session_id = uuid::Uuid::nil();
+
+    if let Some(session_uuid) = &model.session {
+        if let Ok(s_uuid) = uuid::Uuid::parse_str(session_uuid) {
+            session_id = s_uuid
+        }
+    }
+
+    let customer = match get_customer_full(session_id, &state).await {
+        Ok(customer) => customer,
+        Err(e) => {
+            return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+                ResponseCode::Failed,
+                format!("Could not find customer: {}", e),
+                json!({}),
+            )))
+        }
+    };
+
+    let receipt = match get_customer_receipt(params.0.id, customer.id, &state).await {
+        Ok(receipt) => receipt,
+        Err(e) => {
+            return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+                ResponseCode::Failed,
+                format!("Receipt not found: {}", e),
+                json!({}),
+            )))
+        }
+    };
+
+    match get_receipt_document(receipt, &state).await {
+        Ok(res) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Receipt Fetched Successfully".to_string(),
+            json!(res),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Receipt not found: {}", e),
+            json!({}),
+        ))),
+    }
+}
+
+pub async fn order_receipt(
+    req: HttpRequest,
+    params: ValidatedPath<PathParamsModel>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<CustomerResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Customer not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    let mut session_id = uuid::Uuid::nil();
+
+    if let Some(session_uuid) = &model.session {
+        if let Ok(s_uuid) = uuid::Uuid::parse_str(session_uuid) {
+            session_id = s_uuid
+        }
+    }
+
+    let customer = match get_customer_full(session_id, &state).await {
+        Ok(customer) => customer,
+        Err(e) => {
+            return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+                ResponseCode::Failed,
+                format!("Could not find customer: {}", e),
+                json!({}),
+            )))
+        }
+    };
+
+    let receipt = match get_order_receipt(params.0.id, customer.id, &state).await {
+        Ok(receipt) => receipt,
+        Err(e) => {
+            return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+                ResponseCode::Failed,
+                format!("Could not get receipt: {}", e),
+                json!({}),
+            )))
+        }
+    };
+
+    match get_receipt_document(receipt, &state).await {
+        Ok(res) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Receipt Fetched Successfully".to_string(),
+            json!(res),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Receipt not found: {}", e),
+            json!({}),
+        ))),
+    }
+}
+
+pub async fn booking_receipt(
+    req: HttpRequest,
+    params: ValidatedPath<PathParamsModel>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<CustomerResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Customer not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    let mut session_id = uuid::Uuid::nil();
+
+    if let Some(session_uuid) = &model.session {
+        if let Ok(s_uuid) = uuid::Uuid::parse_str(session_uuid) {
+            session_id = s_uuid
+        }
+    }
+
+    let customer = match get_customer_full(session_id, &state).await {
+        Ok(customer) => customer,
+        Err(e) => {
+            return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+                ResponseCode::Failed,
+                format!("Could not find customer: {}", e),
+                json!({}),
+            )))
+        }
+    };
+
+    let receipt = match get_booking_receipt(params.0.id, customer.id, &state).await {
+        Ok(receipt) => receipt,
+        Err(e) => {
+            return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+                ResponseCode::Failed,
+                format!("Could not get receipt: {}", e),
+                json!({}),
+            )))
+        }
+    };
+
+    match get_receipt_document(receipt, &state).await {
+        Ok(res) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Receipt Fetched Successfully".to_string(),
+            json!(res),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Receipt not found: {}", e),
+            json!({}),
+        ))),
+    }
+}
+
+pub async fn receipt_pdf(
+    req: HttpRequest,
+    params: ValidatedPath<PathParamsModel>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<CustomerResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Customer not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    let mut session_id = uuid::Uuid::nil();
+
+    if let Some(session_uuid) = &model.session {
+        if let Ok(s_uuid) = uuid::Uuid::parse_str(session_uuid) {
+            session_id = s_uuid
+        }
+    }
+
+    let customer = match get_customer_full(session_id, &state).await {
+        Ok(customer) => customer,
+        Err(e) => {
+            return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+                ResponseCode::Failed,
+                format!("Could not find customer: {}", e),
+                json!({}),
+            )))
+        }
+    };
+
+    let receipt = match get_customer_receipt(params.0.id, customer.id, &state).await {
+        Ok(receipt) => receipt,
+        Err(e) => {
+            return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+                ResponseCode::Failed,
+                format!("Receipt not found: {}", e),
+                json!({}),
+            )))
+        }
+    };
+
+    match get_receipt_document(receipt, &state).await {
+        Ok(res) => Ok(HttpResponse::Ok()
+            .content_type("application/pdf")
+            .insert_header((
+                header::CONTENT_DISPOSITION,
+                format!(
+                    "attachment; filename=\"{}.pdf\"",
+                    res.receipt.receipt_number
+                ),
+            ))
+            .body(render_receipt_pdf(&res))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Receipt not found: {}", e),
+            json!({}),
+        ))),
+    }
+}
+
+pub async fn resend_receipt(
+    req: HttpRequest,
+    params: ValidatedPath<PathParamsModel>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<CustomerResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Customer not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    let mut session_id = uuid::Uuid::nil();
+
+    if let Some(session_uuid) = &model.session {
+        if let Ok(s_uuid) = uuid::Uuid::parse_str(session_uuid) {
+            session_id = s_uuid
+        }
+    }
+
+    let customer = match get_customer_full(session_id, &state).await {
+        Ok(customer) => customer,
+        Err(e) => {
+            return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+                ResponseCode::Failed,
+                format!("Could not find customer: {}", e),
+                json!({}),
+            )))
+        }
+    };
+
+    let receipt = match get_customer_receipt(params.0.id, customer.id, &state).await {
+        Ok(receipt) => receipt,
+        Err(e) => {
+            return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+                ResponseCode::Failed,
+                format!("Receipt not found: {}", e),
+                json!({}),
+            )))
+        }
+    };
+
+    match email_receipt(receipt, &state).await {
+        Ok(res) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Receipt Emailed Successfully".to_string(),
+            json!(res),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Could not email receipt: {}", e),
+            json!({}),
+        ))),
+    }
+}
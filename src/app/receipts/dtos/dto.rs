@@ -0,0 +1,316 @@
+use actix_web::web;
+use sea_orm::{
+    prelude::Decimal, ActiveModelTrait, ActiveValue, ColumnTrait, Condition, ConnectionTrait,
+    DbErr, EntityTrait, ModelTrait, QueryFilter, QueryOrder, Set,
+};
+
+use crate::{
+    app::receipts::{
+        models::model::ReceiptDocument,
+        services::service::{booking_lines, order_lines, render_receipt_html},
+    },
+    app::taxes::dtos::dto::get_tax_lines,
+    mailer::{
+        mailer::send_mail,
+        model::{MailerModel, MsgType},
+    },
+    AppState,
+};
+
+/// Issues the receipt for a paid order, or returns the one it already has so the receipt
+/// number never changes. Call inside the payment's transaction.
+pub async fn issue_order_receipt<C: ConnectionTrait>(
+    order: &entity::orders::Model,
+    conn: &C,
+) -> Result<entity::receipts::Model, DbErr> {
+    if let Some(receipt) = order
+        .find_related(entity::receipts::Entity)
+        .one(conn)
+        .await?
+    {
+        return Ok(receipt);
+    }
+
+    let payment = order
+        .payment_id
+        .ok_or_else(|| DbErr::Custom("Order has not been paid".to_string()))?;
+
+    let items = order
+        .find_related(entity::order_items::Entity)
+        .all(conn)
+        .await?;
+
+    let subtotal: Decimal = order_lines(&items).iter().map(|line| line.amount).sum();
+    let tip_amount = order.tip_amount.unwrap_or_default();
+
+    let receipt = entity::receipts::ActiveModel {
+        organization_id: Set(order.organization_id),
+        branch_id: Set(order.branch_id),
+        customer_id: Set(order.customer_id),
+        order_id: Set(Some(order.id)),
+        transaction_id: Set(Some(payment)),
+        subtotal: Set(subtotal),
+        discount_amount: Set(order.discount_amount),
+        tax_amount: Set(order.tax_amount),
+        included_tax_amount: Set(order.included_tax_amount),
+        service_charge: Set(order.service_charge),
+        tip_amount: Set(tip_amount),
+        total_amount: Set(order.total_amount),
+        ..Default::default()
+    };
+
+    let result = ActiveModelTrait::insert(receipt, conn)
+        .await
+        .map_err(|err| {
+            eprintln!("Database insert error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    Ok(result)
+}
+
+/// Issues the receipt for a booking, or returns the one it already has.
+pub async fn issue_booking_receipt<C: ConnectionTrait>(
+    booking: &entity::bookings::Model,
+    conn: &C,
+) -> Result<entity::receipts::Model, DbErr> {
+    if let Some(receipt) = booking
+        .find_related(entity::receipts::Entity)
+        .one(conn)
+        .await?
+    {
+        return Ok(receipt);
+    }
+
+    let receipt = entity::receipts::ActiveModel {
+        organization_id: Set(booking.organization_id),
+        branch_id: Set(booking.branch_id),
+        customer_id: Set(booking.customer_id),
+        booking_id: Set(Some(booking.id)),
+        transaction_id: Set(Some(booking.payment_id)),
+        subtotal: Set(booking.total_amount + booking.discount_amount
+            - booking.tax_amount
+            - booking.service_charge),
+        discount_amount: Set(booking.discount_amount),
+        tax_amount: Set(booking.tax_amount),
+        included_tax_amount: Set(booking.included_tax_amount),
+        service_charge: Set(booking.service_charge),
+        total_amount: Set(booking.total_amount),
+        ..Default::default()
+    };
+
+    let result = ActiveModelTrait::insert(receipt, conn)
+        .await
+        .map_err(|err| {
+            eprintln!("Database insert error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    Ok(result)
+}
+
+pub async fn get_customer_receipts(
+    customer: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<Vec<entity::receipts::Model>, DbErr> {
+    let receipts = entity::receipts::Entity::find()
+        .filter(entity::receipts::Column::CustomerId.eq(customer))
+        .order_by_desc(entity::receipts::Column::CreatedAt)
+        .all(state.pg_db.get_ref())
+        .await
+        .map_err(|err| {
+            eprintln!("Database retrieval error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    Ok(receipts)
+}
+
+pub async fn get_customer_receipt(
+    id: uuid::Uuid,
+    customer: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<entity::receipts::Model, DbErr> {
+    let receipt = entity::receipts::Entity::find_by_id(id)
+        .filter(entity::receipts::Column::CustomerId.eq(customer))
+        .one(state.pg_db.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Receipt not found".into()))?;
+
+    Ok(receipt)
+}
+
+/// Receipt for one of the customer's orders. Orders paid before receipts existed get theirs
+/// issued on first request.
+pub async fn get_order_receipt(
+    order: uuid::Uuid,
+    customer: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<entity::receipts::Model, DbErr> {
+    let order = entity::orders::Entity::find_by_id(order)
+        .filter(entity::orders::Column::CustomerId.eq(customer))
+        .one(state.pg_db.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Order not found".into()))?;
+
+    issue_order_receipt(&order, state.pg_db.get_ref()).await
+}
+
+/// Receipt for one of the customer's bookings, issued on first request for older bookings.
+pub async fn get_booking_receipt(
+    booking: uuid::Uuid,
+    customer: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<entity::receipts::Model, DbErr> {
+    let booking = entity::bookings::Entity::find_by_id(booking)
+        .filter(entity::bookings::Column::CustomerId.eq(customer))
+        .one(state.pg_db.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Booking not found".into()))?;
+
+    issue_booking_receipt(&booking, state.pg_db.get_ref()).await
+}
+
+/// Gathers the branch, customer, payment and line details printed on a receipt.
+pub async fn get_receipt_document(
+    receipt: entity::receipts::Model,
+    state: &web::Data<AppState>,
+) -> Result<ReceiptDocument, DbErr> {
+    let db = state.pg_db.get_ref();
+
+    let branch = entity::branches::Entity::find_by_id(receipt.branch_id)
+        .one(db)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Branch not found".into()))?;
+
+    let organization = entity::organizations::Entity::find_by_id(receipt.organization_id)
+        .one(db)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Organization not found".into()))?;
+
+    let customer = entity::customers::Entity::find_by_id(receipt.customer_id)
+        .one(db)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Customer not found".into()))?;
+
+    let payment = match receipt.transaction_id {
+        Some(id) => entity::transactions::Entity::find_by_id(id).one(db).await?,
+        None => None,
+    };
+
+    let (reference, lines) = match (receipt.order_id, receipt.booking_id) {
+        (Some(order), _) => {
+            let order = entity::orders::Entity::find_by_id(order)
+                .one(db)
+                .await?
+                .ok_or_else(|| DbErr::RecordNotFound("Order not found".into()))?;
+
+            let items = order
+                .find_related(entity::order_items::Entity)
+                .all(db)
+                .await?;
+
+            (format!("Order {}", order.order_number), order_lines(&items))
+        }
+        (None, Some(booking)) => {
+            let booking = entity::bookings::Entity::find_by_id(booking)
+                .one(db)
+                .await?
+                .ok_or_else(|| DbErr::RecordNotFound("Booking not found".into()))?;
+
+            (
+                format!("Booking {}", booking.booking_reference),
+                booking_lines(&booking),
+            )
+        }
+        (None, None) => return Err(DbErr::Custom("Receipt has nothing to show".to_string())),
+    };
+
+    let taxes = get_tax_lines(receipt.order_id, receipt.booking_id, db).await?;
+
+    let customer_name = [customer.first_name, customer.last_name]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    Ok(ReceiptDocument {
+        organization_name: organization.name,
+        branch_name: branch.name,
+        branch_location: branch.location,
+        branch_contact: branch.contact,
+        branch_email: branch.email,
+        customer_name: match customer_name.is_empty() {
+            true => customer.customer_number,
+            false => customer_name,
+        },
+        reference,
+        payment_reference: payment.as_ref().map(|payment| {
+            payment
+                .transaction_reference
+                .clone()
+                .unwrap_or_else(|| payment.id.to_string())
+        }),
+        payment_channel: payment.and_then(|payment| payment.debit_channel),
+        lines,
+        taxes,
+        receipt,
+    })
+}
+
+/// Emails the receipt as HTML to the customer's address on file and notes when it went out.
+pub async fn email_receipt(
+    receipt: entity::receipts::Model,
+    state: &web::Data<AppState>,
+) -> Result<entity::receipts::Model, DbErr> {
+    let email = entity::customers::Entity::find_by_id(receipt.customer_id)
+        .filter(
+            Condition::all()
+                .add(entity::customers::Column::Email.is_not_null())
+                .add(entity::customers::Column::IsDeleted.eq(false)),
+        )
+        .one(state.pg_db.get_ref())
+        .await?
+        .and_then(|customer| customer.email)
+        .ok_or_else(|| DbErr::RecordNotFound("Customer has no email address".into()))?;
+
+    let document = get_receipt_document(receipt, state).await?;
+
+    let mail = MailerModel {
+        subject: format!(
+            "Your receipt {} from {}",
+            document.receipt.receipt_number, document.branch_name
+        ),
+        body: render_receipt_html(&document),
+        receiver: email,
+        msg_type: MsgType::HTML,
+    };
+
+    send_mail(mail)
+        .await
+        .map_err(|err| DbErr::Custom(err.to_string()))?;
+
+    let mut model: entity::receipts::ActiveModel = document.receipt.into();
+
+    model.emailed_at = ActiveValue::Set(Some(chrono::Utc::now().into()));
+
+    let result = ActiveModelTrait::update(model, state.pg_db.get_ref())
+        .await
+        .map_err(|err| {
+            eprintln!("Database update error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    Ok(result)
+}
+
+/// Sends the receipt in the background once the payment has committed. Customers without an
+/// email address can still fetch it from their history.
+pub fn send_receipt_email(receipt: entity::receipts::Model, state: web::Data<AppState>) {
+    tokio::spawn(async move {
+        match email_receipt(receipt, &state).await {
+            Ok(_) | Err(DbErr::RecordNotFound(_)) => {}
+            Err(err) => eprintln!("Receipt email error: {}", err),
+        }
+    });
+}
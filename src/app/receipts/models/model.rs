@@ -0,0 +1,27 @@
+use sea_orm::prelude::Decimal;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReceiptLine {
+    pub description: String,
+    pub quantity: i32,
+    pub unit_price: Decimal,
+    pub amount: Decimal,
+}
+
+/// Everything printed on a receipt, gathered from the receipt and the records behind it.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ReceiptDocument {
+    pub receipt: entity::receipts::Model,
+    pub organization_name: String,
+    pub branch_name: String,
+    pub branch_location: Option<String>,
+    pub branch_contact: Option<String>,
+    pub branch_email: Option<String>,
+    pub customer_name: String,
+    pub reference: String,
+    pub payment_reference: Option<String>,
+    pub payment_channel: Option<String>,
+    pub lines: Vec<ReceiptLine>,
+    pub taxes: Vec<entity::tax_lines::Model>,
+}
@@ -0,0 +1,57 @@
+use actix_web::web;
+
+use crate::{
+    app::receipts::controllers::controller::{
+        booking_receipt, my_receipts, order_receipt, receipt_details, receipt_pdf, resend_receipt,
+    },
+    middlewares::{auth::JwtAuthMiddleware, checker::CheckUserMiddleware},
+    AppState,
+};
+
+pub fn routes(cfg: &mut web::ServiceConfig, state: web::Data<AppState>) {
+    cfg.service(
+        web::scope("/api/v1/receipts")
+            .route(
+                "/history",
+                web::get()
+                    .to(my_receipts)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Customer"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/details/{id}",
+                web::get()
+                    .to(receipt_details)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Customer"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/order/{id}",
+                web::get()
+                    .to(order_receipt)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Customer"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/booking/{id}",
+                web::get()
+                    .to(booking_receipt)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Customer"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/pdf/{id}",
+                web::get()
+                    .to(receipt_pdf)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Customer"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/email/{id}",
+                web::post()
+                    .to(resend_receipt)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Customer"))
+                    .wrap(JwtAuthMiddleware),
+            ),
+    );
+}
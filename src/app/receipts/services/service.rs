@@ -0,0 +1,313 @@
+use pdf_writer::{Content, Finish, Name, Pdf, Rect, Ref, Str};
+use sea_orm::prelude::Decimal;
+
+use crate::app::{
+    receipts::models::model::{ReceiptDocument, ReceiptLine},
+    taxes::models::model::TaxRuleType,
+};
+
+/// One line per order item, priced from the snapshot taken when the order was placed.
+pub fn order_lines(items: &[entity::order_items::Model]) -> Vec<ReceiptLine> {
+    items
+        .iter()
+        .map(|item| ReceiptLine {
+            description: item
+                .item_name
+                .clone()
+                .unwrap_or_else(|| item.item_type.clone()),
+            quantity: item.quantity,
+            unit_price: item.price,
+            amount: item.price * Decimal::from(item.quantity),
+        })
+        .collect()
+}
+
+/// A single line for the booking, priced before any discount and the taxes and service
+/// charge added to it.
+pub fn booking_lines(booking: &entity::bookings::Model) -> Vec<ReceiptLine> {
+    let amount = booking.total_amount + booking.discount_amount
+        - booking.tax_amount
+        - booking.service_charge;
+
+    let quantity = match booking.unit_amount > Decimal::ZERO {
+        true => i32::try_from((amount / booking.unit_amount).round()).unwrap_or(1),
+        false => 1,
+    };
+
+    vec![ReceiptLine {
+        description: format!(
+            "Booking {} ({})",
+            booking.booking_number, booking.booking_party
+        ),
+        quantity,
+        unit_price: booking.unit_amount,
+        amount,
+    }]
+}
+
+fn escape_html(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
+fn tax_label(line: &entity::tax_lines::Model) -> String {
+    format!("{} ({}%)", line.name, line.rate.normalize())
+}
+
+/// Totals shown under the lines, skipping rows that are zero. Taxes added on top get a row
+/// each before the total, taxes already inside the prices are noted after it.
+fn total_rows(doc: &ReceiptDocument) -> Vec<(String, Decimal)> {
+    let receipt = &doc.receipt;
+    let mut rows = vec![("Subtotal".to_string(), receipt.subtotal)];
+
+    if receipt.discount_amount > Decimal::ZERO {
+        rows.push(("Discount".to_string(), -receipt.discount_amount));
+    }
+
+    if receipt.service_charge > Decimal::ZERO {
+        rows.push(("Service charge".to_string(), receipt.service_charge));
+    }
+
+    let added: Vec<_> = doc
+        .taxes
+        .iter()
+        .filter(|line| line.line_type == TaxRuleType::Tax.as_str() && !line.is_inclusive)
+        .collect();
+
+    match added.is_empty() {
+        true if receipt.tax_amount > Decimal::ZERO => {
+            rows.push(("Tax".to_string(), receipt.tax_amount))
+        }
+        true => {}
+        false => rows.extend(added.into_iter().map(|line| (tax_label(line), line.amount))),
+    }
+
+    if receipt.tip_amount > Decimal::ZERO {
+        rows.push(("Tip".to_string(), receipt.tip_amount));
+    }
+
+    rows.push(("Total".to_string(), receipt.total_amount));
+
+    rows.extend(
+        doc.taxes
+            .iter()
+            .filter(|line| line.line_type == TaxRuleType::Tax.as_str() && line.is_inclusive)
+            .map(|line| (format!("Includes {}", tax_label(line)), line.amount)),
+    );
+
+    rows
+}
+
+fn branch_details(doc: &ReceiptDocument) -> Vec<String> {
+    [
+        doc.branch_location.clone(),
+        doc.branch_contact.clone(),
+        doc.branch_email.clone(),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+pub fn render_receipt_html(doc: &ReceiptDocument) -> String {
+    let lines: String = doc
+        .lines
+        .iter()
+        .map(|line| {
+            format!(
+                "<tr><td>{}</td><td align=\"right\">{}</td><td align=\"right\">{:.2}</td><td align=\"right\">{:.2}</td></tr>",
+                escape_html(&line.description),
+                line.quantity,
+                line.unit_price,
+                line.amount
+            )
+        })
+        .collect();
+
+    let totals: String = total_rows(doc)
+        .into_iter()
+        .map(|(label, amount)| {
+            format!(
+                "<tr><td colspan=\"3\" align=\"right\"><strong>{}</strong></td><td align=\"right\">{:.2}</td></tr>",
+                escape_html(&label),
+                amount
+            )
+        })
+        .collect();
+
+    let branch: String = branch_details(doc)
+        .iter()
+        .map(|detail| format!("<div>{}</div>", escape_html(detail)))
+        .collect();
+
+    let payment = match (&doc.payment_channel, &doc.payment_reference) {
+        (Some(channel), Some(reference)) => format!(
+            "<p>Paid by {} &middot; Ref {}</p>",
+            escape_html(channel),
+            escape_html(reference)
+        ),
+        (Some(channel), None) => format!("<p>Paid by {}</p>", escape_html(channel)),
+        (None, Some(reference)) => format!("<p>Payment ref {}</p>", escape_html(reference)),
+        (None, None) => String::new(),
+    };
+
+    format!(
+        "<!DOCTYPE html><html><body style=\"font-family: Arial, sans-serif; max-width: 560px; margin: auto;\">\
+<h2>{organization}</h2><div><strong>{branch_name}</strong></div>{branch}<hr/>\
+<p>Receipt <strong>{number}</strong><br/>{reference}<br/>{date}<br/>Customer: {customer}</p>\
+<table width=\"100%\" cellpadding=\"4\" style=\"border-collapse: collapse;\">\
+<tr><th align=\"left\">Item</th><th align=\"right\">Qty</th><th align=\"right\">Price</th><th align=\"right\">Amount</th></tr>\
+{lines}{totals}</table>{payment}<p>Thank you for visiting {branch_name}.</p></body></html>",
+        organization = escape_html(&doc.organization_name),
+        branch_name = escape_html(&doc.branch_name),
+        branch = branch,
+        number = escape_html(&doc.receipt.receipt_number),
+        reference = escape_html(&doc.reference),
+        date = doc.receipt.created_at.format("%d %b %Y %H:%M"),
+        customer = escape_html(&doc.customer_name),
+        lines = lines,
+        totals = totals,
+        payment = payment,
+    )
+}
+
+/// The built-in PDF fonts only cover Latin-1, anything else prints as `?`.
+fn pdf_text(value: &str) -> Vec<u8> {
+    value
+        .chars()
+        .map(|c| match u8::try_from(u32::from(c)) {
+            Ok(byte) if byte >= 0x20 => byte,
+            _ => b'?',
+        })
+        .collect()
+}
+
+/// Lays the receipt out on a single A4 page using the standard Helvetica fonts, so nothing
+/// has to be embedded.
+pub fn render_receipt_pdf(doc: &ReceiptDocument) -> Vec<u8> {
+    let catalog_id = Ref::new(1);
+    let page_tree_id = Ref::new(2);
+    let page_id = Ref::new(3);
+    let font_id = Ref::new(4);
+    let bold_id = Ref::new(5);
+    let content_id = Ref::new(6);
+    let font = Name(b"F1");
+    let bold = Name(b"F2");
+
+    let mut pdf = Pdf::new();
+
+    pdf.catalog(catalog_id).pages(page_tree_id);
+    pdf.pages(page_tree_id).kids([page_id]).count(1);
+
+    let mut page = pdf.page(page_id);
+
+    page.media_box(Rect::new(0.0, 0.0, 595.0, 842.0));
+    page.parent(page_tree_id);
+    page.contents(content_id);
+
+    let mut resources = page.resources();
+    let mut fonts = resources.fonts();
+
+    fonts.pair(font, font_id);
+    fonts.pair(bold, bold_id);
+    fonts.finish();
+    resources.finish();
+    page.finish();
+
+    pdf.type1_font(font_id).base_font(Name(b"Helvetica"));
+    pdf.type1_font(bold_id).base_font(Name(b"Helvetica-Bold"));
+
+    let mut content = Content::new();
+    let mut y = 790.0;
+
+    let mut text = |value: &str, x: f32, y: f32, size: f32, heavy: bool| {
+        let bytes = pdf_text(value);
+
+        content.begin_text();
+        content.set_font(if heavy { bold } else { font }, size);
+        content.next_line(x, y);
+        content.show(Str(&bytes));
+        content.end_text();
+    };
+
+    text(&doc.organization_name, 50.0, y, 16.0, true);
+    y -= 20.0;
+    text(&doc.branch_name, 50.0, y, 11.0, true);
+
+    for detail in branch_details(doc) {
+        y -= 14.0;
+        text(&detail, 50.0, y, 10.0, false);
+    }
+
+    y -= 28.0;
+    text(
+        &format!("Receipt {}", doc.receipt.receipt_number),
+        50.0,
+        y,
+        11.0,
+        true,
+    );
+    y -= 14.0;
+    text(&doc.reference, 50.0, y, 10.0, false);
+    y -= 14.0;
+    text(
+        &doc.receipt.created_at.format("%d %b %Y %H:%M").to_string(),
+        50.0,
+        y,
+        10.0,
+        false,
+    );
+    y -= 14.0;
+    text(
+        &format!("Customer: {}", doc.customer_name),
+        50.0,
+        y,
+        10.0,
+        false,
+    );
+
+    y -= 28.0;
+    text("Item", 50.0, y, 10.0, true);
+    text("Qty", 330.0, y, 10.0, true);
+    text("Price", 400.0, y, 10.0, true);
+    text("Amount", 480.0, y, 10.0, true);
+
+    for line in &doc.lines {
+        y -= 16.0;
+
+        // Long names would run into the quantity column
+        let description: String = line.description.chars().take(45).collect();
+
+        text(&description, 50.0, y, 10.0, false);
+        text(&line.quantity.to_string(), 330.0, y, 10.0, false);
+        text(&format!("{:.2}", line.unit_price), 400.0, y, 10.0, false);
+        text(&format!("{:.2}", line.amount), 480.0, y, 10.0, false);
+    }
+
+    y -= 10.0;
+
+    for (label, amount) in total_rows(doc) {
+        y -= 16.0;
+        text(&label, 330.0, y, 10.0, true);
+        text(&format!("{:.2}", amount), 480.0, y, 10.0, false);
+    }
+
+    y -= 28.0;
+
+    if let Some(channel) = &doc.payment_channel {
+        text(&format!("Paid by {}", channel), 50.0, y, 10.0, false);
+        y -= 14.0;
+    }
+
+    if let Some(reference) = &doc.payment_reference {
+        text(&format!("Payment ref {}", reference), 50.0, y, 10.0, false);
+    }
+
+    pdf.stream(content_id, &content.finish());
+
+    pdf.finish()
+}
@@ -0,0 +1,452 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+use actix_web::web;
+use sea_orm::{
+    prelude::Decimal, ColumnTrait, Condition, ConnectionTrait, DbErr, EntityTrait, QueryFilter,
+    QueryOrder, QuerySelect, Set, TransactionTrait,
+};
+
+use crate::{
+    app::{
+        inventory::dtos::dto::{check_components, pour_components},
+        orders::models::model::{ItemType, OrderStatus},
+        recipes::{
+            models::model::{ItemCosting, SaveRecipeDto, SellableItem},
+            services::service::{component_demand, item_costing, recipe_cost, serving_measure},
+        },
+    },
+    AppState,
+};
+
+fn item_column(item_type: ItemType) -> entity::recipe_items::Column {
+    match item_type {
+        ItemType::Drink => entity::recipe_items::Column::StockDrinkId,
+        ItemType::Food => entity::recipe_items::Column::StockFoodId,
+        ItemType::Cocktail => entity::recipe_items::Column::CustomCocktailId,
+    }
+}
+
+fn recipe_item(line: &entity::recipe_items::Model) -> Option<uuid::Uuid> {
+    line.stock_drink_id
+        .or(line.stock_food_id)
+        .or(line.custom_cocktail_id)
+}
+
+/// Looks up a sellable item of the branch with its menu price, without its recipe.
+async fn find_sellable<C: ConnectionTrait>(
+    item_type: ItemType,
+    item: uuid::Uuid,
+    branch: uuid::Uuid,
+    conn: &C,
+) -> Result<SellableItem, DbErr> {
+    let (name, price) = match item_type {
+        ItemType::Drink => entity::stock_drinks::Entity::find_by_id(item)
+            .filter(entity::stock_drinks::Column::BranchId.eq(branch))
+            .one(conn)
+            .await?
+            .map(|drink| (drink.name, drink.sale_price))
+            .ok_or_else(|| DbErr::RecordNotFound("Drink not found".into()))?,
+        ItemType::Food => entity::stock_foods::Entity::find_by_id(item)
+            .filter(entity::stock_foods::Column::BranchId.eq(branch))
+            .one(conn)
+            .await?
+            .map(|food| (food.name, food.price))
+            .ok_or_else(|| DbErr::RecordNotFound("Food not found".into()))?,
+        ItemType::Cocktail => entity::custom_cocktails::Entity::find_by_id(item)
+            .filter(entity::custom_cocktails::Column::BranchId.eq(branch))
+            .one(conn)
+            .await?
+            .map(|cocktail| (cocktail.name, cocktail.price.unwrap_or_default()))
+            .ok_or_else(|| DbErr::RecordNotFound("Cocktail not found".into()))?,
+    };
+
+    Ok(SellableItem {
+        item_type,
+        item,
+        name,
+        price,
+        recipe: vec![],
+    })
+}
+
+async fn find_components<C: ConnectionTrait>(
+    ids: impl IntoIterator<Item = uuid::Uuid>,
+    conn: &C,
+) -> Result<HashMap<uuid::Uuid, entity::stock_drinks::Model>, DbErr> {
+    Ok(entity::stock_drinks::Entity::find()
+        .filter(entity::stock_drinks::Column::Id.is_in(ids))
+        .all(conn)
+        .await
+        .map_err(|err| {
+            eprintln!("Database retrieval error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?
+        .into_iter()
+        .map(|drink| (drink.id, drink))
+        .collect())
+}
+
+/// Recipes of the given items, keyed by item. Items without a recipe are left out.
+pub async fn item_recipes<C: ConnectionTrait>(
+    items: &[(ItemType, uuid::Uuid)],
+    conn: &C,
+) -> Result<HashMap<uuid::Uuid, Vec<entity::recipe_items::Model>>, DbErr> {
+    let mut condition = Condition::any();
+
+    for item_type in [ItemType::Drink, ItemType::Food, ItemType::Cocktail] {
+        let ids: Vec<uuid::Uuid> = items
+            .iter()
+            .filter(|(kind, _)| *kind == item_type)
+            .map(|(_, item)| *item)
+            .collect();
+
+        if !ids.is_empty() {
+            condition = condition.add(item_column(item_type).is_in(ids));
+        }
+    }
+
+    if condition.is_empty() {
+        return Ok(HashMap::new());
+    }
+
+    let mut recipes: HashMap<uuid::Uuid, Vec<entity::recipe_items::Model>> = HashMap::new();
+
+    let lines = entity::recipe_items::Entity::find()
+        .filter(condition)
+        .order_by_asc(entity::recipe_items::Column::CreatedAt)
+        .all(conn)
+        .await?;
+
+    for line in lines {
+        if let Some(item) = recipe_item(&line) {
+            recipes.entry(item).or_default().push(line);
+        }
+    }
+
+    Ok(recipes)
+}
+
+/// What one serving of each recipe costs in components at today's purchase prices, keyed by item.
+pub async fn recipe_costs<C: ConnectionTrait>(
+    recipes: &HashMap<uuid::Uuid, Vec<entity::recipe_items::Model>>,
+    conn: &C,
+) -> Result<HashMap<uuid::Uuid, Decimal>, DbErr> {
+    let components = find_components(
+        recipes.values().flatten().map(|line| line.component_id),
+        conn,
+    )
+    .await?;
+
+    Ok(recipes
+        .iter()
+        .map(|(item, recipe)| (*item, recipe_cost(recipe, &components)))
+        .collect())
+}
+
+/// The measure of each component order lines made by recipe pour, by the recipes in force now.
+async fn lines_demand<C: ConnectionTrait>(
+    items: &[entity::order_items::Model],
+    conn: &C,
+) -> Result<BTreeMap<uuid::Uuid, Decimal>, DbErr> {
+    let sold: Vec<(ItemType, uuid::Uuid, i32)> = items
+        .iter()
+        .filter_map(|item| {
+            let item_type = item.item_type.parse().ok()?;
+            let id = item
+                .stock_drink_id
+                .or(item.stock_food_id)
+                .or(item.custom_cocktail_id)?;
+
+            Some((item_type, id, item.quantity))
+        })
+        .collect();
+
+    let keys: Vec<(ItemType, uuid::Uuid)> = sold
+        .iter()
+        .map(|(item_type, id, _)| (*item_type, *id))
+        .collect();
+    let recipes = item_recipes(&keys, conn).await?;
+
+    let servings: Vec<(&[entity::recipe_items::Model], i32)> = sold
+        .iter()
+        .filter_map(|(_, id, quantity)| Some((recipes.get(id)?.as_slice(), *quantity)))
+        .collect();
+
+    let components = find_components(
+        recipes.values().flatten().map(|line| line.component_id),
+        conn,
+    )
+    .await?;
+
+    Ok(component_demand(&servings, &components))
+}
+
+/// Refuses a sale when a component of the items it makes by recipe is out. `servings` are
+/// each item's recipe and how many are sold. Components only come off the shelf when an order
+/// completes, so what the branch's open orders will pour is spoken for. Call inside the order's
+/// transaction, before its items are saved.
+pub async fn check_recipe_stock<C: ConnectionTrait>(
+    servings: &[(&[entity::recipe_items::Model], i32)],
+    branch: uuid::Uuid,
+    conn: &C,
+) -> Result<(), DbErr> {
+    let ids: BTreeSet<uuid::Uuid> = servings
+        .iter()
+        .flat_map(|(recipe, _)| recipe.iter().map(|line| line.component_id))
+        .collect();
+
+    if ids.is_empty() {
+        return Ok(());
+    }
+
+    // Orders placed at the same time wait for each other here, so each counts the other's
+    let components: HashMap<uuid::Uuid, entity::stock_drinks::Model> =
+        entity::stock_drinks::Entity::find()
+            .filter(entity::stock_drinks::Column::Id.is_in(ids))
+            .order_by_asc(entity::stock_drinks::Column::Id)
+            .lock_exclusive()
+            .all(conn)
+            .await?
+            .into_iter()
+            .map(|drink| (drink.id, drink))
+            .collect();
+
+    let open = entity::order_items::Entity::find()
+        .inner_join(entity::orders::Entity)
+        .filter(
+            Condition::all()
+                .add(entity::orders::Column::BranchId.eq(branch))
+                .add(entity::orders::Column::OrderStatus.is_in([
+                    OrderStatus::Pending.as_str(),
+                    OrderStatus::Processing.as_str(),
+                ]))
+                .add(entity::order_items::Column::HasRecipe.eq(true)),
+        )
+        .all(conn)
+        .await?;
+
+    let mut demand = component_demand(servings, &components);
+
+    for (component, measure) in lines_demand(&open, conn).await? {
+        if let Some(total) = demand.get_mut(&component) {
+            *total += measure;
+        }
+    }
+
+    check_components(&demand, conn).await
+}
+
+/// Pours the components of an order's items sold by recipe, once the order is completed. The
+/// recipes in force at completion are the ones poured.
+pub async fn deplete_order_components<C: ConnectionTrait>(
+    order: uuid::Uuid,
+    conn: &C,
+) -> Result<(), DbErr> {
+    let items = entity::order_items::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::order_items::Column::OrderId.eq(order))
+                .add(entity::order_items::Column::HasRecipe.eq(true)),
+        )
+        .all(conn)
+        .await?;
+
+    pour_components(&lines_demand(&items, conn).await?, conn).await
+}
+
+/// Replaces an item's recipe. Components are drinks of the same branch, each listed once, in a
+/// unit that fits what the drink is measured in. Call inside a transaction.
+pub async fn replace_recipe<C: ConnectionTrait>(
+    data: SaveRecipeDto,
+    conn: &C,
+) -> Result<Vec<entity::recipe_items::Model>, DbErr> {
+    find_sellable(data.item_type, data.item, data.branch, conn).await?;
+
+    let mut seen = HashSet::new();
+
+    for line in &data.lines {
+        if !seen.insert(line.component) {
+            return Err(DbErr::Custom("Component listed twice".to_string()));
+        }
+
+        if data.item_type == ItemType::Drink && line.component == data.item {
+            return Err(DbErr::Custom(
+                "A drink can't be its own component".to_string(),
+            ));
+        }
+    }
+
+    let components = find_components(seen, conn).await?;
+
+    for line in &data.lines {
+        let component = components
+            .get(&line.component)
+            .filter(|component| component.branch_id == data.branch)
+            .ok_or_else(|| DbErr::RecordNotFound("Component not found".into()))?;
+
+        if serving_measure(line.quantity, line.unit, component).is_none() {
+            return Err(DbErr::Custom(format!(
+                "{} is measured in {}, not {}",
+                component.name,
+                component.measure_unit,
+                line.unit.as_str()
+            )));
+        }
+    }
+
+    entity::recipe_items::Entity::delete_many()
+        .filter(item_column(data.item_type).eq(data.item))
+        .exec(conn)
+        .await
+        .map_err(|err| {
+            eprintln!("Database delete error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    let lines = data
+        .lines
+        .iter()
+        .map(|line| entity::recipe_items::ActiveModel {
+            organization_id: Set(data.organization),
+            branch_id: Set(data.branch),
+            item_type: Set(data.item_type.as_str().to_string()),
+            stock_drink_id: Set((data.item_type == ItemType::Drink).then_some(data.item)),
+            stock_food_id: Set((data.item_type == ItemType::Food).then_some(data.item)),
+            custom_cocktail_id: Set((data.item_type == ItemType::Cocktail).then_some(data.item)),
+            component_id: Set(line.component),
+            quantity: Set(line.quantity),
+            unit: Set(line.unit.as_str().to_string()),
+            ..Default::default()
+        });
+
+    entity::recipe_items::Entity::insert_many(lines)
+        .exec(conn)
+        .await
+        .map_err(|err| {
+            eprintln!("Database insert error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    entity::recipe_items::Entity::find()
+        .filter(item_column(data.item_type).eq(data.item))
+        .order_by_asc(entity::recipe_items::Column::CreatedAt)
+        .all(conn)
+        .await
+        .map_err(|err| {
+            eprintln!("Database retrieval error: {}", err);
+            DbErr::Custom(err.to_string())
+        })
+}
+
+pub async fn save_recipe(
+    data: SaveRecipeDto,
+    state: &web::Data<AppState>,
+) -> Result<Vec<entity::recipe_items::Model>, DbErr> {
+    let txn = state.pg_db.get_ref().begin().await?;
+
+    let result = replace_recipe(data, &txn).await?;
+
+    txn.commit().await?;
+
+    Ok(result)
+}
+
+/// Takes an item off recipe, so it is sold from its own stock again.
+pub async fn delete_recipe(
+    item_type: ItemType,
+    item: uuid::Uuid,
+    branch: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<(), DbErr> {
+    entity::recipe_items::Entity::delete_many()
+        .filter(
+            Condition::all()
+                .add(entity::recipe_items::Column::BranchId.eq(branch))
+                .add(item_column(item_type).eq(item)),
+        )
+        .exec(state.pg_db.get_ref())
+        .await
+        .map_err(|err| {
+            eprintln!("Database delete error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    Ok(())
+}
+
+pub async fn get_item_costing(
+    item_type: ItemType,
+    item: uuid::Uuid,
+    branch: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<ItemCosting, DbErr> {
+    let mut sellable = find_sellable(item_type, item, branch, state.pg_db.get_ref()).await?;
+
+    sellable.recipe = item_recipes(&[(item_type, item)], state.pg_db.get_ref())
+        .await?
+        .remove(&item)
+        .ok_or_else(|| DbErr::RecordNotFound("Recipe not found".into()))?;
+
+    let components = find_components(
+        sellable.recipe.iter().map(|line| line.component_id),
+        state.pg_db.get_ref(),
+    )
+    .await?;
+
+    Ok(item_costing(sellable, &components))
+}
+
+/// Cost and margin of every item the branch sells by recipe, thinnest margin first.
+pub async fn get_branch_costing(
+    branch: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<Vec<ItemCosting>, DbErr> {
+    let lines = entity::recipe_items::Entity::find()
+        .filter(entity::recipe_items::Column::BranchId.eq(branch))
+        .order_by_asc(entity::recipe_items::Column::CreatedAt)
+        .all(state.pg_db.get_ref())
+        .await
+        .map_err(|err| {
+            eprintln!("Database retrieval error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    let components = find_components(
+        lines.iter().map(|line| line.component_id),
+        state.pg_db.get_ref(),
+    )
+    .await?;
+
+    let mut recipes: HashMap<(String, uuid::Uuid), Vec<entity::recipe_items::Model>> =
+        HashMap::new();
+
+    for line in lines {
+        if let Some(item) = recipe_item(&line) {
+            recipes
+                .entry((line.item_type.clone(), item))
+                .or_default()
+                .push(line);
+        }
+    }
+
+    let mut costings = Vec::with_capacity(recipes.len());
+
+    for ((item_type, item), recipe) in recipes {
+        let Ok(item_type) = item_type.parse::<ItemType>() else {
+            continue;
+        };
+
+        let mut sellable = find_sellable(item_type, item, branch, state.pg_db.get_ref()).await?;
+
+        sellable.recipe = recipe;
+        costings.push(item_costing(sellable, &components));
+    }
+
+    costings.sort_by(|a, b| {
+        a.margin_percent
+            .cmp(&b.margin_percent)
+            .then_with(|| a.name.cmp(&b.name))
+    });
+
+    Ok(costings)
+}
@@ -0,0 +1,172 @@
+use std::str::FromStr;
+
+use sea_orm::prelude::Decimal;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::{
+    app::{inventory::models::model::MeasureUnit, orders::models::model::ItemType},
+    libs::validator::{validate_item_type, validate_measure, validate_recipe_unit},
+};
+
+/// The unit a recipe measures a component in. `Unit` is a whole unit of the component as it is
+/// stocked, e.g. one bottle, the others must match what the component is measured in.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum RecipeUnit {
+    Unit,
+    Piece,
+    Ml,
+    Cl,
+    L,
+    G,
+    Kg,
+}
+
+impl RecipeUnit {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RecipeUnit::Unit => "UNIT",
+            RecipeUnit::Piece => "PIECE",
+            RecipeUnit::Ml => "ML",
+            RecipeUnit::Cl => "CL",
+            RecipeUnit::L => "L",
+            RecipeUnit::G => "G",
+            RecipeUnit::Kg => "KG",
+        }
+    }
+
+    /// The measure this unit is a multiple of, and how many of that measure it holds.
+    pub fn measure(&self) -> Option<(MeasureUnit, Decimal)> {
+        match self {
+            RecipeUnit::Unit => None,
+            RecipeUnit::Piece => Some((MeasureUnit::Piece, Decimal::ONE)),
+            RecipeUnit::Ml => Some((MeasureUnit::Ml, Decimal::ONE)),
+            RecipeUnit::Cl => Some((MeasureUnit::Ml, Decimal::from(10))),
+            RecipeUnit::L => Some((MeasureUnit::Ml, Decimal::from(1000))),
+            RecipeUnit::G => Some((MeasureUnit::G, Decimal::ONE)),
+            RecipeUnit::Kg => Some((MeasureUnit::G, Decimal::from(1000))),
+        }
+    }
+}
+
+impl FromStr for RecipeUnit {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_uppercase().as_str() {
+            "UNIT" => Ok(RecipeUnit::Unit),
+            "PIECE" => Ok(RecipeUnit::Piece),
+            "ML" => Ok(RecipeUnit::Ml),
+            "CL" => Ok(RecipeUnit::Cl),
+            "L" => Ok(RecipeUnit::L),
+            "G" => Ok(RecipeUnit::G),
+            "KG" => Ok(RecipeUnit::Kg),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct RecipeLine {
+    pub component: uuid::Uuid,
+    pub quantity: Decimal,
+    pub unit: RecipeUnit,
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct RecipeLineParams {
+    pub component: uuid::Uuid,
+    #[validate(custom(function = "validate_measure"))]
+    pub quantity: Decimal,
+    #[validate(custom(function = "validate_recipe_unit"))]
+    pub unit: String,
+}
+
+impl From<RecipeLineParams> for RecipeLine {
+    fn from(line: RecipeLineParams) -> Self {
+        RecipeLine {
+            component: line.component,
+            quantity: line.quantity,
+            unit: line.unit.parse().unwrap_or(RecipeUnit::Unit),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SaveRecipeDto {
+    pub organization: uuid::Uuid,
+    pub branch: uuid::Uuid,
+    pub item_type: ItemType,
+    pub item: uuid::Uuid,
+    pub lines: Vec<RecipeLine>,
+}
+
+/// Replaces the recipe of a sellable drink, food or custom cocktail. Each line is a branch
+/// drink and how much of it goes into one serving.
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct SaveRecipeParams {
+    #[validate(custom(function = "validate_item_type"))]
+    pub item_type: String,
+    pub item: uuid::Uuid,
+    #[validate(length(min = 1, max = 30, message = "Lines are invalid"), nested)]
+    pub lines: Vec<RecipeLineParams>,
+}
+
+impl SaveRecipeParams {
+    pub fn into_dto(self, organization: uuid::Uuid, branch: uuid::Uuid) -> SaveRecipeDto {
+        SaveRecipeDto {
+            organization,
+            branch,
+            item_type: self.item_type.parse().unwrap_or(ItemType::Drink),
+            item: self.item,
+            lines: self.lines.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct RecipeItemQuery {
+    #[validate(custom(function = "validate_item_type"))]
+    pub item_type: String,
+}
+
+/// A sellable item, its menu price and the recipe it is made from.
+#[derive(Debug, Clone)]
+pub struct SellableItem {
+    pub item_type: ItemType,
+    pub item: uuid::Uuid,
+    pub name: String,
+    pub price: Decimal,
+    pub recipe: Vec<entity::recipe_items::Model>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecipeComponent {
+    pub component: uuid::Uuid,
+    pub name: String,
+    pub quantity: Decimal,
+    pub unit: String,
+    pub measure: Decimal,
+    pub measure_unit: String,
+    pub cost: Decimal,
+    pub servings_left: i64,
+}
+
+/// What one serving of an item costs in components at their purchase price, against its menu
+/// price. `servings_left` is how many more can be made from the stock on hand.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ItemCosting {
+    pub item_type: ItemType,
+    pub item: uuid::Uuid,
+    pub name: String,
+    pub price: Decimal,
+    pub cost: Decimal,
+    pub margin: Decimal,
+    pub margin_percent: Decimal,
+    pub servings_left: i64,
+    pub components: Vec<RecipeComponent>,
+}
@@ -0,0 +1,43 @@
+use actix_web::web;
+
+use crate::{
+    app::recipes::controllers::controller::{
+        branch_costing, item_costing, remove_recipe, save_item_recipe,
+    },
+    middlewares::{auth::JwtAuthMiddleware, checker::CheckUserMiddleware},
+    AppState,
+};
+
+pub fn routes(cfg: &mut web::ServiceConfig, state: web::Data<AppState>) {
+    cfg.service(
+        web::scope("/api/v1/recipes")
+            .route(
+                "/save",
+                web::post()
+                    .to(save_item_recipe)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/item/{id}",
+                web::get()
+                    .to(item_costing)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/item/{id}",
+                web::delete()
+                    .to(remove_recipe)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/costing",
+                web::get()
+                    .to(branch_costing)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            ),
+    );
+}
@@ -0,0 +1,249 @@
+use std::collections::{BTreeMap, HashMap};
+
+use sea_orm::prelude::Decimal;
+
+use crate::app::{
+    inventory::{models::model::MeasureUnit, services::service::available_measure},
+    recipes::models::model::{ItemCosting, RecipeComponent, RecipeUnit, SellableItem},
+};
+
+/// How much of its component's measure one serving of a recipe line pours, or `None` when the
+/// line's unit doesn't fit what the component is measured in.
+pub fn serving_measure(
+    quantity: Decimal,
+    unit: RecipeUnit,
+    component: &entity::stock_drinks::Model,
+) -> Option<Decimal> {
+    match unit.measure() {
+        None => Some(quantity * component.unit_size),
+        Some((measure, factor)) => {
+            let stocked = component.measure_unit.parse::<MeasureUnit>().ok()?;
+
+            (stocked == measure).then_some(quantity * factor)
+        }
+    }
+}
+
+fn line_measure(
+    line: &entity::recipe_items::Model,
+    component: &entity::stock_drinks::Model,
+) -> Option<Decimal> {
+    serving_measure(line.quantity, line.unit.parse().ok()?, component)
+}
+
+/// The measure of each component poured by `servings` of each recipe.
+pub fn component_demand(
+    servings: &[(&[entity::recipe_items::Model], i32)],
+    components: &HashMap<uuid::Uuid, entity::stock_drinks::Model>,
+) -> BTreeMap<uuid::Uuid, Decimal> {
+    let mut demand: BTreeMap<uuid::Uuid, Decimal> = BTreeMap::new();
+
+    for (recipe, quantity) in servings {
+        for line in recipe.iter() {
+            let Some(measure) = components
+                .get(&line.component_id)
+                .and_then(|component| line_measure(line, component))
+            else {
+                continue;
+            };
+
+            *demand.entry(line.component_id).or_default() += measure * Decimal::from(*quantity);
+        }
+    }
+
+    demand
+}
+
+/// What `measure` of a component costs at its purchase price per unit, rounded to the cent.
+fn component_cost(component: &entity::stock_drinks::Model, measure: Decimal) -> Decimal {
+    (component.unit_price * measure / component.unit_size).round_dp(2)
+}
+
+/// What one serving of a recipe costs in components at their purchase price.
+pub fn recipe_cost(
+    recipe: &[entity::recipe_items::Model],
+    components: &HashMap<uuid::Uuid, entity::stock_drinks::Model>,
+) -> Decimal {
+    recipe
+        .iter()
+        .filter_map(|line| {
+            let component = components.get(&line.component_id)?;
+
+            Some(component_cost(component, line_measure(line, component)?))
+        })
+        .sum()
+}
+
+/// Whether the stock on hand makes one more serving of a recipe.
+pub fn can_serve(
+    recipe: &[entity::recipe_items::Model],
+    components: &HashMap<uuid::Uuid, entity::stock_drinks::Model>,
+) -> bool {
+    recipe
+        .iter()
+        .all(|line| components.contains_key(&line.component_id))
+        && component_demand(&[(recipe, 1)], components)
+            .iter()
+            .all(|(component, measure)| {
+                components
+                    .get(component)
+                    .is_some_and(|component| available_measure(component) >= *measure)
+            })
+}
+
+/// The theoretical cost of one serving of an item, each component charged at its purchase
+/// price per unit spread over what the unit holds.
+pub fn item_costing(
+    item: SellableItem,
+    components: &HashMap<uuid::Uuid, entity::stock_drinks::Model>,
+) -> ItemCosting {
+    let lines: Vec<RecipeComponent> = item
+        .recipe
+        .iter()
+        .map(|line| {
+            let component = components.get(&line.component_id);
+            let measure = component
+                .and_then(|component| line_measure(line, component))
+                .unwrap_or_default();
+
+            RecipeComponent {
+                component: line.component_id,
+                name: component.map(|c| c.name.clone()).unwrap_or_default(),
+                quantity: line.quantity,
+                unit: line.unit.clone(),
+                measure,
+                measure_unit: component
+                    .map(|c| c.measure_unit.clone())
+                    .unwrap_or_default(),
+                cost: component
+                    .map(|c| component_cost(c, measure))
+                    .unwrap_or_default(),
+                servings_left: match component {
+                    Some(c) if measure > Decimal::ZERO => (available_measure(c) / measure)
+                        .floor()
+                        .try_into()
+                        .unwrap_or(i64::MAX),
+                    _ => 0,
+                },
+            }
+        })
+        .collect();
+
+    let cost: Decimal = lines.iter().map(|line| line.cost).sum();
+    let margin = item.price - cost;
+
+    ItemCosting {
+        item_type: item.item_type,
+        item: item.item,
+        name: item.name,
+        price: item.price,
+        cost,
+        margin,
+        margin_percent: if item.price > Decimal::ZERO {
+            (margin / item.price * Decimal::from(100)).round_dp(2)
+        } else {
+            Decimal::ZERO
+        },
+        servings_left: lines
+            .iter()
+            .map(|line| line.servings_left)
+            .min()
+            .unwrap_or(0),
+        components: lines,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::fixtures::{dec, recipe_item, stock_drink};
+
+    fn measured(unit: MeasureUnit, size: &str, unit_price: &str) -> entity::stock_drinks::Model {
+        entity::stock_drinks::Model {
+            measure_unit: unit.as_str().to_string(),
+            unit_size: dec(size),
+            unit_price: dec(unit_price),
+            ..stock_drink("Gin", "10")
+        }
+    }
+
+    #[test]
+    fn converts_recipe_units_to_the_stocked_measure() {
+        let gin = measured(MeasureUnit::Ml, "700", "70");
+
+        assert_eq!(
+            serving_measure(dec("50"), RecipeUnit::Ml, &gin),
+            Some(dec("50"))
+        );
+        assert_eq!(
+            serving_measure(dec("5"), RecipeUnit::Cl, &gin),
+            Some(dec("50"))
+        );
+        assert_eq!(
+            serving_measure(dec("0.5"), RecipeUnit::L, &gin),
+            Some(dec("500"))
+        );
+    }
+
+    #[test]
+    fn whole_units_pour_the_units_size() {
+        let gin = measured(MeasureUnit::Ml, "700", "70");
+
+        assert_eq!(
+            serving_measure(dec("2"), RecipeUnit::Unit, &gin),
+            Some(dec("1400"))
+        );
+    }
+
+    #[test]
+    fn refuses_units_that_do_not_fit_the_measure() {
+        let gin = measured(MeasureUnit::Ml, "700", "70");
+        let lime = measured(MeasureUnit::Piece, "1", "0.5");
+
+        assert_eq!(serving_measure(dec("10"), RecipeUnit::G, &gin), None);
+        assert_eq!(serving_measure(dec("10"), RecipeUnit::Ml, &lime), None);
+        assert_eq!(
+            serving_measure(dec("1"), RecipeUnit::Piece, &lime),
+            Some(dec("1"))
+        );
+    }
+
+    #[test]
+    fn costs_a_serving_at_purchase_prices() {
+        let gin = measured(MeasureUnit::Ml, "700", "70");
+        let lime = measured(MeasureUnit::Piece, "1", "0.5");
+        let components = HashMap::from([(gin.id, gin.clone()), (lime.id, lime.clone())]);
+        let recipe = [
+            recipe_item(&gin, "50", RecipeUnit::Ml),
+            recipe_item(&lime, "1", RecipeUnit::Piece),
+        ];
+
+        assert_eq!(recipe_cost(&recipe, &components), dec("5.5"));
+    }
+
+    #[test]
+    fn leaves_out_lines_it_cannot_cost() {
+        let gin = measured(MeasureUnit::Ml, "700", "70");
+        let missing = measured(MeasureUnit::Ml, "700", "70");
+        let components = HashMap::from([(gin.id, gin.clone())]);
+        let recipe = [
+            recipe_item(&gin, "10", RecipeUnit::G),
+            recipe_item(&gin, "25", RecipeUnit::Ml),
+            recipe_item(&missing, "25", RecipeUnit::Ml),
+        ];
+
+        assert_eq!(recipe_cost(&recipe, &components), dec("2.5"));
+    }
+
+    #[test]
+    fn adds_up_the_demand_of_every_serving() {
+        let gin = measured(MeasureUnit::Ml, "700", "70");
+        let components = HashMap::from([(gin.id, gin.clone())]);
+        let gin_tonic = [recipe_item(&gin, "50", RecipeUnit::Ml)];
+        let martini = [recipe_item(&gin, "6", RecipeUnit::Cl)];
+
+        let demand = component_demand(&[(&gin_tonic[..], 2), (&martini[..], 1)], &components);
+
+        assert_eq!(demand[&gin.id], dec("160"));
+    }
+}
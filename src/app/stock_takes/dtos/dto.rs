@@ -0,0 +1,496 @@
+use std::collections::{HashMap, HashSet};
+
+use actix_web::web;
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, Condition, ConnectionTrait, DbErr, EntityTrait,
+    QueryFilter, QueryOrder, QuerySelect, Set, TransactionTrait,
+};
+
+use crate::{
+    app::{
+        inventory::dtos::dto::adjust_stock,
+        stock_takes::{
+            models::model::{
+                AdjustmentReason, ApproveStockTakeDto, ItemShrinkage, ShiftShrinkage,
+                StartStockTakeDto, StockTakeDetails, SubmitCountsDto, TakeStatus,
+            },
+            services::service::{count_variances, shrinkage_by_item, shrinkage_by_shift},
+        },
+    },
+    AppState,
+};
+
+async fn find_stock_take<C: ConnectionTrait>(
+    id: uuid::Uuid,
+    branch: uuid::Uuid,
+    conn: &C,
+) -> Result<entity::stock_takes::Model, DbErr> {
+    entity::stock_takes::Entity::find_by_id(id)
+        .filter(entity::stock_takes::Column::BranchId.eq(branch))
+        .one(conn)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Stock take not found".into()))
+}
+
+/// Locks an open stock take of the branch, so counts can't land while it is being approved.
+async fn lock_open_stock_take<C: ConnectionTrait>(
+    id: uuid::Uuid,
+    branch: uuid::Uuid,
+    conn: &C,
+) -> Result<entity::stock_takes::Model, DbErr> {
+    entity::stock_takes::Entity::find_by_id(id)
+        .filter(
+            Condition::all()
+                .add(entity::stock_takes::Column::BranchId.eq(branch))
+                .add(entity::stock_takes::Column::TakeStatus.eq(TakeStatus::Open.as_str())),
+        )
+        .lock_exclusive()
+        .one(conn)
+        .await?
+        .ok_or_else(|| DbErr::Custom("Stock take is not open".to_string()))
+}
+
+async fn branch_drinks<C: ConnectionTrait>(
+    branch: uuid::Uuid,
+    conn: &C,
+) -> Result<HashMap<uuid::Uuid, entity::stock_drinks::Model>, DbErr> {
+    Ok(entity::stock_drinks::Entity::find()
+        .filter(entity::stock_drinks::Column::BranchId.eq(branch))
+        .all(conn)
+        .await
+        .map_err(|err| {
+            eprintln!("Database retrieval error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?
+        .into_iter()
+        .map(|drink| (drink.id, drink))
+        .collect())
+}
+
+async fn stock_take_details<C: ConnectionTrait>(
+    stock_take: entity::stock_takes::Model,
+    conn: &C,
+) -> Result<StockTakeDetails, DbErr> {
+    let counts = entity::stock_take_counts::Entity::find()
+        .filter(entity::stock_take_counts::Column::StockTakeId.eq(stock_take.id))
+        .order_by_asc(entity::stock_take_counts::Column::CreatedAt)
+        .all(conn)
+        .await
+        .map_err(|err| {
+            eprintln!("Database retrieval error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    let adjustments = entity::stock_adjustments::Entity::find()
+        .filter(entity::stock_adjustments::Column::StockTakeId.eq(stock_take.id))
+        .order_by_asc(entity::stock_adjustments::Column::CreatedAt)
+        .all(conn)
+        .await
+        .map_err(|err| {
+            eprintln!("Database retrieval error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    let drinks = branch_drinks(stock_take.branch_id, conn).await?;
+    let variances = count_variances(&counts, &drinks);
+
+    Ok(StockTakeDetails {
+        stock_take,
+        counts,
+        variances,
+        adjustments,
+    })
+}
+
+/// Starts a stock take of the branch, optionally closing a till session so its shrinkage is
+/// put down to that shift. A branch has one stock take open at a time.
+pub async fn start_stock_take(
+    data: StartStockTakeDto,
+    state: &web::Data<AppState>,
+) -> Result<entity::stock_takes::Model, DbErr> {
+    if let Some(session) = data.till_session {
+        entity::till_sessions::Entity::find_by_id(session)
+            .filter(entity::till_sessions::Column::BranchId.eq(data.branch))
+            .one(state.pg_db.get_ref())
+            .await?
+            .ok_or_else(|| DbErr::RecordNotFound("Till session not found".into()))?;
+    }
+
+    let open = entity::stock_takes::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::stock_takes::Column::BranchId.eq(data.branch))
+                .add(entity::stock_takes::Column::TakeStatus.eq(TakeStatus::Open.as_str())),
+        )
+        .one(state.pg_db.get_ref())
+        .await?;
+
+    if open.is_some() {
+        return Err(DbErr::Custom(
+            "A stock take is already in progress".to_string(),
+        ));
+    }
+
+    let stock_take = entity::stock_takes::ActiveModel {
+        organization_id: Set(data.organization),
+        branch_id: Set(data.branch),
+        till_session_id: Set(data.till_session),
+        take_status: Set(TakeStatus::Open.as_str().to_string()),
+        notes: Set(data.notes),
+        started_by: Set(data.employee),
+        ..Default::default()
+    };
+
+    let result = ActiveModelTrait::insert(stock_take, state.pg_db.get_ref())
+        .await
+        .map_err(|err| {
+            eprintln!("Database insert error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    Ok(result)
+}
+
+/// The branch's stock take in progress, for staff to submit counts against.
+pub async fn get_open_stock_take(
+    branch: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<entity::stock_takes::Model, DbErr> {
+    entity::stock_takes::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::stock_takes::Column::BranchId.eq(branch))
+                .add(entity::stock_takes::Column::TakeStatus.eq(TakeStatus::Open.as_str())),
+        )
+        .one(state.pg_db.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("No stock take in progress".into()))
+}
+
+/// Records an employee's counts. What the system holds of each drink is noted alongside for
+/// the variance, without being shown to the counter.
+pub async fn submit_counts(
+    id: uuid::Uuid,
+    data: SubmitCountsDto,
+    state: &web::Data<AppState>,
+) -> Result<entity::stock_takes::Model, DbErr> {
+    let txn = state.pg_db.get_ref().begin().await?;
+
+    let stock_take = lock_open_stock_take(id, data.branch, &txn).await?;
+    let drinks = branch_drinks(data.branch, &txn).await?;
+
+    let mut counts = Vec::with_capacity(data.counts.len());
+
+    for count in data.counts {
+        let drink = drinks
+            .get(&count.drink)
+            .ok_or_else(|| DbErr::RecordNotFound("Drink not found".into()))?;
+
+        counts.push(entity::stock_take_counts::ActiveModel {
+            stock_take_id: Set(stock_take.id),
+            stock_drink_id: Set(drink.id),
+            pass: Set(data.pass),
+            counted_quantity: Set(count.quantity),
+            expected_quantity: Set(drink.quantity),
+            counted_by: Set(data.employee),
+            ..Default::default()
+        });
+    }
+
+    entity::stock_take_counts::Entity::insert_many(counts)
+        .exec(&txn)
+        .await
+        .map_err(|err| {
+            eprintln!("Database insert error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    let mut model: entity::stock_takes::ActiveModel = stock_take.into();
+
+    model.updated_at = ActiveValue::Set(chrono::Utc::now().into());
+
+    let result = ActiveModelTrait::update(model, &txn).await.map_err(|err| {
+        eprintln!("Database update error: {}", err);
+        DbErr::Custom(err.to_string())
+    })?;
+
+    txn.commit().await?;
+
+    Ok(result)
+}
+
+/// Approves a stock take: every counted drink off from what was expected has its stock moved
+/// by the variance and an adjustment recorded under the reason given, `UNEXPLAINED` if none.
+/// Sales made since a drink was counted are kept.
+pub async fn approve_stock_take(
+    id: uuid::Uuid,
+    data: ApproveStockTakeDto,
+    state: &web::Data<AppState>,
+) -> Result<StockTakeDetails, DbErr> {
+    let txn = state.pg_db.get_ref().begin().await?;
+
+    let stock_take = lock_open_stock_take(id, data.branch, &txn).await?;
+
+    let counts = entity::stock_take_counts::Entity::find()
+        .filter(entity::stock_take_counts::Column::StockTakeId.eq(stock_take.id))
+        .all(&txn)
+        .await?;
+
+    if counts.is_empty() {
+        return Err(DbErr::Custom("Nothing has been counted yet".to_string()));
+    }
+
+    let drinks = branch_drinks(data.branch, &txn).await?;
+    let variances = count_variances(&counts, &drinks);
+    let counted: HashSet<uuid::Uuid> = variances.iter().map(|v| v.drink).collect();
+
+    if let Some((drink, _)) = data.reasons.iter().find(|(d, _)| !counted.contains(d)) {
+        return Err(DbErr::Custom(format!(
+            "Drink {} was not counted in this stock take",
+            drink
+        )));
+    }
+
+    let reasons: HashMap<uuid::Uuid, AdjustmentReason> = data.reasons.into_iter().collect();
+    let mut adjustments = Vec::new();
+
+    for variance in variances.iter().filter(|v| v.variance != 0) {
+        let Some(drink) = drinks.get(&variance.drink) else {
+            continue;
+        };
+
+        adjust_stock(drink.id, variance.variance, &txn).await?;
+
+        adjustments.push(entity::stock_adjustments::ActiveModel {
+            organization_id: Set(stock_take.organization_id),
+            branch_id: Set(stock_take.branch_id),
+            stock_drink_id: Set(drink.id),
+            stock_take_id: Set(Some(stock_take.id)),
+            expected_quantity: Set(variance.expected_quantity),
+            counted_quantity: Set(variance.counted_quantity),
+            quantity: Set(variance.variance),
+            reason: Set(reasons
+                .get(&drink.id)
+                .unwrap_or(&AdjustmentReason::Unexplained)
+                .as_str()
+                .to_string()),
+            unit_cost: Set(drink.unit_price),
+            created_by: Set(data.employee),
+            ..Default::default()
+        });
+    }
+
+    if !adjustments.is_empty() {
+        entity::stock_adjustments::Entity::insert_many(adjustments)
+            .exec(&txn)
+            .await
+            .map_err(|err| {
+                eprintln!("Database insert error: {}", err);
+                DbErr::Custom(err.to_string())
+            })?;
+    }
+
+    let now = chrono::Utc::now();
+    let mut model: entity::stock_takes::ActiveModel = stock_take.into();
+
+    model.take_status = ActiveValue::Set(TakeStatus::Approved.as_str().to_string());
+    model.approved_by = ActiveValue::Set(Some(data.employee));
+    model.approved_at = ActiveValue::Set(Some(now.into()));
+    model.updated_at = ActiveValue::Set(now.into());
+
+    let stock_take = ActiveModelTrait::update(model, &txn).await.map_err(|err| {
+        eprintln!("Database update error: {}", err);
+        DbErr::Custom(err.to_string())
+    })?;
+
+    let details = stock_take_details(stock_take, &txn).await?;
+
+    txn.commit().await?;
+
+    Ok(details)
+}
+
+pub async fn cancel_stock_take(
+    id: uuid::Uuid,
+    branch: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<entity::stock_takes::Model, DbErr> {
+    let txn = state.pg_db.get_ref().begin().await?;
+
+    let stock_take = lock_open_stock_take(id, branch, &txn).await?;
+
+    let mut model: entity::stock_takes::ActiveModel = stock_take.into();
+
+    model.take_status = ActiveValue::Set(TakeStatus::Cancelled.as_str().to_string());
+    model.updated_at = ActiveValue::Set(chrono::Utc::now().into());
+
+    let result = ActiveModelTrait::update(model, &txn).await.map_err(|err| {
+        eprintln!("Database update error: {}", err);
+        DbErr::Custom(err.to_string())
+    })?;
+
+    txn.commit().await?;
+
+    Ok(result)
+}
+
+pub async fn get_stock_take(
+    id: uuid::Uuid,
+    branch: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<StockTakeDetails, DbErr> {
+    let stock_take = find_stock_take(id, branch, state.pg_db.get_ref()).await?;
+
+    stock_take_details(stock_take, state.pg_db.get_ref()).await
+}
+
+pub async fn get_branch_stock_takes(
+    branch: uuid::Uuid,
+    status: Option<TakeStatus>,
+    state: &web::Data<AppState>,
+) -> Result<Vec<entity::stock_takes::Model>, DbErr> {
+    let mut condition = Condition::all().add(entity::stock_takes::Column::BranchId.eq(branch));
+
+    if let Some(status) = status {
+        condition = condition.add(entity::stock_takes::Column::TakeStatus.eq(status.as_str()));
+    }
+
+    let stock_takes = entity::stock_takes::Entity::find()
+        .filter(condition)
+        .order_by_desc(entity::stock_takes::Column::CreatedAt)
+        .all(state.pg_db.get_ref())
+        .await
+        .map_err(|err| {
+            eprintln!("Database retrieval error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    Ok(stock_takes)
+}
+
+/// The branch's stock adjustments made in the period, ends included.
+async fn period_adjustments<C: ConnectionTrait>(
+    branch: uuid::Uuid,
+    period_start: chrono::NaiveDate,
+    period_end: chrono::NaiveDate,
+    conn: &C,
+) -> Result<Vec<entity::stock_adjustments::Model>, DbErr> {
+    let from = period_start
+        .and_hms_opt(0, 0, 0)
+        .unwrap_or_default()
+        .and_utc();
+    let to = period_end
+        .succ_opt()
+        .unwrap_or(period_end)
+        .and_hms_opt(0, 0, 0)
+        .unwrap_or_default()
+        .and_utc();
+
+    // Transfers move stock between branches, nothing is lost
+    entity::stock_adjustments::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::stock_adjustments::Column::BranchId.eq(branch))
+                .add(entity::stock_adjustments::Column::Reason.is_not_in([
+                    AdjustmentReason::TransferOut.as_str(),
+                    AdjustmentReason::TransferIn.as_str(),
+                ]))
+                .add(entity::stock_adjustments::Column::CreatedAt.gte(from))
+                .add(entity::stock_adjustments::Column::CreatedAt.lt(to)),
+        )
+        .all(conn)
+        .await
+        .map_err(|err| {
+            eprintln!("Database retrieval error: {}", err);
+            DbErr::Custom(err.to_string())
+        })
+}
+
+pub async fn get_item_shrinkage(
+    branch: uuid::Uuid,
+    period_start: chrono::NaiveDate,
+    period_end: chrono::NaiveDate,
+    state: &web::Data<AppState>,
+) -> Result<Vec<ItemShrinkage>, DbErr> {
+    let adjustments =
+        period_adjustments(branch, period_start, period_end, state.pg_db.get_ref()).await?;
+    let drinks = branch_drinks(branch, state.pg_db.get_ref()).await?;
+
+    Ok(shrinkage_by_item(&adjustments, &drinks))
+}
+
+pub async fn get_shift_shrinkage(
+    branch: uuid::Uuid,
+    period_start: chrono::NaiveDate,
+    period_end: chrono::NaiveDate,
+    state: &web::Data<AppState>,
+) -> Result<Vec<ShiftShrinkage>, DbErr> {
+    let adjustments =
+        period_adjustments(branch, period_start, period_end, state.pg_db.get_ref()).await?;
+
+    let takes: HashMap<uuid::Uuid, entity::stock_takes::Model> =
+        entity::stock_takes::Entity::find()
+            .filter(
+                entity::stock_takes::Column::Id
+                    .is_in(adjustments.iter().filter_map(|a| a.stock_take_id)),
+            )
+            .all(state.pg_db.get_ref())
+            .await?
+            .into_iter()
+            .map(|take| (take.id, take))
+            .collect();
+
+    let sessions: HashMap<uuid::Uuid, entity::till_sessions::Model> =
+        entity::till_sessions::Entity::find()
+            .filter(
+                entity::till_sessions::Column::Id
+                    .is_in(takes.values().filter_map(|t| t.till_session_id)),
+            )
+            .all(state.pg_db.get_ref())
+            .await?
+            .into_iter()
+            .map(|session| (session.id, session))
+            .collect();
+
+    Ok(shrinkage_by_shift(&adjustments, &takes, &sessions))
+}
+
+/// The branch's stock adjustments over the period, latest first: stock take corrections and
+/// stock sent to or received from other branches.
+pub async fn get_stock_history(
+    branch: uuid::Uuid,
+    drink: Option<uuid::Uuid>,
+    period_start: chrono::NaiveDate,
+    period_end: chrono::NaiveDate,
+    state: &web::Data<AppState>,
+) -> Result<Vec<entity::stock_adjustments::Model>, DbErr> {
+    let from = period_start
+        .and_hms_opt(0, 0, 0)
+        .unwrap_or_default()
+        .and_utc();
+    let to = period_end
+        .succ_opt()
+        .unwrap_or(period_end)
+        .and_hms_opt(0, 0, 0)
+        .unwrap_or_default()
+        .and_utc();
+
+    let mut condition = Condition::all()
+        .add(entity::stock_adjustments::Column::BranchId.eq(branch))
+        .add(entity::stock_adjustments::Column::CreatedAt.gte(from))
+        .add(entity::stock_adjustments::Column::CreatedAt.lt(to));
+
+    if let Some(drink) = drink {
+        condition = condition.add(entity::stock_adjustments::Column::StockDrinkId.eq(drink));
+    }
+
+    entity::stock_adjustments::Entity::find()
+        .filter(condition)
+        .order_by_desc(entity::stock_adjustments::Column::CreatedAt)
+        .all(state.pg_db.get_ref())
+        .await
+        .map_err(|err| {
+            eprintln!("Database retrieval error: {}", err);
+            DbErr::Custom(err.to_string())
+        })
+}
@@ -0,0 +1,294 @@
+use std::{collections::BTreeMap, str::FromStr};
+
+use chrono::NaiveDate;
+use sea_orm::prelude::{DateTimeWithTimeZone, Decimal};
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::libs::validator::{
+    validate_adjustment_reason, validate_naive_date_rest, validate_take_status,
+};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum TakeStatus {
+    Open,
+    Approved,
+    Cancelled,
+}
+
+impl TakeStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TakeStatus::Open => "OPEN",
+            TakeStatus::Approved => "APPROVED",
+            TakeStatus::Cancelled => "CANCELLED",
+        }
+    }
+}
+
+impl FromStr for TakeStatus {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_uppercase().as_str() {
+            "OPEN" => Ok(TakeStatus::Open),
+            "APPROVED" => Ok(TakeStatus::Approved),
+            "CANCELLED" => Ok(TakeStatus::Cancelled),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Why a drink's stock was adjusted: a stock take found it off, or it was sent to or received
+/// from another branch.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum AdjustmentReason {
+    Breakage,
+    Spillage,
+    Theft,
+    Comp,
+    Miscount,
+    Unexplained,
+    TransferOut,
+    TransferIn,
+}
+
+impl AdjustmentReason {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AdjustmentReason::Breakage => "BREAKAGE",
+            AdjustmentReason::Spillage => "SPILLAGE",
+            AdjustmentReason::Theft => "THEFT",
+            AdjustmentReason::Comp => "COMP",
+            AdjustmentReason::Miscount => "MISCOUNT",
+            AdjustmentReason::Unexplained => "UNEXPLAINED",
+            AdjustmentReason::TransferOut => "TRANSFEROUT",
+            AdjustmentReason::TransferIn => "TRANSFERIN",
+        }
+    }
+}
+
+impl FromStr for AdjustmentReason {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_uppercase().as_str() {
+            "BREAKAGE" => Ok(AdjustmentReason::Breakage),
+            "SPILLAGE" => Ok(AdjustmentReason::Spillage),
+            "THEFT" => Ok(AdjustmentReason::Theft),
+            "COMP" => Ok(AdjustmentReason::Comp),
+            "MISCOUNT" => Ok(AdjustmentReason::Miscount),
+            "UNEXPLAINED" => Ok(AdjustmentReason::Unexplained),
+            "TRANSFEROUT" => Ok(AdjustmentReason::TransferOut),
+            "TRANSFERIN" => Ok(AdjustmentReason::TransferIn),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct StartStockTakeDto {
+    pub organization: uuid::Uuid,
+    pub branch: uuid::Uuid,
+    pub employee: uuid::Uuid,
+    pub till_session: Option<uuid::Uuid>,
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct StartStockTakeParams {
+    pub till_session: Option<uuid::Uuid>,
+    #[validate(length(max = 500, message = "Notes are too long"))]
+    pub notes: Option<String>,
+}
+
+impl StartStockTakeParams {
+    pub fn into_dto(
+        self,
+        organization: uuid::Uuid,
+        branch: uuid::Uuid,
+        employee: uuid::Uuid,
+    ) -> StartStockTakeDto {
+        StartStockTakeDto {
+            organization,
+            branch,
+            employee,
+            till_session: self.till_session,
+            notes: self.notes,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct CountLine {
+    pub drink: uuid::Uuid,
+    pub quantity: i32,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SubmitCountsDto {
+    pub branch: uuid::Uuid,
+    pub employee: uuid::Uuid,
+    pub pass: i32,
+    pub counts: Vec<CountLine>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct CountLineParams {
+    pub drink: uuid::Uuid,
+    #[validate(range(min = 0, max = 100000, message = "Quantity is invalid"))]
+    pub quantity: i32,
+}
+
+/// Counts from one employee. Counts of a drink in the same pass add up, so a bar and its
+/// cellar can be counted separately. A later pass recounts the drinks it covers.
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct SubmitCountsParams {
+    #[validate(range(min = 1, max = 20, message = "Pass is invalid"))]
+    pub pass: Option<i32>,
+    #[validate(length(min = 1, max = 500, message = "Counts are invalid"), nested)]
+    pub counts: Vec<CountLineParams>,
+}
+
+impl SubmitCountsParams {
+    pub fn into_dto(self, branch: uuid::Uuid, employee: uuid::Uuid) -> SubmitCountsDto {
+        SubmitCountsDto {
+            branch,
+            employee,
+            pass: self.pass.unwrap_or(1),
+            counts: self
+                .counts
+                .into_iter()
+                .map(|count| CountLine {
+                    drink: count.drink,
+                    quantity: count.quantity,
+                })
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ApproveStockTakeDto {
+    pub branch: uuid::Uuid,
+    pub employee: uuid::Uuid,
+    pub reasons: Vec<(uuid::Uuid, AdjustmentReason)>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct AdjustmentReasonParams {
+    pub drink: uuid::Uuid,
+    #[validate(custom(function = "validate_adjustment_reason"))]
+    pub reason: String,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct ApproveStockTakeParams {
+    #[serde(default)]
+    #[validate(length(max = 500, message = "Reasons are invalid"), nested)]
+    pub reasons: Vec<AdjustmentReasonParams>,
+}
+
+impl ApproveStockTakeParams {
+    pub fn into_dto(self, branch: uuid::Uuid, employee: uuid::Uuid) -> ApproveStockTakeDto {
+        ApproveStockTakeDto {
+            branch,
+            employee,
+            reasons: self
+                .reasons
+                .into_iter()
+                .map(|reason| {
+                    (
+                        reason.drink,
+                        reason
+                            .reason
+                            .parse()
+                            .unwrap_or(AdjustmentReason::Unexplained),
+                    )
+                })
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct StockTakeQuery {
+    #[validate(custom(function = "validate_take_status"))]
+    pub status: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct ShrinkageQuery {
+    #[validate(custom(function = "validate_naive_date_rest"))]
+    pub period_start: NaiveDate,
+    #[validate(custom(function = "validate_naive_date_rest"))]
+    pub period_end: NaiveDate,
+}
+
+/// The branch's stock history over the period, ends included, optionally of one drink.
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct StockHistoryQuery {
+    #[validate(custom(function = "validate_naive_date_rest"))]
+    pub period_start: NaiveDate,
+    #[validate(custom(function = "validate_naive_date_rest"))]
+    pub period_end: NaiveDate,
+    pub drink: Option<uuid::Uuid>,
+}
+
+/// A counted drink against what the system expected, from the drink's latest pass.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CountVariance {
+    pub drink: uuid::Uuid,
+    pub name: String,
+    pub pass: i32,
+    pub expected_quantity: i32,
+    pub counted_quantity: i32,
+    pub variance: i32,
+    pub variance_value: Decimal,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct StockTakeDetails {
+    pub stock_take: entity::stock_takes::Model,
+    pub counts: Vec<entity::stock_take_counts::Model>,
+    pub variances: Vec<CountVariance>,
+    pub adjustments: Vec<entity::stock_adjustments::Model>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ShrinkageTotals {
+    pub units_lost: i64,
+    pub units_found: i64,
+    pub value_lost: Decimal,
+    pub units_by_reason: BTreeMap<String, i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ItemShrinkage {
+    pub drink: uuid::Uuid,
+    pub name: String,
+    #[serde(flatten)]
+    pub totals: ShrinkageTotals,
+}
+
+/// Shrinkage found by the stock takes closing a bartender's till session. Stock takes not tied
+/// to a session are grouped with no session.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShiftShrinkage {
+    pub till_session: Option<uuid::Uuid>,
+    pub employee: Option<uuid::Uuid>,
+    pub opened_at: Option<DateTimeWithTimeZone>,
+    pub closed_at: Option<DateTimeWithTimeZone>,
+    #[serde(flatten)]
+    pub totals: ShrinkageTotals,
+}
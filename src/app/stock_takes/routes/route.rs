@@ -0,0 +1,86 @@
+use actix_web::web;
+
+use crate::{
+    app::stock_takes::controllers::controller::{
+        approve_take, branch_takes, cancel_take, count_stock, current_take, item_shrinkage,
+        shift_shrinkage, start_take, stock_history, take_details,
+    },
+    middlewares::{auth::JwtAuthMiddleware, checker::CheckUserMiddleware},
+    AppState,
+};
+
+pub fn routes(cfg: &mut web::ServiceConfig, state: web::Data<AppState>) {
+    cfg.service(
+        web::scope("/api/v1/stock")
+            .route(
+                "/takes",
+                web::post()
+                    .to(start_take)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/takes",
+                web::get()
+                    .to(branch_takes)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/takes/current",
+                web::get()
+                    .to(current_take)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/takes/{id}",
+                web::get()
+                    .to(take_details)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/takes/counts/{id}",
+                web::post()
+                    .to(count_stock)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/takes/approve/{id}",
+                web::post()
+                    .to(approve_take)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/takes/cancel/{id}",
+                web::post()
+                    .to(cancel_take)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/shrinkage/items",
+                web::get()
+                    .to(item_shrinkage)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/shrinkage/shifts",
+                web::get()
+                    .to(shift_shrinkage)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/history",
+                web::get()
+                    .to(stock_history)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            ),
+    );
+}
@@ -0,0 +1,227 @@
+use std::{
+    cmp::Reverse,
+    collections::{BTreeMap, HashMap},
+};
+
+use sea_orm::prelude::Decimal;
+
+use crate::app::stock_takes::models::model::{
+    CountVariance, ItemShrinkage, ShiftShrinkage, ShrinkageTotals,
+};
+
+/// Variance of each counted drink. Only a drink's latest pass counts, its counts in that pass
+/// are added up and set against what the system held at the most recent of them. The system's
+/// stock already nets off sales, so sales made while counting don't show up as shrinkage.
+pub fn count_variances(
+    counts: &[entity::stock_take_counts::Model],
+    drinks: &HashMap<uuid::Uuid, entity::stock_drinks::Model>,
+) -> Vec<CountVariance> {
+    let mut latest: BTreeMap<uuid::Uuid, Vec<&entity::stock_take_counts::Model>> = BTreeMap::new();
+
+    for count in counts {
+        let entry = latest.entry(count.stock_drink_id).or_default();
+
+        match entry.first().map(|c| c.pass) {
+            Some(pass) if pass > count.pass => continue,
+            Some(pass) if pass < count.pass => entry.clear(),
+            _ => {}
+        }
+
+        entry.push(count);
+    }
+
+    let mut variances: Vec<CountVariance> = latest
+        .into_iter()
+        .filter_map(|(drink, counts)| {
+            let last = counts.iter().max_by_key(|c| c.created_at)?;
+            let counted: i32 = counts.iter().map(|c| c.counted_quantity).sum();
+            let variance = counted - last.expected_quantity;
+            let stock = drinks.get(&drink);
+
+            Some(CountVariance {
+                drink,
+                name: stock.map(|s| s.name.clone()).unwrap_or_default(),
+                pass: last.pass,
+                expected_quantity: last.expected_quantity,
+                counted_quantity: counted,
+                variance,
+                variance_value: stock
+                    .map(|s| s.unit_price * Decimal::from(variance))
+                    .unwrap_or_default(),
+            })
+        })
+        .collect();
+
+    variances.sort_by(|a, b| a.name.cmp(&b.name));
+    variances
+}
+
+fn add_adjustment(totals: &mut ShrinkageTotals, adjustment: &entity::stock_adjustments::Model) {
+    let units = i64::from(adjustment.quantity);
+
+    if units < 0 {
+        totals.units_lost -= units;
+        totals.value_lost -= adjustment.unit_cost * Decimal::from(units);
+    } else {
+        totals.units_found += units;
+    }
+
+    *totals
+        .units_by_reason
+        .entry(adjustment.reason.clone())
+        .or_default() += units;
+}
+
+pub fn shrinkage_by_item(
+    adjustments: &[entity::stock_adjustments::Model],
+    drinks: &HashMap<uuid::Uuid, entity::stock_drinks::Model>,
+) -> Vec<ItemShrinkage> {
+    let mut items: HashMap<uuid::Uuid, ItemShrinkage> = HashMap::new();
+
+    for adjustment in adjustments {
+        let item = items
+            .entry(adjustment.stock_drink_id)
+            .or_insert_with(|| ItemShrinkage {
+                drink: adjustment.stock_drink_id,
+                name: drinks
+                    .get(&adjustment.stock_drink_id)
+                    .map(|d| d.name.clone())
+                    .unwrap_or_default(),
+                totals: ShrinkageTotals::default(),
+            });
+
+        add_adjustment(&mut item.totals, adjustment);
+    }
+
+    let mut items: Vec<ItemShrinkage> = items.into_values().collect();
+
+    items.sort_by(|a, b| {
+        b.totals
+            .value_lost
+            .cmp(&a.totals.value_lost)
+            .then_with(|| a.name.cmp(&b.name))
+    });
+    items
+}
+
+pub fn shrinkage_by_shift(
+    adjustments: &[entity::stock_adjustments::Model],
+    takes: &HashMap<uuid::Uuid, entity::stock_takes::Model>,
+    sessions: &HashMap<uuid::Uuid, entity::till_sessions::Model>,
+) -> Vec<ShiftShrinkage> {
+    let mut shifts: BTreeMap<Option<uuid::Uuid>, ShiftShrinkage> = BTreeMap::new();
+
+    for adjustment in adjustments {
+        let session = adjustment
+            .stock_take_id
+            .and_then(|take| takes.get(&take))
+            .and_then(|take| take.till_session_id)
+            .and_then(|session| sessions.get(&session));
+
+        let shift = shifts
+            .entry(session.map(|s| s.id))
+            .or_insert_with(|| ShiftShrinkage {
+                till_session: session.map(|s| s.id),
+                employee: session.map(|s| s.employee_id),
+                opened_at: session.map(|s| s.opened_at),
+                closed_at: session.and_then(|s| s.closed_at),
+                totals: ShrinkageTotals::default(),
+            });
+
+        add_adjustment(&mut shift.totals, adjustment);
+    }
+
+    let mut shifts: Vec<ShiftShrinkage> = shifts.into_values().collect();
+
+    shifts.sort_by_key(|shift| Reverse(shift.opened_at));
+    shifts
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+
+    use super::*;
+    use crate::utils::fixtures::{dec, stock_adjustment, stock_drink, stock_take_count};
+
+    fn costed(name: &str, unit_price: &str) -> entity::stock_drinks::Model {
+        entity::stock_drinks::Model {
+            unit_price: dec(unit_price),
+            ..stock_drink(name, "10")
+        }
+    }
+
+    /// A count `minutes` into the stock take.
+    fn count(
+        drink: &entity::stock_drinks::Model,
+        pass: i32,
+        counted: i32,
+        expected: i32,
+        minutes: i64,
+    ) -> entity::stock_take_counts::Model {
+        let at = (chrono::Utc::now() + Duration::minutes(minutes)).into();
+
+        entity::stock_take_counts::Model {
+            created_at: at,
+            updated_at: at,
+            ..stock_take_count(drink, pass, counted, expected)
+        }
+    }
+
+    #[test]
+    fn adds_up_a_drinks_counts_in_its_latest_pass() {
+        let gin = costed("Gin", "4");
+        let drinks = HashMap::from([(gin.id, gin.clone())]);
+        let counts = [
+            count(&gin, 2, 6, 20, 10),
+            count(&gin, 1, 30, 20, 0),
+            count(&gin, 2, 8, 18, 12),
+        ];
+
+        let variances = count_variances(&counts, &drinks);
+
+        assert_eq!(variances.len(), 1);
+        assert_eq!(variances[0].pass, 2);
+        assert_eq!(variances[0].counted_quantity, 14);
+        assert_eq!(variances[0].expected_quantity, 18);
+        assert_eq!(variances[0].variance, -4);
+        assert_eq!(variances[0].variance_value, dec("-16"));
+    }
+
+    #[test]
+    fn lists_variances_by_name() {
+        let vodka = costed("Vodka", "3");
+        let gin = costed("Gin", "4");
+        let drinks = HashMap::from([(vodka.id, vodka.clone()), (gin.id, gin.clone())]);
+        let counts = [count(&vodka, 1, 12, 10, 0), count(&gin, 1, 5, 5, 1)];
+
+        let variances = count_variances(&counts, &drinks);
+        let names: Vec<&str> = variances.iter().map(|v| v.name.as_str()).collect();
+
+        assert_eq!(names, ["Gin", "Vodka"]);
+        assert_eq!(variances[0].variance_value, Decimal::ZERO);
+        assert_eq!(variances[1].variance_value, dec("6"));
+    }
+
+    #[test]
+    fn totals_shrinkage_per_item_biggest_loss_first() {
+        let gin = costed("Gin", "4");
+        let rum = costed("Rum", "9");
+        let drinks = HashMap::from([(gin.id, gin.clone()), (rum.id, rum.clone())]);
+        let adjustments = [
+            stock_adjustment(&gin, -3, "BREAKAGE"),
+            stock_adjustment(&gin, 1, "COUNT"),
+            stock_adjustment(&rum, -2, "THEFT"),
+        ];
+
+        let items = shrinkage_by_item(&adjustments, &drinks);
+
+        assert_eq!(items[0].name, "Rum");
+        assert_eq!(items[0].totals.value_lost, dec("18"));
+        assert_eq!(items[1].name, "Gin");
+        assert_eq!(items[1].totals.units_lost, 3);
+        assert_eq!(items[1].totals.units_found, 1);
+        assert_eq!(items[1].totals.value_lost, dec("12"));
+        assert_eq!(items[1].totals.units_by_reason["BREAKAGE"], -3);
+    }
+}
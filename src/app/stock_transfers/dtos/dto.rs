@@ -0,0 +1,506 @@
+use std::collections::{HashMap, HashSet};
+
+use actix_web::web;
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, Condition, ConnectionTrait, DbErr, EntityTrait,
+    QueryFilter, QueryOrder, QuerySelect, Set, TransactionTrait,
+};
+
+use crate::{
+    app::{
+        inventory::dtos::dto::adjust_stock,
+        stock_takes::models::model::AdjustmentReason,
+        stock_transfers::{
+            models::model::{
+                DispatchTransferDto, ReceiveTransferDto, RequestTransferDto, StockTransferDetails,
+                TransferStatus,
+            },
+            services::service::{has_discrepancy, line_quantities, same_measure, source_drink},
+        },
+    },
+    AppState,
+};
+
+/// Either end of the transfer is the branch.
+fn involving(branch: uuid::Uuid) -> Condition {
+    Condition::any()
+        .add(entity::stock_transfers::Column::FromBranchId.eq(branch))
+        .add(entity::stock_transfers::Column::ToBranchId.eq(branch))
+}
+
+/// Locks a transfer in `status` on which `condition` holds for the branch, so it can't be
+/// dispatched, received or cancelled twice.
+async fn lock_transfer<C: ConnectionTrait>(
+    id: uuid::Uuid,
+    condition: Condition,
+    status: TransferStatus,
+    message: &str,
+    conn: &C,
+) -> Result<entity::stock_transfers::Model, DbErr> {
+    entity::stock_transfers::Entity::find_by_id(id)
+        .filter(
+            Condition::all()
+                .add(condition)
+                .add(entity::stock_transfers::Column::TransferStatus.eq(status.as_str())),
+        )
+        .lock_exclusive()
+        .one(conn)
+        .await?
+        .ok_or_else(|| DbErr::Custom(message.to_string()))
+}
+
+async fn transfer_lines<C: ConnectionTrait>(
+    transfer: uuid::Uuid,
+    conn: &C,
+) -> Result<Vec<entity::stock_transfer_lines::Model>, DbErr> {
+    entity::stock_transfer_lines::Entity::find()
+        .filter(entity::stock_transfer_lines::Column::StockTransferId.eq(transfer))
+        .order_by_asc(entity::stock_transfer_lines::Column::CreatedAt)
+        .all(conn)
+        .await
+        .map_err(|err| {
+            eprintln!("Database retrieval error: {}", err);
+            DbErr::Custom(err.to_string())
+        })
+}
+
+async fn transfer_details<C: ConnectionTrait>(
+    transfer: entity::stock_transfers::Model,
+    conn: &C,
+) -> Result<StockTransferDetails, DbErr> {
+    let lines = transfer_lines(transfer.id, conn).await?;
+
+    Ok(StockTransferDetails { transfer, lines })
+}
+
+/// A line's drink, locked so its stock can't move between being checked and adjusted.
+async fn lock_drink<C: ConnectionTrait>(
+    drink: uuid::Uuid,
+    conn: &C,
+) -> Result<entity::stock_drinks::Model, DbErr> {
+    entity::stock_drinks::Entity::find_by_id(drink)
+        .lock_exclusive()
+        .one(conn)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Drink not found".into()))
+}
+
+async fn insert_adjustments<C: ConnectionTrait>(
+    adjustments: Vec<entity::stock_adjustments::ActiveModel>,
+    conn: &C,
+) -> Result<(), DbErr> {
+    if adjustments.is_empty() {
+        return Ok(());
+    }
+
+    entity::stock_adjustments::Entity::insert_many(adjustments)
+        .exec(conn)
+        .await
+        .map_err(|err| {
+            eprintln!("Database insert error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    Ok(())
+}
+
+async fn update_line<C: ConnectionTrait>(
+    model: entity::stock_transfer_lines::ActiveModel,
+    conn: &C,
+) -> Result<(), DbErr> {
+    ActiveModelTrait::update(model, conn).await.map_err(|err| {
+        eprintln!("Database update error: {}", err);
+        DbErr::Custom(err.to_string())
+    })?;
+
+    Ok(())
+}
+
+/// Requests drinks from another branch of the organization; foods have no stock to move. Each
+/// line names a drink of the requesting branch and is matched to the sending branch's drink, which must be measured the
+/// same way. Nothing moves until the sending branch dispatches.
+pub async fn request_transfer(
+    data: RequestTransferDto,
+    state: &web::Data<AppState>,
+) -> Result<StockTransferDetails, DbErr> {
+    if data.from_branch == data.to_branch {
+        return Err(DbErr::Custom(
+            "A branch can't transfer stock to itself".to_string(),
+        ));
+    }
+
+    entity::branches::Entity::find_by_id(data.from_branch)
+        .filter(
+            Condition::all()
+                .add(entity::branches::Column::OrganizationId.eq(data.organization))
+                .add(entity::branches::Column::IsActive.eq(true))
+                .add(entity::branches::Column::IsDeleted.eq(false)),
+        )
+        .one(state.pg_db.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Branch not found".into()))?;
+
+    let drinks: HashMap<uuid::Uuid, entity::stock_drinks::Model> =
+        entity::stock_drinks::Entity::find()
+            .filter(
+                Condition::all()
+                    .add(entity::stock_drinks::Column::BranchId.eq(data.to_branch))
+                    .add(
+                        entity::stock_drinks::Column::Id
+                            .is_in(data.lines.iter().map(|line| line.drink)),
+                    ),
+            )
+            .all(state.pg_db.get_ref())
+            .await?
+            .into_iter()
+            .map(|drink| (drink.id, drink))
+            .collect();
+
+    let sources = entity::stock_drinks::Entity::find()
+        .filter(entity::stock_drinks::Column::BranchId.eq(data.from_branch))
+        .all(state.pg_db.get_ref())
+        .await
+        .map_err(|err| {
+            eprintln!("Database retrieval error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    let mut requested = HashSet::with_capacity(data.lines.len());
+    let mut lines = Vec::with_capacity(data.lines.len());
+
+    for line in &data.lines {
+        let drink = drinks
+            .get(&line.drink)
+            .ok_or_else(|| DbErr::RecordNotFound("Drink not found".into()))?;
+
+        let source = match line.source_drink {
+            Some(id) => sources.iter().find(|source| source.id == id),
+            None => source_drink(drink, &sources),
+        }
+        .ok_or_else(|| {
+            DbErr::Custom(format!(
+                "{} is not stocked at the sending branch",
+                drink.name
+            ))
+        })?;
+
+        if !same_measure(drink, source) {
+            return Err(DbErr::Custom(format!(
+                "{} is measured differently at the sending branch",
+                drink.name
+            )));
+        }
+
+        if !requested.insert(source.id) {
+            return Err(DbErr::Custom(format!(
+                "{} is requested more than once",
+                drink.name
+            )));
+        }
+
+        lines.push((source.id, drink.id, line.quantity, source.unit_price));
+    }
+
+    let txn = state.pg_db.get_ref().begin().await?;
+
+    let transfer = entity::stock_transfers::ActiveModel {
+        organization_id: Set(data.organization),
+        from_branch_id: Set(data.from_branch),
+        to_branch_id: Set(data.to_branch),
+        transfer_status: Set(TransferStatus::Requested.as_str().to_string()),
+        notes: Set(data.notes),
+        requested_by: Set(data.employee),
+        ..Default::default()
+    };
+
+    let transfer = ActiveModelTrait::insert(transfer, &txn)
+        .await
+        .map_err(|err| {
+            eprintln!("Database insert error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    let lines = lines
+        .into_iter()
+        .map(|(from_drink, to_drink, quantity, unit_cost)| {
+            entity::stock_transfer_lines::ActiveModel {
+                stock_transfer_id: Set(transfer.id),
+                from_drink_id: Set(from_drink),
+                to_drink_id: Set(to_drink),
+                quantity_requested: Set(quantity),
+                unit_cost: Set(unit_cost),
+                ..Default::default()
+            }
+        });
+
+    entity::stock_transfer_lines::Entity::insert_many(lines)
+        .exec(&txn)
+        .await
+        .map_err(|err| {
+            eprintln!("Database insert error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    let details = transfer_details(transfer, &txn).await?;
+
+    txn.commit().await?;
+
+    Ok(details)
+}
+
+/// Dispatches a requested transfer from the sending branch: the stock of each line leaves the
+/// branch, recorded as a `TRANSFEROUT` adjustment, and the transfer is in transit until the
+/// requesting branch receives it. A line can't take more than the branch holds.
+pub async fn dispatch_transfer(
+    id: uuid::Uuid,
+    data: DispatchTransferDto,
+    state: &web::Data<AppState>,
+) -> Result<StockTransferDetails, DbErr> {
+    let txn = state.pg_db.get_ref().begin().await?;
+
+    let transfer = lock_transfer(
+        id,
+        Condition::all().add(entity::stock_transfers::Column::FromBranchId.eq(data.branch)),
+        TransferStatus::Requested,
+        "Transfer is not awaiting dispatch",
+        &txn,
+    )
+    .await?;
+
+    let mut lines = transfer_lines(transfer.id, &txn).await?;
+    let quantities = line_quantities(
+        &lines,
+        &data.lines,
+        |line| line.quantity_requested,
+        |line| line.quantity_requested,
+    )
+    .map_err(DbErr::Custom)?;
+
+    if quantities.values().all(|quantity| *quantity == 0) {
+        return Err(DbErr::Custom("Nothing to dispatch".to_string()));
+    }
+
+    // Drinks are locked in the same order everywhere so concurrent transfers can't deadlock
+    lines.sort_by_key(|line| line.from_drink_id);
+
+    let mut adjustments = Vec::new();
+
+    for line in lines {
+        let quantity = quantities.get(&line.id).copied().unwrap_or_default();
+        let mut model: entity::stock_transfer_lines::ActiveModel = line.clone().into();
+
+        model.quantity_dispatched = ActiveValue::Set(Some(quantity));
+        model.updated_at = ActiveValue::Set(chrono::Utc::now().into());
+
+        if quantity > 0 {
+            let drink = lock_drink(line.from_drink_id, &txn).await?;
+
+            if drink.quantity < quantity {
+                return Err(DbErr::Custom(format!("Not enough {} in stock", drink.name)));
+            }
+
+            let stock = adjust_stock(drink.id, -quantity, &txn).await?;
+
+            adjustments.push(entity::stock_adjustments::ActiveModel {
+                organization_id: Set(transfer.organization_id),
+                branch_id: Set(transfer.from_branch_id),
+                stock_drink_id: Set(drink.id),
+                stock_transfer_id: Set(Some(transfer.id)),
+                expected_quantity: Set(drink.quantity),
+                counted_quantity: Set(stock.quantity),
+                quantity: Set(-quantity),
+                reason: Set(AdjustmentReason::TransferOut.as_str().to_string()),
+                unit_cost: Set(drink.unit_price),
+                created_by: Set(data.employee),
+                ..Default::default()
+            });
+
+            model.unit_cost = ActiveValue::Set(drink.unit_price);
+        }
+
+        update_line(model, &txn).await?;
+    }
+
+    insert_adjustments(adjustments, &txn).await?;
+
+    let now = chrono::Utc::now();
+    let mut model: entity::stock_transfers::ActiveModel = transfer.into();
+
+    model.transfer_status = ActiveValue::Set(TransferStatus::InTransit.as_str().to_string());
+    model.dispatched_by = ActiveValue::Set(Some(data.employee));
+    model.dispatched_at = ActiveValue::Set(Some(now.into()));
+    model.updated_at = ActiveValue::Set(now.into());
+
+    let transfer = ActiveModelTrait::update(model, &txn).await.map_err(|err| {
+        eprintln!("Database update error: {}", err);
+        DbErr::Custom(err.to_string())
+    })?;
+
+    let details = transfer_details(transfer, &txn).await?;
+
+    txn.commit().await?;
+
+    Ok(details)
+}
+
+/// Receives a transfer in transit at the requesting branch: what arrived of each line is added
+/// to the branch's stock and recorded as a `TRANSFERIN` adjustment. Arrivals short of what was
+/// dispatched need a discrepancy note, which stays on the transfer.
+pub async fn receive_transfer(
+    id: uuid::Uuid,
+    data: ReceiveTransferDto,
+    state: &web::Data<AppState>,
+) -> Result<StockTransferDetails, DbErr> {
+    let txn = state.pg_db.get_ref().begin().await?;
+
+    let transfer = lock_transfer(
+        id,
+        Condition::all().add(entity::stock_transfers::Column::ToBranchId.eq(data.branch)),
+        TransferStatus::InTransit,
+        "Transfer is not in transit",
+        &txn,
+    )
+    .await?;
+
+    let mut lines = transfer_lines(transfer.id, &txn).await?;
+    let quantities = line_quantities(
+        &lines,
+        &data.lines,
+        |line| line.quantity_dispatched.unwrap_or_default(),
+        |line| line.quantity_dispatched.unwrap_or_default(),
+    )
+    .map_err(DbErr::Custom)?;
+
+    if data.discrepancy_note.is_none() && has_discrepancy(&lines, &quantities) {
+        return Err(DbErr::Custom(
+            "A discrepancy note is required when less arrives than was dispatched".to_string(),
+        ));
+    }
+
+    lines.sort_by_key(|line| line.to_drink_id);
+
+    let mut adjustments = Vec::new();
+
+    for line in lines {
+        let quantity = quantities.get(&line.id).copied().unwrap_or_default();
+        let mut model: entity::stock_transfer_lines::ActiveModel = line.clone().into();
+
+        model.quantity_received = ActiveValue::Set(Some(quantity));
+        model.updated_at = ActiveValue::Set(chrono::Utc::now().into());
+
+        if quantity > 0 {
+            let drink = lock_drink(line.to_drink_id, &txn).await?;
+            let stock = adjust_stock(drink.id, quantity, &txn).await?;
+
+            adjustments.push(entity::stock_adjustments::ActiveModel {
+                organization_id: Set(transfer.organization_id),
+                branch_id: Set(transfer.to_branch_id),
+                stock_drink_id: Set(drink.id),
+                stock_transfer_id: Set(Some(transfer.id)),
+                expected_quantity: Set(drink.quantity),
+                counted_quantity: Set(stock.quantity),
+                quantity: Set(quantity),
+                reason: Set(AdjustmentReason::TransferIn.as_str().to_string()),
+                unit_cost: Set(line.unit_cost),
+                created_by: Set(data.employee),
+                ..Default::default()
+            });
+        }
+
+        update_line(model, &txn).await?;
+    }
+
+    insert_adjustments(adjustments, &txn).await?;
+
+    let now = chrono::Utc::now();
+    let mut model: entity::stock_transfers::ActiveModel = transfer.into();
+
+    model.transfer_status = ActiveValue::Set(TransferStatus::Received.as_str().to_string());
+    model.discrepancy_note = ActiveValue::Set(data.discrepancy_note);
+    model.received_by = ActiveValue::Set(Some(data.employee));
+    model.received_at = ActiveValue::Set(Some(now.into()));
+    model.updated_at = ActiveValue::Set(now.into());
+
+    let transfer = ActiveModelTrait::update(model, &txn).await.map_err(|err| {
+        eprintln!("Database update error: {}", err);
+        DbErr::Custom(err.to_string())
+    })?;
+
+    let details = transfer_details(transfer, &txn).await?;
+
+    txn.commit().await?;
+
+    Ok(details)
+}
+
+/// Withdraws a transfer not yet dispatched. Either branch may cancel it.
+pub async fn cancel_transfer(
+    id: uuid::Uuid,
+    branch: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<entity::stock_transfers::Model, DbErr> {
+    let txn = state.pg_db.get_ref().begin().await?;
+
+    let transfer = lock_transfer(
+        id,
+        involving(branch),
+        TransferStatus::Requested,
+        "Transfer is not awaiting dispatch",
+        &txn,
+    )
+    .await?;
+
+    let mut model: entity::stock_transfers::ActiveModel = transfer.into();
+
+    model.transfer_status = ActiveValue::Set(TransferStatus::Cancelled.as_str().to_string());
+    model.updated_at = ActiveValue::Set(chrono::Utc::now().into());
+
+    let result = ActiveModelTrait::update(model, &txn).await.map_err(|err| {
+        eprintln!("Database update error: {}", err);
+        DbErr::Custom(err.to_string())
+    })?;
+
+    txn.commit().await?;
+
+    Ok(result)
+}
+
+pub async fn get_transfer(
+    id: uuid::Uuid,
+    branch: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<StockTransferDetails, DbErr> {
+    let transfer = entity::stock_transfers::Entity::find_by_id(id)
+        .filter(involving(branch))
+        .one(state.pg_db.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Transfer not found".into()))?;
+
+    transfer_details(transfer, state.pg_db.get_ref()).await
+}
+
+/// Transfers the branch sends or receives, latest first.
+pub async fn get_branch_transfers(
+    branch: uuid::Uuid,
+    status: Option<TransferStatus>,
+    state: &web::Data<AppState>,
+) -> Result<Vec<entity::stock_transfers::Model>, DbErr> {
+    let mut condition = Condition::all().add(involving(branch));
+
+    if let Some(status) = status {
+        condition =
+            condition.add(entity::stock_transfers::Column::TransferStatus.eq(status.as_str()));
+    }
+
+    let transfers = entity::stock_transfers::Entity::find()
+        .filter(condition)
+        .order_by_desc(entity::stock_transfers::Column::CreatedAt)
+        .all(state.pg_db.get_ref())
+        .await
+        .map_err(|err| {
+            eprintln!("Database retrieval error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    Ok(transfers)
+}
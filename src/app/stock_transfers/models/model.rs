@@ -0,0 +1,205 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::libs::validator::validate_transfer_status;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum TransferStatus {
+    Requested,
+    InTransit,
+    Received,
+    Cancelled,
+}
+
+impl TransferStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TransferStatus::Requested => "REQUESTED",
+            TransferStatus::InTransit => "INTRANSIT",
+            TransferStatus::Received => "RECEIVED",
+            TransferStatus::Cancelled => "CANCELLED",
+        }
+    }
+}
+
+impl FromStr for TransferStatus {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_uppercase().as_str() {
+            "REQUESTED" => Ok(TransferStatus::Requested),
+            "INTRANSIT" => Ok(TransferStatus::InTransit),
+            "RECEIVED" => Ok(TransferStatus::Received),
+            "CANCELLED" => Ok(TransferStatus::Cancelled),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct TransferLine {
+    pub drink: uuid::Uuid,
+    pub source_drink: Option<uuid::Uuid>,
+    pub quantity: i32,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RequestTransferDto {
+    pub organization: uuid::Uuid,
+    pub from_branch: uuid::Uuid,
+    pub to_branch: uuid::Uuid,
+    pub employee: uuid::Uuid,
+    pub notes: Option<String>,
+    pub lines: Vec<TransferLine>,
+}
+
+/// A drink the branch wants, as it stocks it. The sending branch's drink is the one with the
+/// same SKU, or else the same name, unless `source_drink` says which it is.
+#[derive(Debug, Serialize, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct TransferLineParams {
+    pub drink: uuid::Uuid,
+    pub source_drink: Option<uuid::Uuid>,
+    #[validate(range(min = 1, max = 100000, message = "Quantity is invalid"))]
+    pub quantity: i32,
+}
+
+/// Asks another branch of the organization for drinks. Foods aren't kept by count, so only
+/// drinks can be transferred.
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct RequestTransferParams {
+    pub from_branch: uuid::Uuid,
+    #[validate(length(max = 500, message = "Notes are too long"))]
+    pub notes: Option<String>,
+    #[validate(length(min = 1, max = 200, message = "Lines are invalid"), nested)]
+    pub lines: Vec<TransferLineParams>,
+}
+
+impl RequestTransferParams {
+    pub fn into_dto(
+        self,
+        organization: uuid::Uuid,
+        branch: uuid::Uuid,
+        employee: uuid::Uuid,
+    ) -> RequestTransferDto {
+        RequestTransferDto {
+            organization,
+            from_branch: self.from_branch,
+            to_branch: branch,
+            employee,
+            notes: self.notes,
+            lines: self
+                .lines
+                .into_iter()
+                .map(|line| TransferLine {
+                    drink: line.drink,
+                    source_drink: line.source_drink,
+                    quantity: line.quantity,
+                })
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct LineQuantity {
+    pub line: uuid::Uuid,
+    pub quantity: i32,
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct LineQuantityParams {
+    pub line: uuid::Uuid,
+    #[validate(range(min = 0, max = 100000, message = "Quantity is invalid"))]
+    pub quantity: i32,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DispatchTransferDto {
+    pub branch: uuid::Uuid,
+    pub employee: uuid::Uuid,
+    pub lines: Vec<LineQuantity>,
+}
+
+/// What the sending branch puts on the way. Lines left out go as requested, and none can go
+/// beyond what was requested.
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct DispatchTransferParams {
+    #[serde(default)]
+    #[validate(length(max = 200, message = "Lines are invalid"), nested)]
+    pub lines: Vec<LineQuantityParams>,
+}
+
+impl DispatchTransferParams {
+    pub fn into_dto(self, branch: uuid::Uuid, employee: uuid::Uuid) -> DispatchTransferDto {
+        DispatchTransferDto {
+            branch,
+            employee,
+            lines: self
+                .lines
+                .into_iter()
+                .map(|line| LineQuantity {
+                    line: line.line,
+                    quantity: line.quantity,
+                })
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ReceiveTransferDto {
+    pub branch: uuid::Uuid,
+    pub employee: uuid::Uuid,
+    pub lines: Vec<LineQuantity>,
+    pub discrepancy_note: Option<String>,
+}
+
+/// What the requesting branch counted on arrival. Lines left out arrived as dispatched. When
+/// any count differs from what was dispatched, `discrepancy_note` says why.
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct ReceiveTransferParams {
+    #[serde(default)]
+    #[validate(length(max = 200, message = "Lines are invalid"), nested)]
+    pub lines: Vec<LineQuantityParams>,
+    #[validate(length(min = 1, max = 500, message = "Discrepancy note is invalid"))]
+    pub discrepancy_note: Option<String>,
+}
+
+impl ReceiveTransferParams {
+    pub fn into_dto(self, branch: uuid::Uuid, employee: uuid::Uuid) -> ReceiveTransferDto {
+        ReceiveTransferDto {
+            branch,
+            employee,
+            lines: self
+                .lines
+                .into_iter()
+                .map(|line| LineQuantity {
+                    line: line.line,
+                    quantity: line.quantity,
+                })
+                .collect(),
+            discrepancy_note: self.discrepancy_note,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct TransferQuery {
+    #[validate(custom(function = "validate_transfer_status"))]
+    pub status: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct StockTransferDetails {
+    pub transfer: entity::stock_transfers::Model,
+    pub lines: Vec<entity::stock_transfer_lines::Model>,
+}
@@ -0,0 +1 @@
+pub mod service;
\ No newline at end of file
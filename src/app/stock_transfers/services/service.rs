@@ -0,0 +1,204 @@
+use std::collections::HashMap;
+
+use crate::app::stock_transfers::models::model::LineQuantity;
+
+/// The sending branch's drink matching one the receiving branch stocks: the one with the same
+/// SKU, or else the one with the same name, ignoring case.
+pub fn source_drink<'a>(
+    drink: &entity::stock_drinks::Model,
+    candidates: &'a [entity::stock_drinks::Model],
+) -> Option<&'a entity::stock_drinks::Model> {
+    drink
+        .sku
+        .as_ref()
+        .and_then(|sku| candidates.iter().find(|c| c.sku.as_ref() == Some(sku)))
+        .or_else(|| {
+            let name = drink.name.to_lowercase();
+
+            candidates.iter().find(|c| c.name.to_lowercase() == name)
+        })
+}
+
+/// Whether a unit of one drink is a unit of the other, so stock can move between them as is.
+pub fn same_measure(a: &entity::stock_drinks::Model, b: &entity::stock_drinks::Model) -> bool {
+    a.measure_unit == b.measure_unit && a.unit_size == b.unit_size
+}
+
+/// The quantity of each line of a transfer: what `quantities` gives for it, else `default`.
+/// Quantities for lines not on the transfer, or more than `limit` of a line, are refused.
+pub fn line_quantities(
+    lines: &[entity::stock_transfer_lines::Model],
+    quantities: &[LineQuantity],
+    default: impl Fn(&entity::stock_transfer_lines::Model) -> i32,
+    limit: impl Fn(&entity::stock_transfer_lines::Model) -> i32,
+) -> Result<HashMap<uuid::Uuid, i32>, String> {
+    let mut given: HashMap<uuid::Uuid, i32> = HashMap::new();
+
+    for quantity in quantities {
+        if !lines.iter().any(|line| line.id == quantity.line) {
+            return Err(format!("Line {} is not on this transfer", quantity.line));
+        }
+
+        if given.insert(quantity.line, quantity.quantity).is_some() {
+            return Err(format!("Line {} is given more than once", quantity.line));
+        }
+    }
+
+    let mut resolved = HashMap::with_capacity(lines.len());
+
+    for line in lines {
+        let quantity = given
+            .get(&line.id)
+            .copied()
+            .unwrap_or_else(|| default(line));
+
+        if quantity > limit(line) {
+            return Err(format!(
+                "Line {} can't be more than {}",
+                line.id,
+                limit(line)
+            ));
+        }
+
+        resolved.insert(line.id, quantity);
+    }
+
+    Ok(resolved)
+}
+
+/// Whether what arrived of any line differs from what was dispatched.
+pub fn has_discrepancy(
+    lines: &[entity::stock_transfer_lines::Model],
+    received: &HashMap<uuid::Uuid, i32>,
+) -> bool {
+    lines.iter().any(|line| {
+        received.get(&line.id).copied() != Some(line.quantity_dispatched.unwrap_or_default())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        app::inventory::models::model::MeasureUnit,
+        utils::fixtures::{dec, stock_drink, stock_transfer_line},
+    };
+
+    fn given(line: &entity::stock_transfer_lines::Model, quantity: i32) -> LineQuantity {
+        LineQuantity {
+            line: line.id,
+            quantity,
+        }
+    }
+
+    fn dispatch(
+        lines: &[entity::stock_transfer_lines::Model],
+        quantities: &[LineQuantity],
+    ) -> Result<HashMap<uuid::Uuid, i32>, String> {
+        line_quantities(lines, quantities, |l| l.quantity_requested, |_| 10)
+    }
+
+    #[test]
+    fn falls_back_to_the_default_quantity() {
+        let (a, b) = (stock_transfer_line(4, None), stock_transfer_line(6, None));
+
+        let quantities = dispatch(&[a.clone(), b.clone()], &[given(&b, 2)]).unwrap();
+
+        assert_eq!(quantities[&a.id], 4);
+        assert_eq!(quantities[&b.id], 2);
+    }
+
+    #[test]
+    fn refuses_lines_not_on_the_transfer() {
+        let (a, stray) = (stock_transfer_line(4, None), stock_transfer_line(1, None));
+
+        assert_eq!(
+            dispatch(&[a], &[given(&stray, 1)]),
+            Err(format!("Line {} is not on this transfer", stray.id))
+        );
+    }
+
+    #[test]
+    fn refuses_a_line_given_twice() {
+        let a = stock_transfer_line(4, None);
+
+        assert_eq!(
+            dispatch(std::slice::from_ref(&a), &[given(&a, 1), given(&a, 2)]),
+            Err(format!("Line {} is given more than once", a.id))
+        );
+    }
+
+    #[test]
+    fn refuses_more_than_the_limit() {
+        let (a, b) = (stock_transfer_line(4, None), stock_transfer_line(12, None));
+
+        assert_eq!(
+            dispatch(&[a.clone(), b.clone()], &[given(&a, 11)]),
+            Err(format!("Line {} can't be more than 10", a.id))
+        );
+        assert_eq!(
+            dispatch(std::slice::from_ref(&b), &[]),
+            Err(format!("Line {} can't be more than 10", b.id))
+        );
+    }
+
+    #[test]
+    fn flags_lines_received_short() {
+        let (a, b) = (
+            stock_transfer_line(4, Some(4)),
+            stock_transfer_line(6, Some(5)),
+        );
+        let lines = [a.clone(), b.clone()];
+
+        assert!(!has_discrepancy(
+            &lines,
+            &HashMap::from([(a.id, 4), (b.id, 5)])
+        ));
+        assert!(has_discrepancy(
+            &lines,
+            &HashMap::from([(a.id, 4), (b.id, 4)])
+        ));
+        assert!(has_discrepancy(&lines, &HashMap::from([(a.id, 4)])));
+    }
+
+    #[test]
+    fn matches_drinks_by_sku_then_name() {
+        let wanted = entity::stock_drinks::Model {
+            sku: Some("GIN-70".to_string()),
+            ..stock_drink("Gin", "10")
+        };
+        let by_name = stock_drink("gin", "10");
+        let by_sku = entity::stock_drinks::Model {
+            sku: Some("GIN-70".to_string()),
+            ..stock_drink("London Dry", "10")
+        };
+
+        let candidates = [by_name.clone(), by_sku.clone()];
+
+        assert_eq!(
+            source_drink(&wanted, &candidates).map(|d| d.id),
+            Some(by_sku.id)
+        );
+        assert_eq!(
+            source_drink(&wanted, &candidates[..1]).map(|d| d.id),
+            Some(by_name.id)
+        );
+    }
+
+    #[test]
+    fn moves_stock_only_between_the_same_measure() {
+        let bottle = entity::stock_drinks::Model {
+            measure_unit: MeasureUnit::Ml.as_str().to_string(),
+            unit_size: dec("700"),
+            ..stock_drink("Gin", "10")
+        };
+        let litre = entity::stock_drinks::Model {
+            unit_size: dec("1000"),
+            ..bottle.clone()
+        };
+
+        assert!(same_measure(&bottle, &bottle.clone()));
+        assert!(!same_measure(&bottle, &litre));
+        assert!(!same_measure(&bottle, &stock_drink("Gin", "10")));
+    }
+}
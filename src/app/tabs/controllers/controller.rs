@@ -0,0 +1,433 @@
+use std::sync::Arc;
+
+use actix_web::{web, HttpMessage, HttpRequest, HttpResponse};
+use serde_json::json;
+
+use crate::{
+    app::{
+        customers::{
+            dtos::dto::{get_active_customer_by_contact, get_customer_full},
+            models::model::CustomerResponse,
+        },
+        employees::models::model::EmployeeResponse,
+        orders::{dtos::dto::save_order, models::model::AddOrderDto},
+        tabs::{
+            dtos::dto::{
+                cancel_tab_share, get_branch_tabs, get_customer_shares, get_tab, get_tab_summary,
+                open_tab, pay_tab_share, split_tab,
+            },
+            models::model::{
+                OpenTabDto, OpenTabParams, SplitTabDto, SplitTabParams, SplitType, TabOrderParams,
+                TabShareDto,
+            },
+        },
+        tills::dtos::dto::get_open_till,
+        wallets::models::model::PaymentChannel,
+    },
+    libs::error::Error,
+    utils::{
+        json_validator::{ValidatedJson, ValidatedPath},
+        models::{HttpClientResponse, PathParamsModel, ResponseCode},
+    },
+    AppState,
+};
+
+pub async fn start_tab(
+    req: HttpRequest,
+    payload: ValidatedJson<OpenTabParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    let data = payload.0;
+
+    if data.booking.is_none() && data.table_label.is_none() {
+        return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            "A tab needs a table or a booking".to_string(),
+            json!({}),
+        )));
+    }
+
+    let customer = match get_active_customer_by_contact(data.phone, &state).await {
+        Ok(customer) => customer,
+        Err(e) => {
+            return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+                ResponseCode::Failed,
+                format!("Could not find customer: {}", e),
+                json!({}),
+            )))
+        }
+    };
+
+    let tab = OpenTabDto {
+        organization: model.organization_id,
+        branch: model.branch_id,
+        customer: customer.id,
+        employee: model.id,
+        booking: data.booking,
+        table_label: data.table_label,
+    };
+
+    match open_tab(tab, &state).await {
+        Ok(res) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Tab Opened Successfully".to_string(),
+            json!(res),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Could not open tab: {}", e),
+            json!({}),
+        ))),
+    }
+}
+
+pub async fn branch_tabs(
+    req: HttpRequest,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    match get_branch_tabs(model.branch_id, &state).await {
+        Ok(res) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Tabs Fetched Successfully".to_string(),
+            json!(res),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Tabs not found: {}", e),
+            json!({}),
+        ))),
+    }
+}
+
+pub async fn tab_details(
+    req: HttpRequest,
+    params: ValidatedPath<PathParamsModel>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    let tab = match get_tab(params.0.id, model.branch_id, &state).await {
+        Ok(tab) => tab,
+        Err(e) => {
+            return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+                ResponseCode::Failed,
+                format!("Tab not found: {}", e),
+                json!({}),
+            )))
+        }
+    };
+
+    match get_tab_summary(tab, &state).await {
+        Ok(res) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Tab Fetched Successfully".to_string(),
+            json!(res),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Tab not found: {}", e),
+            json!({}),
+        ))),
+    }
+}
+
+pub async fn add_tab_order(
+    req: HttpRequest,
+    params: ValidatedPath<PathParamsModel>,
+    payload: ValidatedJson<TabOrderParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    let till = match get_open_till(model.id, &state).await {
+        Ok(Some(till)) => till,
+        Ok(None) => {
+            return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+                ResponseCode::Failed,
+                "No open till, open a till before taking orders".to_string(),
+                json!({}),
+            )))
+        }
+        Err(e) => {
+            return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+                ResponseCode::Failed,
+                format!("Could not find till: {}", e),
+                json!({}),
+            )))
+        }
+    };
+
+    let tab = match get_tab(params.0.id, model.branch_id, &state).await {
+        Ok(tab) => tab,
+        Err(e) => {
+            return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+                ResponseCode::Failed,
+                format!("Tab not found: {}", e),
+                json!({}),
+            )))
+        }
+    };
+
+    let order = AddOrderDto {
+        customer: tab.customer_id,
+        organization: tab.organization_id,
+        branch: tab.branch_id,
+        served_by: Some(model.id),
+        booking: None,
+        till_session: Some(till.id),
+        tab: Some(tab.id),
+        payment_channel: PaymentChannel::Wallet,
+        items: payload.0.items.into_iter().map(Into::into).collect(),
+        tip_amount: None,
+        promo_code: None,
+    };
+
+    match save_order(order, &state).await {
+        Ok(res) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Order Added To Tab Successfully".to_string(),
+            json!({
+                "id": res.id,
+                "order_number": res.order_number,
+                "tab_id": res.tab_id,
+                "total_amount": res.total_amount,
+                "order_status": res.order_status,
+            }),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Could not add order to tab: {}", e),
+            json!({}),
+        ))),
+    }
+}
+
+pub async fn split_bill(
+    req: HttpRequest,
+    params: ValidatedPath<PathParamsModel>,
+    payload: ValidatedJson<SplitTabParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    let data = payload.0;
+    let mut shares = Vec::with_capacity(data.shares.len());
+
+    for share in data.shares {
+        let customer = match get_active_customer_by_contact(share.phone, &state).await {
+            Ok(customer) => customer,
+            Err(e) => {
+                return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+                    ResponseCode::Failed,
+                    format!("Could not find customer: {}", e),
+                    json!({}),
+                )))
+            }
+        };
+
+        shares.push(TabShareDto {
+            customer: customer.id,
+            items: share.items.unwrap_or_default(),
+            amount: share.amount,
+        });
+    }
+
+    let split = SplitTabDto {
+        tab: params.0.id,
+        branch: model.branch_id,
+        split_type: data.split_type.parse().unwrap_or(SplitType::Even),
+        shares,
+    };
+
+    match split_tab(split, &state).await {
+        Ok(res) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Tab Split Successfully".to_string(),
+            json!(res),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Could not split tab: {}", e),
+            json!({}),
+        ))),
+    }
+}
+
+pub async fn cancel_share(
+    req: HttpRequest,
+    params: ValidatedPath<PathParamsModel>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    match cancel_tab_share(params.0.id, model.branch_id, &state).await {
+        Ok(res) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Share Cancelled Successfully".to_string(),
+            json!(res),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Could not cancel share: {}", e),
+            json!({}),
+        ))),
+    }
+}
+
+pub async fn my_shares(
+    req: HttpRequest,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<CustomerResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Customer not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    let mut session_id = uuid::Uuid::nil();
+
+    if let Some(session_uuid) = &model.session {
+        if let Ok(s_uuid) = uuid::Uuid::parse_str(session_uuid) {
+            session_id = s_uuid
+        }
+    }
+
+    let customer = match get_customer_full(session_id, &state).await {
+        Ok(customer) => customer,
+        Err(e) => {
+            return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+                ResponseCode::Failed,
+                format!("Could not find customer: {}", e),
+                json!({}),
+            )))
+        }
+    };
+
+    match get_customer_shares(customer.id, &state).await {
+        Ok(res) => {
+            let shares: Vec<_> = res
+                .into_iter()
+                .map(|(share, tab)| json!({ "share": share, "tab": tab }))
+                .collect();
+
+            Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+                ResponseCode::Success,
+                "Shares Fetched Successfully".to_string(),
+                json!(shares),
+            )))
+        }
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Shares not found: {}", e),
+            json!({}),
+        ))),
+    }
+}
+
+pub async fn pay_share(
+    req: HttpRequest,
+    params: ValidatedPath<PathParamsModel>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<CustomerResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Customer not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    let mut session_id = uuid::Uuid::nil();
+
+    if let Some(session_uuid) = &model.session {
+        if let Ok(s_uuid) = uuid::Uuid::parse_str(session_uuid) {
+            session_id = s_uuid
+        }
+    }
+
+    let customer = match get_customer_full(session_id, &state).await {
+        Ok(customer) => customer,
+        Err(e) => {
+            return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+                ResponseCode::Failed,
+                format!("Could not find customer: {}", e),
+                json!({}),
+            )))
+        }
+    };
+
+    match pay_tab_share(params.0.id, customer.id, &state).await {
+        Ok((share, tab)) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Share Paid Successfully".to_string(),
+            json!({
+                "share": share,
+                "tab_status": tab.tab_status,
+                "tab_paid_amount": tab.paid_amount,
+            }),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Could not pay share: {}", e),
+            json!({}),
+        ))),
+    }
+}
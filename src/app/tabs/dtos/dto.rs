@@ -0,0 +1,448 @@
+use std::collections::HashSet;
+
+use actix_web::web;
+use sea_orm::{
+    prelude::Decimal, ActiveModelTrait, ActiveValue, ColumnTrait, Condition, ConnectionTrait,
+    DbErr, EntityTrait, QueryFilter, QueryOrder, QuerySelect, Set, TransactionTrait,
+};
+
+use crate::{
+    app::{
+        orders::models::model::{OrderDetailsResponse, OrderStatus},
+        tabs::{
+            models::model::{
+                OpenTabDto, ShareStatus, SplitTabDto, SplitType, TabStatus, TabSummary,
+            },
+            services::service::{charged_items, price_item_shares, split_evenly},
+        },
+        wallets::{dtos::dto::debit_customer_wallet, models::model::WalletDebitDto},
+    },
+    AppState,
+};
+
+/// Orders that count towards a tab's bill, rejected and cancelled ones are left out.
+async fn live_tab_orders<C: ConnectionTrait>(
+    tab: uuid::Uuid,
+    conn: &C,
+) -> Result<Vec<(entity::orders::Model, Vec<entity::order_items::Model>)>, DbErr> {
+    entity::orders::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::orders::Column::TabId.eq(tab))
+                .add(entity::orders::Column::OrderStatus.is_not_in([
+                    OrderStatus::Rejected.as_str(),
+                    OrderStatus::Cancelled.as_str(),
+                ])),
+        )
+        .find_with_related(entity::order_items::Entity)
+        .order_by_asc(entity::orders::Column::CreatedAt)
+        .all(conn)
+        .await
+}
+
+async fn tab_shares<C: ConnectionTrait>(
+    tab: uuid::Uuid,
+    conn: &C,
+) -> Result<Vec<entity::tab_shares::Model>, DbErr> {
+    entity::tab_shares::Entity::find()
+        .filter(entity::tab_shares::Column::TabId.eq(tab))
+        .order_by_asc(entity::tab_shares::Column::CreatedAt)
+        .all(conn)
+        .await
+}
+
+async fn summarize_tab<C: ConnectionTrait>(
+    tab: entity::tabs::Model,
+    conn: &C,
+) -> Result<TabSummary, DbErr> {
+    let orders = live_tab_orders(tab.id, conn).await?;
+    let shares = tab_shares(tab.id, conn).await?;
+
+    let total_amount: Decimal = orders.iter().map(|(order, _)| order.total_amount).sum();
+    let pending_amount: Decimal = shares
+        .iter()
+        .filter(|share| share.share_status == ShareStatus::Pending.as_str())
+        .map(|share| share.amount)
+        .sum();
+
+    Ok(TabSummary {
+        total_amount,
+        paid_amount: tab.paid_amount,
+        pending_amount,
+        outstanding_amount: (total_amount - tab.paid_amount - pending_amount).max(Decimal::ZERO),
+        orders: orders
+            .into_iter()
+            .map(|(order, items)| OrderDetailsResponse { order, items })
+            .collect(),
+        shares,
+        tab,
+    })
+}
+
+/// Opens a tab for the host customer, on a table, a booking or both. A booking only runs one
+/// tab at a time.
+pub async fn open_tab(
+    data: OpenTabDto,
+    state: &web::Data<AppState>,
+) -> Result<entity::tabs::Model, DbErr> {
+    if let Some(booking) = data.booking {
+        entity::bookings::Entity::find_by_id(booking)
+            .filter(
+                Condition::all()
+                    .add(entity::bookings::Column::BranchId.eq(data.branch))
+                    .add(entity::bookings::Column::IsCancelled.eq(false)),
+            )
+            .one(state.pg_db.get_ref())
+            .await?
+            .ok_or_else(|| DbErr::RecordNotFound("Booking not found".into()))?;
+
+        let running = entity::tabs::Entity::find()
+            .filter(
+                Condition::all()
+                    .add(entity::tabs::Column::BookingId.eq(booking))
+                    .add(entity::tabs::Column::TabStatus.ne(TabStatus::Settled.as_str())),
+            )
+            .one(state.pg_db.get_ref())
+            .await?;
+
+        if running.is_some() {
+            return Err(DbErr::Custom("Booking already has an open tab".to_string()));
+        }
+    }
+
+    let tab = entity::tabs::ActiveModel {
+        organization_id: Set(data.organization),
+        branch_id: Set(data.branch),
+        customer_id: Set(data.customer),
+        booking_id: Set(data.booking),
+        table_label: Set(data.table_label),
+        tab_status: Set(TabStatus::Open.as_str().to_string()),
+        opened_by: Set(Some(data.employee)),
+        ..Default::default()
+    };
+
+    let result = ActiveModelTrait::insert(tab, state.pg_db.get_ref())
+        .await
+        .map_err(|err| {
+            eprintln!("Database insert error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    Ok(result)
+}
+
+pub async fn get_tab(
+    id: uuid::Uuid,
+    branch: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<entity::tabs::Model, DbErr> {
+    let tab = entity::tabs::Entity::find_by_id(id)
+        .filter(entity::tabs::Column::BranchId.eq(branch))
+        .one(state.pg_db.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Tab not found".into()))?;
+
+    Ok(tab)
+}
+
+pub async fn get_tab_summary(
+    tab: entity::tabs::Model,
+    state: &web::Data<AppState>,
+) -> Result<TabSummary, DbErr> {
+    summarize_tab(tab, state.pg_db.get_ref()).await
+}
+
+/// Tabs in the branch that are not settled yet, oldest first.
+pub async fn get_branch_tabs(
+    branch: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<Vec<entity::tabs::Model>, DbErr> {
+    let tabs = entity::tabs::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::tabs::Column::BranchId.eq(branch))
+                .add(entity::tabs::Column::TabStatus.ne(TabStatus::Settled.as_str())),
+        )
+        .order_by_asc(entity::tabs::Column::CreatedAt)
+        .all(state.pg_db.get_ref())
+        .await
+        .map_err(|err| {
+            eprintln!("Database retrieval error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    Ok(tabs)
+}
+
+/// Splits what is still outstanding on a tab into shares, one per paying customer. Even splits
+/// divide the outstanding amount, item splits charge each customer for the line items they
+/// had and custom splits take the amounts given. Once split, the tab takes no new orders
+/// until its pending shares are paid or cancelled.
+pub async fn split_tab(
+    data: SplitTabDto,
+    state: &web::Data<AppState>,
+) -> Result<Vec<entity::tab_shares::Model>, DbErr> {
+    let txn = state.pg_db.get_ref().begin().await?;
+
+    let tab = entity::tabs::Entity::find_by_id(data.tab)
+        .filter(
+            Condition::all()
+                .add(entity::tabs::Column::BranchId.eq(data.branch))
+                .add(entity::tabs::Column::TabStatus.ne(TabStatus::Settled.as_str())),
+        )
+        .lock_exclusive()
+        .one(&txn)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Tab not found".into()))?;
+
+    let summary = summarize_tab(tab, &txn).await?;
+
+    if summary.outstanding_amount <= Decimal::ZERO {
+        return Err(DbErr::Custom(
+            "Nothing left on the tab to split".to_string(),
+        ));
+    }
+
+    let amounts = match data.split_type {
+        SplitType::Even => split_evenly(summary.outstanding_amount, data.shares.len()),
+        SplitType::Custom => data
+            .shares
+            .iter()
+            .map(|share| {
+                share
+                    .amount
+                    .filter(|amount| *amount > Decimal::ZERO)
+                    .ok_or_else(|| DbErr::Custom("Every share needs an amount".to_string()))
+            })
+            .collect::<Result<Vec<_>, _>>()?,
+        SplitType::Items => {
+            let items: Vec<_> = summary.orders.iter().flat_map(charged_items).collect();
+
+            let taken: HashSet<_> = summary
+                .shares
+                .iter()
+                .filter(|share| share.share_status != ShareStatus::Cancelled.as_str())
+                .flat_map(|share| share.item_ids.clone().unwrap_or_default())
+                .collect();
+
+            let picks: Vec<_> = data
+                .shares
+                .iter()
+                .map(|share| share.items.clone())
+                .collect();
+
+            price_item_shares(&picks, &items, &taken, summary.outstanding_amount)
+                .map_err(DbErr::Custom)?
+        }
+    };
+
+    if amounts.iter().sum::<Decimal>() > summary.outstanding_amount {
+        return Err(DbErr::Custom(format!(
+            "Shares come to more than the {} left on the tab",
+            summary.outstanding_amount
+        )));
+    }
+
+    let mut shares = Vec::with_capacity(amounts.len());
+
+    for (share, amount) in data.shares.into_iter().zip(amounts) {
+        let model = entity::tab_shares::ActiveModel {
+            tab_id: Set(summary.tab.id),
+            customer_id: Set(share.customer),
+            split_type: Set(data.split_type.as_str().to_string()),
+            item_ids: Set((data.split_type == SplitType::Items).then_some(share.items)),
+            amount: Set(amount),
+            share_status: Set(ShareStatus::Pending.as_str().to_string()),
+            ..Default::default()
+        };
+
+        let result = ActiveModelTrait::insert(model, &txn).await.map_err(|err| {
+            eprintln!("Database insert error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+        shares.push(result);
+    }
+
+    let mut model: entity::tabs::ActiveModel = summary.tab.into();
+
+    model.tab_status = ActiveValue::Set(TabStatus::Settling.as_str().to_string());
+    model.updated_at = ActiveValue::Set(chrono::Utc::now().into());
+
+    ActiveModelTrait::update(model, &txn).await.map_err(|err| {
+        eprintln!("Database update error: {}", err);
+        DbErr::Custom(err.to_string())
+    })?;
+
+    txn.commit().await?;
+
+    Ok(shares)
+}
+
+/// Pays a customer's share from their own wallet. The tab is settled once its shares have
+/// paid for everything on it.
+pub async fn pay_tab_share(
+    id: uuid::Uuid,
+    customer: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<(entity::tab_shares::Model, entity::tabs::Model), DbErr> {
+    let txn = state.pg_db.get_ref().begin().await?;
+
+    let share = entity::tab_shares::Entity::find_by_id(id)
+        .filter(
+            Condition::all()
+                .add(entity::tab_shares::Column::CustomerId.eq(customer))
+                .add(entity::tab_shares::Column::ShareStatus.eq(ShareStatus::Pending.as_str())),
+        )
+        .lock_exclusive()
+        .one(&txn)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Share not found".into()))?;
+
+    let tab = entity::tabs::Entity::find_by_id(share.tab_id)
+        .lock_exclusive()
+        .one(&txn)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Tab not found".into()))?;
+
+    let payment = debit_customer_wallet(
+        WalletDebitDto {
+            customer,
+            organization: tab.organization_id,
+            branch: tab.branch_id,
+            amount: share.amount,
+            description: format!("Share of tab {}", tab.id),
+        },
+        &txn,
+    )
+    .await?;
+
+    let amount = share.amount;
+    let now = chrono::Utc::now();
+
+    let mut model: entity::tab_shares::ActiveModel = share.into();
+
+    model.share_status = ActiveValue::Set(ShareStatus::Paid.as_str().to_string());
+    model.transaction_id = ActiveValue::Set(Some(payment.id));
+    model.paid_at = ActiveValue::Set(Some(now.into()));
+    model.updated_at = ActiveValue::Set(now.into());
+
+    let share = ActiveModelTrait::update(model, &txn).await.map_err(|err| {
+        eprintln!("Database update error: {}", err);
+        DbErr::Custom(err.to_string())
+    })?;
+
+    let total_amount: Decimal = live_tab_orders(tab.id, &txn)
+        .await?
+        .iter()
+        .map(|(order, _)| order.total_amount)
+        .sum();
+
+    let pending = tab_shares(tab.id, &txn)
+        .await?
+        .iter()
+        .any(|share| share.share_status == ShareStatus::Pending.as_str());
+
+    let paid_amount = tab.paid_amount + amount;
+
+    let mut model: entity::tabs::ActiveModel = tab.into();
+
+    model.paid_amount = ActiveValue::Set(paid_amount);
+
+    if paid_amount >= total_amount {
+        model.tab_status = ActiveValue::Set(TabStatus::Settled.as_str().to_string());
+        model.settled_at = ActiveValue::Set(Some(now.into()));
+    } else if !pending {
+        // The shares didn't cover the bill, what's left can be ordered on or split anew
+        model.tab_status = ActiveValue::Set(TabStatus::Open.as_str().to_string());
+    }
+
+    model.updated_at = ActiveValue::Set(now.into());
+
+    let tab = ActiveModelTrait::update(model, &txn).await.map_err(|err| {
+        eprintln!("Database update error: {}", err);
+        DbErr::Custom(err.to_string())
+    })?;
+
+    txn.commit().await?;
+
+    Ok((share, tab))
+}
+
+/// Drops a share nobody has paid yet. When no pending shares are left the tab opens up for
+/// orders again and what the share covered can be split anew.
+pub async fn cancel_tab_share(
+    id: uuid::Uuid,
+    branch: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<entity::tab_shares::Model, DbErr> {
+    let txn = state.pg_db.get_ref().begin().await?;
+
+    let share = entity::tab_shares::Entity::find_by_id(id)
+        .filter(entity::tab_shares::Column::ShareStatus.eq(ShareStatus::Pending.as_str()))
+        .lock_exclusive()
+        .one(&txn)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Share not found".into()))?;
+
+    let tab = entity::tabs::Entity::find_by_id(share.tab_id)
+        .filter(entity::tabs::Column::BranchId.eq(branch))
+        .lock_exclusive()
+        .one(&txn)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Tab not found".into()))?;
+
+    let mut model: entity::tab_shares::ActiveModel = share.into();
+
+    model.share_status = ActiveValue::Set(ShareStatus::Cancelled.as_str().to_string());
+    model.updated_at = ActiveValue::Set(chrono::Utc::now().into());
+
+    let share = ActiveModelTrait::update(model, &txn).await.map_err(|err| {
+        eprintln!("Database update error: {}", err);
+        DbErr::Custom(err.to_string())
+    })?;
+
+    let pending = tab_shares(tab.id, &txn)
+        .await?
+        .iter()
+        .any(|share| share.share_status == ShareStatus::Pending.as_str());
+
+    if !pending && tab.tab_status == TabStatus::Settling.as_str() {
+        let mut model: entity::tabs::ActiveModel = tab.into();
+
+        model.tab_status = ActiveValue::Set(TabStatus::Open.as_str().to_string());
+        model.updated_at = ActiveValue::Set(chrono::Utc::now().into());
+
+        ActiveModelTrait::update(model, &txn).await.map_err(|err| {
+            eprintln!("Database update error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+    }
+
+    txn.commit().await?;
+
+    Ok(share)
+}
+
+/// Shares waiting on the customer to pay, with the tab each belongs to.
+pub async fn get_customer_shares(
+    customer: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<Vec<(entity::tab_shares::Model, Option<entity::tabs::Model>)>, DbErr> {
+    let shares = entity::tab_shares::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::tab_shares::Column::CustomerId.eq(customer))
+                .add(entity::tab_shares::Column::ShareStatus.eq(ShareStatus::Pending.as_str())),
+        )
+        .find_also_related(entity::tabs::Entity)
+        .order_by_asc(entity::tab_shares::Column::CreatedAt)
+        .all(state.pg_db.get_ref())
+        .await
+        .map_err(|err| {
+            eprintln!("Database retrieval error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    Ok(shares)
+}
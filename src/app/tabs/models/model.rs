@@ -0,0 +1,152 @@
+use std::str::FromStr;
+
+use sea_orm::prelude::Decimal;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::{
+    app::orders::models::model::{OrderDetailsResponse, OrderItemParams},
+    libs::validator::{validate_cash_amount, validate_contact, validate_split_type},
+};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum TabStatus {
+    Open,
+    Settling,
+    Settled,
+}
+
+impl TabStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TabStatus::Open => "OPEN",
+            TabStatus::Settling => "SETTLING",
+            TabStatus::Settled => "SETTLED",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum SplitType {
+    Even,
+    Items,
+    Custom,
+}
+
+impl SplitType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SplitType::Even => "EVEN",
+            SplitType::Items => "ITEMS",
+            SplitType::Custom => "CUSTOM",
+        }
+    }
+}
+
+impl FromStr for SplitType {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_uppercase().as_str() {
+            "EVEN" => Ok(SplitType::Even),
+            "ITEMS" => Ok(SplitType::Items),
+            "CUSTOM" => Ok(SplitType::Custom),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum ShareStatus {
+    Pending,
+    Paid,
+    Cancelled,
+}
+
+impl ShareStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ShareStatus::Pending => "PENDING",
+            ShareStatus::Paid => "PAID",
+            ShareStatus::Cancelled => "CANCELLED",
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct OpenTabDto {
+    pub organization: uuid::Uuid,
+    pub branch: uuid::Uuid,
+    pub customer: uuid::Uuid,
+    pub employee: uuid::Uuid,
+    pub booking: Option<uuid::Uuid>,
+    pub table_label: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TabShareDto {
+    pub customer: uuid::Uuid,
+    pub items: Vec<uuid::Uuid>,
+    pub amount: Option<Decimal>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SplitTabDto {
+    pub tab: uuid::Uuid,
+    pub branch: uuid::Uuid,
+    pub split_type: SplitType,
+    pub shares: Vec<TabShareDto>,
+}
+
+/// Where a tab stands: what has been ordered on it, what its shares have paid or still owe,
+/// and what is left that no share covers yet.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TabSummary {
+    pub tab: entity::tabs::Model,
+    pub total_amount: Decimal,
+    pub paid_amount: Decimal,
+    pub pending_amount: Decimal,
+    pub outstanding_amount: Decimal,
+    pub orders: Vec<OrderDetailsResponse>,
+    pub shares: Vec<entity::tab_shares::Model>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct OpenTabParams {
+    #[validate(custom(function = "validate_contact"))]
+    pub phone: String,
+    pub booking: Option<uuid::Uuid>,
+    #[validate(length(min = 1, max = 50, message = "Table label is invalid"))]
+    pub table_label: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct TabOrderParams {
+    #[validate(length(min = 1, max = 30, message = "Items are invalid"), nested)]
+    pub items: Vec<OrderItemParams>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct TabShareParams {
+    #[validate(custom(function = "validate_contact"))]
+    pub phone: String,
+    #[validate(length(min = 1, max = 100, message = "Items are invalid"))]
+    pub items: Option<Vec<uuid::Uuid>>,
+    #[validate(custom(function = "validate_cash_amount"))]
+    pub amount: Option<Decimal>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct SplitTabParams {
+    #[validate(custom(function = "validate_split_type"))]
+    pub split_type: String,
+    #[validate(length(min = 1, max = 20, message = "Shares are invalid"), nested)]
+    pub shares: Vec<TabShareParams>,
+}
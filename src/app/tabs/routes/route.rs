@@ -0,0 +1,72 @@
+use actix_web::web;
+
+use crate::{
+    app::tabs::controllers::controller::{
+        add_tab_order, branch_tabs, cancel_share, my_shares, pay_share, split_bill, start_tab,
+        tab_details,
+    },
+    middlewares::{auth::JwtAuthMiddleware, checker::CheckUserMiddleware},
+    AppState,
+};
+
+pub fn routes(cfg: &mut web::ServiceConfig, state: web::Data<AppState>) {
+    cfg.service(
+        web::scope("/api/v1/tabs")
+            .route(
+                "/open",
+                web::post()
+                    .to(start_tab)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/branch",
+                web::get()
+                    .to(branch_tabs)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/details/{id}",
+                web::get()
+                    .to(tab_details)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/orders/{id}",
+                web::post()
+                    .to(add_tab_order)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/split/{id}",
+                web::post()
+                    .to(split_bill)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/shares/cancel/{id}",
+                web::put()
+                    .to(cancel_share)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/shares",
+                web::get()
+                    .to(my_shares)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Customer"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/shares/pay/{id}",
+                web::put()
+                    .to(pay_share)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Customer"))
+                    .wrap(JwtAuthMiddleware),
+            ),
+    );
+}
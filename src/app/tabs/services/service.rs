@@ -0,0 +1,187 @@
+use std::collections::{HashMap, HashSet};
+
+use sea_orm::prelude::Decimal;
+
+use crate::app::orders::models::model::OrderDetailsResponse;
+
+/// Splits `amount` into `count` equal shares rounded down to the cent, with the leftover cents
+/// on the first share so the shares always add up to the amount.
+pub fn split_evenly(amount: Decimal, count: usize) -> Vec<Decimal> {
+    if count == 0 {
+        return Vec::new();
+    }
+
+    let share = (amount / Decimal::from(count as u64)).trunc_with_scale(2);
+    let mut shares = vec![share; count];
+
+    shares[0] += amount - share * Decimal::from(count as u64);
+
+    shares
+}
+
+/// The order's items with their unit prices adjusted by the order's share of tax, service
+/// charge, tip and discount, so a share that picks items pays what was charged for them.
+pub fn charged_items(order: &OrderDetailsResponse) -> Vec<entity::order_items::Model> {
+    let subtotal: Decimal = order
+        .items
+        .iter()
+        .map(|item| item.price * Decimal::from(item.quantity))
+        .sum();
+
+    let charges = order.order.tax_amount + order.order.service_charge - order.order.discount_amount
+        + order.order.tip_amount.unwrap_or_default();
+
+    let adjustment = match subtotal > Decimal::ZERO {
+        true => Decimal::ONE + charges / subtotal,
+        false => Decimal::ONE,
+    };
+
+    order
+        .items
+        .iter()
+        .cloned()
+        .map(|mut item| {
+            item.price *= adjustment;
+            item
+        })
+        .collect()
+}
+
+/// Prices each share's line items. Every item must be on the tab, not yet taken by another
+/// share and picked by only one share in the split. Rounding each share to the cent never
+/// takes the split past what is `outstanding`, any cents over come off the last share.
+pub fn price_item_shares(
+    shares: &[Vec<uuid::Uuid>],
+    items: &[entity::order_items::Model],
+    taken: &HashSet<uuid::Uuid>,
+    outstanding: Decimal,
+) -> Result<Vec<Decimal>, String> {
+    let prices: HashMap<uuid::Uuid, Decimal> = items
+        .iter()
+        .map(|item| (item.id, item.price * Decimal::from(item.quantity)))
+        .collect();
+
+    let mut picked = HashSet::new();
+    let mut amounts = Vec::with_capacity(shares.len());
+    let mut exact_total = Decimal::ZERO;
+
+    for share in shares {
+        if share.is_empty() {
+            return Err("Every share needs at least one item".to_string());
+        }
+
+        let mut amount = Decimal::ZERO;
+
+        for item in share {
+            let price = prices
+                .get(item)
+                .ok_or_else(|| format!("Item {} is not on this tab", item))?;
+
+            if taken.contains(item) || !picked.insert(*item) {
+                return Err(format!("Item {} is already in a share", item));
+            }
+
+            amount += *price;
+        }
+
+        exact_total += amount;
+        amounts.push(amount.round_dp(2));
+    }
+
+    let over = amounts.iter().sum::<Decimal>() - outstanding;
+
+    if over > Decimal::ZERO && exact_total <= outstanding {
+        if let Some(last) = amounts.last_mut() {
+            *last -= over;
+        }
+    }
+
+    Ok(amounts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::fixtures::{dec, order, order_item};
+
+    #[test]
+    fn splits_evenly_with_leftover_cents_first() {
+        assert_eq!(
+            split_evenly(dec("100"), 3),
+            vec![dec("33.34"), dec("33.33"), dec("33.33")]
+        );
+        assert_eq!(split_evenly(dec("10"), 4), vec![dec("2.5"); 4]);
+        assert!(split_evenly(dec("10"), 0).is_empty());
+    }
+
+    #[test]
+    fn spreads_the_tip_and_charges_across_the_items() {
+        let order = OrderDetailsResponse {
+            order: entity::orders::Model {
+                tax_amount: dec("3"),
+                discount_amount: dec("1"),
+                tip_amount: Some(dec("4")),
+                ..order("36")
+            },
+            items: vec![order_item("10", 2), order_item("10", 1)],
+        };
+
+        let items = charged_items(&order);
+        let charged: Decimal = items
+            .iter()
+            .map(|item| item.price * Decimal::from(item.quantity))
+            .sum();
+
+        assert_eq!(items[0].price, dec("12"));
+        assert_eq!(charged, dec("36"));
+    }
+
+    #[test]
+    fn prices_each_share_by_its_items() {
+        let items = [order_item("4", 2), order_item("5.5", 1), order_item("3", 1)];
+        let shares = vec![vec![items[0].id], vec![items[1].id, items[2].id]];
+
+        let amounts = price_item_shares(&shares, &items, &HashSet::new(), dec("19.5")).unwrap();
+
+        assert_eq!(amounts, vec![dec("8"), dec("8.5")]);
+    }
+
+    #[test]
+    fn rounding_never_takes_the_split_past_what_is_outstanding() {
+        // Each item charged a third of 10 after the order's discount
+        let items = [
+            order_item("3.335", 1),
+            order_item("3.335", 1),
+            order_item("3.33", 1),
+        ];
+        let shares: Vec<Vec<uuid::Uuid>> = items.iter().map(|item| vec![item.id]).collect();
+
+        let amounts = price_item_shares(&shares, &items, &HashSet::new(), dec("10")).unwrap();
+
+        assert_eq!(amounts, vec![dec("3.34"), dec("3.34"), dec("3.32")]);
+        assert_eq!(amounts.iter().sum::<Decimal>(), dec("10"));
+    }
+
+    #[test]
+    fn refuses_items_off_the_tab_or_already_shared() {
+        let items = [order_item("4", 1), order_item("5", 1)];
+        let stranger = uuid::Uuid::new_v4();
+
+        assert!(price_item_shares(&[vec![stranger]], &items, &HashSet::new(), dec("9")).is_err());
+        assert!(price_item_shares(
+            &[vec![items[0].id], vec![items[0].id]],
+            &items,
+            &HashSet::new(),
+            dec("9")
+        )
+        .is_err());
+        assert!(price_item_shares(
+            &[vec![items[0].id]],
+            &items,
+            &HashSet::from([items[0].id]),
+            dec("9")
+        )
+        .is_err());
+        assert!(price_item_shares(&[vec![]], &items, &HashSet::new(), dec("9")).is_err());
+    }
+}
@@ -0,0 +1,170 @@
+use std::sync::Arc;
+
+use actix_web::{web, HttpMessage, HttpRequest, HttpResponse};
+use serde_json::json;
+
+use crate::{
+    app::{
+        taxes::{
+            dtos::dto::{
+                get_organization_tax_rules, save_tax_rule, toggle_tax_rule, update_tax_rule,
+            },
+            models::model::{SaveTaxRuleDto, TaxRuleParams, TaxRuleType},
+        },
+        users::models::model::UserResponse,
+    },
+    libs::error,
+    utils::{
+        json_validator::{ValidatedJson, ValidatedPath},
+        models::{HttpClientResponse, PathParamsModel, ResponseCode},
+    },
+    AppState,
+};
+
+/// Service charges are always added on top of the listed price and never taxed on, so they
+/// can be neither inclusive nor compound.
+fn check_rule(rule: &SaveTaxRuleDto) -> Option<String> {
+    if rule.rule_type == TaxRuleType::Service && (rule.is_inclusive || rule.is_compound) {
+        return Some("A service charge cannot be inclusive or compound".to_string());
+    }
+
+    None
+}
+
+pub async fn add_rule(
+    req: HttpRequest,
+    payload: ValidatedJson<TaxRuleParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, error::Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<UserResponse>>()
+        .cloned()
+        .ok_or(error::Error {
+            message: "User not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    let rule = payload.0.into_dto(model.organization_id);
+
+    if let Some(message) = check_rule(&rule) {
+        return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            message,
+            json!({}),
+        )));
+    }
+
+    match save_tax_rule(rule, &state).await {
+        Ok(res) => Ok(HttpResponse::Created().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Tax Rule Saved Successfully".to_string(),
+            json!(res),
+        ))),
+        Err(e) => Ok(
+            HttpResponse::InternalServerError().json(HttpClientResponse::new(
+                ResponseCode::Failed,
+                format!("Error Adding Tax Rule: {}", e),
+                json!({}),
+            )),
+        ),
+    }
+}
+
+pub async fn organization_rules(
+    req: HttpRequest,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, error::Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<UserResponse>>()
+        .cloned()
+        .ok_or(error::Error {
+            message: "User not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    match get_organization_tax_rules(model.organization_id, &state).await {
+        Ok(res) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Tax Rules Fetched Successfully".to_string(),
+            json!(res),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Error Retrieving: {}", e),
+            json!([]),
+        ))),
+    }
+}
+
+pub async fn edit_rule(
+    req: HttpRequest,
+    params: ValidatedPath<PathParamsModel>,
+    payload: ValidatedJson<TaxRuleParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, error::Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<UserResponse>>()
+        .cloned()
+        .ok_or(error::Error {
+            message: "User not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    let rule = payload.0.into_dto(model.organization_id);
+
+    if let Some(message) = check_rule(&rule) {
+        return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            message,
+            json!({}),
+        )));
+    }
+
+    match update_tax_rule(params.0.id, rule, &state).await {
+        Ok(res) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Tax Rule Updated Successfully".to_string(),
+            json!(res),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Error Updating Tax Rule: {}", e),
+            json!({}),
+        ))),
+    }
+}
+
+pub async fn toggle_rule(
+    req: HttpRequest,
+    params: ValidatedPath<PathParamsModel>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, error::Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<UserResponse>>()
+        .cloned()
+        .ok_or(error::Error {
+            message: "User not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    match toggle_tax_rule(params.0.id, model.organization_id, &state).await {
+        Ok(res) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Tax Rule Updated Successfully".to_string(),
+            json!(res),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Error Updating Tax Rule: {}", e),
+            json!({}),
+        ))),
+    }
+}
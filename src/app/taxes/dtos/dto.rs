@@ -0,0 +1,197 @@
+use actix_web::web;
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, Condition, ConnectionTrait, DbErr, EntityTrait,
+    QueryFilter, QueryOrder, Set,
+};
+
+use crate::{
+    app::taxes::models::model::{SaveTaxRuleDto, TaxLineDto, TaxTarget},
+    AppState,
+};
+
+/// Active rules an organization charges on `target`, in priority order.
+pub async fn applicable_tax_rules<C: ConnectionTrait>(
+    organization: uuid::Uuid,
+    target: TaxTarget,
+    conn: &C,
+) -> Result<Vec<entity::tax_rules::Model>, DbErr> {
+    entity::tax_rules::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::tax_rules::Column::OrganizationId.eq(organization))
+                .add(entity::tax_rules::Column::IsActive.eq(true))
+                .add(
+                    entity::tax_rules::Column::AppliesTo
+                        .is_in([TaxTarget::All.as_str(), target.as_str()]),
+                ),
+        )
+        .order_by_asc(entity::tax_rules::Column::Priority)
+        .order_by_asc(entity::tax_rules::Column::CreatedAt)
+        .all(conn)
+        .await
+}
+
+/// Stores the breakdown lines against an order or a booking. Call inside the charge's
+/// transaction.
+pub async fn record_tax_lines<C: ConnectionTrait>(
+    lines: &[TaxLineDto],
+    order: Option<uuid::Uuid>,
+    booking: Option<uuid::Uuid>,
+    conn: &C,
+) -> Result<(), DbErr> {
+    if lines.is_empty() {
+        return Ok(());
+    }
+
+    let models = lines.iter().map(|line| entity::tax_lines::ActiveModel {
+        order_id: Set(order),
+        booking_id: Set(booking),
+        tax_rule_id: Set(Some(line.rule)),
+        name: Set(line.name.clone()),
+        code: Set(line.code.clone()),
+        line_type: Set(line.line_type.as_str().to_string()),
+        rate: Set(line.rate),
+        is_inclusive: Set(line.is_inclusive),
+        taxable_amount: Set(line.taxable_amount),
+        amount: Set(line.amount),
+        ..Default::default()
+    });
+
+    entity::tax_lines::Entity::insert_many(models)
+        .exec(conn)
+        .await
+        .map_err(|err| {
+            eprintln!("Database insert error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    Ok(())
+}
+
+pub async fn get_tax_lines<C: ConnectionTrait>(
+    order: Option<uuid::Uuid>,
+    booking: Option<uuid::Uuid>,
+    conn: &C,
+) -> Result<Vec<entity::tax_lines::Model>, DbErr> {
+    let condition = match (order, booking) {
+        (Some(order), _) => entity::tax_lines::Column::OrderId.eq(order),
+        (None, Some(booking)) => entity::tax_lines::Column::BookingId.eq(booking),
+        (None, None) => return Ok(Vec::new()),
+    };
+
+    entity::tax_lines::Entity::find()
+        .filter(condition)
+        .order_by_asc(entity::tax_lines::Column::CreatedAt)
+        .all(conn)
+        .await
+}
+
+pub async fn save_tax_rule(
+    data: SaveTaxRuleDto,
+    state: &web::Data<AppState>,
+) -> Result<entity::tax_rules::Model, DbErr> {
+    let rule = entity::tax_rules::ActiveModel {
+        organization_id: Set(data.organization),
+        name: Set(data.name),
+        code: Set(data.code),
+        rule_type: Set(data.rule_type.as_str().to_string()),
+        rate: Set(data.rate),
+        is_inclusive: Set(data.is_inclusive),
+        is_compound: Set(data.is_compound),
+        priority: Set(data.priority),
+        applies_to: Set(data.applies_to.as_str().to_string()),
+        exempt_category_ids: Set(Some(data.exempt_categories)),
+        ..Default::default()
+    };
+
+    let result = ActiveModelTrait::insert(rule, state.pg_db.get_ref())
+        .await
+        .map_err(|err| {
+            eprintln!("Database insert error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    Ok(result)
+}
+
+/// Rewrites an organization's rule. Charges already made keep the breakdown they were
+/// stored with.
+pub async fn update_tax_rule(
+    id: uuid::Uuid,
+    data: SaveTaxRuleDto,
+    state: &web::Data<AppState>,
+) -> Result<entity::tax_rules::Model, DbErr> {
+    let rule = entity::tax_rules::Entity::find_by_id(id)
+        .filter(entity::tax_rules::Column::OrganizationId.eq(data.organization))
+        .one(state.pg_db.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Tax rule not found".into()))?;
+
+    let mut model: entity::tax_rules::ActiveModel = rule.into();
+
+    model.name = ActiveValue::Set(data.name);
+    model.code = ActiveValue::Set(data.code);
+    model.rule_type = ActiveValue::Set(data.rule_type.as_str().to_string());
+    model.rate = ActiveValue::Set(data.rate);
+    model.is_inclusive = ActiveValue::Set(data.is_inclusive);
+    model.is_compound = ActiveValue::Set(data.is_compound);
+    model.priority = ActiveValue::Set(data.priority);
+    model.applies_to = ActiveValue::Set(data.applies_to.as_str().to_string());
+    model.exempt_category_ids = ActiveValue::Set(Some(data.exempt_categories));
+    model.updated_at = ActiveValue::Set(chrono::Utc::now().into());
+
+    let result = ActiveModelTrait::update(model, state.pg_db.get_ref())
+        .await
+        .map_err(|err| {
+            eprintln!("Database update error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    Ok(result)
+}
+
+/// Switches an organization's rule on or off.
+pub async fn toggle_tax_rule(
+    id: uuid::Uuid,
+    organization: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<entity::tax_rules::Model, DbErr> {
+    let rule = entity::tax_rules::Entity::find_by_id(id)
+        .filter(entity::tax_rules::Column::OrganizationId.eq(organization))
+        .one(state.pg_db.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Tax rule not found".into()))?;
+
+    let is_active = rule.is_active;
+
+    let mut model: entity::tax_rules::ActiveModel = rule.into();
+
+    model.is_active = ActiveValue::Set(!is_active);
+    model.updated_at = ActiveValue::Set(chrono::Utc::now().into());
+
+    let result = ActiveModelTrait::update(model, state.pg_db.get_ref())
+        .await
+        .map_err(|err| {
+            eprintln!("Database update error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    Ok(result)
+}
+
+pub async fn get_organization_tax_rules(
+    organization: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<Vec<entity::tax_rules::Model>, DbErr> {
+    let rules = entity::tax_rules::Entity::find()
+        .filter(entity::tax_rules::Column::OrganizationId.eq(organization))
+        .order_by_asc(entity::tax_rules::Column::Priority)
+        .all(state.pg_db.get_ref())
+        .await
+        .map_err(|err| {
+            eprintln!("Database retrieval error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    Ok(rules)
+}
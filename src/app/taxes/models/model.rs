@@ -0,0 +1,153 @@
+use std::str::FromStr;
+
+use sea_orm::prelude::Decimal;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::libs::validator::{validate_percent_range, validate_tax_rule_type, validate_tax_target};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum TaxRuleType {
+    Tax,
+    Service,
+}
+
+impl TaxRuleType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TaxRuleType::Tax => "TAX",
+            TaxRuleType::Service => "SERVICE",
+        }
+    }
+}
+
+impl FromStr for TaxRuleType {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_uppercase().as_str() {
+            "TAX" => Ok(TaxRuleType::Tax),
+            "SERVICE" => Ok(TaxRuleType::Service),
+            _ => Err(()),
+        }
+    }
+}
+
+/// What a rule is charged on. `All` covers both orders and bookings.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum TaxTarget {
+    All,
+    Orders,
+    Bookings,
+}
+
+impl TaxTarget {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TaxTarget::All => "ALL",
+            TaxTarget::Orders => "ORDERS",
+            TaxTarget::Bookings => "BOOKINGS",
+        }
+    }
+}
+
+impl FromStr for TaxTarget {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_uppercase().as_str() {
+            "ALL" => Ok(TaxTarget::All),
+            "ORDERS" => Ok(TaxTarget::Orders),
+            "BOOKINGS" => Ok(TaxTarget::Bookings),
+            _ => Err(()),
+        }
+    }
+}
+
+/// A priced line on an order or booking. Bookings have no category, so category exemptions
+/// never apply to them.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaxableLine {
+    pub category: Option<uuid::Uuid>,
+    pub amount: Decimal,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaxLineDto {
+    pub rule: uuid::Uuid,
+    pub name: String,
+    pub code: String,
+    pub line_type: TaxRuleType,
+    pub rate: Decimal,
+    pub is_inclusive: bool,
+    pub taxable_amount: Decimal,
+    pub amount: Decimal,
+}
+
+/// Taxes and service charge worked out for a set of lines. `tax_amount` is added on top of
+/// the listed prices, `included_tax_amount` is already inside them.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TaxBreakdown {
+    pub lines: Vec<TaxLineDto>,
+    pub tax_amount: Decimal,
+    pub included_tax_amount: Decimal,
+    pub service_charge: Decimal,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SaveTaxRuleDto {
+    pub organization: uuid::Uuid,
+    pub name: String,
+    pub code: String,
+    pub rule_type: TaxRuleType,
+    pub rate: Decimal,
+    pub is_inclusive: bool,
+    pub is_compound: bool,
+    pub priority: i32,
+    pub applies_to: TaxTarget,
+    pub exempt_categories: Vec<uuid::Uuid>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct TaxRuleParams {
+    #[validate(length(min = 2, max = 50, message = "Name is invalid"))]
+    pub name: String,
+    #[validate(length(min = 2, max = 20, message = "Code is invalid"))]
+    pub code: String,
+    #[validate(custom(function = "validate_tax_rule_type"))]
+    pub rule_type: String,
+    #[validate(custom(function = "validate_percent_range"))]
+    pub rate: Decimal,
+    pub is_inclusive: Option<bool>,
+    pub is_compound: Option<bool>,
+    #[validate(range(min = 0, max = 100, message = "Priority is invalid"))]
+    pub priority: Option<i32>,
+    #[validate(custom(function = "validate_tax_target"))]
+    pub applies_to: Option<String>,
+    #[validate(length(max = 50, message = "Exempt categories are invalid"))]
+    pub exempt_categories: Option<Vec<uuid::Uuid>>,
+}
+
+impl TaxRuleParams {
+    pub fn into_dto(self, organization: uuid::Uuid) -> SaveTaxRuleDto {
+        SaveTaxRuleDto {
+            organization,
+            name: self.name,
+            code: self.code.to_uppercase(),
+            rule_type: self.rule_type.parse().unwrap_or(TaxRuleType::Tax),
+            rate: self.rate,
+            is_inclusive: self.is_inclusive.unwrap_or(false),
+            is_compound: self.is_compound.unwrap_or(false),
+            priority: self.priority.unwrap_or(0),
+            applies_to: self
+                .applies_to
+                .as_deref()
+                .and_then(|target| target.parse().ok())
+                .unwrap_or(TaxTarget::All),
+            exempt_categories: self.exempt_categories.unwrap_or_default(),
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use actix_web::web;
+
+use crate::{
+    app::taxes::controllers::controller::{add_rule, edit_rule, organization_rules, toggle_rule},
+    middlewares::{auth::JwtAuthMiddleware, checker::CheckUserMiddleware},
+    AppState,
+};
+
+pub fn routes(cfg: &mut web::ServiceConfig, state: web::Data<AppState>) {
+    cfg.service(
+        web::scope("/api/v1/taxes")
+            .route(
+                "/rules",
+                web::post()
+                    .to(add_rule)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "User"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/rules",
+                web::get()
+                    .to(organization_rules)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "User"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/rules/{id}",
+                web::put()
+                    .to(edit_rule)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "User"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/rules/toggle/{id}",
+                web::put()
+                    .to(toggle_rule)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "User"))
+                    .wrap(JwtAuthMiddleware),
+            ),
+    );
+}
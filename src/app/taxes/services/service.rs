@@ -0,0 +1,207 @@
+use sea_orm::prelude::Decimal;
+
+use crate::app::taxes::models::model::{TaxBreakdown, TaxLineDto, TaxRuleType, TaxableLine};
+
+fn is_exempt(rule: &entity::tax_rules::Model, line: &TaxableLine) -> bool {
+    match (&rule.exempt_category_ids, line.category) {
+        (Some(exempt), Some(category)) => exempt.contains(&category),
+        _ => false,
+    }
+}
+
+/// Works out taxes and service charge for `lines` under `rules`, which must already be in
+/// priority order.
+///
+/// Each line's taxes are stacked in order: a compound levy is charged on the net amount plus
+/// the levies before it, any other on the net amount alone. For inclusive rules the listed
+/// price already holds the tax, so the net amount is backed out of it first. Service charges
+/// are a plain percentage of the listed price. Rounding to the cent happens once per rule.
+pub fn compute_taxes(lines: &[TaxableLine], rules: &[entity::tax_rules::Model]) -> TaxBreakdown {
+    let hundred = Decimal::from(100);
+
+    let mut totals: Vec<TaxLineDto> = rules
+        .iter()
+        .map(|rule| TaxLineDto {
+            rule: rule.id,
+            name: rule.name.clone(),
+            code: rule.code.clone(),
+            line_type: rule.rule_type.parse().unwrap_or(TaxRuleType::Tax),
+            rate: rule.rate,
+            is_inclusive: rule.is_inclusive,
+            taxable_amount: Decimal::ZERO,
+            amount: Decimal::ZERO,
+        })
+        .collect();
+
+    for line in lines {
+        // Each levy as a multiple of the net amount, zero where it doesn't apply
+        let mut levies = vec![Decimal::ZERO; rules.len()];
+        let mut bases = vec![Decimal::ZERO; rules.len()];
+        let mut stacked = Decimal::ZERO;
+
+        for (index, rule) in rules.iter().enumerate() {
+            if totals[index].line_type != TaxRuleType::Tax || is_exempt(rule, line) {
+                continue;
+            }
+
+            let base = match rule.is_compound {
+                true => Decimal::ONE + stacked,
+                false => Decimal::ONE,
+            };
+
+            bases[index] = base;
+            levies[index] = base * rule.rate / hundred;
+            stacked += levies[index];
+        }
+
+        let inclusive: Decimal = rules
+            .iter()
+            .zip(&levies)
+            .filter(|(rule, _)| rule.is_inclusive)
+            .map(|(_, levy)| *levy)
+            .sum();
+
+        let net = line.amount / (Decimal::ONE + inclusive);
+
+        for (index, rule) in rules.iter().enumerate() {
+            let total = &mut totals[index];
+
+            if total.line_type == TaxRuleType::Service {
+                if !is_exempt(rule, line) {
+                    total.taxable_amount += line.amount;
+                    total.amount += line.amount * rule.rate / hundred;
+                }
+                continue;
+            }
+
+            if levies[index] > Decimal::ZERO {
+                total.taxable_amount += net * bases[index];
+                total.amount += net * levies[index];
+            }
+        }
+    }
+
+    let mut breakdown = TaxBreakdown::default();
+
+    for mut total in totals {
+        total.taxable_amount = total.taxable_amount.round_dp(2);
+        total.amount = total.amount.round_dp(2);
+
+        if total.amount <= Decimal::ZERO {
+            continue;
+        }
+
+        match (total.line_type, total.is_inclusive) {
+            (TaxRuleType::Service, _) => breakdown.service_charge += total.amount,
+            (TaxRuleType::Tax, true) => breakdown.included_tax_amount += total.amount,
+            (TaxRuleType::Tax, false) => breakdown.tax_amount += total.amount,
+        }
+
+        breakdown.lines.push(total);
+    }
+
+    breakdown
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::fixtures::{dec, tax_rule};
+
+    fn line(amount: &str) -> TaxableLine {
+        TaxableLine {
+            category: None,
+            amount: dec(amount),
+        }
+    }
+
+    #[test]
+    fn adds_exclusive_taxes_on_top() {
+        let taxes = compute_taxes(&[line("100")], &[tax_rule(TaxRuleType::Tax, "15")]);
+
+        assert_eq!(taxes.tax_amount, dec("15"));
+        assert_eq!(taxes.included_tax_amount, Decimal::ZERO);
+        assert_eq!(taxes.lines[0].taxable_amount, dec("100"));
+    }
+
+    #[test]
+    fn stacks_compound_levies_on_those_before_them() {
+        let levy = tax_rule(TaxRuleType::Tax, "5");
+        let vat = entity::tax_rules::Model {
+            is_compound: true,
+            ..tax_rule(TaxRuleType::Tax, "15")
+        };
+
+        let taxes = compute_taxes(&[line("100")], &[levy, vat]);
+
+        assert_eq!(taxes.lines[0].amount, dec("5"));
+        assert_eq!(taxes.lines[1].taxable_amount, dec("105"));
+        assert_eq!(taxes.lines[1].amount, dec("15.75"));
+        assert_eq!(taxes.tax_amount, dec("20.75"));
+    }
+
+    #[test]
+    fn backs_inclusive_taxes_out_of_the_price() {
+        let vat = entity::tax_rules::Model {
+            is_inclusive: true,
+            ..tax_rule(TaxRuleType::Tax, "25")
+        };
+
+        let taxes = compute_taxes(&[line("100")], &[vat]);
+
+        assert_eq!(taxes.included_tax_amount, dec("20"));
+        assert_eq!(taxes.tax_amount, Decimal::ZERO);
+        assert_eq!(taxes.lines[0].taxable_amount, dec("80"));
+    }
+
+    #[test]
+    fn charges_service_on_the_listed_price() {
+        let vat = entity::tax_rules::Model {
+            is_inclusive: true,
+            ..tax_rule(TaxRuleType::Tax, "25")
+        };
+
+        let taxes = compute_taxes(&[line("100")], &[vat, tax_rule(TaxRuleType::Service, "10")]);
+
+        assert_eq!(taxes.service_charge, dec("10"));
+        assert_eq!(taxes.included_tax_amount, dec("20"));
+    }
+
+    #[test]
+    fn skips_exempt_categories() {
+        let food = uuid::Uuid::new_v4();
+        let vat = entity::tax_rules::Model {
+            exempt_category_ids: Some(vec![food]),
+            ..tax_rule(TaxRuleType::Tax, "10")
+        };
+        let lines = [
+            TaxableLine {
+                category: Some(food),
+                amount: dec("50"),
+            },
+            line("30"),
+        ];
+
+        let taxes = compute_taxes(&lines, &[vat]);
+
+        assert_eq!(taxes.lines[0].taxable_amount, dec("30"));
+        assert_eq!(taxes.tax_amount, dec("3"));
+    }
+
+    #[test]
+    fn rounds_once_per_rule() {
+        let lines = [line("1.05"), line("1.05"), line("1.05")];
+
+        let taxes = compute_taxes(&lines, &[tax_rule(TaxRuleType::Tax, "5")]);
+
+        // 0.0525 a line, 0.1575 in all
+        assert_eq!(taxes.tax_amount, dec("0.16"));
+    }
+
+    #[test]
+    fn leaves_out_rules_that_charge_nothing() {
+        let taxes = compute_taxes(&[line("0")], &[tax_rule(TaxRuleType::Tax, "15")]);
+
+        assert!(taxes.lines.is_empty());
+    }
+}
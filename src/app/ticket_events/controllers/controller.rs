@@ -0,0 +1,390 @@
+use std::sync::Arc;
+
+use actix_web::{web, HttpMessage, HttpRequest, HttpResponse};
+use serde_json::json;
+
+use crate::{
+    app::{
+        approvals::models::model::{ApprovalStatus, ReviewDto, ReviewParams},
+        employees::models::model::EmployeeResponse,
+        permissions::{dtos::dto::has_permission, models::model::ModelAction},
+        ticket_events::{
+            dtos::dto::{
+                cancel_ticket_event, get_pending_ticket_events, get_public_ticket_event,
+                get_ticket_event, get_ticket_events, review_ticket_event, save_ticket_event,
+                update_ticket_event,
+            },
+            models::model::{
+                AddTicketEventDto, AddTicketEventParams, CancelTicketEventDto,
+                CancelTicketEventParams, SlugParams, UpdateTicketEventDto, UpdateTicketEventParams,
+            },
+        },
+        tickets::dtos::dto::{get_event_tickets, get_public_tickets},
+    },
+    libs::error::Error,
+    utils::{
+        json_validator::{ValidatedJson, ValidatedPath},
+        models::{HttpClientResponse, PathParamsModel, ResponseCode},
+    },
+    AppState,
+};
+
+pub async fn create_ticket_event(
+    req: HttpRequest,
+    payload: ValidatedJson<AddTicketEventParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    let data = payload.0;
+
+    if data.event_date <= chrono::Utc::now() {
+        return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            "Event date must be in the future".to_string(),
+            json!({}),
+        )));
+    }
+
+    let event = AddTicketEventDto {
+        organization: model.organization_id,
+        branch: model.branch_id,
+        created_by: model.id,
+        title: data.title,
+        description: data.description,
+        location: data.location,
+        event_date: data.event_date,
+        ticket_price: data.ticket_price,
+        capacity: data.capacity,
+        min_age: data.min_age,
+        max_age: data.max_age,
+    };
+
+    match save_ticket_event(event, &state).await {
+        Ok(res) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Event Created Successful".to_string(),
+            json!({ "id": res.id, "slug": res.slug }),
+        ))),
+        Err(e) => Ok(
+            HttpResponse::InternalServerError().json(HttpClientResponse::new(
+                ResponseCode::Failed,
+                format!("Error Creating Event: {}", e),
+                json!({}),
+            )),
+        ),
+    }
+}
+
+pub async fn upd_ticket_event(
+    req: HttpRequest,
+    params: ValidatedPath<PathParamsModel>,
+    payload: ValidatedJson<UpdateTicketEventParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    let data = payload.0;
+
+    let event = UpdateTicketEventDto {
+        organization: model.organization_id,
+        branch: model.branch_id,
+        updated_by: model.id,
+        title: data.title,
+        description: data.description,
+        location: data.location,
+        event_date: data.event_date,
+        capacity: data.capacity,
+        min_age: data.min_age,
+        max_age: data.max_age,
+    };
+
+    match update_ticket_event(params.0.id, event, &state).await {
+        Ok(_) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Event Updated Successfully".to_string(),
+            json!({}),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Could not update event: {}", e),
+            json!({}),
+        ))),
+    }
+}
+
+pub async fn get_branch_ticket_events(
+    req: HttpRequest,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    let results = get_ticket_events(model.organization_id, model.branch_id, &state).await;
+
+    if let Err(e) = results {
+        return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Events not found: {}", e),
+            json!({}),
+        )));
+    };
+
+    let events = results.unwrap();
+
+    if events.is_empty() {
+        return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "No Events Found".to_string(),
+            json!([]),
+        )));
+    }
+
+    Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+        ResponseCode::Success,
+        "Events Fetched Successfully".to_string(),
+        json!(events),
+    )))
+}
+
+pub async fn get_ticket_event_details(
+    req: HttpRequest,
+    params: ValidatedPath<PathParamsModel>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    let event =
+        match get_ticket_event(params.0.id, model.organization_id, model.branch_id, &state).await {
+            Ok(event) => event,
+            Err(e) => {
+                return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+                    ResponseCode::Failed,
+                    format!("Event not found: {}", e),
+                    json!({}),
+                )))
+            }
+        };
+
+    let tickets = get_event_tickets(event.id, &state)
+        .await
+        .unwrap_or_default();
+
+    Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+        ResponseCode::Success,
+        "Event Fetched Successfully".to_string(),
+        json!({ "event": event, "tickets": tickets }),
+    )))
+}
+
+pub async fn get_event_by_slug(
+    params: ValidatedPath<SlugParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let event = match get_public_ticket_event(params.0.slug, &state).await {
+        Ok(event) => event,
+        Err(e) => {
+            return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+                ResponseCode::Failed,
+                format!("Event not found: {}", e),
+                json!({}),
+            )))
+        }
+    };
+
+    let tickets = get_public_tickets(event.id, &state)
+        .await
+        .unwrap_or_default();
+
+    Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+        ResponseCode::Success,
+        "Event Fetched Successfully".to_string(),
+        json!({
+            "id": event.id,
+            "title": event.title,
+            "description": event.description,
+            "location": event.location,
+            "event_date": event.event_date,
+            "event_status": event.event_status,
+            "min_age_limit": event.min_age_limit,
+            "max_age_limit": event.max_age_limit,
+            "slug": event.slug,
+            "tickets": tickets,
+        }),
+    )))
+}
+
+pub async fn cancel_event(
+    req: HttpRequest,
+    params: ValidatedPath<PathParamsModel>,
+    payload: ValidatedJson<CancelTicketEventParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    let data = CancelTicketEventDto {
+        organization: model.organization_id,
+        branch: model.branch_id,
+        reason: payload.0.reason,
+    };
+
+    match cancel_ticket_event(params.0.id, data, &state).await {
+        Ok(refunded) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Event Cancelled Successfully".to_string(),
+            json!({ "refunded": refunded }),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Could not cancel event: {}", e),
+            json!({}),
+        ))),
+    }
+}
+
+pub async fn review_event(
+    req: HttpRequest,
+    params: ValidatedPath<PathParamsModel>,
+    payload: ValidatedJson<ReviewParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    if !has_permission(
+        model.role_permissions,
+        ModelAction::ApproveTicketEvents,
+        &state,
+    )
+    .await
+    .unwrap_or(false)
+    {
+        return Ok(HttpResponse::Forbidden().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            "Not Permitted To Review Events".to_string(),
+            json!({}),
+        )));
+    }
+
+    let data = payload.0;
+
+    let decision = data
+        .decision
+        .parse::<ApprovalStatus>()
+        .unwrap_or(ApprovalStatus::Rejected);
+
+    if decision == ApprovalStatus::Rejected && data.comment.is_none() {
+        return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            "A comment is required when rejecting".to_string(),
+            json!({}),
+        )));
+    }
+
+    let review = ReviewDto {
+        organization: model.organization_id,
+        reviewer: model.id,
+        decision,
+        comment: data.comment,
+    };
+
+    match review_ticket_event(params.0.id, review, &state).await {
+        Ok(_) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Event Reviewed Successfully".to_string(),
+            json!({ "approval_status": decision }),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Could not review event: {}", e),
+            json!({}),
+        ))),
+    }
+}
+
+pub async fn get_pending_events(
+    req: HttpRequest,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    let results = get_pending_ticket_events(model.organization_id, model.branch_id, &state).await;
+
+    if let Err(e) = results {
+        return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Events not found: {}", e),
+            json!({}),
+        )));
+    };
+
+    let events = results.unwrap();
+
+    if events.is_empty() {
+        return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "No Events Found".to_string(),
+            json!([]),
+        )));
+    }
+
+    Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+        ResponseCode::Success,
+        "Events Fetched Successfully".to_string(),
+        json!(events),
+    )))
+}
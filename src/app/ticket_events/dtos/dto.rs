@@ -0,0 +1,483 @@
+use std::collections::HashMap;
+
+use actix_web::web;
+use migration::Expr;
+use sea_orm::{
+    prelude::Decimal, ActiveModelTrait, ActiveValue, ColumnTrait, Condition, DbErr, EntityTrait,
+    QueryFilter, QueryOrder, QuerySelect, Set, TransactionTrait,
+};
+
+use crate::{
+    app::{
+        approvals::{
+            dtos::dto::log_transition,
+            models::model::{ApprovalEntity, ApprovalStatus, ApprovalTransitionDto, ReviewDto},
+        },
+        promos::{dtos::dto::void_redemptions, models::model::PromoTarget},
+        ticket_events::models::model::{
+            AddTicketEventDto, CancelTicketEventDto, TicketEventStatus, UpdateTicketEventDto,
+        },
+        tickets::models::model::{ListingStatus, PurchaseStatus},
+        wallets::{
+            dtos::dto::credit_customer_wallet,
+            models::model::{TransactionType, WalletCreditDto},
+        },
+    },
+    libs::jwt::gen_string,
+    utils::shared::slugify,
+    AppState,
+};
+
+pub async fn save_ticket_event(
+    data: AddTicketEventDto,
+    state: &web::Data<AppState>,
+) -> Result<entity::ticket_events::Model, DbErr> {
+    let slug = format!("{}-{}", slugify(&data.title), gen_string(6).to_lowercase());
+
+    let event = entity::ticket_events::ActiveModel {
+        organization_id: Set(data.organization),
+        branch_id: Set(data.branch),
+        created_by: Set(Some(data.created_by)),
+        title: Set(data.title),
+        description: Set(data.description),
+        location: Set(data.location),
+        event_date: Set(data.event_date.into()),
+        ticket_price: Set(data.ticket_price),
+        capacity: Set(data.capacity),
+        event_status: Set(TicketEventStatus::Active.as_str().to_string()),
+        min_age_limit: Set(data.min_age.unwrap_or(18)),
+        max_age_limit: Set(data.max_age.unwrap_or(100)),
+        slug: Set(Some(slug)),
+        approval_status: Set(ApprovalStatus::Pending.as_str().to_string()),
+        ..Default::default()
+    };
+
+    let txn = state.pg_db.get_ref().begin().await?;
+
+    let result = ActiveModelTrait::insert(event, &txn)
+        .await
+        .map_err(|err| {
+            eprintln!("Database insert error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    log_transition(
+        ApprovalTransitionDto {
+            organization: data.organization,
+            entity: ApprovalEntity::TicketEvent,
+            entity_id: result.id,
+            from: None,
+            to: ApprovalStatus::Pending,
+            actor: data.created_by,
+            comment: None,
+        },
+        &txn,
+    )
+    .await?;
+
+    txn.commit().await?;
+
+    Ok(result)
+}
+
+pub async fn update_ticket_event(
+    id: uuid::Uuid,
+    data: UpdateTicketEventDto,
+    state: &web::Data<AppState>,
+) -> Result<(), DbErr> {
+    let event = get_ticket_event(id, data.organization, data.branch, state).await?;
+
+    if event.is_cancelled {
+        return Err(DbErr::Custom("Event has been cancelled".to_string()));
+    }
+
+    // Editing a rejected event sends it back for review
+    let resubmit = event.approval_status == ApprovalStatus::Rejected.as_str();
+
+    let mut model: entity::ticket_events::ActiveModel = event.into();
+
+    if resubmit {
+        model.approval_status = ActiveValue::Set(ApprovalStatus::Pending.as_str().to_string());
+    }
+
+    if let Some(title) = data.title {
+        model.title = ActiveValue::Set(title);
+    }
+
+    if let Some(description) = data.description {
+        model.description = ActiveValue::Set(description);
+    }
+
+    if let Some(location) = data.location {
+        model.location = ActiveValue::Set(Some(location));
+    }
+
+    if let Some(event_date) = data.event_date {
+        model.event_date = ActiveValue::Set(event_date.into());
+    }
+
+    if let Some(capacity) = data.capacity {
+        model.capacity = ActiveValue::Set(Some(capacity));
+    }
+
+    if let Some(min_age) = data.min_age {
+        model.min_age_limit = ActiveValue::Set(min_age);
+    }
+
+    if let Some(max_age) = data.max_age {
+        model.max_age_limit = ActiveValue::Set(max_age);
+    }
+
+    model.updated_at = ActiveValue::Set(chrono::Utc::now().into());
+
+    let txn = state.pg_db.get_ref().begin().await?;
+
+    ActiveModelTrait::update(model, &txn)
+        .await
+        .map_err(|err| {
+            eprintln!("Database update error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    if resubmit {
+        log_transition(
+            ApprovalTransitionDto {
+                organization: data.organization,
+                entity: ApprovalEntity::TicketEvent,
+                entity_id: id,
+                from: Some(ApprovalStatus::Rejected),
+                to: ApprovalStatus::Pending,
+                actor: data.updated_by,
+                comment: Some("Resubmitted after changes".to_string()),
+            },
+            &txn,
+        )
+        .await?;
+    }
+
+    txn.commit().await?;
+
+    Ok(())
+}
+
+/// Approves or rejects a pending event. The reviewer cannot be the employee who created it.
+pub async fn review_ticket_event(
+    id: uuid::Uuid,
+    data: ReviewDto,
+    state: &web::Data<AppState>,
+) -> Result<(), DbErr> {
+    let txn = state.pg_db.get_ref().begin().await?;
+
+    let event = entity::ticket_events::Entity::find_by_id(id)
+        .filter(
+            Condition::all()
+                .add(entity::ticket_events::Column::OrganizationId.eq(data.organization))
+                .add(
+                    entity::ticket_events::Column::ApprovalStatus
+                        .eq(ApprovalStatus::Pending.as_str()),
+                ),
+        )
+        .lock_exclusive()
+        .one(&txn)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Pending event not found".into()))?;
+
+    if event.created_by == Some(data.reviewer) {
+        return Err(DbErr::Custom(
+            "Events cannot be reviewed by their creator".to_string(),
+        ));
+    }
+
+    let now = chrono::Utc::now();
+    let approved = data.decision == ApprovalStatus::Approved;
+
+    let mut model: entity::ticket_events::ActiveModel = event.into();
+
+    model.approval_status = ActiveValue::Set(data.decision.as_str().to_string());
+    model.approval_comment = ActiveValue::Set(data.comment.clone());
+    model.approved_by = ActiveValue::Set(approved.then_some(data.reviewer));
+    model.approved_at = ActiveValue::Set(approved.then_some(now.into()));
+    model.updated_at = ActiveValue::Set(now.into());
+
+    ActiveModelTrait::update(model, &txn)
+        .await
+        .map_err(|err| {
+            eprintln!("Database update error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    log_transition(
+        ApprovalTransitionDto {
+            organization: data.organization,
+            entity: ApprovalEntity::TicketEvent,
+            entity_id: id,
+            from: Some(ApprovalStatus::Pending),
+            to: data.decision,
+            actor: data.reviewer,
+            comment: data.comment,
+        },
+        &txn,
+    )
+    .await?;
+
+    txn.commit().await?;
+
+    Ok(())
+}
+
+pub async fn get_pending_ticket_events(
+    organization: uuid::Uuid,
+    branch: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<Vec<entity::ticket_events::Model>, DbErr> {
+    let events = entity::ticket_events::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::ticket_events::Column::OrganizationId.eq(organization))
+                .add(entity::ticket_events::Column::BranchId.eq(branch))
+                .add(entity::ticket_events::Column::IsCancelled.eq(false))
+                .add(
+                    entity::ticket_events::Column::ApprovalStatus
+                        .eq(ApprovalStatus::Pending.as_str()),
+                ),
+        )
+        .order_by_asc(entity::ticket_events::Column::CreatedAt)
+        .all(state.pg_db.get_ref())
+        .await
+        .map_err(|err| {
+            eprintln!("Database retrieval error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    Ok(events)
+}
+
+pub async fn get_ticket_events(
+    organization: uuid::Uuid,
+    branch: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<Vec<entity::ticket_events::Model>, DbErr> {
+    let events = entity::ticket_events::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::ticket_events::Column::OrganizationId.eq(organization))
+                .add(entity::ticket_events::Column::BranchId.eq(branch)),
+        )
+        .order_by_desc(entity::ticket_events::Column::EventDate)
+        .all(state.pg_db.get_ref())
+        .await
+        .map_err(|err| {
+            eprintln!("Database retrieval error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    Ok(events)
+}
+
+pub async fn get_ticket_event(
+    id: uuid::Uuid,
+    organization: uuid::Uuid,
+    branch: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<entity::ticket_events::Model, DbErr> {
+    let event = entity::ticket_events::Entity::find_by_id(id)
+        .filter(
+            Condition::all()
+                .add(entity::ticket_events::Column::OrganizationId.eq(organization))
+                .add(entity::ticket_events::Column::BranchId.eq(branch)),
+        )
+        .one(state.pg_db.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Event not found".into()));
+
+    event
+}
+
+pub async fn get_ticket_event_by_id(
+    id: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<entity::ticket_events::Model, DbErr> {
+    let event = entity::ticket_events::Entity::find_by_id(id)
+        .filter(
+            Condition::all()
+                .add(entity::ticket_events::Column::IsCancelled.eq(false))
+                .add(
+                    entity::ticket_events::Column::ApprovalStatus
+                        .eq(ApprovalStatus::Approved.as_str()),
+                ),
+        )
+        .one(state.pg_db.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Event not found".into()));
+
+    event
+}
+
+pub async fn get_public_ticket_event(
+    slug: String,
+    state: &web::Data<AppState>,
+) -> Result<entity::ticket_events::Model, DbErr> {
+    let event = entity::ticket_events::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::ticket_events::Column::Slug.eq(slug))
+                .add(entity::ticket_events::Column::IsCancelled.eq(false))
+                .add(
+                    entity::ticket_events::Column::ApprovalStatus
+                        .eq(ApprovalStatus::Approved.as_str()),
+                )
+                .add(entity::ticket_events::Column::EventDate.gt(chrono::Utc::now())),
+        )
+        .one(state.pg_db.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Event not found".into()));
+
+    event
+}
+
+/// The purchase that paid for a pass: the pass itself, or for a pass received as a free
+/// transfer, the one it was handed on from.
+fn paying_purchase<'a>(
+    purchase: &'a entity::ticket_purchases::Model,
+    history: &'a HashMap<uuid::Uuid, entity::ticket_purchases::Model>,
+) -> &'a entity::ticket_purchases::Model {
+    let mut current = purchase;
+
+    while current.transaction_id.is_none() {
+        match current.transferred_from.and_then(|from| history.get(&from)) {
+            Some(previous) => current = previous,
+            None => break,
+        }
+    }
+
+    current
+}
+
+/// Cancels an event, withdraws its tickets from sale and refunds every buyer to their wallet.
+/// Returns the number of purchases refunded.
+pub async fn cancel_ticket_event(
+    id: uuid::Uuid,
+    data: CancelTicketEventDto,
+    state: &web::Data<AppState>,
+) -> Result<usize, DbErr> {
+    let txn = state.pg_db.get_ref().begin().await?;
+
+    let event = entity::ticket_events::Entity::find_by_id(id)
+        .filter(
+            Condition::all()
+                .add(entity::ticket_events::Column::OrganizationId.eq(data.organization))
+                .add(entity::ticket_events::Column::BranchId.eq(data.branch))
+                .add(entity::ticket_events::Column::IsCancelled.eq(false)),
+        )
+        .lock_exclusive()
+        .one(&txn)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Event not found".into()))?;
+
+    let now = chrono::Utc::now();
+
+    let mut model: entity::ticket_events::ActiveModel = event.clone().into();
+
+    model.is_cancelled = ActiveValue::Set(true);
+    model.cancelled_date = ActiveValue::Set(Some(now.into()));
+    model.cancellation_reason = ActiveValue::Set(Some(data.reason));
+    model.updated_at = ActiveValue::Set(now.into());
+
+    ActiveModelTrait::update(model, &txn).await.map_err(|err| {
+        eprintln!("Database update error: {}", err);
+        DbErr::Custom(err.to_string())
+    })?;
+
+    entity::tickets::Entity::update_many()
+        .filter(entity::tickets::Column::TicketEventId.eq(event.id))
+        .col_expr(entity::tickets::Column::IsActive, Expr::value(false))
+        .col_expr(entity::tickets::Column::UpdatedAt, Expr::value(now))
+        .exec(&txn)
+        .await
+        .map_err(|err| {
+            eprintln!("Database update error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    entity::ticket_listings::Entity::update_many()
+        .filter(
+            Condition::all()
+                .add(entity::ticket_listings::Column::TicketEventId.eq(event.id))
+                .add(
+                    entity::ticket_listings::Column::ListingStatus
+                        .eq(ListingStatus::Active.as_str()),
+                ),
+        )
+        .col_expr(
+            entity::ticket_listings::Column::ListingStatus,
+            Expr::value(ListingStatus::Cancelled.as_str()),
+        )
+        .col_expr(entity::ticket_listings::Column::UpdatedAt, Expr::value(now))
+        .exec(&txn)
+        .await
+        .map_err(|err| {
+            eprintln!("Database update error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    let history: HashMap<uuid::Uuid, entity::ticket_purchases::Model> =
+        entity::ticket_purchases::Entity::find()
+            .filter(entity::ticket_purchases::Column::TicketEventId.eq(event.id))
+            .all(&txn)
+            .await?
+            .into_iter()
+            .map(|purchase| (purchase.id, purchase))
+            .collect();
+
+    let purchases: Vec<entity::ticket_purchases::Model> = history
+        .values()
+        .filter(|purchase| purchase.purchase_status == PurchaseStatus::Active.as_str())
+        .cloned()
+        .collect();
+
+    let refunded = purchases.len();
+
+    for purchase in purchases {
+        // A pass received as a free transfer is refunded to whoever paid for it
+        let payer = paying_purchase(&purchase, &history);
+
+        let refund = if payer.total_amount > Decimal::ZERO {
+            let refund = credit_customer_wallet(
+                WalletCreditDto {
+                    customer: payer.customer_id,
+                    organization: event.organization_id,
+                    branch: event.branch_id,
+                    amount: payer.total_amount,
+                    description: format!("Refund for cancelled event {}", event.title),
+                    transaction_type: TransactionType::Refund,
+                },
+                &txn,
+            )
+            .await?;
+
+            Some(refund.id)
+        } else {
+            None
+        };
+
+        void_redemptions(PromoTarget::Tickets, payer.id, &txn).await?;
+
+        let mut purchase: entity::ticket_purchases::ActiveModel = purchase.into();
+
+        purchase.purchase_status = ActiveValue::Set(PurchaseStatus::Refunded.as_str().to_string());
+        purchase.refund_transaction_id = ActiveValue::Set(refund);
+        purchase.refunded_at = ActiveValue::Set(Some(now.into()));
+        purchase.updated_at = ActiveValue::Set(now.into());
+
+        ActiveModelTrait::update(purchase, &txn)
+            .await
+            .map_err(|err| {
+                eprintln!("Database update error: {}", err);
+                DbErr::Custom(err.to_string())
+            })?;
+    }
+
+    txn.commit().await?;
+
+    Ok(refunded)
+}
@@ -0,0 +1,109 @@
+use chrono::{DateTime, Utc};
+use sea_orm::prelude::Decimal;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::libs::validator::validate_val_range;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum TicketEventStatus {
+    Active,
+    Full,
+}
+
+impl TicketEventStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TicketEventStatus::Active => "ACTIVE",
+            TicketEventStatus::Full => "FULL",
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AddTicketEventDto {
+    pub organization: uuid::Uuid,
+    pub branch: uuid::Uuid,
+    pub created_by: uuid::Uuid,
+    pub title: String,
+    pub description: String,
+    pub location: Option<String>,
+    pub event_date: DateTime<Utc>,
+    pub ticket_price: Decimal,
+    pub capacity: Option<i32>,
+    pub min_age: Option<i32>,
+    pub max_age: Option<i32>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UpdateTicketEventDto {
+    pub organization: uuid::Uuid,
+    pub branch: uuid::Uuid,
+    pub updated_by: uuid::Uuid,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub location: Option<String>,
+    pub event_date: Option<DateTime<Utc>>,
+    pub capacity: Option<i32>,
+    pub min_age: Option<i32>,
+    pub max_age: Option<i32>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CancelTicketEventDto {
+    pub organization: uuid::Uuid,
+    pub branch: uuid::Uuid,
+    pub reason: String,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct AddTicketEventParams {
+    #[validate(length(min = 3, max = 100, message = "Title is invalid"))]
+    pub title: String,
+    #[validate(length(min = 3, max = 500, message = "Description is invalid"))]
+    pub description: String,
+    #[validate(length(min = 3, max = 100, message = "Location is invalid"))]
+    pub location: Option<String>,
+    pub event_date: DateTime<Utc>,
+    #[validate(custom(function = "validate_val_range"))]
+    pub ticket_price: Decimal,
+    #[validate(range(min = 1, max = 100000, message = "Capacity is invalid"))]
+    pub capacity: Option<i32>,
+    #[validate(range(min = 0, max = 100, message = "Minimum age is invalid"))]
+    pub min_age: Option<i32>,
+    #[validate(range(min = 0, max = 100, message = "Maximum age is invalid"))]
+    pub max_age: Option<i32>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct UpdateTicketEventParams {
+    #[validate(length(min = 3, max = 100, message = "Title is invalid"))]
+    pub title: Option<String>,
+    #[validate(length(min = 3, max = 500, message = "Description is invalid"))]
+    pub description: Option<String>,
+    #[validate(length(min = 3, max = 100, message = "Location is invalid"))]
+    pub location: Option<String>,
+    pub event_date: Option<DateTime<Utc>>,
+    #[validate(range(min = 1, max = 100000, message = "Capacity is invalid"))]
+    pub capacity: Option<i32>,
+    #[validate(range(min = 0, max = 100, message = "Minimum age is invalid"))]
+    pub min_age: Option<i32>,
+    #[validate(range(min = 0, max = 100, message = "Maximum age is invalid"))]
+    pub max_age: Option<i32>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct CancelTicketEventParams {
+    #[validate(length(min = 3, max = 200, message = "Reason is invalid"))]
+    pub reason: String,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct SlugParams {
+    #[validate(length(min = 3, max = 150, message = "Slug is invalid"))]
+    pub slug: String,
+}
@@ -0,0 +1,66 @@
+use actix_web::web;
+
+use crate::{
+    app::ticket_events::controllers::controller::{
+        cancel_event, create_ticket_event, get_branch_ticket_events, get_event_by_slug,
+        get_pending_events, get_ticket_event_details, review_event, upd_ticket_event,
+    },
+    middlewares::{auth::JwtAuthMiddleware, checker::CheckUserMiddleware},
+    AppState,
+};
+
+pub fn routes(cfg: &mut web::ServiceConfig, state: web::Data<AppState>) {
+    cfg.service(
+        web::scope("/api/v1/ticket-events")
+            .route(
+                "/add",
+                web::post()
+                    .to(create_ticket_event)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/update/{id}",
+                web::put()
+                    .to(upd_ticket_event)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/branch",
+                web::get()
+                    .to(get_branch_ticket_events)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/details/{id}",
+                web::get()
+                    .to(get_ticket_event_details)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route("/public/{slug}", web::get().to(get_event_by_slug))
+            .route(
+                "/pending",
+                web::get()
+                    .to(get_pending_events)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/review/{id}",
+                web::put()
+                    .to(review_event)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/cancel/{id}",
+                web::put()
+                    .to(cancel_event)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            ),
+    );
+}
@@ -0,0 +1,840 @@
+use std::sync::Arc;
+
+use actix_web::{web, HttpMessage, HttpRequest, HttpResponse};
+use sea_orm::{prelude::Decimal, DbErr};
+use serde_json::json;
+
+use crate::{
+    app::{
+        approvals::models::model::{ApprovalStatus, ReviewDto, ReviewParams},
+        bans::dtos::dto::get_active_bans,
+        customers::{
+            dtos::dto::{get_active_customer_by_contact, get_customer_full},
+            models::model::{
+                CustomerResponse, EligibilityRejection, EligibilityRules, RejectionReason,
+            },
+            services::service::evaluate_eligibility,
+        },
+        employees::models::model::EmployeeResponse,
+        permissions::{dtos::dto::has_permission, models::model::ModelAction},
+        ticket_events::dtos::dto::{get_ticket_event, get_ticket_event_by_id},
+        tickets::{
+            dtos::dto::{
+                buy_listing, cancel_listing, get_customer_purchases, get_event_listings,
+                get_listing_by_id, get_pending_tickets, get_purchase_by_id, get_ticket_by_id,
+                purchase_ticket, review_ticket, save_listing, save_ticket, transfer_ticket,
+                update_ticket,
+            },
+            models::model::{
+                AddListingDto, AddListingParams, AddTicketDto, AddTicketParams, BuyListingDto,
+                PurchaseTicketDto, PurchaseTicketParams, TransferTicketDto, TransferTicketParams,
+                UpdateTicketDto, UpdateTicketParams,
+            },
+        },
+    },
+    libs::error::Error,
+    utils::{
+        json_validator::{ValidatedJson, ValidatedPath},
+        models::{HttpClientResponse, PathParamsModel, ResponseCode},
+    },
+    AppState,
+};
+
+/// Reasons the customer may not hold a pass for the event, including any ban at its venue.
+async fn event_rejections(
+    customer: &entity::customers::Model,
+    event: &entity::ticket_events::Model,
+    state: &web::Data<AppState>,
+) -> Result<Vec<EligibilityRejection>, DbErr> {
+    let mut rejections = evaluate_eligibility(
+        customer,
+        &EligibilityRules::from(event),
+        event.event_date.date_naive(),
+    );
+
+    let bans = get_active_bans(customer.id, event.organization_id, event.branch_id, state).await?;
+
+    if !bans.is_empty() {
+        rejections.push(RejectionReason::Banned.into());
+    }
+
+    Ok(rejections)
+}
+
+pub async fn create_ticket(
+    req: HttpRequest,
+    payload: ValidatedJson<AddTicketParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    let data = payload.0;
+
+    let event = match get_ticket_event(
+        data.ticket_event,
+        model.organization_id,
+        model.branch_id,
+        &state,
+    )
+    .await
+    {
+        Ok(event) => event,
+        Err(e) => {
+            return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+                ResponseCode::Failed,
+                format!("Event not found: {}", e),
+                json!({}),
+            )))
+        }
+    };
+
+    if event.is_cancelled {
+        return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            "Event has been cancelled".to_string(),
+            json!({}),
+        )));
+    }
+
+    if data.end_date > event.event_date {
+        return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            "Ticket sales must end before the event".to_string(),
+            json!({}),
+        )));
+    }
+
+    let ticket = AddTicketDto {
+        organization: model.organization_id,
+        created_by: model.id,
+        ticket_event: event.id,
+        event_slug: event.slug.unwrap_or_default(),
+        ticket_type: data.ticket_type,
+        price: data.price,
+        discount_rate: data.discount_rate,
+        quantity: data.quantity,
+        end_date: data.end_date,
+        location: data.location.or(event.location),
+    };
+
+    match save_ticket(ticket, &state).await {
+        Ok(res) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Ticket Created Successful".to_string(),
+            json!({ "id": res.id, "slug": res.slug, "sku": res.sku }),
+        ))),
+        Err(e) => Ok(
+            HttpResponse::InternalServerError().json(HttpClientResponse::new(
+                ResponseCode::Failed,
+                format!("Error Creating Ticket: {}", e),
+                json!({}),
+            )),
+        ),
+    }
+}
+
+pub async fn upd_ticket(
+    req: HttpRequest,
+    params: ValidatedPath<PathParamsModel>,
+    payload: ValidatedJson<UpdateTicketParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    let ticket = match get_ticket_by_id(params.0.id, &state).await {
+        Ok(ticket) => ticket,
+        Err(e) => {
+            return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+                ResponseCode::Failed,
+                format!("Ticket not found: {}", e),
+                json!({}),
+            )))
+        }
+    };
+
+    if let Err(e) = get_ticket_event(
+        ticket.ticket_event_id,
+        model.organization_id,
+        model.branch_id,
+        &state,
+    )
+    .await
+    {
+        return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Event not found: {}", e),
+            json!({}),
+        )));
+    }
+
+    let data = payload.0;
+
+    let update = UpdateTicketDto {
+        organization: model.organization_id,
+        updated_by: model.id,
+        price: data.price,
+        discount_rate: data.discount_rate,
+        quantity: data.quantity,
+        end_date: data.end_date,
+        is_active: data.is_active,
+    };
+
+    match update_ticket(ticket, update, &state).await {
+        Ok(_) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Ticket Updated Successfully".to_string(),
+            json!({}),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Could not update ticket: {}", e),
+            json!({}),
+        ))),
+    }
+}
+
+pub async fn buy_ticket(
+    req: HttpRequest,
+    payload: ValidatedJson<PurchaseTicketParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<CustomerResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Customer not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    let mut session_id = uuid::Uuid::nil();
+
+    if let Some(session_uuid) = &model.session {
+        if let Ok(s_uuid) = uuid::Uuid::parse_str(session_uuid) {
+            session_id = s_uuid
+        }
+    }
+
+    let customer = match get_customer_full(session_id, &state).await {
+        Ok(customer) => customer,
+        Err(e) => {
+            return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+                ResponseCode::Failed,
+                format!("Could not find customer: {}", e),
+                json!({}),
+            )))
+        }
+    };
+
+    let data = payload.0;
+
+    let ticket = match get_ticket_by_id(data.ticket, &state).await {
+        Ok(ticket) => ticket,
+        Err(e) => {
+            return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+                ResponseCode::Failed,
+                format!("Ticket not found: {}", e),
+                json!({}),
+            )))
+        }
+    };
+
+    let event = match get_ticket_event_by_id(ticket.ticket_event_id, &state).await {
+        Ok(event) => event,
+        Err(e) => {
+            return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+                ResponseCode::Failed,
+                format!("Event not found: {}", e),
+                json!({}),
+            )))
+        }
+    };
+
+    let rejections = match event_rejections(&customer, &event, &state).await {
+        Ok(rejections) => rejections,
+        Err(e) => {
+            return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+                ResponseCode::Failed,
+                format!("Could not check eligibility: {}", e),
+                json!({}),
+            )))
+        }
+    };
+
+    if !rejections.is_empty() {
+        return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            "Customer Not Eligible For Event".to_string(),
+            json!({ "reasons": rejections }),
+        )));
+    }
+
+    let purchase = PurchaseTicketDto {
+        ticket: ticket.id,
+        customer: customer.id,
+        quantity: data.quantity,
+        promo_code: data.promo_code.map(|code| code.trim().to_uppercase()),
+    };
+
+    match purchase_ticket(purchase, &state).await {
+        Ok(res) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Ticket Purchased Successfully".to_string(),
+            json!({
+                "id": res.id,
+                "pass_code": res.pass_code,
+                "qr_code_id": res.qr_code_id,
+                "quantity": res.quantity,
+                "total_amount": res.total_amount,
+            }),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Could not purchase ticket: {}", e),
+            json!({}),
+        ))),
+    }
+}
+
+pub async fn purchase_history(
+    req: HttpRequest,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<CustomerResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Customer not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    let mut session_id = uuid::Uuid::nil();
+
+    if let Some(session_uuid) = &model.session {
+        if let Ok(s_uuid) = uuid::Uuid::parse_str(session_uuid) {
+            session_id = s_uuid
+        }
+    }
+
+    let customer = match get_customer_full(session_id, &state).await {
+        Ok(customer) => customer,
+        Err(e) => {
+            return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+                ResponseCode::Failed,
+                format!("Could not find customer: {}", e),
+                json!({}),
+            )))
+        }
+    };
+
+    let results = get_customer_purchases(customer.id, &state).await;
+
+    if let Err(e) = results {
+        return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Tickets not found: {}", e),
+            json!({}),
+        )));
+    };
+
+    let purchases = results.unwrap();
+
+    if purchases.is_empty() {
+        return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "No Tickets Found".to_string(),
+            json!([]),
+        )));
+    }
+
+    Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+        ResponseCode::Success,
+        "Tickets Fetched Successfully".to_string(),
+        json!(purchases),
+    )))
+}
+
+pub async fn review_tier(
+    req: HttpRequest,
+    params: ValidatedPath<PathParamsModel>,
+    payload: ValidatedJson<ReviewParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    if !has_permission(model.role_permissions, ModelAction::ApproveTickets, &state)
+        .await
+        .unwrap_or(false)
+    {
+        return Ok(HttpResponse::Forbidden().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            "Not Permitted To Review Tickets".to_string(),
+            json!({}),
+        )));
+    }
+
+    let data = payload.0;
+
+    let decision = data
+        .decision
+        .parse::<ApprovalStatus>()
+        .unwrap_or(ApprovalStatus::Rejected);
+
+    if decision == ApprovalStatus::Rejected && data.comment.is_none() {
+        return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            "A comment is required when rejecting".to_string(),
+            json!({}),
+        )));
+    }
+
+    let review = ReviewDto {
+        organization: model.organization_id,
+        reviewer: model.id,
+        decision,
+        comment: data.comment,
+    };
+
+    match review_ticket(params.0.id, review, &state).await {
+        Ok(_) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Ticket Reviewed Successfully".to_string(),
+            json!({ "approval_status": decision }),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Could not review ticket: {}", e),
+            json!({}),
+        ))),
+    }
+}
+
+pub async fn pending_tickets(
+    req: HttpRequest,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    let results = get_pending_tickets(model.organization_id, model.branch_id, &state).await;
+
+    if let Err(e) = results {
+        return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Tickets not found: {}", e),
+            json!({}),
+        )));
+    };
+
+    let tickets = results.unwrap();
+
+    if tickets.is_empty() {
+        return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "No Tickets Found".to_string(),
+            json!([]),
+        )));
+    }
+
+    Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+        ResponseCode::Success,
+        "Tickets Fetched Successfully".to_string(),
+        json!(tickets),
+    )))
+}
+
+pub async fn transfer_pass(
+    req: HttpRequest,
+    payload: ValidatedJson<TransferTicketParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<CustomerResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Customer not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    let mut session_id = uuid::Uuid::nil();
+
+    if let Some(session_uuid) = &model.session {
+        if let Ok(s_uuid) = uuid::Uuid::parse_str(session_uuid) {
+            session_id = s_uuid
+        }
+    }
+
+    let customer = match get_customer_full(session_id, &state).await {
+        Ok(customer) => customer,
+        Err(e) => {
+            return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+                ResponseCode::Failed,
+                format!("Could not find customer: {}", e),
+                json!({}),
+            )))
+        }
+    };
+
+    let data = payload.0;
+
+    let recipient = match get_active_customer_by_contact(data.phone, &state).await {
+        Ok(recipient) => recipient,
+        Err(e) => {
+            return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+                ResponseCode::Failed,
+                format!("Could not find recipient: {}", e),
+                json!({}),
+            )))
+        }
+    };
+
+    let purchase = match get_purchase_by_id(data.purchase, &state).await {
+        Ok(purchase) => purchase,
+        Err(e) => {
+            return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+                ResponseCode::Failed,
+                format!("Purchase not found: {}", e),
+                json!({}),
+            )))
+        }
+    };
+
+    let event = match get_ticket_event_by_id(purchase.ticket_event_id, &state).await {
+        Ok(event) => event,
+        Err(e) => {
+            return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+                ResponseCode::Failed,
+                format!("Event not found: {}", e),
+                json!({}),
+            )))
+        }
+    };
+
+    let rejections = match event_rejections(&recipient, &event, &state).await {
+        Ok(rejections) => rejections,
+        Err(e) => {
+            return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+                ResponseCode::Failed,
+                format!("Could not check eligibility: {}", e),
+                json!({}),
+            )))
+        }
+    };
+
+    if !rejections.is_empty() {
+        return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            "Recipient Not Eligible For Event".to_string(),
+            json!({ "reasons": rejections }),
+        )));
+    }
+
+    let transfer = TransferTicketDto {
+        purchase: purchase.id,
+        sender: customer.id,
+        recipient: recipient.id,
+    };
+
+    match transfer_ticket(transfer, &state).await {
+        Ok(_) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Ticket Transferred Successfully".to_string(),
+            json!({}),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Could not transfer ticket: {}", e),
+            json!({}),
+        ))),
+    }
+}
+
+pub async fn list_for_resale(
+    req: HttpRequest,
+    payload: ValidatedJson<AddListingParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<CustomerResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Customer not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    let mut session_id = uuid::Uuid::nil();
+
+    if let Some(session_uuid) = &model.session {
+        if let Ok(s_uuid) = uuid::Uuid::parse_str(session_uuid) {
+            session_id = s_uuid
+        }
+    }
+
+    let customer = match get_customer_full(session_id, &state).await {
+        Ok(customer) => customer,
+        Err(e) => {
+            return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+                ResponseCode::Failed,
+                format!("Could not find customer: {}", e),
+                json!({}),
+            )))
+        }
+    };
+
+    let data = payload.0;
+
+    let listing = AddListingDto {
+        purchase: data.purchase,
+        seller: customer.id,
+        asking_price: data.asking_price,
+    };
+
+    match save_listing(listing, &state).await {
+        Ok(res) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Ticket Listed Successfully".to_string(),
+            json!({ "id": res.id, "asking_price": res.asking_price }),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Could not list ticket: {}", e),
+            json!({}),
+        ))),
+    }
+}
+
+pub async fn withdraw_listing(
+    req: HttpRequest,
+    params: ValidatedPath<PathParamsModel>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<CustomerResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Customer not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    let mut session_id = uuid::Uuid::nil();
+
+    if let Some(session_uuid) = &model.session {
+        if let Ok(s_uuid) = uuid::Uuid::parse_str(session_uuid) {
+            session_id = s_uuid
+        }
+    }
+
+    let customer = match get_customer_full(session_id, &state).await {
+        Ok(customer) => customer,
+        Err(e) => {
+            return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+                ResponseCode::Failed,
+                format!("Could not find customer: {}", e),
+                json!({}),
+            )))
+        }
+    };
+
+    match cancel_listing(params.0.id, customer.id, &state).await {
+        Ok(_) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Listing Cancelled Successfully".to_string(),
+            json!({}),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Could not cancel listing: {}", e),
+            json!({}),
+        ))),
+    }
+}
+
+pub async fn resale_listings(
+    params: ValidatedPath<PathParamsModel>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let results = get_event_listings(params.0.id, &state).await;
+
+    if let Err(e) = results {
+        return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Listings not found: {}", e),
+            json!({}),
+        )));
+    };
+
+    let listings: Vec<_> = results
+        .unwrap()
+        .into_iter()
+        .map(|listing| {
+            json!({
+                "id": listing.id,
+                "ticket_event_id": listing.ticket_event_id,
+                "asking_price": listing.asking_price,
+                "created_at": listing.created_at,
+            })
+        })
+        .collect();
+
+    if listings.is_empty() {
+        return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "No Listings Found".to_string(),
+            json!([]),
+        )));
+    }
+
+    Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+        ResponseCode::Success,
+        "Listings Fetched Successfully".to_string(),
+        json!(listings),
+    )))
+}
+
+pub async fn buy_resale(
+    req: HttpRequest,
+    params: ValidatedPath<PathParamsModel>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<CustomerResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Customer not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    let mut session_id = uuid::Uuid::nil();
+
+    if let Some(session_uuid) = &model.session {
+        if let Ok(s_uuid) = uuid::Uuid::parse_str(session_uuid) {
+            session_id = s_uuid
+        }
+    }
+
+    let customer = match get_customer_full(session_id, &state).await {
+        Ok(customer) => customer,
+        Err(e) => {
+            return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+                ResponseCode::Failed,
+                format!("Could not find customer: {}", e),
+                json!({}),
+            )))
+        }
+    };
+
+    let listing = match get_listing_by_id(params.0.id, &state).await {
+        Ok(listing) => listing,
+        Err(e) => {
+            return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+                ResponseCode::Failed,
+                format!("Listing not found: {}", e),
+                json!({}),
+            )))
+        }
+    };
+
+    let event = match get_ticket_event_by_id(listing.ticket_event_id, &state).await {
+        Ok(event) => event,
+        Err(e) => {
+            return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+                ResponseCode::Failed,
+                format!("Event not found: {}", e),
+                json!({}),
+            )))
+        }
+    };
+
+    let rejections = match event_rejections(&customer, &event, &state).await {
+        Ok(rejections) => rejections,
+        Err(e) => {
+            return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+                ResponseCode::Failed,
+                format!("Could not check eligibility: {}", e),
+                json!({}),
+            )))
+        }
+    };
+
+    if !rejections.is_empty() {
+        return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            "Customer Not Eligible For Event".to_string(),
+            json!({ "reasons": rejections }),
+        )));
+    }
+
+    let fee_rate = state
+        .config
+        .get::<f64>("tickets.resale_fee_rate")
+        .ok()
+        .and_then(|rate| Decimal::try_from(rate).ok())
+        .unwrap_or_default();
+
+    let purchase = BuyListingDto {
+        listing: listing.id,
+        buyer: customer.id,
+        fee_rate,
+    };
+
+    match buy_listing(purchase, &state).await {
+        Ok(res) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Ticket Purchased Successfully".to_string(),
+            json!({
+                "id": res.id,
+                "pass_code": res.pass_code,
+                "qr_code_id": res.qr_code_id,
+                "quantity": res.quantity,
+                "total_amount": res.total_amount,
+            }),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Could not purchase ticket: {}", e),
+            json!({}),
+        ))),
+    }
+}
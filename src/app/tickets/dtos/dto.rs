@@ -0,0 +1,868 @@
+use actix_web::web;
+use sea_orm::{
+    prelude::Decimal, ActiveModelTrait, ActiveValue, ColumnTrait, Condition, ConnectionTrait,
+    DbErr, EntityTrait, JoinType, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect,
+    RelationTrait, Set, TransactionTrait,
+};
+
+use crate::{
+    app::{
+        approvals::{
+            dtos::dto::log_transition,
+            models::model::{ApprovalEntity, ApprovalStatus, ApprovalTransitionDto, ReviewDto},
+        },
+        branch::dtos::dto::get_branch_timezone,
+        promos::{
+            dtos::dto::{record_redemptions, redeemable_promos},
+            models::model::{PromoContext, PromoLine, PromoLookupDto, PromoTarget, RedemptionDto},
+            services::service::apply_promos,
+        },
+        ticket_events::models::model::TicketEventStatus,
+        tickets::models::model::{
+            AddListingDto, AddTicketDto, BuyListingDto, ListingStatus, PurchaseStatus,
+            PurchaseTicketDto, TransferTicketDto, UpdateTicketDto,
+        },
+        wallets::{
+            dtos::dto::{credit_customer_wallet, debit_customer_wallet},
+            models::model::{TransactionType, WalletCreditDto, WalletDebitDto},
+        },
+    },
+    libs::jwt::gen_string,
+    utils::shared::slugify,
+    AppState,
+};
+
+/// Price of one ticket after the tier's discount, if any.
+pub fn ticket_unit_price(ticket: &entity::tickets::Model) -> Decimal {
+    if ticket.is_discount_applied {
+        ticket.price - (ticket.price * ticket.discount_rate / Decimal::from(100))
+    } else {
+        ticket.price
+    }
+}
+
+pub async fn save_ticket(
+    data: AddTicketDto,
+    state: &web::Data<AppState>,
+) -> Result<entity::tickets::Model, DbErr> {
+    let ticket = entity::tickets::ActiveModel {
+        ticket_event_id: Set(data.ticket_event),
+        slug: Set(Some(format!(
+            "{}-{}-{}",
+            data.event_slug,
+            slugify(&data.ticket_type),
+            gen_string(6).to_lowercase()
+        ))),
+        sku: Set(Some(format!("TKT{}", gen_string(8).to_uppercase()))),
+        ticket_type: Set(Some(data.ticket_type)),
+        price: Set(data.price),
+        is_discount_applied: Set(data.discount_rate.is_some()),
+        discount_rate: Set(data.discount_rate.unwrap_or_default()),
+        quantity: Set(data.quantity),
+        end_date: Set(data.end_date.into()),
+        location: Set(data.location),
+        is_active: Set(true),
+        is_approved: Set(false),
+        approval_status: Set(ApprovalStatus::Pending.as_str().to_string()),
+        created_by: Set(Some(data.created_by)),
+        ..Default::default()
+    };
+
+    let txn = state.pg_db.get_ref().begin().await?;
+
+    let result = ActiveModelTrait::insert(ticket, &txn)
+        .await
+        .map_err(|err| {
+            eprintln!("Database insert error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    log_transition(
+        ApprovalTransitionDto {
+            organization: data.organization,
+            entity: ApprovalEntity::Ticket,
+            entity_id: result.id,
+            from: None,
+            to: ApprovalStatus::Pending,
+            actor: data.created_by,
+            comment: None,
+        },
+        &txn,
+    )
+    .await?;
+
+    txn.commit().await?;
+
+    Ok(result)
+}
+
+pub async fn update_ticket(
+    ticket: entity::tickets::Model,
+    data: UpdateTicketDto,
+    state: &web::Data<AppState>,
+) -> Result<(), DbErr> {
+    let status = ticket
+        .approval_status
+        .parse::<ApprovalStatus>()
+        .unwrap_or(ApprovalStatus::Pending);
+
+    // A rejected tier goes back for review once it is edited, and an approved one must be
+    // re-approved if what the customer pays changes.
+    let resubmit = match status {
+        ApprovalStatus::Rejected => true,
+        ApprovalStatus::Approved => data.price.is_some() || data.discount_rate.is_some(),
+        ApprovalStatus::Pending => false,
+    };
+
+    let id = ticket.id;
+
+    let mut model: entity::tickets::ActiveModel = ticket.into();
+
+    if resubmit {
+        model.approval_status = ActiveValue::Set(ApprovalStatus::Pending.as_str().to_string());
+        model.is_approved = ActiveValue::Set(false);
+        model.approved_by = ActiveValue::Set(None);
+        model.approved_at = ActiveValue::Set(None);
+    }
+
+    if let Some(price) = data.price {
+        model.price = ActiveValue::Set(price);
+    }
+
+    if let Some(rate) = data.discount_rate {
+        model.is_discount_applied = ActiveValue::Set(true);
+        model.discount_rate = ActiveValue::Set(rate);
+    }
+
+    if let Some(quantity) = data.quantity {
+        model.quantity = ActiveValue::Set(quantity);
+    }
+
+    if let Some(end_date) = data.end_date {
+        model.end_date = ActiveValue::Set(end_date.into());
+    }
+
+    if let Some(is_active) = data.is_active {
+        model.is_active = ActiveValue::Set(is_active);
+    }
+
+    model.updated_at = ActiveValue::Set(chrono::Utc::now().into());
+
+    let txn = state.pg_db.get_ref().begin().await?;
+
+    ActiveModelTrait::update(model, &txn)
+        .await
+        .map_err(|err| {
+            eprintln!("Database update error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    if resubmit {
+        log_transition(
+            ApprovalTransitionDto {
+                organization: data.organization,
+                entity: ApprovalEntity::Ticket,
+                entity_id: id,
+                from: Some(status),
+                to: ApprovalStatus::Pending,
+                actor: data.updated_by,
+                comment: Some("Resubmitted after changes".to_string()),
+            },
+            &txn,
+        )
+        .await?;
+    }
+
+    txn.commit().await?;
+
+    Ok(())
+}
+
+/// Approves or rejects a pending ticket tier. The reviewer cannot be the employee who created it.
+pub async fn review_ticket(
+    id: uuid::Uuid,
+    data: ReviewDto,
+    state: &web::Data<AppState>,
+) -> Result<(), DbErr> {
+    let txn = state.pg_db.get_ref().begin().await?;
+
+    let ticket = entity::tickets::Entity::find_by_id(id)
+        .join(JoinType::InnerJoin, entity::tickets::Relation::TicketEvents.def())
+        .filter(
+            Condition::all()
+                .add(entity::ticket_events::Column::OrganizationId.eq(data.organization))
+                .add(
+                    entity::tickets::Column::ApprovalStatus.eq(ApprovalStatus::Pending.as_str()),
+                ),
+        )
+        .lock_exclusive()
+        .one(&txn)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Pending ticket not found".into()))?;
+
+    if ticket.created_by == Some(data.reviewer) {
+        return Err(DbErr::Custom(
+            "Tickets cannot be reviewed by their creator".to_string(),
+        ));
+    }
+
+    let now = chrono::Utc::now();
+    let approved = data.decision == ApprovalStatus::Approved;
+
+    let mut model: entity::tickets::ActiveModel = ticket.into();
+
+    model.approval_status = ActiveValue::Set(data.decision.as_str().to_string());
+    model.approval_comment = ActiveValue::Set(data.comment.clone());
+    model.is_approved = ActiveValue::Set(approved);
+    model.approved_by = ActiveValue::Set(approved.then_some(data.reviewer));
+    model.approved_at = ActiveValue::Set(approved.then_some(now.into()));
+    model.updated_at = ActiveValue::Set(now.into());
+
+    ActiveModelTrait::update(model, &txn)
+        .await
+        .map_err(|err| {
+            eprintln!("Database update error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    log_transition(
+        ApprovalTransitionDto {
+            organization: data.organization,
+            entity: ApprovalEntity::Ticket,
+            entity_id: id,
+            from: Some(ApprovalStatus::Pending),
+            to: data.decision,
+            actor: data.reviewer,
+            comment: data.comment,
+        },
+        &txn,
+    )
+    .await?;
+
+    txn.commit().await?;
+
+    Ok(())
+}
+
+pub async fn get_pending_tickets(
+    organization: uuid::Uuid,
+    branch: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<Vec<entity::tickets::Model>, DbErr> {
+    let tickets = entity::tickets::Entity::find()
+        .join(JoinType::InnerJoin, entity::tickets::Relation::TicketEvents.def())
+        .filter(
+            Condition::all()
+                .add(entity::ticket_events::Column::OrganizationId.eq(organization))
+                .add(entity::ticket_events::Column::BranchId.eq(branch))
+                .add(entity::ticket_events::Column::IsCancelled.eq(false))
+                .add(
+                    entity::tickets::Column::ApprovalStatus.eq(ApprovalStatus::Pending.as_str()),
+                ),
+        )
+        .order_by_asc(entity::tickets::Column::CreatedAt)
+        .all(state.pg_db.get_ref())
+        .await
+        .map_err(|err| {
+            eprintln!("Database retrieval error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    Ok(tickets)
+}
+
+pub async fn get_ticket_by_id(
+    id: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<entity::tickets::Model, DbErr> {
+    let ticket = entity::tickets::Entity::find_by_id(id)
+        .one(state.pg_db.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Ticket not found".into()));
+
+    ticket
+}
+
+pub async fn get_event_tickets(
+    event: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<Vec<entity::tickets::Model>, DbErr> {
+    let tickets = entity::tickets::Entity::find()
+        .filter(entity::tickets::Column::TicketEventId.eq(event))
+        .order_by_asc(entity::tickets::Column::Price)
+        .all(state.pg_db.get_ref())
+        .await
+        .map_err(|err| {
+            eprintln!("Database retrieval error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    Ok(tickets)
+}
+
+pub async fn get_public_tickets(
+    event: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<Vec<entity::tickets::Model>, DbErr> {
+    let tickets = entity::tickets::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::tickets::Column::TicketEventId.eq(event))
+                .add(entity::tickets::Column::IsActive.eq(true))
+                .add(entity::tickets::Column::IsApproved.eq(true))
+                .add(
+                    entity::tickets::Column::ApprovalStatus.eq(ApprovalStatus::Approved.as_str()),
+                )
+                .add(entity::tickets::Column::EndDate.gt(chrono::Utc::now())),
+        )
+        .order_by_asc(entity::tickets::Column::Price)
+        .all(state.pg_db.get_ref())
+        .await
+        .map_err(|err| {
+            eprintln!("Database retrieval error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    Ok(tickets)
+}
+
+/// Sells tickets of one tier from the customer's wallet, less any promo, and issues a pass.
+/// The tier and event stay locked until commit so concurrent buyers can't oversell them.
+pub async fn purchase_ticket(
+    data: PurchaseTicketDto,
+    state: &web::Data<AppState>,
+) -> Result<entity::ticket_purchases::Model, DbErr> {
+    let txn = state.pg_db.get_ref().begin().await?;
+
+    let now = chrono::Utc::now();
+
+    let ticket = entity::tickets::Entity::find_by_id(data.ticket)
+        .filter(
+            Condition::all()
+                .add(entity::tickets::Column::IsActive.eq(true))
+                .add(
+                    entity::tickets::Column::ApprovalStatus.eq(ApprovalStatus::Approved.as_str()),
+                )
+                .add(entity::tickets::Column::EndDate.gt(now)),
+        )
+        .lock_exclusive()
+        .one(&txn)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Ticket not found".into()))?;
+
+    let event = entity::ticket_events::Entity::find_by_id(ticket.ticket_event_id)
+        .filter(
+            Condition::all()
+                .add(entity::ticket_events::Column::IsCancelled.eq(false))
+                .add(
+                    entity::ticket_events::Column::ApprovalStatus
+                        .eq(ApprovalStatus::Approved.as_str()),
+                )
+                .add(entity::ticket_events::Column::EventDate.gt(now)),
+        )
+        .lock_exclusive()
+        .one(&txn)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Event not found".into()))?;
+
+    if ticket.quantity < data.quantity {
+        return Err(DbErr::Custom("Not enough tickets left".to_string()));
+    }
+
+    let sold: Option<i64> = entity::ticket_purchases::Entity::find()
+        .select_only()
+        .column_as(entity::ticket_purchases::Column::Quantity.sum(), "sold")
+        .filter(
+            Condition::all()
+                .add(entity::ticket_purchases::Column::TicketEventId.eq(event.id))
+                .add(
+                    entity::ticket_purchases::Column::PurchaseStatus
+                        .eq(PurchaseStatus::Active.as_str()),
+                ),
+        )
+        .into_tuple()
+        .one(&txn)
+        .await?
+        .flatten();
+
+    let sold = sold.unwrap_or(0) + i64::from(data.quantity);
+
+    if let Some(capacity) = event.capacity {
+        if sold > i64::from(capacity) {
+            return Err(DbErr::Custom("Event is sold out".to_string()));
+        }
+    }
+
+    let unit_price = ticket_unit_price(&ticket);
+
+    let promos = redeemable_promos(
+        &PromoLookupDto {
+            organization: event.organization_id,
+            customer: data.customer,
+            target: PromoTarget::Tickets,
+            code: data.promo_code.clone(),
+        },
+        &txn,
+    )
+    .await?;
+
+    let context = PromoContext {
+        branch: event.branch_id,
+        schedule: None,
+        ticket: Some(ticket.id),
+        at: now,
+        timezone: get_branch_timezone(event.branch_id, &txn).await?,
+    };
+
+    let discount = apply_promos(
+        &promos,
+        &context,
+        &[PromoLine {
+            category: None,
+            amount: unit_price * Decimal::from(data.quantity),
+        }],
+        data.promo_code.as_deref(),
+    )
+    .map_err(DbErr::Custom)?;
+
+    let total_amount = unit_price * Decimal::from(data.quantity) - discount.discount_amount;
+    let ticket_type = ticket.ticket_type.clone().unwrap_or_default();
+
+    let payment = debit_customer_wallet(
+        WalletDebitDto {
+            customer: data.customer,
+            organization: event.organization_id,
+            branch: event.branch_id,
+            amount: total_amount,
+            description: format!(
+                "{} x {} ticket for {}",
+                data.quantity, ticket_type, event.title
+            ),
+        },
+        &txn,
+    )
+    .await?;
+
+    let remaining = ticket.quantity - data.quantity;
+
+    let mut model: entity::tickets::ActiveModel = ticket.into();
+
+    model.quantity = ActiveValue::Set(remaining);
+    model.updated_at = ActiveValue::Set(now.into());
+
+    ActiveModelTrait::update(model, &txn).await.map_err(|err| {
+        eprintln!("Database update error: {}", err);
+        DbErr::Custom(err.to_string())
+    })?;
+
+    if event
+        .capacity
+        .is_some_and(|capacity| sold >= i64::from(capacity))
+    {
+        let mut model: entity::ticket_events::ActiveModel = event.clone().into();
+
+        model.event_status = ActiveValue::Set(TicketEventStatus::Full.as_str().to_string());
+        model.updated_at = ActiveValue::Set(now.into());
+
+        ActiveModelTrait::update(model, &txn).await.map_err(|err| {
+            eprintln!("Database update error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+    }
+
+    let purchase = entity::ticket_purchases::ActiveModel {
+        ticket_id: Set(data.ticket),
+        ticket_event_id: Set(event.id),
+        customer_id: Set(data.customer),
+        transaction_id: Set(Some(payment.id)),
+        quantity: Set(data.quantity),
+        unit_price: Set(unit_price),
+        total_amount: Set(total_amount),
+        discount_amount: Set(discount.discount_amount),
+        pass_code: Set(gen_string(10).to_uppercase()),
+        qr_code_id: Set(uuid::Uuid::new_v4()),
+        purchase_status: Set(PurchaseStatus::Active.as_str().to_string()),
+        ..Default::default()
+    };
+
+    let result = ActiveModelTrait::insert(purchase, &txn)
+        .await
+        .map_err(|err| {
+            eprintln!("Database insert error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    record_redemptions(
+        &discount,
+        RedemptionDto {
+            customer: data.customer,
+            order: None,
+            booking: None,
+            ticket_purchase: Some(result.id),
+        },
+        &txn,
+    )
+    .await?;
+
+    txn.commit().await?;
+
+    Ok(result)
+}
+
+pub async fn get_customer_purchases(
+    customer: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<Vec<entity::ticket_purchases::Model>, DbErr> {
+    let purchases = entity::ticket_purchases::Entity::find()
+        .filter(entity::ticket_purchases::Column::CustomerId.eq(customer))
+        .order_by_desc(entity::ticket_purchases::Column::CreatedAt)
+        .all(state.pg_db.get_ref())
+        .await
+        .map_err(|err| {
+            eprintln!("Database retrieval error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    Ok(purchases)
+}
+
+pub async fn get_purchase_by_id(
+    id: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<entity::ticket_purchases::Model, DbErr> {
+    let purchase = entity::ticket_purchases::Entity::find_by_id(id)
+        .one(state.pg_db.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Purchase not found".into()))?;
+
+    Ok(purchase)
+}
+
+/// Locks an active pass owned by `owner` together with its event, as long as the event has not
+/// been cancelled or already taken place.
+async fn lock_transferable_purchase<C: ConnectionTrait>(
+    purchase: uuid::Uuid,
+    owner: uuid::Uuid,
+    conn: &C,
+) -> Result<
+    (
+        entity::ticket_purchases::Model,
+        entity::ticket_events::Model,
+    ),
+    DbErr,
+> {
+    let purchase = entity::ticket_purchases::Entity::find_by_id(purchase)
+        .filter(
+            Condition::all()
+                .add(entity::ticket_purchases::Column::CustomerId.eq(owner))
+                .add(
+                    entity::ticket_purchases::Column::PurchaseStatus
+                        .eq(PurchaseStatus::Active.as_str()),
+                ),
+        )
+        .lock_exclusive()
+        .one(conn)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Purchase not found".into()))?;
+
+    let event = entity::ticket_events::Entity::find_by_id(purchase.ticket_event_id)
+        .filter(
+            Condition::all()
+                .add(entity::ticket_events::Column::IsCancelled.eq(false))
+                .add(entity::ticket_events::Column::EventDate.gt(chrono::Utc::now())),
+        )
+        .one(conn)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Event not found".into()))?;
+
+    Ok((purchase, event))
+}
+
+/// Moves a pass to a new holder. The old record is kept as `TRANSFERRED` so its pass code and QR
+/// code stop working, and the new holder gets freshly generated ones.
+async fn reissue_pass<C: ConnectionTrait>(
+    purchase: entity::ticket_purchases::Model,
+    owner: uuid::Uuid,
+    transaction: Option<uuid::Uuid>,
+    total_amount: Decimal,
+    conn: &C,
+) -> Result<entity::ticket_purchases::Model, DbErr> {
+    let now = chrono::Utc::now();
+
+    let pass = entity::ticket_purchases::ActiveModel {
+        ticket_id: Set(purchase.ticket_id),
+        ticket_event_id: Set(purchase.ticket_event_id),
+        customer_id: Set(owner),
+        transaction_id: Set(transaction),
+        quantity: Set(purchase.quantity),
+        unit_price: Set(total_amount / Decimal::from(purchase.quantity)),
+        total_amount: Set(total_amount),
+        pass_code: Set(gen_string(10).to_uppercase()),
+        qr_code_id: Set(uuid::Uuid::new_v4()),
+        purchase_status: Set(PurchaseStatus::Active.as_str().to_string()),
+        transferred_from: Set(Some(purchase.id)),
+        transferred_at: Set(Some(now.into())),
+        ..Default::default()
+    };
+
+    let mut model: entity::ticket_purchases::ActiveModel = purchase.into();
+
+    model.purchase_status = ActiveValue::Set(PurchaseStatus::Transferred.as_str().to_string());
+    model.updated_at = ActiveValue::Set(now.into());
+
+    ActiveModelTrait::update(model, conn).await.map_err(|err| {
+        eprintln!("Database update error: {}", err);
+        DbErr::Custom(err.to_string())
+    })?;
+
+    ActiveModelTrait::insert(pass, conn).await.map_err(|err| {
+        eprintln!("Database insert error: {}", err);
+        DbErr::Custom(err.to_string())
+    })
+}
+
+async fn has_active_listing<C: ConnectionTrait>(
+    purchase: uuid::Uuid,
+    conn: &C,
+) -> Result<bool, DbErr> {
+    let listings = entity::ticket_listings::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::ticket_listings::Column::PurchaseId.eq(purchase))
+                .add(
+                    entity::ticket_listings::Column::ListingStatus
+                        .eq(ListingStatus::Active.as_str()),
+                ),
+        )
+        .count(conn)
+        .await?;
+
+    Ok(listings > 0)
+}
+
+/// Hands a pass to another customer free of charge. The new pass carries no payment, if the
+/// event is cancelled the refund goes to whoever paid for it.
+pub async fn transfer_ticket(
+    data: TransferTicketDto,
+    state: &web::Data<AppState>,
+) -> Result<entity::ticket_purchases::Model, DbErr> {
+    if data.sender == data.recipient {
+        return Err(DbErr::Custom(
+            "Tickets cannot be transferred to yourself".to_string(),
+        ));
+    }
+
+    let txn = state.pg_db.get_ref().begin().await?;
+
+    let (purchase, _) = lock_transferable_purchase(data.purchase, data.sender, &txn).await?;
+
+    if has_active_listing(purchase.id, &txn).await? {
+        return Err(DbErr::Custom(
+            "Ticket is listed for resale, cancel the listing first".to_string(),
+        ));
+    }
+
+    // The recipient paid nothing, a cancelled event refunds the pass's buyer instead
+    let result = reissue_pass(purchase, data.recipient, None, Decimal::ZERO, &txn).await?;
+
+    txn.commit().await?;
+
+    Ok(result)
+}
+
+/// Puts a pass up for resale. The asking price can never exceed the tier's face value.
+pub async fn save_listing(
+    data: AddListingDto,
+    state: &web::Data<AppState>,
+) -> Result<entity::ticket_listings::Model, DbErr> {
+    let txn = state.pg_db.get_ref().begin().await?;
+
+    let (purchase, event) = lock_transferable_purchase(data.purchase, data.seller, &txn).await?;
+
+    let ticket = entity::tickets::Entity::find_by_id(purchase.ticket_id)
+        .one(&txn)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Ticket not found".into()))?;
+
+    let face_value = ticket_unit_price(&ticket) * Decimal::from(purchase.quantity);
+
+    if data.asking_price > face_value {
+        return Err(DbErr::Custom(format!(
+            "Asking price cannot exceed the face value of {}",
+            face_value
+        )));
+    }
+
+    if has_active_listing(purchase.id, &txn).await? {
+        return Err(DbErr::Custom("Ticket is already listed".to_string()));
+    }
+
+    let listing = entity::ticket_listings::ActiveModel {
+        purchase_id: Set(purchase.id),
+        ticket_event_id: Set(event.id),
+        organization_id: Set(event.organization_id),
+        seller_id: Set(data.seller),
+        asking_price: Set(data.asking_price),
+        listing_status: Set(ListingStatus::Active.as_str().to_string()),
+        ..Default::default()
+    };
+
+    let result = ActiveModelTrait::insert(listing, &txn)
+        .await
+        .map_err(|err| {
+            eprintln!("Database insert error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    txn.commit().await?;
+
+    Ok(result)
+}
+
+pub async fn cancel_listing(
+    id: uuid::Uuid,
+    seller: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<(), DbErr> {
+    let listing = entity::ticket_listings::Entity::find_by_id(id)
+        .filter(
+            Condition::all()
+                .add(entity::ticket_listings::Column::SellerId.eq(seller))
+                .add(
+                    entity::ticket_listings::Column::ListingStatus
+                        .eq(ListingStatus::Active.as_str()),
+                ),
+        )
+        .one(state.pg_db.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Listing not found".into()))?;
+
+    let mut model: entity::ticket_listings::ActiveModel = listing.into();
+
+    model.listing_status = ActiveValue::Set(ListingStatus::Cancelled.as_str().to_string());
+    model.updated_at = ActiveValue::Set(chrono::Utc::now().into());
+
+    ActiveModelTrait::update(model, state.pg_db.get_ref())
+        .await
+        .map_err(|err| {
+            eprintln!("Database update error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    Ok(())
+}
+
+pub async fn get_listing_by_id(
+    id: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<entity::ticket_listings::Model, DbErr> {
+    let listing = entity::ticket_listings::Entity::find_by_id(id)
+        .filter(entity::ticket_listings::Column::ListingStatus.eq(ListingStatus::Active.as_str()))
+        .one(state.pg_db.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Listing not found".into()))?;
+
+    Ok(listing)
+}
+
+pub async fn get_event_listings(
+    event: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<Vec<entity::ticket_listings::Model>, DbErr> {
+    let listings = entity::ticket_listings::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::ticket_listings::Column::TicketEventId.eq(event))
+                .add(
+                    entity::ticket_listings::Column::ListingStatus
+                        .eq(ListingStatus::Active.as_str()),
+                ),
+        )
+        .order_by_asc(entity::ticket_listings::Column::AskingPrice)
+        .all(state.pg_db.get_ref())
+        .await
+        .map_err(|err| {
+            eprintln!("Database retrieval error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    Ok(listings)
+}
+
+/// Settles a resale: the buyer pays the asking price from their wallet, the seller is paid the
+/// asking price less the organization's fee, and the pass is reissued to the buyer.
+pub async fn buy_listing(
+    data: BuyListingDto,
+    state: &web::Data<AppState>,
+) -> Result<entity::ticket_purchases::Model, DbErr> {
+    let txn = state.pg_db.get_ref().begin().await?;
+
+    let listing = entity::ticket_listings::Entity::find_by_id(data.listing)
+        .filter(entity::ticket_listings::Column::ListingStatus.eq(ListingStatus::Active.as_str()))
+        .lock_exclusive()
+        .one(&txn)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Listing not found".into()))?;
+
+    if listing.seller_id == data.buyer {
+        return Err(DbErr::Custom("You cannot buy your own listing".to_string()));
+    }
+
+    let (purchase, event) =
+        lock_transferable_purchase(listing.purchase_id, listing.seller_id, &txn).await?;
+
+    let fee = (listing.asking_price * data.fee_rate / Decimal::from(100)).round_dp(2);
+
+    let payment = debit_customer_wallet(
+        WalletDebitDto {
+            customer: data.buyer,
+            organization: event.organization_id,
+            branch: event.branch_id,
+            amount: listing.asking_price,
+            description: format!("Resale ticket for {}", event.title),
+        },
+        &txn,
+    )
+    .await?;
+
+    credit_customer_wallet(
+        WalletCreditDto {
+            customer: listing.seller_id,
+            organization: event.organization_id,
+            branch: event.branch_id,
+            amount: listing.asking_price - fee,
+            description: format!("Resale of ticket for {}", event.title),
+            transaction_type: TransactionType::Payout,
+        },
+        &txn,
+    )
+    .await?;
+
+    let result = reissue_pass(
+        purchase,
+        data.buyer,
+        Some(payment.id),
+        listing.asking_price,
+        &txn,
+    )
+    .await?;
+
+    let now = chrono::Utc::now();
+
+    let mut model: entity::ticket_listings::ActiveModel = listing.into();
+
+    model.listing_status = ActiveValue::Set(ListingStatus::Sold.as_str().to_string());
+    model.buyer_id = ActiveValue::Set(Some(data.buyer));
+    model.fee_amount = ActiveValue::Set(fee);
+    model.sold_at = ActiveValue::Set(Some(now.into()));
+    model.updated_at = ActiveValue::Set(now.into());
+
+    ActiveModelTrait::update(model, &txn).await.map_err(|err| {
+        eprintln!("Database update error: {}", err);
+        DbErr::Custom(err.to_string())
+    })?;
+
+    txn.commit().await?;
+
+    Ok(result)
+}
@@ -0,0 +1,152 @@
+use chrono::{DateTime, Utc};
+use sea_orm::prelude::Decimal;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::libs::validator::{validate_contact, validate_percent_range, validate_val_range};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum PurchaseStatus {
+    Active,
+    Refunded,
+    Transferred,
+}
+
+impl PurchaseStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PurchaseStatus::Active => "ACTIVE",
+            PurchaseStatus::Refunded => "REFUNDED",
+            PurchaseStatus::Transferred => "TRANSFERRED",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum ListingStatus {
+    Active,
+    Sold,
+    Cancelled,
+}
+
+impl ListingStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ListingStatus::Active => "ACTIVE",
+            ListingStatus::Sold => "SOLD",
+            ListingStatus::Cancelled => "CANCELLED",
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AddTicketDto {
+    pub organization: uuid::Uuid,
+    pub created_by: uuid::Uuid,
+    pub ticket_event: uuid::Uuid,
+    pub event_slug: String,
+    pub ticket_type: String,
+    pub price: Decimal,
+    pub discount_rate: Option<Decimal>,
+    pub quantity: i32,
+    pub end_date: DateTime<Utc>,
+    pub location: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UpdateTicketDto {
+    pub organization: uuid::Uuid,
+    pub updated_by: uuid::Uuid,
+    pub price: Option<Decimal>,
+    pub discount_rate: Option<Decimal>,
+    pub quantity: Option<i32>,
+    pub end_date: Option<DateTime<Utc>>,
+    pub is_active: Option<bool>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PurchaseTicketDto {
+    pub ticket: uuid::Uuid,
+    pub customer: uuid::Uuid,
+    pub quantity: i32,
+    pub promo_code: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TransferTicketDto {
+    pub purchase: uuid::Uuid,
+    pub sender: uuid::Uuid,
+    pub recipient: uuid::Uuid,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AddListingDto {
+    pub purchase: uuid::Uuid,
+    pub seller: uuid::Uuid,
+    pub asking_price: Decimal,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BuyListingDto {
+    pub listing: uuid::Uuid,
+    pub buyer: uuid::Uuid,
+    pub fee_rate: Decimal,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct AddTicketParams {
+    pub ticket_event: uuid::Uuid,
+    #[validate(length(min = 2, max = 30, message = "Ticket type is invalid"))]
+    pub ticket_type: String,
+    #[validate(custom(function = "validate_val_range"))]
+    pub price: Decimal,
+    #[validate(custom(function = "validate_percent_range"))]
+    pub discount_rate: Option<Decimal>,
+    #[validate(range(min = 1, max = 100000, message = "Quantity is invalid"))]
+    pub quantity: i32,
+    pub end_date: DateTime<Utc>,
+    #[validate(length(min = 3, max = 100, message = "Location is invalid"))]
+    pub location: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct UpdateTicketParams {
+    #[validate(custom(function = "validate_val_range"))]
+    pub price: Option<Decimal>,
+    #[validate(custom(function = "validate_percent_range"))]
+    pub discount_rate: Option<Decimal>,
+    #[validate(range(min = 0, max = 100000, message = "Quantity is invalid"))]
+    pub quantity: Option<i32>,
+    pub end_date: Option<DateTime<Utc>>,
+    pub is_active: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct PurchaseTicketParams {
+    pub ticket: uuid::Uuid,
+    #[validate(range(min = 1, max = 10, message = "Quantity is invalid"))]
+    pub quantity: i32,
+    #[validate(length(min = 3, max = 30, message = "Promo code is invalid"))]
+    pub promo_code: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct TransferTicketParams {
+    pub purchase: uuid::Uuid,
+    #[validate(custom(function = "validate_contact"))]
+    pub phone: String,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct AddListingParams {
+    pub purchase: uuid::Uuid,
+    #[validate(custom(function = "validate_val_range"))]
+    pub asking_price: Decimal,
+}
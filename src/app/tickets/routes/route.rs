@@ -0,0 +1,87 @@
+use actix_web::web;
+
+use crate::{
+    app::tickets::controllers::controller::{
+        buy_resale, buy_ticket, create_ticket, list_for_resale, pending_tickets, purchase_history,
+        resale_listings, review_tier, transfer_pass, upd_ticket, withdraw_listing,
+    },
+    middlewares::{auth::JwtAuthMiddleware, checker::CheckUserMiddleware},
+    AppState,
+};
+
+pub fn routes(cfg: &mut web::ServiceConfig, state: web::Data<AppState>) {
+    cfg.service(
+        web::scope("/api/v1/tickets")
+            .route(
+                "/add",
+                web::post()
+                    .to(create_ticket)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/update/{id}",
+                web::put()
+                    .to(upd_ticket)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/pending",
+                web::get()
+                    .to(pending_tickets)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/review/{id}",
+                web::put()
+                    .to(review_tier)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/purchase",
+                web::post()
+                    .to(buy_ticket)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Customer"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/history",
+                web::get()
+                    .to(purchase_history)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Customer"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/transfer",
+                web::post()
+                    .to(transfer_pass)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Customer"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/resale/add",
+                web::post()
+                    .to(list_for_resale)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Customer"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/resale/cancel/{id}",
+                web::put()
+                    .to(withdraw_listing)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Customer"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/resale/buy/{id}",
+                web::post()
+                    .to(buy_resale)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Customer"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route("/resale/event/{id}", web::get().to(resale_listings)),
+    );
+}
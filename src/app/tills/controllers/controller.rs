@@ -0,0 +1,220 @@
+use std::sync::Arc;
+
+use actix_web::{web, HttpMessage, HttpRequest, HttpResponse};
+use serde_json::json;
+
+use crate::{
+    app::{
+        employees::models::model::EmployeeResponse,
+        tills::{
+            dtos::dto::{
+                close_till, get_branch_tills, get_open_till, get_till_session, get_z_report,
+                open_till,
+            },
+            models::model::{CloseTillDto, CloseTillParams, OpenTillDto, OpenTillParams},
+        },
+    },
+    libs::error::Error,
+    utils::{
+        json_validator::{ValidatedJson, ValidatedPath},
+        models::{HttpClientResponse, PathParamsModel, ResponseCode},
+    },
+    AppState,
+};
+
+pub async fn open_till_session(
+    req: HttpRequest,
+    payload: ValidatedJson<OpenTillParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    if let Ok(Some(_)) = get_open_till(model.id, &state).await {
+        return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            "Till already open, close it before opening another".to_string(),
+            json!({}),
+        )));
+    }
+
+    let till = OpenTillDto {
+        organization: model.organization_id,
+        branch: model.branch_id,
+        employee: model.id,
+        opening_float: payload.0.opening_float,
+    };
+
+    match open_till(till, &state).await {
+        Ok(res) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Till Opened Successfully".to_string(),
+            json!(res),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Could not open till: {}", e),
+            json!({}),
+        ))),
+    }
+}
+
+pub async fn current_till(
+    req: HttpRequest,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    let till = match get_open_till(model.id, &state).await {
+        Ok(Some(till)) => till,
+        Ok(None) => {
+            return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+                ResponseCode::Failed,
+                "No open till".to_string(),
+                json!({}),
+            )))
+        }
+        Err(e) => {
+            return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+                ResponseCode::Failed,
+                format!("Could not find till: {}", e),
+                json!({}),
+            )))
+        }
+    };
+
+    match get_z_report(till, &state).await {
+        Ok(report) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Till Fetched Successfully".to_string(),
+            json!(report),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Could not total till: {}", e),
+            json!({}),
+        ))),
+    }
+}
+
+pub async fn close_till_session(
+    req: HttpRequest,
+    payload: ValidatedJson<CloseTillParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    let data = payload.0;
+
+    let close = CloseTillDto {
+        employee: model.id,
+        counted_cash: data.counted_cash,
+        counted_card: data.counted_card,
+        notes: data.notes,
+    };
+
+    match close_till(close, &state).await {
+        Ok(report) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Till Closed Successfully".to_string(),
+            json!(report),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Could not close till: {}", e),
+            json!({}),
+        ))),
+    }
+}
+
+pub async fn branch_tills(
+    req: HttpRequest,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    match get_branch_tills(model.organization_id, model.branch_id, &state).await {
+        Ok(tills) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Tills Fetched Successfully".to_string(),
+            json!(tills),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Tills not found: {}", e),
+            json!({}),
+        ))),
+    }
+}
+
+pub async fn till_report(
+    req: HttpRequest,
+    params: ValidatedPath<PathParamsModel>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    let till =
+        match get_till_session(params.0.id, model.organization_id, model.branch_id, &state).await {
+            Ok(till) => till,
+            Err(e) => {
+                return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+                    ResponseCode::Failed,
+                    format!("Till not found: {}", e),
+                    json!({}),
+                )))
+            }
+        };
+
+    match get_z_report(till, &state).await {
+        Ok(report) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Z-Report Generated Successfully".to_string(),
+            json!(report),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Could not generate Z-report: {}", e),
+            json!({}),
+        ))),
+    }
+}
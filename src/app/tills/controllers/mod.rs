@@ -0,0 +1 @@
+pub mod controller;
\ No newline at end of file
@@ -0,0 +1,196 @@
+use std::collections::HashMap;
+
+use actix_web::web;
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, Condition, ConnectionTrait, DbErr, EntityTrait,
+    QueryFilter, QueryOrder, QuerySelect, Set, TransactionTrait,
+};
+
+use crate::{
+    app::{
+        tills::{
+            models::model::{CloseTillDto, OpenTillDto, TillStatus, ZReport},
+            services::service::build_z_report,
+        },
+        wallets::models::model::PaymentChannel,
+    },
+    AppState,
+};
+
+pub async fn open_till(
+    data: OpenTillDto,
+    state: &web::Data<AppState>,
+) -> Result<entity::till_sessions::Model, DbErr> {
+    let now = chrono::Utc::now();
+
+    let till = entity::till_sessions::ActiveModel {
+        organization_id: Set(data.organization),
+        branch_id: Set(data.branch),
+        employee_id: Set(data.employee),
+        start_time: Set(Some(now.time())),
+        session_status: Set(TillStatus::Open.as_str().to_string()),
+        opening_float: Set(data.opening_float),
+        opened_at: Set(now.into()),
+        ..Default::default()
+    };
+
+    ActiveModelTrait::insert(till, state.pg_db.get_ref())
+        .await
+        .map_err(|err| {
+            eprintln!("Database insert error: {}", err);
+            DbErr::Custom(err.to_string())
+        })
+}
+
+pub async fn get_open_till(
+    employee: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<Option<entity::till_sessions::Model>, DbErr> {
+    entity::till_sessions::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::till_sessions::Column::EmployeeId.eq(employee))
+                .add(entity::till_sessions::Column::SessionStatus.eq(TillStatus::Open.as_str())),
+        )
+        .one(state.pg_db.get_ref())
+        .await
+}
+
+pub async fn get_till_session(
+    id: uuid::Uuid,
+    organization: uuid::Uuid,
+    branch: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<entity::till_sessions::Model, DbErr> {
+    entity::till_sessions::Entity::find_by_id(id)
+        .filter(
+            Condition::all()
+                .add(entity::till_sessions::Column::OrganizationId.eq(organization))
+                .add(entity::till_sessions::Column::BranchId.eq(branch)),
+        )
+        .one(state.pg_db.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Till session not found".into()))
+}
+
+pub async fn get_branch_tills(
+    organization: uuid::Uuid,
+    branch: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<Vec<entity::till_sessions::Model>, DbErr> {
+    entity::till_sessions::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::till_sessions::Column::OrganizationId.eq(organization))
+                .add(entity::till_sessions::Column::BranchId.eq(branch)),
+        )
+        .order_by_desc(entity::till_sessions::Column::OpenedAt)
+        .all(state.pg_db.get_ref())
+        .await
+        .map_err(|err| {
+            eprintln!("Database retrieval error: {}", err);
+            DbErr::Custom(err.to_string())
+        })
+}
+
+async fn session_orders<C: ConnectionTrait>(
+    session: uuid::Uuid,
+    conn: &C,
+) -> Result<Vec<(entity::orders::Model, Option<entity::transactions::Model>)>, DbErr> {
+    entity::orders::Entity::find()
+        .filter(entity::orders::Column::TillSessionId.eq(session))
+        .find_also_related(entity::transactions::Entity)
+        .order_by_asc(entity::orders::Column::CreatedAt)
+        .all(conn)
+        .await
+}
+
+/// Z-report for a till session. Closed sessions report the amounts counted at close, open
+/// ones show the running totals so far.
+pub async fn get_z_report(
+    session: entity::till_sessions::Model,
+    state: &web::Data<AppState>,
+) -> Result<ZReport, DbErr> {
+    let orders = session_orders(session.id, state.pg_db.get_ref()).await?;
+
+    let counted: HashMap<PaymentChannel, _> = entity::till_reconciliations::Entity::find()
+        .filter(entity::till_reconciliations::Column::TillSessionId.eq(session.id))
+        .all(state.pg_db.get_ref())
+        .await?
+        .into_iter()
+        .filter_map(|row| {
+            let channel = row.payment_channel.parse::<PaymentChannel>().ok()?;
+            Some((channel, row.counted_amount?))
+        })
+        .collect();
+
+    Ok(build_z_report(session, orders, &counted))
+}
+
+/// Closes the employee's open till against the counted cash, and card total when given, and
+/// stores the expected-vs-counted figures for every payment channel.
+pub async fn close_till(data: CloseTillDto, state: &web::Data<AppState>) -> Result<ZReport, DbErr> {
+    let txn = state.pg_db.get_ref().begin().await?;
+
+    let session = entity::till_sessions::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::till_sessions::Column::EmployeeId.eq(data.employee))
+                .add(entity::till_sessions::Column::SessionStatus.eq(TillStatus::Open.as_str())),
+        )
+        .lock_exclusive()
+        .one(&txn)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("No open till".into()))?;
+
+    let orders = session_orders(session.id, &txn).await?;
+
+    let mut counted = HashMap::from([(PaymentChannel::Cash, data.counted_cash)]);
+
+    if let Some(card) = data.counted_card {
+        counted.insert(PaymentChannel::Card, card);
+    }
+
+    let mut report = build_z_report(session.clone(), orders, &counted);
+
+    let rows = report
+        .channels
+        .iter()
+        .map(|totals| entity::till_reconciliations::ActiveModel {
+            till_session_id: Set(session.id),
+            payment_channel: Set(totals.payment_channel.as_str().to_string()),
+            expected_amount: Set(totals.expected),
+            counted_amount: Set(totals.counted),
+            variance: Set(totals.variance),
+            ..Default::default()
+        });
+
+    entity::till_reconciliations::Entity::insert_many(rows)
+        .exec(&txn)
+        .await
+        .map_err(|err| {
+            eprintln!("Database insert error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    let now = chrono::Utc::now();
+
+    let mut model: entity::till_sessions::ActiveModel = session.into();
+
+    model.session_status = ActiveValue::Set(TillStatus::Closed.as_str().to_string());
+    model.end_time = ActiveValue::Set(Some(now.time()));
+    model.closed_at = ActiveValue::Set(Some(now.into()));
+    model.closed_by = ActiveValue::Set(Some(data.employee));
+    model.counted_cash = ActiveValue::Set(Some(data.counted_cash));
+    model.notes = ActiveValue::Set(data.notes);
+    model.updated_at = ActiveValue::Set(now.into());
+
+    report.session = ActiveModelTrait::update(model, &txn).await.map_err(|err| {
+        eprintln!("Database update error: {}", err);
+        DbErr::Custom(err.to_string())
+    })?;
+
+    txn.commit().await?;
+
+    Ok(report)
+}
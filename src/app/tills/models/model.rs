@@ -0,0 +1,83 @@
+use sea_orm::prelude::Decimal;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::{
+    app::wallets::models::model::PaymentChannel,
+    libs::validator::{validate_cash_amount, validate_val_range},
+};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum TillStatus {
+    Open,
+    Closed,
+}
+
+impl TillStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TillStatus::Open => "OPEN",
+            TillStatus::Closed => "CLOSED",
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct OpenTillDto {
+    pub organization: uuid::Uuid,
+    pub branch: uuid::Uuid,
+    pub employee: uuid::Uuid,
+    pub opening_float: Decimal,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CloseTillDto {
+    pub employee: uuid::Uuid,
+    pub counted_cash: Decimal,
+    pub counted_card: Option<Decimal>,
+    pub notes: Option<String>,
+}
+
+/// Money a till should hold for one payment channel against what was counted at close.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ChannelTotals {
+    pub payment_channel: PaymentChannel,
+    pub sales: Decimal,
+    pub refunds: Decimal,
+    pub expected: Decimal,
+    pub counted: Option<Decimal>,
+    pub variance: Option<Decimal>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ZReport {
+    pub session: entity::till_sessions::Model,
+    pub order_count: u64,
+    pub gross_sales: Decimal,
+    pub tips: Decimal,
+    pub net_sales: Decimal,
+    pub void_count: u64,
+    pub void_amount: Decimal,
+    pub discounted_orders: u64,
+    pub discounts: Decimal,
+    pub channels: Vec<ChannelTotals>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct OpenTillParams {
+    #[validate(custom(function = "validate_val_range"))]
+    pub opening_float: Decimal,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct CloseTillParams {
+    #[validate(custom(function = "validate_cash_amount"))]
+    pub counted_cash: Decimal,
+    #[validate(custom(function = "validate_cash_amount"))]
+    pub counted_card: Option<Decimal>,
+    #[validate(length(min = 3, max = 500, message = "Notes are invalid"))]
+    pub notes: Option<String>,
+}
@@ -0,0 +1,205 @@
+use std::collections::HashMap;
+
+use sea_orm::prelude::Decimal;
+
+use crate::app::{
+    orders::models::model::OrderStatus,
+    tills::models::model::{ChannelTotals, ZReport},
+    wallets::models::model::PaymentChannel,
+};
+
+/// Totals up a till session from its orders and the payments behind them. Cash is expected to
+/// be the opening float plus cash taken, less cash handed back on refunds; every channel with
+/// a count in `counted` gets its variance, positive when the till is over.
+pub fn build_z_report(
+    session: entity::till_sessions::Model,
+    orders: Vec<(entity::orders::Model, Option<entity::transactions::Model>)>,
+    counted: &HashMap<PaymentChannel, Decimal>,
+) -> ZReport {
+    let mut channels: Vec<ChannelTotals> = [
+        PaymentChannel::Cash,
+        PaymentChannel::Card,
+        PaymentChannel::Wallet,
+    ]
+    .into_iter()
+    .map(|payment_channel| ChannelTotals {
+        payment_channel,
+        sales: Decimal::ZERO,
+        refunds: Decimal::ZERO,
+        expected: Decimal::ZERO,
+        counted: None,
+        variance: None,
+    })
+    .collect();
+
+    let mut report = ZReport {
+        order_count: orders.len() as u64,
+        gross_sales: Decimal::ZERO,
+        tips: Decimal::ZERO,
+        net_sales: Decimal::ZERO,
+        void_count: 0,
+        void_amount: Decimal::ZERO,
+        discounted_orders: 0,
+        discounts: Decimal::ZERO,
+        channels: Vec::new(),
+        session,
+    };
+
+    for (order, payment) in orders {
+        let channel = payment
+            .and_then(|payment| payment.debit_channel)
+            .and_then(|channel| channel.parse::<PaymentChannel>().ok())
+            .unwrap_or(PaymentChannel::Wallet);
+
+        if let Some(totals) = channels.iter_mut().find(|c| c.payment_channel == channel) {
+            totals.sales += order.total_amount;
+
+            if order.refund_transaction_id.is_some() {
+                totals.refunds += order.total_amount;
+            }
+        }
+
+        let is_void = order
+            .order_status
+            .parse::<OrderStatus>()
+            .is_ok_and(|status| status.is_refundable());
+
+        if is_void {
+            report.void_count += 1;
+            report.void_amount += order.total_amount;
+            continue;
+        }
+
+        if order.is_discount_applied {
+            report.discounted_orders += 1;
+            report.discounts += order.discount_amount;
+        }
+
+        report.gross_sales += order.total_amount;
+        report.tips += order.tip_amount.unwrap_or_default();
+    }
+
+    report.net_sales = report.gross_sales - report.tips;
+
+    for totals in channels.iter_mut() {
+        totals.expected = totals.sales - totals.refunds;
+
+        if totals.payment_channel == PaymentChannel::Cash {
+            totals.expected += report.session.opening_float;
+        }
+
+        if let Some(amount) = counted.get(&totals.payment_channel) {
+            totals.counted = Some(*amount);
+            totals.variance = Some(*amount - totals.expected);
+        }
+    }
+
+    report.channels = channels;
+
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::fixtures::{self, dec, payment, till_session};
+
+    fn order(total: &str, status: OrderStatus) -> entity::orders::Model {
+        entity::orders::Model {
+            order_status: status.as_str().to_string(),
+            ..fixtures::order(total)
+        }
+    }
+
+    fn paid(channel: PaymentChannel, amount: &str) -> Option<entity::transactions::Model> {
+        Some(entity::transactions::Model {
+            debit_channel: Some(channel.as_str().to_string()),
+            ..payment(amount)
+        })
+    }
+
+    fn channel(report: &ZReport, channel: PaymentChannel) -> &ChannelTotals {
+        report
+            .channels
+            .iter()
+            .find(|totals| totals.payment_channel == channel)
+            .unwrap()
+    }
+
+    #[test]
+    fn totals_sales_tips_and_discounts() {
+        let tipped = entity::orders::Model {
+            tip_amount: Some(dec("5")),
+            is_discount_applied: true,
+            discount_amount: dec("4.50"),
+            ..order("45", OrderStatus::Completed)
+        };
+        let orders = vec![
+            (tipped, paid(PaymentChannel::Card, "45")),
+            (
+                order("20", OrderStatus::Completed),
+                paid(PaymentChannel::Cash, "20"),
+            ),
+        ];
+
+        let report = build_z_report(till_session("100"), orders, &HashMap::new());
+
+        assert_eq!(report.order_count, 2);
+        assert_eq!(report.gross_sales, dec("65"));
+        assert_eq!(report.tips, dec("5"));
+        assert_eq!(report.net_sales, dec("60"));
+        assert_eq!(report.discounted_orders, 1);
+        assert_eq!(report.discounts, dec("4.50"));
+        assert_eq!(channel(&report, PaymentChannel::Card).sales, dec("45"));
+    }
+
+    #[test]
+    fn counts_voids_and_takes_refunded_cash_off_the_drawer() {
+        let refunded = entity::orders::Model {
+            refund_transaction_id: Some(uuid::Uuid::new_v4()),
+            ..order("30", OrderStatus::Cancelled)
+        };
+        let orders = vec![
+            (refunded, paid(PaymentChannel::Cash, "30")),
+            (
+                order("20", OrderStatus::Completed),
+                paid(PaymentChannel::Cash, "20"),
+            ),
+        ];
+
+        let report = build_z_report(till_session("100"), orders, &HashMap::new());
+        let cash = channel(&report, PaymentChannel::Cash);
+
+        assert_eq!(report.void_count, 1);
+        assert_eq!(report.void_amount, dec("30"));
+        assert_eq!(report.gross_sales, dec("20"));
+        assert_eq!(cash.refunds, dec("30"));
+        assert_eq!(cash.expected, dec("120"));
+    }
+
+    #[test]
+    fn orders_without_a_payment_count_as_wallet() {
+        let orders = vec![(order("15", OrderStatus::Completed), None)];
+
+        let report = build_z_report(till_session("0"), orders, &HashMap::new());
+
+        assert_eq!(channel(&report, PaymentChannel::Wallet).sales, dec("15"));
+    }
+
+    #[test]
+    fn gives_variances_for_counted_channels_only() {
+        let orders = vec![(
+            order("20", OrderStatus::Completed),
+            paid(PaymentChannel::Cash, "20"),
+        )];
+        let counted = HashMap::from([(PaymentChannel::Cash, dec("115"))]);
+
+        let report = build_z_report(till_session("100"), orders, &counted);
+
+        assert_eq!(
+            channel(&report, PaymentChannel::Cash).variance,
+            Some(dec("-5"))
+        );
+        assert_eq!(channel(&report, PaymentChannel::Card).variance, None);
+    }
+}
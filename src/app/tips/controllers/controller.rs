@@ -0,0 +1,267 @@
+use std::sync::Arc;
+
+use actix_web::{web, HttpMessage, HttpRequest, HttpResponse};
+use serde_json::json;
+
+use crate::{
+    app::{
+        employees::models::model::EmployeeResponse,
+        permissions::{dtos::dto::has_permission, models::model::ModelAction},
+        tips::{
+            dtos::dto::{
+                get_branch_payouts, get_payout_details, get_tip_pool_rule, get_tip_statement,
+                run_tip_payout, save_tip_pool_rule,
+            },
+            models::model::{
+                SetTipPoolRuleDto, SetTipPoolRuleParams, SplitMethod, TipPayoutDto, TipPayoutParams,
+            },
+        },
+    },
+    libs::error::Error,
+    utils::{
+        json_validator::{ValidatedJson, ValidatedPath},
+        models::{HttpClientResponse, PathParamsModel, ResponseCode},
+    },
+    AppState,
+};
+
+pub async fn set_pool_rule(
+    req: HttpRequest,
+    payload: ValidatedJson<SetTipPoolRuleParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    if !has_permission(model.role_permissions, ModelAction::ManageTips, &state)
+        .await
+        .unwrap_or(false)
+    {
+        return Ok(HttpResponse::Forbidden().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            "Not Permitted To Manage Tips".to_string(),
+            json!({}),
+        )));
+    }
+
+    let data = payload.0;
+    let split_method = data.split_method.parse().unwrap_or(SplitMethod::Hours);
+    let shares: Vec<_> = data
+        .shares
+        .unwrap_or_default()
+        .into_iter()
+        .map(Into::into)
+        .collect();
+
+    if split_method == SplitMethod::Department && shares.is_empty() {
+        return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            "Department shares are required to split by department".to_string(),
+            json!({}),
+        )));
+    }
+
+    let rule = SetTipPoolRuleDto {
+        organization: model.organization_id,
+        branch: model.branch_id,
+        employee: model.id,
+        split_method,
+        shares,
+    };
+
+    match save_tip_pool_rule(rule, &state).await {
+        Ok(res) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Tip Pool Rule Saved Successfully".to_string(),
+            json!(res),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Could not save tip pool rule: {}", e),
+            json!({}),
+        ))),
+    }
+}
+
+pub async fn pool_rule(
+    req: HttpRequest,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    match get_tip_pool_rule(model.branch_id, &state).await {
+        Ok(Some(res)) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Tip Pool Rule Fetched Successfully".to_string(),
+            json!(res),
+        ))),
+        Ok(None) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "No Tip Pool Rule, Tips Are Split By Hours Worked".to_string(),
+            json!({}),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Tip pool rule not found: {}", e),
+            json!({}),
+        ))),
+    }
+}
+
+pub async fn run_payout(
+    req: HttpRequest,
+    payload: ValidatedJson<TipPayoutParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    if !has_permission(model.role_permissions, ModelAction::ManageTips, &state)
+        .await
+        .unwrap_or(false)
+    {
+        return Ok(HttpResponse::Forbidden().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            "Not Permitted To Manage Tips".to_string(),
+            json!({}),
+        )));
+    }
+
+    let data = payload.0;
+
+    if data.period_start > data.period_end {
+        return Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            "Period start must not be after period end".to_string(),
+            json!({}),
+        )));
+    }
+
+    let payout = TipPayoutDto {
+        organization: model.organization_id,
+        branch: model.branch_id,
+        employee: model.id,
+        period_start: data.period_start,
+        period_end: data.period_end,
+    };
+
+    match run_tip_payout(payout, &state).await {
+        Ok(res) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Tips Paid Out Successfully".to_string(),
+            json!(res),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Could not pay out tips: {}", e),
+            json!({}),
+        ))),
+    }
+}
+
+pub async fn branch_payouts(
+    req: HttpRequest,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    match get_branch_payouts(model.organization_id, model.branch_id, &state).await {
+        Ok(res) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Tip Payouts Fetched Successfully".to_string(),
+            json!(res),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Tip payouts not found: {}", e),
+            json!({}),
+        ))),
+    }
+}
+
+pub async fn payout_details(
+    req: HttpRequest,
+    params: ValidatedPath<PathParamsModel>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    match get_payout_details(params.0.id, model.organization_id, model.branch_id, &state).await {
+        Ok(res) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Tip Payout Fetched Successfully".to_string(),
+            json!(res),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Tip payout not found: {}", e),
+            json!({}),
+        ))),
+    }
+}
+
+pub async fn my_statement(
+    req: HttpRequest,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let model = req
+        .extensions()
+        .get::<Arc<EmployeeResponse>>()
+        .cloned()
+        .ok_or(Error {
+            message: "Employee not found".to_string(),
+            code: 2001,
+            status: 500,
+        })?;
+
+    match get_tip_statement(model.id, &state).await {
+        Ok(res) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Success,
+            "Tip Statement Fetched Successfully".to_string(),
+            json!(res),
+        ))),
+        Err(e) => Ok(HttpResponse::Ok().json(HttpClientResponse::new(
+            ResponseCode::Failed,
+            format!("Tip statement not found: {}", e),
+            json!({}),
+        ))),
+    }
+}
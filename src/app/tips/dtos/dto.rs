@@ -0,0 +1,452 @@
+use std::collections::HashMap;
+
+use actix_web::web;
+use migration::Expr;
+use sea_orm::{
+    prelude::Decimal, ActiveModelTrait, ActiveValue, ColumnTrait, Condition, ConnectionTrait,
+    DbErr, EntityTrait, QueryFilter, QueryOrder, QuerySelect, Set, TransactionTrait,
+};
+
+use crate::{
+    app::{
+        branch::dtos::dto::get_branch_timezone,
+        tips::{
+            models::model::{
+                DepartmentShareDto, SetTipPoolRuleDto, SplitMethod, StaffHours, TipPayoutDto,
+                TipPayoutResponse, TipPoolRuleResponse, TipStatement, TipStatementLine, TipStatus,
+            },
+            services::service::{allocate_tips, shift_hours},
+        },
+        wallets::{
+            dtos::dto::credit_employee_wallet,
+            models::model::{EmployeeWalletCreditDto, TransactionType},
+        },
+    },
+    utils::time::day_bounds,
+    AppState,
+};
+
+/// Puts the tip on an order into the branch's pool. Call inside the order's transaction.
+pub async fn record_order_tip<C: ConnectionTrait>(
+    order: &entity::orders::Model,
+    conn: &C,
+) -> Result<(), DbErr> {
+    let amount = order.tip_amount.unwrap_or_default();
+
+    if amount <= Decimal::ZERO {
+        return Ok(());
+    }
+
+    let tip = entity::tips::ActiveModel {
+        organization_id: Set(order.organization_id),
+        branch_id: Set(order.branch_id),
+        order_id: Set(order.id),
+        amount: Set(amount),
+        tip_status: Set(TipStatus::Pending.as_str().to_string()),
+        ..Default::default()
+    };
+
+    ActiveModelTrait::insert(tip, conn).await.map_err(|err| {
+        eprintln!("Database insert error: {}", err);
+        DbErr::Custom(err.to_string())
+    })?;
+
+    Ok(())
+}
+
+/// Takes a refunded order's tip back out of the pool, unless it has already been paid out.
+pub async fn void_order_tip<C: ConnectionTrait>(order: uuid::Uuid, conn: &C) -> Result<(), DbErr> {
+    entity::tips::Entity::update_many()
+        .col_expr(
+            entity::tips::Column::TipStatus,
+            Expr::value(TipStatus::Voided.as_str()),
+        )
+        .col_expr(
+            entity::tips::Column::UpdatedAt,
+            Expr::value(chrono::Utc::now()),
+        )
+        .filter(
+            Condition::all()
+                .add(entity::tips::Column::OrderId.eq(order))
+                .add(entity::tips::Column::TipStatus.eq(TipStatus::Pending.as_str())),
+        )
+        .exec(conn)
+        .await?;
+
+    Ok(())
+}
+
+async fn get_rule<C: ConnectionTrait>(
+    branch: uuid::Uuid,
+    conn: &C,
+) -> Result<Option<TipPoolRuleResponse>, DbErr> {
+    let Some(rule) = entity::tip_pool_rules::Entity::find()
+        .filter(entity::tip_pool_rules::Column::BranchId.eq(branch))
+        .one(conn)
+        .await?
+    else {
+        return Ok(None);
+    };
+
+    let shares = entity::tip_pool_shares::Entity::find()
+        .filter(entity::tip_pool_shares::Column::RuleId.eq(rule.id))
+        .all(conn)
+        .await?;
+
+    Ok(Some(TipPoolRuleResponse { rule, shares }))
+}
+
+pub async fn get_tip_pool_rule(
+    branch: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<Option<TipPoolRuleResponse>, DbErr> {
+    get_rule(branch, state.pg_db.get_ref()).await
+}
+
+/// Replaces the branch's pooling rule and its department shares.
+pub async fn save_tip_pool_rule(
+    data: SetTipPoolRuleDto,
+    state: &web::Data<AppState>,
+) -> Result<TipPoolRuleResponse, DbErr> {
+    let txn = state.pg_db.get_ref().begin().await?;
+
+    let existing = entity::tip_pool_rules::Entity::find()
+        .filter(entity::tip_pool_rules::Column::BranchId.eq(data.branch))
+        .lock_exclusive()
+        .one(&txn)
+        .await?;
+
+    let rule = match existing {
+        Some(rule) => {
+            let mut model: entity::tip_pool_rules::ActiveModel = rule.into();
+
+            model.split_method = ActiveValue::Set(data.split_method.as_str().to_string());
+            model.created_by = ActiveValue::Set(Some(data.employee));
+            model.updated_at = ActiveValue::Set(chrono::Utc::now().into());
+
+            ActiveModelTrait::update(model, &txn).await
+        }
+        None => {
+            let model = entity::tip_pool_rules::ActiveModel {
+                organization_id: Set(data.organization),
+                branch_id: Set(data.branch),
+                split_method: Set(data.split_method.as_str().to_string()),
+                created_by: Set(Some(data.employee)),
+                ..Default::default()
+            };
+
+            ActiveModelTrait::insert(model, &txn).await
+        }
+    }
+    .map_err(|err| {
+        eprintln!("Database update error: {}", err);
+        DbErr::Custom(err.to_string())
+    })?;
+
+    entity::tip_pool_shares::Entity::delete_many()
+        .filter(entity::tip_pool_shares::Column::RuleId.eq(rule.id))
+        .exec(&txn)
+        .await?;
+
+    if !data.shares.is_empty() {
+        let shares = data
+            .shares
+            .into_iter()
+            .map(|share| entity::tip_pool_shares::ActiveModel {
+                rule_id: Set(rule.id),
+                department_id: Set(share.department),
+                share_percent: Set(share.share_percent),
+                ..Default::default()
+            });
+
+        entity::tip_pool_shares::Entity::insert_many(shares)
+            .exec(&txn)
+            .await
+            .map_err(|err| {
+                eprintln!("Database insert error: {}", err);
+                DbErr::Custom(err.to_string())
+            })?;
+    }
+
+    let result = get_rule(data.branch, &txn)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Tip pool rule not found".into()))?;
+
+    txn.commit().await?;
+
+    Ok(result)
+}
+
+/// Hours each of the branch's employees clocked in the period, from their shifts.
+async fn staff_hours<C: ConnectionTrait>(
+    branch: uuid::Uuid,
+    from: chrono::DateTime<chrono::Utc>,
+    to: chrono::DateTime<chrono::Utc>,
+    conn: &C,
+) -> Result<Vec<StaffHours>, DbErr> {
+    let employees = entity::employees::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::employees::Column::BranchId.eq(branch))
+                .add(entity::employees::Column::IsDeleted.eq(false)),
+        )
+        .all(conn)
+        .await?;
+
+    let shifts = entity::staff_shifts::Entity::find()
+        .filter(
+            Condition::all()
+                .add(
+                    entity::staff_shifts::Column::EmployeeId
+                        .is_in(employees.iter().map(|e| e.id).collect::<Vec<_>>()),
+                )
+                .add(entity::staff_shifts::Column::CreatedAt.gte(from))
+                .add(entity::staff_shifts::Column::CreatedAt.lt(to)),
+        )
+        .all(conn)
+        .await?;
+
+    let mut hours: HashMap<uuid::Uuid, Decimal> = HashMap::new();
+
+    for shift in &shifts {
+        *hours.entry(shift.employee_id).or_default() += shift_hours(shift);
+    }
+
+    Ok(employees
+        .into_iter()
+        .filter_map(|employee| {
+            let worked = hours.get(&employee.id).copied()?;
+
+            Some(StaffHours {
+                employee: employee.id,
+                department: employee.department_id,
+                hours: worked,
+            })
+        })
+        .collect())
+}
+
+/// Pays out the branch's pending tips for the period, whose days run on the branch's clock:
+/// allocates them under the branch rule (by hours if none is set), credits each employee's
+/// wallet and marks the tips paid.
+pub async fn run_tip_payout(
+    data: TipPayoutDto,
+    state: &web::Data<AppState>,
+) -> Result<TipPayoutResponse, DbErr> {
+    let txn = state.pg_db.get_ref().begin().await?;
+
+    let (from, to) = day_bounds(
+        data.period_start,
+        data.period_end,
+        get_branch_timezone(data.branch, &txn).await?,
+    );
+
+    let tips = entity::tips::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::tips::Column::OrganizationId.eq(data.organization))
+                .add(entity::tips::Column::BranchId.eq(data.branch))
+                .add(entity::tips::Column::TipStatus.eq(TipStatus::Pending.as_str()))
+                .add(entity::tips::Column::CreatedAt.gte(from))
+                .add(entity::tips::Column::CreatedAt.lt(to)),
+        )
+        .lock_exclusive()
+        .all(&txn)
+        .await?;
+
+    if tips.is_empty() {
+        return Err(DbErr::Custom(
+            "No tips to pay out for the period".to_string(),
+        ));
+    }
+
+    let pool: Decimal = tips.iter().map(|tip| tip.amount).sum();
+
+    let (method, shares) = match get_rule(data.branch, &txn).await? {
+        Some(rule) => (
+            rule.rule
+                .split_method
+                .parse::<SplitMethod>()
+                .unwrap_or(SplitMethod::Hours),
+            rule.shares
+                .into_iter()
+                .map(|share| DepartmentShareDto {
+                    department: share.department_id,
+                    share_percent: share.share_percent,
+                })
+                .collect(),
+        ),
+        None => (SplitMethod::Hours, Vec::new()),
+    };
+
+    let staff = staff_hours(data.branch, from, to, &txn).await?;
+    let allocation = allocate_tips(pool, method, &staff, &shares);
+
+    if allocation.is_empty() {
+        return Err(DbErr::Custom(
+            "No shifts worked in the period to share tips between".to_string(),
+        ));
+    }
+
+    let payout = entity::tip_payouts::ActiveModel {
+        organization_id: Set(data.organization),
+        branch_id: Set(data.branch),
+        period_start: Set(data.period_start),
+        period_end: Set(data.period_end),
+        split_method: Set(method.as_str().to_string()),
+        total_amount: Set(pool),
+        created_by: Set(Some(data.employee)),
+        ..Default::default()
+    };
+
+    let payout = ActiveModelTrait::insert(payout, &txn)
+        .await
+        .map_err(|err| {
+            eprintln!("Database insert error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+    let mut allocations = Vec::with_capacity(allocation.len());
+
+    for share in allocation {
+        let mut transaction = None;
+
+        if share.amount > Decimal::ZERO {
+            let credit = credit_employee_wallet(
+                EmployeeWalletCreditDto {
+                    employee: share.employee,
+                    organization: data.organization,
+                    branch: data.branch,
+                    amount: share.amount,
+                    description: format!("Tips for {} to {}", data.period_start, data.period_end),
+                    transaction_type: TransactionType::Tip,
+                },
+                &txn,
+            )
+            .await?;
+
+            transaction = Some(credit.id);
+        }
+
+        let model = entity::tip_allocations::ActiveModel {
+            payout_id: Set(payout.id),
+            employee_id: Set(share.employee),
+            department_id: Set(share.department),
+            hours_worked: Set(share.hours),
+            amount: Set(share.amount),
+            transaction_id: Set(transaction),
+            ..Default::default()
+        };
+
+        let result = ActiveModelTrait::insert(model, &txn).await.map_err(|err| {
+            eprintln!("Database insert error: {}", err);
+            DbErr::Custom(err.to_string())
+        })?;
+
+        allocations.push(result);
+    }
+
+    entity::tips::Entity::update_many()
+        .col_expr(
+            entity::tips::Column::TipStatus,
+            Expr::value(TipStatus::PaidOut.as_str()),
+        )
+        .col_expr(entity::tips::Column::PayoutId, Expr::value(payout.id))
+        .col_expr(
+            entity::tips::Column::UpdatedAt,
+            Expr::value(chrono::Utc::now()),
+        )
+        .filter(entity::tips::Column::Id.is_in(tips.iter().map(|tip| tip.id).collect::<Vec<_>>()))
+        .exec(&txn)
+        .await?;
+
+    txn.commit().await?;
+
+    Ok(TipPayoutResponse {
+        payout,
+        allocations,
+    })
+}
+
+pub async fn get_branch_payouts(
+    organization: uuid::Uuid,
+    branch: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<Vec<entity::tip_payouts::Model>, DbErr> {
+    entity::tip_payouts::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::tip_payouts::Column::OrganizationId.eq(organization))
+                .add(entity::tip_payouts::Column::BranchId.eq(branch)),
+        )
+        .order_by_desc(entity::tip_payouts::Column::CreatedAt)
+        .all(state.pg_db.get_ref())
+        .await
+        .map_err(|err| {
+            eprintln!("Database retrieval error: {}", err);
+            DbErr::Custom(err.to_string())
+        })
+}
+
+pub async fn get_payout_details(
+    id: uuid::Uuid,
+    organization: uuid::Uuid,
+    branch: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<TipPayoutResponse, DbErr> {
+    let payout = entity::tip_payouts::Entity::find_by_id(id)
+        .filter(
+            Condition::all()
+                .add(entity::tip_payouts::Column::OrganizationId.eq(organization))
+                .add(entity::tip_payouts::Column::BranchId.eq(branch)),
+        )
+        .one(state.pg_db.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Tip payout not found".into()))?;
+
+    let allocations = entity::tip_allocations::Entity::find()
+        .filter(entity::tip_allocations::Column::PayoutId.eq(payout.id))
+        .order_by_desc(entity::tip_allocations::Column::Amount)
+        .all(state.pg_db.get_ref())
+        .await?;
+
+    Ok(TipPayoutResponse {
+        payout,
+        allocations,
+    })
+}
+
+/// Every tip share paid to an employee, newest first.
+pub async fn get_tip_statement(
+    employee: uuid::Uuid,
+    state: &web::Data<AppState>,
+) -> Result<TipStatement, DbErr> {
+    let lines: Vec<TipStatementLine> = entity::tip_allocations::Entity::find()
+        .filter(entity::tip_allocations::Column::EmployeeId.eq(employee))
+        .find_also_related(entity::tip_payouts::Entity)
+        .order_by_desc(entity::tip_allocations::Column::CreatedAt)
+        .all(state.pg_db.get_ref())
+        .await?
+        .into_iter()
+        .filter_map(|(allocation, payout)| {
+            let payout = payout?;
+
+            Some(TipStatementLine {
+                payout_id: payout.id,
+                period_start: payout.period_start,
+                period_end: payout.period_end,
+                hours_worked: allocation.hours_worked,
+                amount: allocation.amount,
+                transaction_id: allocation.transaction_id,
+                paid_at: allocation.created_at,
+            })
+        })
+        .collect();
+
+    Ok(TipStatement {
+        employee,
+        total_amount: lines.iter().map(|line| line.amount).sum(),
+        total_hours: lines.iter().map(|line| line.hours_worked).sum(),
+        lines,
+    })
+}
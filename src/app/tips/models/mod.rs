@@ -0,0 +1 @@
+pub mod model;
\ No newline at end of file
@@ -0,0 +1,165 @@
+use std::str::FromStr;
+
+use chrono::NaiveDate;
+use sea_orm::prelude::{DateTimeWithTimeZone, Decimal};
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::libs::validator::{
+    validate_naive_date_rest, validate_percent_range, validate_split_method,
+};
+
+/// How a branch's tip pool is shared out. `Hours` splits the whole pool by hours worked;
+/// `Department` first gives each department its configured percentage, then splits that by
+/// hours worked within the department.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum SplitMethod {
+    Hours,
+    Department,
+}
+
+impl SplitMethod {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SplitMethod::Hours => "HOURS",
+            SplitMethod::Department => "DEPARTMENT",
+        }
+    }
+}
+
+impl FromStr for SplitMethod {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_uppercase().as_str() {
+            "HOURS" => Ok(SplitMethod::Hours),
+            "DEPARTMENT" => Ok(SplitMethod::Department),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum TipStatus {
+    Pending,
+    PaidOut,
+    Voided,
+}
+
+impl TipStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TipStatus::Pending => "PENDING",
+            TipStatus::PaidOut => "PAIDOUT",
+            TipStatus::Voided => "VOIDED",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DepartmentShareDto {
+    pub department: uuid::Uuid,
+    pub share_percent: Decimal,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SetTipPoolRuleDto {
+    pub organization: uuid::Uuid,
+    pub branch: uuid::Uuid,
+    pub employee: uuid::Uuid,
+    pub split_method: SplitMethod,
+    pub shares: Vec<DepartmentShareDto>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TipPayoutDto {
+    pub organization: uuid::Uuid,
+    pub branch: uuid::Uuid,
+    pub employee: uuid::Uuid,
+    pub period_start: NaiveDate,
+    pub period_end: NaiveDate,
+}
+
+/// Hours an employee worked in a payout period, taken from their shifts.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StaffHours {
+    pub employee: uuid::Uuid,
+    pub department: uuid::Uuid,
+    pub hours: Decimal,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TipShare {
+    pub employee: uuid::Uuid,
+    pub department: uuid::Uuid,
+    pub hours: Decimal,
+    pub amount: Decimal,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TipPoolRuleResponse {
+    pub rule: entity::tip_pool_rules::Model,
+    pub shares: Vec<entity::tip_pool_shares::Model>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TipPayoutResponse {
+    pub payout: entity::tip_payouts::Model,
+    pub allocations: Vec<entity::tip_allocations::Model>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TipStatementLine {
+    pub payout_id: uuid::Uuid,
+    pub period_start: NaiveDate,
+    pub period_end: NaiveDate,
+    pub hours_worked: Decimal,
+    pub amount: Decimal,
+    pub transaction_id: Option<uuid::Uuid>,
+    pub paid_at: DateTimeWithTimeZone,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TipStatement {
+    pub employee: uuid::Uuid,
+    pub total_amount: Decimal,
+    pub total_hours: Decimal,
+    pub lines: Vec<TipStatementLine>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct DepartmentShareParams {
+    pub department: uuid::Uuid,
+    #[validate(custom(function = "validate_percent_range"))]
+    pub share_percent: Decimal,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct SetTipPoolRuleParams {
+    #[validate(custom(function = "validate_split_method"))]
+    pub split_method: String,
+    #[validate(length(min = 1, max = 20, message = "Shares are invalid"), nested)]
+    pub shares: Option<Vec<DepartmentShareParams>>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct TipPayoutParams {
+    #[validate(custom(function = "validate_naive_date_rest"))]
+    pub period_start: NaiveDate,
+    #[validate(custom(function = "validate_naive_date_rest"))]
+    pub period_end: NaiveDate,
+}
+
+impl From<DepartmentShareParams> for DepartmentShareDto {
+    fn from(share: DepartmentShareParams) -> Self {
+        Self {
+            department: share.department,
+            share_percent: share.share_percent,
+        }
+    }
+}
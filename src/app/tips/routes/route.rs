@@ -0,0 +1,57 @@
+use actix_web::web;
+
+use crate::{
+    app::tips::controllers::controller::{
+        branch_payouts, my_statement, payout_details, pool_rule, run_payout, set_pool_rule,
+    },
+    middlewares::{auth::JwtAuthMiddleware, checker::CheckUserMiddleware},
+    AppState,
+};
+
+pub fn routes(cfg: &mut web::ServiceConfig, state: web::Data<AppState>) {
+    cfg.service(
+        web::scope("/api/v1/tips")
+            .route(
+                "/rule",
+                web::put()
+                    .to(set_pool_rule)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/rule",
+                web::get()
+                    .to(pool_rule)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/payouts",
+                web::post()
+                    .to(run_payout)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/payouts",
+                web::get()
+                    .to(branch_payouts)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/payouts/{id}",
+                web::get()
+                    .to(payout_details)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            )
+            .route(
+                "/statement",
+                web::get()
+                    .to(my_statement)
+                    .wrap(CheckUserMiddleware::new(state.clone(), "Employee"))
+                    .wrap(JwtAuthMiddleware),
+            ),
+    );
+}
@@ -0,0 +1,189 @@
+use sea_orm::prelude::Decimal;
+
+use crate::app::tips::models::model::{DepartmentShareDto, SplitMethod, StaffHours, TipShare};
+
+/// Splits `pot` between `staff` in proportion to hours worked. Shares are cut to the cent and
+/// whatever the rounding leaves over goes to whoever worked the most, so the shares always add
+/// up to the pot.
+fn split_by_hours(pot: Decimal, staff: &[&StaffHours]) -> Vec<TipShare> {
+    let total_hours: Decimal = staff.iter().map(|s| s.hours).sum();
+
+    if total_hours <= Decimal::ZERO {
+        return Vec::new();
+    }
+
+    let mut shares: Vec<TipShare> = staff
+        .iter()
+        .map(|s| TipShare {
+            employee: s.employee,
+            department: s.department,
+            hours: s.hours,
+            amount: (pot * s.hours / total_hours).trunc_with_scale(2),
+        })
+        .collect();
+
+    let remainder = pot - shares.iter().map(|s| s.amount).sum::<Decimal>();
+
+    if let Some(top) = shares.iter_mut().max_by(|a, b| a.hours.cmp(&b.hours)) {
+        top.amount += remainder;
+    }
+
+    shares
+}
+
+/// Allocates a tip pool between the staff who worked the period. Under the department rule,
+/// departments nobody worked in are left out and the remaining percentages are scaled up so
+/// the whole pool is still paid out.
+pub fn allocate_tips(
+    pool: Decimal,
+    method: SplitMethod,
+    staff: &[StaffHours],
+    shares: &[DepartmentShareDto],
+) -> Vec<TipShare> {
+    let working: Vec<&StaffHours> = staff.iter().filter(|s| s.hours > Decimal::ZERO).collect();
+
+    match method {
+        SplitMethod::Hours => split_by_hours(pool, &working),
+        SplitMethod::Department => {
+            let staffed: Vec<(&DepartmentShareDto, Vec<&StaffHours>)> = shares
+                .iter()
+                .map(|share| {
+                    let members = working
+                        .iter()
+                        .copied()
+                        .filter(|s| s.department == share.department)
+                        .collect::<Vec<_>>();
+                    (share, members)
+                })
+                .filter(|(_, members)| !members.is_empty())
+                .collect();
+
+            let total_percent: Decimal = staffed.iter().map(|(share, _)| share.share_percent).sum();
+
+            if total_percent <= Decimal::ZERO {
+                return Vec::new();
+            }
+
+            let mut allocated = Decimal::ZERO;
+            let mut result = Vec::new();
+
+            for (index, (share, members)) in staffed.iter().enumerate() {
+                // The last department takes the rounding remainder of the pool
+                let pot = if index + 1 == staffed.len() {
+                    pool - allocated
+                } else {
+                    (pool * share.share_percent / total_percent).trunc_with_scale(2)
+                };
+
+                allocated += pot;
+                result.extend(split_by_hours(pot, members));
+            }
+
+            result
+        }
+    }
+}
+
+/// Length of a shift in hours. Shifts only record clock times, so one that clocks out earlier
+/// than it clocked in is taken to run past midnight.
+pub fn shift_hours(shift: &entity::staff_shifts::Model) -> Decimal {
+    let mut seconds = (shift.clock_out_time - shift.clock_in_time).num_seconds();
+
+    if seconds < 0 {
+        seconds += 24 * 60 * 60;
+    }
+
+    (Decimal::from(seconds) / Decimal::from(3600)).round_dp(2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::fixtures::dec;
+
+    fn staff(department: uuid::Uuid, hours: &str) -> StaffHours {
+        StaffHours {
+            employee: uuid::Uuid::new_v4(),
+            department,
+            hours: dec(hours),
+        }
+    }
+
+    fn amounts(shares: &[TipShare]) -> Vec<Decimal> {
+        shares.iter().map(|share| share.amount).collect()
+    }
+
+    #[test]
+    fn splits_by_hours_with_the_remainder_to_the_longest_shift() {
+        let bar = uuid::Uuid::new_v4();
+        let team = [staff(bar, "2"), staff(bar, "4"), staff(bar, "3")];
+        let working: Vec<&StaffHours> = team.iter().collect();
+
+        let shares = split_by_hours(dec("100"), &working);
+
+        assert_eq!(
+            amounts(&shares),
+            vec![dec("22.22"), dec("44.45"), dec("33.33")]
+        );
+        assert!(split_by_hours(dec("100"), &[]).is_empty());
+    }
+
+    #[test]
+    fn leaves_out_staff_who_did_not_work() {
+        let bar = uuid::Uuid::new_v4();
+        let team = [staff(bar, "0"), staff(bar, "5")];
+
+        let shares = allocate_tips(dec("50"), SplitMethod::Hours, &team, &[]);
+
+        assert_eq!(shares.len(), 1);
+        assert_eq!(shares[0].amount, dec("50"));
+    }
+
+    #[test]
+    fn splits_by_department_then_by_hours() {
+        let bar = uuid::Uuid::new_v4();
+        let kitchen = uuid::Uuid::new_v4();
+        let team = [staff(bar, "4"), staff(bar, "4"), staff(kitchen, "6")];
+        let rule = [
+            DepartmentShareDto {
+                department: bar,
+                share_percent: dec("70"),
+            },
+            DepartmentShareDto {
+                department: kitchen,
+                share_percent: dec("30"),
+            },
+        ];
+
+        let shares = allocate_tips(dec("200"), SplitMethod::Department, &team, &rule);
+
+        assert_eq!(amounts(&shares), vec![dec("70"), dec("70"), dec("60")]);
+    }
+
+    #[test]
+    fn scales_up_departments_when_one_is_unstaffed() {
+        let bar = uuid::Uuid::new_v4();
+        let kitchen = uuid::Uuid::new_v4();
+        let door = uuid::Uuid::new_v4();
+        let team = [staff(bar, "4"), staff(kitchen, "4")];
+        let rule = [
+            DepartmentShareDto {
+                department: bar,
+                share_percent: dec("40"),
+            },
+            DepartmentShareDto {
+                department: kitchen,
+                share_percent: dec("20"),
+            },
+            DepartmentShareDto {
+                department: door,
+                share_percent: dec("40"),
+            },
+        ];
+
+        let shares = allocate_tips(dec("100"), SplitMethod::Department, &team, &rule);
+
+        assert_eq!(amounts(&shares), vec![dec("66.66"), dec("33.34")]);
+        assert_eq!(amounts(&shares).iter().sum::<Decimal>(), dec("100"));
+    }
+}
@@ -0,0 +1,204 @@
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, ConnectionTrait, DbErr, EntityTrait, QueryFilter,
+    QuerySelect, Set,
+};
+
+use crate::{
+    app::wallets::models::model::{
+        CounterTransactionDto, EmployeeWalletCreditDto, TransactionStatus, TransactionType,
+        WalletCreditDto, WalletDebitDto,
+    },
+    libs::jwt::gen_string,
+};
+
+pub fn gen_reference(prefix: &str) -> String {
+    format!("{}{}", prefix, gen_string(12).to_uppercase())
+}
+
+/// Takes a payment out of a customer's wallet and records it as a `PAYMENT` transaction.
+/// Pass an open database transaction so the debit commits or rolls back with the caller's writes.
+pub async fn debit_customer_wallet<C: ConnectionTrait>(
+    data: WalletDebitDto,
+    conn: &C,
+) -> Result<entity::transactions::Model, DbErr> {
+    let wallet = entity::wallets::Entity::find()
+        .filter(entity::wallets::Column::CustomerId.eq(data.customer))
+        .lock_exclusive()
+        .one(conn)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Wallet not found".into()))?;
+
+    if wallet.wallet_status.as_deref() != Some("ACTIVE") {
+        return Err(DbErr::Custom("Wallet is not active".to_string()));
+    }
+
+    if wallet.is_withdrawal_blocked.unwrap_or(false) {
+        return Err(DbErr::Custom("Wallet withdrawals are blocked".to_string()));
+    }
+
+    if wallet.balance < data.amount {
+        return Err(DbErr::Custom("Insufficient wallet balance".to_string()));
+    }
+
+    let balance = wallet.balance - data.amount;
+
+    let mut model: entity::wallets::ActiveModel = wallet.into();
+
+    model.balance = ActiveValue::Set(balance);
+    model.updated_at = ActiveValue::Set(Some(chrono::Utc::now().into()));
+
+    ActiveModelTrait::update(model, conn).await.map_err(|err| {
+        eprintln!("Database update error: {}", err);
+        DbErr::Custom(err.to_string())
+    })?;
+
+    let transaction = entity::transactions::ActiveModel {
+        customer_id: Set(Some(data.customer)),
+        organization_id: Set(Some(data.organization)),
+        branch_id: Set(Some(data.branch)),
+        amount: Set(data.amount),
+        transaction_type: Set(TransactionType::Payment.as_str().to_string()),
+        debit_channel: Set(Some("WALLET".to_string())),
+        transaction_status: Set(TransactionStatus::Success.as_str().to_string()),
+        description: Set(Some(data.description)),
+        transaction_reference: Set(Some(gen_reference("TXN"))),
+        ..Default::default()
+    };
+
+    ActiveModelTrait::insert(transaction, conn)
+        .await
+        .map_err(|err| {
+            eprintln!("Database insert error: {}", err);
+            DbErr::Custom(err.to_string())
+        })
+}
+
+/// Pays money back into a customer's wallet, e.g. a refund, and records the matching transaction.
+pub async fn credit_customer_wallet<C: ConnectionTrait>(
+    data: WalletCreditDto,
+    conn: &C,
+) -> Result<entity::transactions::Model, DbErr> {
+    let wallet = entity::wallets::Entity::find()
+        .filter(entity::wallets::Column::CustomerId.eq(data.customer))
+        .lock_exclusive()
+        .one(conn)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Wallet not found".into()))?;
+
+    if wallet.wallet_status.as_deref() == Some("CLOSED") {
+        return Err(DbErr::Custom("Wallet is closed".to_string()));
+    }
+
+    let balance = wallet.balance + data.amount;
+
+    let mut model: entity::wallets::ActiveModel = wallet.into();
+
+    model.balance = ActiveValue::Set(balance);
+    model.updated_at = ActiveValue::Set(Some(chrono::Utc::now().into()));
+
+    ActiveModelTrait::update(model, conn).await.map_err(|err| {
+        eprintln!("Database update error: {}", err);
+        DbErr::Custom(err.to_string())
+    })?;
+
+    let transaction = entity::transactions::ActiveModel {
+        customer_id: Set(Some(data.customer)),
+        organization_id: Set(Some(data.organization)),
+        branch_id: Set(Some(data.branch)),
+        amount: Set(data.amount),
+        transaction_type: Set(data.transaction_type.as_str().to_string()),
+        credit_channel: Set(Some("WALLET".to_string())),
+        transaction_status: Set(TransactionStatus::Success.as_str().to_string()),
+        description: Set(Some(data.description)),
+        transaction_reference: Set(Some(gen_reference("TXN"))),
+        ..Default::default()
+    };
+
+    ActiveModelTrait::insert(transaction, conn)
+        .await
+        .map_err(|err| {
+            eprintln!("Database insert error: {}", err);
+            DbErr::Custom(err.to_string())
+        })
+}
+
+/// Records cash or card taken, or handed back on a refund, at a till. The wallet balance is
+/// not touched; the transaction only tells the till what money it should hold.
+pub async fn record_counter_transaction<C: ConnectionTrait>(
+    data: CounterTransactionDto,
+    conn: &C,
+) -> Result<entity::transactions::Model, DbErr> {
+    let channel = Some(data.channel.as_str().to_string());
+    let is_refund = data.transaction_type == TransactionType::Refund;
+
+    let transaction = entity::transactions::ActiveModel {
+        customer_id: Set(Some(data.customer)),
+        organization_id: Set(Some(data.organization)),
+        branch_id: Set(Some(data.branch)),
+        amount: Set(data.amount),
+        transaction_type: Set(data.transaction_type.as_str().to_string()),
+        debit_channel: Set(if is_refund { None } else { channel.clone() }),
+        credit_channel: Set(if is_refund { channel } else { None }),
+        transaction_status: Set(TransactionStatus::Success.as_str().to_string()),
+        description: Set(Some(data.description)),
+        transaction_reference: Set(Some(gen_reference("TXN"))),
+        ..Default::default()
+    };
+
+    ActiveModelTrait::insert(transaction, conn)
+        .await
+        .map_err(|err| {
+            eprintln!("Database insert error: {}", err);
+            DbErr::Custom(err.to_string())
+        })
+}
+
+/// Pays money into an employee's staff wallet, e.g. their share of the tip pool.
+pub async fn credit_employee_wallet<C: ConnectionTrait>(
+    data: EmployeeWalletCreditDto,
+    conn: &C,
+) -> Result<entity::transactions::Model, DbErr> {
+    let wallet = entity::wallets::Entity::find()
+        .filter(entity::wallets::Column::EmployeeId.eq(data.employee))
+        .lock_exclusive()
+        .one(conn)
+        .await?
+        .ok_or_else(|| {
+            DbErr::RecordNotFound(format!("Wallet not found for employee {}", data.employee))
+        })?;
+
+    if wallet.wallet_status.as_deref() == Some("CLOSED") {
+        return Err(DbErr::Custom("Wallet is closed".to_string()));
+    }
+
+    let balance = wallet.balance + data.amount;
+
+    let mut model: entity::wallets::ActiveModel = wallet.into();
+
+    model.balance = ActiveValue::Set(balance);
+    model.updated_at = ActiveValue::Set(Some(chrono::Utc::now().into()));
+
+    ActiveModelTrait::update(model, conn).await.map_err(|err| {
+        eprintln!("Database update error: {}", err);
+        DbErr::Custom(err.to_string())
+    })?;
+
+    let transaction = entity::transactions::ActiveModel {
+        organization_id: Set(Some(data.organization)),
+        branch_id: Set(Some(data.branch)),
+        amount: Set(data.amount),
+        transaction_type: Set(data.transaction_type.as_str().to_string()),
+        credit_channel: Set(Some("WALLET".to_string())),
+        transaction_status: Set(TransactionStatus::Success.as_str().to_string()),
+        description: Set(Some(data.description)),
+        transaction_reference: Set(Some(gen_reference("TXN"))),
+        ..Default::default()
+    };
+
+    ActiveModelTrait::insert(transaction, conn)
+        .await
+        .map_err(|err| {
+            eprintln!("Database insert error: {}", err);
+            DbErr::Custom(err.to_string())
+        })
+}
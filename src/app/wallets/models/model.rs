@@ -0,0 +1,112 @@
+use std::str::FromStr;
+
+use sea_orm::prelude::Decimal;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum TransactionType {
+    Payment,
+    Refund,
+    Payout,
+    Tip,
+    Reward,
+}
+
+impl TransactionType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TransactionType::Payment => "PAYMENT",
+            TransactionType::Refund => "REFUND",
+            TransactionType::Payout => "PAYOUT",
+            TransactionType::Tip => "TIP",
+            TransactionType::Reward => "REWARD",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum TransactionStatus {
+    Success,
+}
+
+impl TransactionStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TransactionStatus::Success => "SUCCESS",
+        }
+    }
+}
+
+/// How a customer paid: from their wallet, or cash or card handed over at a till.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum PaymentChannel {
+    Wallet,
+    Cash,
+    Card,
+}
+
+impl PaymentChannel {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PaymentChannel::Wallet => "WALLET",
+            PaymentChannel::Cash => "CASH",
+            PaymentChannel::Card => "CARD",
+        }
+    }
+}
+
+impl FromStr for PaymentChannel {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_uppercase().as_str() {
+            "WALLET" => Ok(PaymentChannel::Wallet),
+            "CASH" => Ok(PaymentChannel::Cash),
+            "CARD" => Ok(PaymentChannel::Card),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WalletDebitDto {
+    pub customer: uuid::Uuid,
+    pub organization: uuid::Uuid,
+    pub branch: uuid::Uuid,
+    pub amount: Decimal,
+    pub description: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WalletCreditDto {
+    pub customer: uuid::Uuid,
+    pub organization: uuid::Uuid,
+    pub branch: uuid::Uuid,
+    pub amount: Decimal,
+    pub description: String,
+    pub transaction_type: TransactionType,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CounterTransactionDto {
+    pub customer: uuid::Uuid,
+    pub organization: uuid::Uuid,
+    pub branch: uuid::Uuid,
+    pub amount: Decimal,
+    pub channel: PaymentChannel,
+    pub description: String,
+    pub transaction_type: TransactionType,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EmployeeWalletCreditDto {
+    pub employee: uuid::Uuid,
+    pub organization: uuid::Uuid,
+    pub branch: uuid::Uuid,
+    pub amount: Decimal,
+    pub description: String,
+    pub transaction_type: TransactionType,
+}
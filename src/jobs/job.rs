@@ -2,10 +2,13 @@ use actix_web::web;
 
 use crate::{
     app::{
+        bans::dtos::dto::lift_expired_bans,
+        order_queue::dtos::dto::prune_order_events,
         events::{
             models::model::AddEventDto,
             services::service::{end_event, process_schedule_days},
         },
+        inventory::{dtos::dto::alert_low_stock, models::model::ReorderSettings},
         schedules::dtos::dto::{get_non_recurring_schedules, get_schedules},
     },
     AppState,
@@ -61,3 +64,53 @@ pub async fn stop_non_recurring_event(
 
     Ok(())
 }
+
+pub async fn lift_elapsed_bans(
+    state: &web::Data<AppState>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let lifted = match lift_expired_bans(state).await {
+        Ok(count) => count,
+        Err(e) => return Err(Box::new(e)),
+    };
+
+    println!("Lifted {} expired bans", lifted);
+
+    Ok(())
+}
+
+pub async fn prune_queue_events(
+    state: &web::Data<AppState>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let pruned = match prune_order_events(state).await {
+        Ok(count) => count,
+        Err(e) => return Err(Box::new(e)),
+    };
+
+    println!("Pruned {} order queue events", pruned);
+
+    Ok(())
+}
+
+pub async fn alert_low_stock_items(
+    state: &web::Data<AppState>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let settings = ReorderSettings {
+        days: state
+            .config
+            .get::<i64>("inventory.reorder_days")
+            .unwrap_or(7),
+        sales_window_days: state
+            .config
+            .get::<i64>("inventory.sales_window_days")
+            .unwrap_or(14),
+    };
+
+    let alerted = match alert_low_stock(settings, state).await {
+        Ok(count) => count,
+        Err(e) => return Err(Box::new(e)),
+    };
+
+    println!("Sent low stock alerts to {} branches", alerted);
+
+    Ok(())
+}
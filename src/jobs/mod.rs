@@ -1,5 +1,8 @@
 use actix_web::web;
-use job::{generate_event, stop_non_recurring_event};
+use job::{
+    alert_low_stock_items, generate_event, lift_elapsed_bans, prune_queue_events,
+    stop_non_recurring_event,
+};
 use tokio_cron_scheduler::{Job, JobScheduler};
 
 use crate::AppState;
@@ -52,6 +55,42 @@ pub async fn launchjobs(state: web::Data<AppState>) {
                 })
             }
         }),
+        Job::new_async("0 * * * *", {
+            let state = state.clone();
+            move |_uuid, _l| {
+                let state = state.clone();
+                Box::pin(async move {
+                    println!("Running ban expiry job at: {}", chrono::Utc::now());
+                    if let Err(e) = lift_elapsed_bans(&state).await {
+                        eprintln!("Ban expiry failed: {e:?}");
+                    }
+                })
+            }
+        }),
+        Job::new_async("30 3 * * *", {
+            let state = state.clone();
+            move |_uuid, _l| {
+                let state = state.clone();
+                Box::pin(async move {
+                    println!("Running order queue pruning job at: {}", chrono::Utc::now());
+                    if let Err(e) = prune_queue_events(&state).await {
+                        eprintln!("Order queue pruning failed: {e:?}");
+                    }
+                })
+            }
+        }),
+        Job::new_async("15 * * * *", {
+            let state = state.clone();
+            move |_uuid, _l| {
+                let state = state.clone();
+                Box::pin(async move {
+                    println!("Running low stock alert job at: {}", chrono::Utc::now());
+                    if let Err(e) = alert_low_stock_items(&state).await {
+                        eprintln!("Low stock alert failed: {e:?}");
+                    }
+                })
+            }
+        }),
     ];
 
     for (index, job_result) in jobs.into_iter().enumerate() {
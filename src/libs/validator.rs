@@ -148,6 +148,18 @@ pub fn validate_val_range(fee: &Decimal) -> Result<(), ValidationError> {
     Ok(())
 }
 
+pub fn validate_cash_amount(amount: &Decimal) -> Result<(), ValidationError> {
+    let max = Decimal::from(1_000_000);
+
+    if amount < &Decimal::ZERO || amount > &max {
+        let mut err = ValidationError::new("amount_out_of_range");
+        err.message = Some("Amount must be between 0 and 1,000,000.".into());
+        return Err(err);
+    }
+
+    Ok(())
+}
+
 pub fn validate_percent_range(fee: &Decimal) -> Result<(), ValidationError> {
     let min = Decimal::from_f64_retain(0.1).unwrap();
     let max = Decimal::from_f64_retain(100.0).unwrap();
@@ -160,3 +172,193 @@ pub fn validate_percent_range(fee: &Decimal) -> Result<(), ValidationError> {
 
     Ok(())
 }
+
+pub fn validate_booking_party(party: &str) -> Result<(), ValidationError> {
+    match party {
+        "INDIVIDUAL" | "GROUP" => Ok(()),
+        _ => Err(ValidationError::new("Invalid booking party")),
+    }
+}
+
+pub fn validate_ban_duration_type(duration_type: &str) -> Result<(), ValidationError> {
+    match duration_type {
+        "HOURS" | "DAYS" | "WEEKS" | "MONTHS" | "PERMANENT" => Ok(()),
+        _ => Err(ValidationError::new("Invalid ban duration type")),
+    }
+}
+
+pub fn validate_ban_scope(scope: &str) -> Result<(), ValidationError> {
+    match scope {
+        "BRANCH" | "ORGANIZATION" => Ok(()),
+        _ => Err(ValidationError::new("Invalid ban scope")),
+    }
+}
+
+pub fn validate_approval_decision(decision: &str) -> Result<(), ValidationError> {
+    match decision {
+        "APPROVED" | "REJECTED" => Ok(()),
+        _ => Err(ValidationError::new("Invalid approval decision")),
+    }
+}
+
+pub fn validate_approval_status(status: &str) -> Result<(), ValidationError> {
+    match status {
+        "PENDING" | "APPROVED" | "REJECTED" => Ok(()),
+        _ => Err(ValidationError::new("Invalid approval status")),
+    }
+}
+
+pub fn validate_item_type(item_type: &str) -> Result<(), ValidationError> {
+    match item_type {
+        "DRINK" | "FOOD" | "COCKTAIL" => Ok(()),
+        _ => Err(ValidationError::new("Invalid item type")),
+    }
+}
+
+pub fn validate_order_status(status: &str) -> Result<(), ValidationError> {
+    match status {
+        "PENDING" | "PROCESSING" | "COMPLETED" | "REJECTED" | "CANCELLED" => Ok(()),
+        _ => Err(ValidationError::new("Invalid order status")),
+    }
+}
+
+pub fn validate_payment_channel(channel: &str) -> Result<(), ValidationError> {
+    match channel {
+        "WALLET" | "CASH" | "CARD" => Ok(()),
+        _ => Err(ValidationError::new("Invalid payment channel")),
+    }
+}
+
+pub fn validate_split_method(method: &str) -> Result<(), ValidationError> {
+    match method {
+        "HOURS" | "DEPARTMENT" => Ok(()),
+        _ => Err(ValidationError::new("Invalid split method")),
+    }
+}
+
+pub fn validate_station(station: &str) -> Result<(), ValidationError> {
+    match station {
+        "BAR" | "KITCHEN" => Ok(()),
+        _ => Err(ValidationError::new("Invalid station")),
+    }
+}
+
+pub fn validate_split_type(split_type: &str) -> Result<(), ValidationError> {
+    match split_type {
+        "EVEN" | "ITEMS" | "CUSTOM" => Ok(()),
+        _ => Err(ValidationError::new("Invalid split type")),
+    }
+}
+
+pub fn validate_tax_rule_type(rule_type: &str) -> Result<(), ValidationError> {
+    match rule_type {
+        "TAX" | "SERVICE" => Ok(()),
+        _ => Err(ValidationError::new("Invalid tax rule type")),
+    }
+}
+
+pub fn validate_tax_target(target: &str) -> Result<(), ValidationError> {
+    match target {
+        "ALL" | "ORDERS" | "BOOKINGS" => Ok(()),
+        _ => Err(ValidationError::new("Invalid tax target")),
+    }
+}
+
+pub fn validate_promo_type(promo_type: &str) -> Result<(), ValidationError> {
+    match promo_type {
+        "CODE" | "AUTOMATIC" => Ok(()),
+        _ => Err(ValidationError::new("Invalid promo type")),
+    }
+}
+
+pub fn validate_promo_target(target: &str) -> Result<(), ValidationError> {
+    match target {
+        "ORDERS" | "BOOKINGS" | "TICKETS" => Ok(()),
+        _ => Err(ValidationError::new("Invalid promo target")),
+    }
+}
+
+pub fn validate_weekdays(weekdays: &[i32]) -> Result<(), ValidationError> {
+    if weekdays.is_empty() || weekdays.iter().any(|day| !(1..=7).contains(day)) {
+        let mut err = ValidationError::new("weekdays_out_of_range");
+        err.message = Some("Weekdays must be numbers from 1 (Monday) to 7 (Sunday).".into());
+        return Err(err);
+    }
+
+    Ok(())
+}
+
+pub fn validate_adjustment_type(adjustment_type: &str) -> Result<(), ValidationError> {
+    match adjustment_type {
+        "DISCOUNT" | "MARKUP" => Ok(()),
+        _ => Err(ValidationError::new("Invalid adjustment type")),
+    }
+}
+
+pub fn validate_po_status(status: &str) -> Result<(), ValidationError> {
+    match status {
+        "DRAFT" | "SENT" | "PARTIAL" | "RECEIVED" | "CANCELLED" => Ok(()),
+        _ => Err(ValidationError::new("Invalid purchase order status")),
+    }
+}
+
+pub fn validate_take_status(status: &str) -> Result<(), ValidationError> {
+    match status {
+        "OPEN" | "APPROVED" | "CANCELLED" => Ok(()),
+        _ => Err(ValidationError::new("Invalid stock take status")),
+    }
+}
+
+pub fn validate_transfer_status(status: &str) -> Result<(), ValidationError> {
+    match status {
+        "REQUESTED" | "INTRANSIT" | "RECEIVED" | "CANCELLED" => Ok(()),
+        _ => Err(ValidationError::new("Invalid transfer status")),
+    }
+}
+
+pub fn validate_adjustment_reason(reason: &str) -> Result<(), ValidationError> {
+    match reason {
+        "BREAKAGE" | "SPILLAGE" | "THEFT" | "COMP" | "MISCOUNT" | "UNEXPLAINED" => Ok(()),
+        _ => Err(ValidationError::new("Invalid adjustment reason")),
+    }
+}
+
+pub fn validate_measure_unit(unit: &str) -> Result<(), ValidationError> {
+    match unit {
+        "PIECE" | "ML" | "G" => Ok(()),
+        _ => Err(ValidationError::new("Invalid measure unit")),
+    }
+}
+
+pub fn validate_recipe_unit(unit: &str) -> Result<(), ValidationError> {
+    match unit {
+        "UNIT" | "PIECE" | "ML" | "CL" | "L" | "G" | "KG" => Ok(()),
+        _ => Err(ValidationError::new("Invalid recipe unit")),
+    }
+}
+
+pub fn validate_measure(measure: &Decimal) -> Result<(), ValidationError> {
+    let max = Decimal::from(100_000);
+
+    if measure <= &Decimal::ZERO || measure > &max {
+        let mut err = ValidationError::new("measure_out_of_range");
+        err.message = Some("Measure must be above 0 and at most 100,000.".into());
+        return Err(err);
+    }
+
+    Ok(())
+}
+
+pub fn validate_rank_by(rank_by: &str) -> Result<(), ValidationError> {
+    match rank_by {
+        "REVENUE" | "MARGIN" | "UNITS" => Ok(()),
+        _ => Err(ValidationError::new("Invalid ranking")),
+    }
+}
+
+pub fn validate_timezone(timezone: &str) -> Result<(), ValidationError> {
+    match timezone.parse::<chrono_tz::Tz>() {
+        Ok(_) => Ok(()),
+        Err(_) => Err(ValidationError::new("Invalid timezone")),
+    }
+}
@@ -15,6 +15,7 @@ use libs::error;
 use middlewares::helmet::SecurityHeaders;
 use sea_orm::DatabaseConnection;
 use setup::db::pg::pg_conn;
+use tokio::sync::broadcast;
 
 mod app;
 mod jobs;
@@ -28,6 +29,7 @@ mod utils;
 pub struct AppState {
     pub config: ConfigLoader,
     pub pg_db: Arc<Data<DatabaseConnection>>,
+    pub order_feed: broadcast::Sender<entity::order_events::Model>,
 }
 
 fn load_config() -> Result<ConfigLoader, ConfigError> {
@@ -60,9 +62,12 @@ async fn main() -> std::io::Result<()> {
     let _port = port.clone();
     let _host = host.clone();
 
+    let (order_feed, _) = broadcast::channel(1024);
+
     let state = web::Data::new(AppState {
         config: settings.clone(),
         pg_db: pg_conn.clone(),
+        order_feed,
     });
 
     let job_state = state.clone();
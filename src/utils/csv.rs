@@ -0,0 +1,251 @@
+/// Characters a spreadsheet reads as the start of a formula.
+const FORMULA_START: [char; 6] = ['=', '+', '-', '@', '\t', '\r'];
+
+/// A CSV field, quoted when it holds a separator, quote or line break. Text a spreadsheet would
+/// run as a formula is led by an apostrophe so it opens as text; numbers are left as they are.
+pub fn csv_field(value: &str) -> String {
+    let value = if value.starts_with(FORMULA_START) && value.parse::<f64>().is_err() {
+        format!("'{}", value)
+    } else {
+        value.to_string()
+    };
+
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value
+    }
+}
+
+/// A field as it was before `csv_field` led it with an apostrophe, so exports import back as
+/// they were.
+pub fn csv_value(field: &str) -> &str {
+    match field.strip_prefix('\'') {
+        Some(value) if value.starts_with(FORMULA_START) => value,
+        _ => field,
+    }
+}
+
+/// A CSV document with a header line, lines ending in CRLF.
+pub fn csv_document(header: &[&str], rows: impl Iterator<Item = Vec<String>>) -> String {
+    let mut document = header.join(",");
+    document.push_str("\r\n");
+
+    for row in rows {
+        let fields: Vec<String> = row.iter().map(|field| csv_field(field)).collect();
+
+        document.push_str(&fields.join(","));
+        document.push_str("\r\n");
+    }
+
+    document
+}
+
+/// The records of a CSV document with the line each starts on. Quoted fields may hold
+/// separators, doubled quotes and line breaks, and must end where the field does. Blank lines
+/// are skipped.
+pub fn parse_csv(document: &str) -> Result<Vec<(usize, Vec<String>)>, String> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut closed = false;
+    let mut line = 1;
+    let mut start = 1;
+    let mut chars = document.trim_start_matches('\u{feff}').chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if quoted {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    field.push('"');
+                }
+                '"' => {
+                    quoted = false;
+                    closed = true;
+                }
+                '\n' => {
+                    line += 1;
+                    field.push(c);
+                }
+                _ => field.push(c),
+            }
+
+            continue;
+        }
+
+        match c {
+            ',' | '\r' | '\n' => closed = false,
+            _ if closed => return Err(format!("Line {}: text after closing quote", line)),
+            _ => {}
+        }
+
+        match c {
+            '"' if field.is_empty() => quoted = true,
+            '"' => return Err(format!("Line {}: unexpected quote", line)),
+            ',' => record.push(std::mem::take(&mut field)),
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\n' | '\r' => {
+                record.push(std::mem::take(&mut field));
+
+                if record.iter().any(|field| !field.is_empty()) {
+                    records.push((start, std::mem::take(&mut record)));
+                } else {
+                    record.clear();
+                }
+
+                line += 1;
+                start = line;
+            }
+            _ => field.push(c),
+        }
+    }
+
+    if quoted {
+        return Err(format!("Line {}: unclosed quote", start));
+    }
+
+    record.push(field);
+
+    if record.iter().any(|field| !field.is_empty()) {
+        records.push((start, record));
+    }
+
+    Ok(records)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fields(records: &[(usize, Vec<String>)]) -> Vec<(usize, Vec<&str>)> {
+        records
+            .iter()
+            .map(|(line, record)| (*line, record.iter().map(String::as_str).collect()))
+            .collect()
+    }
+
+    #[test]
+    fn quotes_fields_that_need_it() {
+        assert_eq!(csv_field("plain"), "plain");
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(csv_field("two\nlines"), "\"two\nlines\"");
+    }
+
+    #[test]
+    fn neutralises_formulas_but_not_numbers() {
+        assert_eq!(csv_field("=SUM(A1:A2)"), "'=SUM(A1:A2)");
+        assert_eq!(csv_field("+1"), "+1");
+        assert_eq!(csv_field("-12.50"), "-12.50");
+        assert_eq!(csv_field("@cmd"), "'@cmd");
+        assert_eq!(csv_field("-x,y"), "\"'-x,y\"");
+    }
+
+    #[test]
+    fn exported_formulas_import_back() {
+        assert_eq!(csv_value(&csv_field("=1+1")), "=1+1");
+        assert_eq!(csv_value("'quoted"), "'quoted");
+        assert_eq!(csv_value("gin"), "gin");
+    }
+
+    #[test]
+    fn writes_a_header_and_crlf_lines() {
+        let document = csv_document(
+            &["name", "price"],
+            vec![vec!["Gin, dry".to_string(), "12".to_string()]].into_iter(),
+        );
+
+        assert_eq!(document, "name,price\r\n\"Gin, dry\",12\r\n");
+    }
+
+    #[test]
+    fn reads_back_what_it_writes() {
+        let row = vec![
+            "a,b".to_string(),
+            "say \"hi\"".to_string(),
+            "two\r\nlines".to_string(),
+            String::new(),
+        ];
+        let document = csv_document(&["w", "x", "y", "z"], vec![row.clone()].into_iter());
+        let records = parse_csv(&document).unwrap();
+
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[1].1, row);
+    }
+
+    #[test]
+    fn parses_quoted_fields() {
+        let records = parse_csv("name,note\n\"Gin, dry\",\"a \"\"b\"\"\"\n").unwrap();
+
+        assert_eq!(
+            fields(&records),
+            vec![(1, vec!["name", "note"]), (2, vec!["Gin, dry", "a \"b\""])]
+        );
+    }
+
+    #[test]
+    fn handles_crlf_lf_and_bare_cr() {
+        let records = parse_csv("a,b\r\nc,d\ne,f\rg,h").unwrap();
+
+        assert_eq!(
+            fields(&records),
+            vec![
+                (1, vec!["a", "b"]),
+                (2, vec!["c", "d"]),
+                (3, vec!["e", "f"]),
+                (4, vec!["g", "h"]),
+            ]
+        );
+    }
+
+    #[test]
+    fn strips_a_byte_order_mark() {
+        let records = parse_csv("\u{feff}name\r\nGin\r\n").unwrap();
+
+        assert_eq!(fields(&records), vec![(1, vec!["name"]), (2, vec!["Gin"])]);
+    }
+
+    #[test]
+    fn numbers_records_by_their_first_line() {
+        let records = parse_csv("name,note\n\nGin,\"two\nlines\"\nRum,x\n").unwrap();
+
+        assert_eq!(
+            fields(&records),
+            vec![
+                (1, vec!["name", "note"]),
+                (3, vec!["Gin", "two\nlines"]),
+                (5, vec!["Rum", "x"]),
+            ]
+        );
+    }
+
+    #[test]
+    fn skips_blank_lines() {
+        let records = parse_csv("\r\na\r\n,\r\n\r\n").unwrap();
+
+        assert_eq!(fields(&records), vec![(2, vec!["a"])]);
+    }
+
+    #[test]
+    fn refuses_text_after_a_closing_quote() {
+        assert_eq!(
+            parse_csv("name\n\"ab\"c\n"),
+            Err("Line 2: text after closing quote".to_string())
+        );
+        assert!(parse_csv("\"ab\",c\n").is_ok());
+    }
+
+    #[test]
+    fn refuses_stray_and_unclosed_quotes() {
+        assert_eq!(
+            parse_csv("a\nab\"c\n"),
+            Err("Line 2: unexpected quote".to_string())
+        );
+        assert_eq!(
+            parse_csv("a\n\"open\nstill open"),
+            Err("Line 2: unclosed quote".to_string())
+        );
+    }
+}
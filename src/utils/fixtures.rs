@@ -0,0 +1,388 @@
+//! Records for unit tests, filled with plain values the test can override.
+
+use crate::app::inventory::models::model::{MeasureUnit, StockStatus};
+use crate::app::orders::models::model::ItemType;
+use crate::app::orders::models::model::{OrderState, OrderStatus};
+use crate::app::pricing::models::model::AdjustmentType;
+use crate::app::promos::models::model::{PromoTarget, PromoType};
+use crate::app::recipes::models::model::RecipeUnit;
+use crate::app::taxes::models::model::{TaxRuleType, TaxTarget};
+use crate::app::tills::models::model::TillStatus;
+use crate::app::wallets::models::model::{TransactionStatus, TransactionType};
+use sea_orm::prelude::Decimal;
+
+pub fn dec(value: &str) -> Decimal {
+    value.parse().unwrap()
+}
+
+/// A verified adult customer born on 1 May 2000.
+pub fn customer() -> entity::customers::Model {
+    let now = chrono::Utc::now().into();
+
+    entity::customers::Model {
+        id: uuid::Uuid::new_v4(),
+        first_name: None,
+        last_name: None,
+        contact: "0200000000".to_string(),
+        email: None,
+        is_student: false,
+        student_id_number: None,
+        student_id_image_id: None,
+        is_student_id_verified: false,
+        customer_number: "CUS-0001".to_string(),
+        identification_type: None,
+        identification_number: None,
+        identification_image_id: None,
+        is_blocked: false,
+        is_deleted: false,
+        blocked_reason: None,
+        is_banned: false,
+        password: None,
+        salt: None,
+        session: None,
+        student_end_year: None,
+        username: None,
+        is_active: true,
+        date_of_birth: chrono::NaiveDate::from_ymd_opt(2000, 5, 1),
+        is_id_verified: true,
+        created_at: now,
+        updated_at: now,
+        deleted_at: None,
+    }
+}
+
+/// An open till session.
+pub fn till_session(opening_float: &str) -> entity::till_sessions::Model {
+    let now = chrono::Utc::now().into();
+
+    entity::till_sessions::Model {
+        id: uuid::Uuid::new_v4(),
+        employee_id: uuid::Uuid::new_v4(),
+        branch_id: uuid::Uuid::new_v4(),
+        organization_id: uuid::Uuid::new_v4(),
+        start_time: None,
+        end_time: None,
+        session_status: TillStatus::Open.as_str().to_string(),
+        opening_float: dec(opening_float),
+        opened_at: now,
+        closed_at: None,
+        closed_by: None,
+        counted_cash: None,
+        notes: None,
+        created_at: now,
+        updated_at: now,
+    }
+}
+
+/// A pending instant order without tips, taxes or discounts.
+pub fn order(total_amount: &str) -> entity::orders::Model {
+    let now = chrono::Utc::now().into();
+
+    entity::orders::Model {
+        id: uuid::Uuid::new_v4(),
+        customer_id: uuid::Uuid::new_v4(),
+        organization_id: uuid::Uuid::new_v4(),
+        branch_id: uuid::Uuid::new_v4(),
+        order_number: "250514-0001".to_string(),
+        payment_id: None,
+        total_amount: dec(total_amount),
+        is_discount_applied: false,
+        is_cancelled: false,
+        served_by: None,
+        order_status: OrderStatus::Pending.as_str().to_string(),
+        till_session_id: None,
+        is_tip_applied: false,
+        tip_amount: None,
+        order_state: OrderState::InstantOrder.as_str().to_string(),
+        status_reason: None,
+        refund_transaction_id: None,
+        booking_id: None,
+        released_at: None,
+        tab_id: None,
+        tax_amount: Decimal::ZERO,
+        included_tax_amount: Decimal::ZERO,
+        service_charge: Decimal::ZERO,
+        discount_amount: Decimal::ZERO,
+        promo_reference: None,
+        created_at: now,
+        updated_at: now,
+    }
+}
+
+/// A successful payment, paid from no channel in particular.
+pub fn payment(amount: &str) -> entity::transactions::Model {
+    let now = chrono::Utc::now().into();
+
+    entity::transactions::Model {
+        id: uuid::Uuid::new_v4(),
+        customer_id: None,
+        organization_id: None,
+        branch_id: None,
+        amount: dec(amount),
+        transaction_type: TransactionType::Payment.as_str().to_string(),
+        debit_channel: None,
+        credit_channel: None,
+        transaction_status: TransactionStatus::Success.as_str().to_string(),
+        charges: Decimal::ZERO,
+        description: None,
+        transaction_reference: None,
+        external_reference: None,
+        created_at: now,
+        updated_at: now,
+    }
+}
+
+/// A drink on an order, without a discount.
+pub fn order_item(price: &str, quantity: i32) -> entity::order_items::Model {
+    let now = chrono::Utc::now().into();
+
+    entity::order_items::Model {
+        id: uuid::Uuid::new_v4(),
+        order_id: uuid::Uuid::new_v4(),
+        stock_drink_id: Some(uuid::Uuid::new_v4()),
+        stock_food_id: None,
+        item_type: ItemType::Drink.as_str().to_string(),
+        item_name: None,
+        price: dec(price),
+        quantity,
+        custom_cocktail_id: None,
+        has_recipe: false,
+        discount_amount: Decimal::ZERO,
+        unit_cost: None,
+        created_at: now,
+        updated_at: now,
+    }
+}
+
+/// An exclusive rule on everything, not compounded.
+pub fn tax_rule(rule_type: TaxRuleType, rate: &str) -> entity::tax_rules::Model {
+    let now = chrono::Utc::now().into();
+
+    entity::tax_rules::Model {
+        id: uuid::Uuid::new_v4(),
+        organization_id: uuid::Uuid::new_v4(),
+        name: "VAT".to_string(),
+        code: "VAT".to_string(),
+        rule_type: rule_type.as_str().to_string(),
+        rate: dec(rate),
+        is_inclusive: false,
+        is_compound: false,
+        priority: 0,
+        applies_to: TaxTarget::All.as_str().to_string(),
+        exempt_category_ids: None,
+        is_active: true,
+        created_at: now,
+        updated_at: now,
+    }
+}
+
+/// An automatic promo on orders everywhere, at any time and without limits.
+pub fn promo(title: &str, discount_rate: &str) -> entity::promos::Model {
+    let now = chrono::Utc::now().into();
+
+    entity::promos::Model {
+        id: uuid::Uuid::new_v4(),
+        title: title.to_string(),
+        description: String::new(),
+        discount_rate: dec(discount_rate),
+        valid_from: now,
+        valid_to: now,
+        created_at: now,
+        updated_at: now,
+        organization_id: None,
+        code: None,
+        promo_type: PromoType::Automatic.as_str().to_string(),
+        applies_to: PromoTarget::Orders.as_str().to_string(),
+        max_discount: None,
+        min_spend: None,
+        branch_ids: None,
+        schedule_ids: None,
+        category_ids: None,
+        ticket_ids: None,
+        start_time: None,
+        end_time: None,
+        weekdays: None,
+        usage_limit: None,
+        per_customer_limit: None,
+        is_stackable: false,
+        is_active: true,
+    }
+}
+
+/// A rule on every drink, every day, with a window of midnight to midnight.
+pub fn price_rule(
+    name: &str,
+    adjustment: AdjustmentType,
+    rate: &str,
+) -> entity::price_rules::Model {
+    let now = chrono::Utc::now().into();
+
+    entity::price_rules::Model {
+        id: uuid::Uuid::new_v4(),
+        organization_id: uuid::Uuid::new_v4(),
+        branch_id: uuid::Uuid::new_v4(),
+        name: name.to_string(),
+        adjustment_type: adjustment.as_str().to_string(),
+        rate: dec(rate),
+        category_ids: None,
+        drink_ids: None,
+        weekdays: None,
+        start_time: chrono::NaiveTime::MIN,
+        end_time: chrono::NaiveTime::MIN,
+        priority: 0,
+        is_active: true,
+        created_at: now,
+        updated_at: now,
+    }
+}
+
+/// A drink in stock, sold by the piece.
+pub fn stock_drink(name: &str, sale_price: &str) -> entity::stock_drinks::Model {
+    let now = chrono::Utc::now().into();
+
+    entity::stock_drinks::Model {
+        id: uuid::Uuid::new_v4(),
+        organization_id: uuid::Uuid::new_v4(),
+        branch_id: uuid::Uuid::new_v4(),
+        name: name.to_string(),
+        unit_price: Decimal::ZERO,
+        is_alcholic: false,
+        is_custom: false,
+        quantity: 20,
+        quantity_sold: 0,
+        sale_price: dec(sale_price),
+        is_markup_applied: false,
+        markup_rate: Decimal::ZERO,
+        is_reduced: false,
+        reduction_rate: Decimal::ZERO,
+        image_id: None,
+        stock_status: StockStatus::InStock.as_str().to_string(),
+        category_id: uuid::Uuid::new_v4(),
+        supplier_id: uuid::Uuid::new_v4(),
+        reorder_threshold: 10,
+        low_stock_alerted_at: None,
+        measure_unit: MeasureUnit::Piece.as_str().to_string(),
+        unit_size: Decimal::ONE,
+        open_measure: Decimal::ZERO,
+        sku: None,
+        created_at: now,
+        updated_at: now,
+    }
+}
+
+/// A count of a drink in a stock take.
+pub fn stock_take_count(
+    drink: &entity::stock_drinks::Model,
+    pass: i32,
+    counted_quantity: i32,
+    expected_quantity: i32,
+) -> entity::stock_take_counts::Model {
+    let now = chrono::Utc::now().into();
+
+    entity::stock_take_counts::Model {
+        id: uuid::Uuid::new_v4(),
+        stock_take_id: uuid::Uuid::nil(),
+        stock_drink_id: drink.id,
+        pass,
+        counted_quantity,
+        expected_quantity,
+        counted_by: uuid::Uuid::new_v4(),
+        created_at: now,
+        updated_at: now,
+    }
+}
+
+/// An adjustment of a drink's stock at its purchase price, outside any stock take.
+pub fn stock_adjustment(
+    drink: &entity::stock_drinks::Model,
+    quantity: i32,
+    reason: &str,
+) -> entity::stock_adjustments::Model {
+    let now = chrono::Utc::now().into();
+
+    entity::stock_adjustments::Model {
+        id: uuid::Uuid::new_v4(),
+        organization_id: drink.organization_id,
+        branch_id: drink.branch_id,
+        stock_drink_id: drink.id,
+        stock_take_id: None,
+        expected_quantity: 0,
+        counted_quantity: 0,
+        quantity,
+        reason: reason.to_string(),
+        unit_cost: drink.unit_price,
+        created_by: uuid::Uuid::new_v4(),
+        created_at: now,
+        updated_at: now,
+        stock_transfer_id: None,
+    }
+}
+
+/// A line of a drink's recipe pouring `quantity` `unit` of `component`.
+pub fn recipe_item(
+    component: &entity::stock_drinks::Model,
+    quantity: &str,
+    unit: RecipeUnit,
+) -> entity::recipe_items::Model {
+    let now = chrono::Utc::now().into();
+
+    entity::recipe_items::Model {
+        id: uuid::Uuid::new_v4(),
+        organization_id: component.organization_id,
+        branch_id: component.branch_id,
+        item_type: ItemType::Drink.as_str().to_string(),
+        stock_drink_id: Some(uuid::Uuid::new_v4()),
+        stock_food_id: None,
+        custom_cocktail_id: None,
+        component_id: component.id,
+        quantity: dec(quantity),
+        unit: unit.as_str().to_string(),
+        created_at: now,
+        updated_at: now,
+    }
+}
+
+/// An active category served all day.
+pub fn category(
+    name: &str,
+    parent: Option<&entity::categories::Model>,
+) -> entity::categories::Model {
+    let now = chrono::Utc::now().into();
+
+    entity::categories::Model {
+        id: uuid::Uuid::new_v4(),
+        name: name.to_string(),
+        description: None,
+        category_type: None,
+        organization_id: None,
+        branch_id: None,
+        parent_id: parent.map(|p| p.id),
+        sort_order: 0,
+        is_active: true,
+        available_from: None,
+        available_until: None,
+        created_at: now,
+        updated_at: now,
+    }
+}
+
+/// A line of a transfer not yet received.
+pub fn stock_transfer_line(
+    quantity_requested: i32,
+    quantity_dispatched: Option<i32>,
+) -> entity::stock_transfer_lines::Model {
+    let now = chrono::Utc::now().into();
+
+    entity::stock_transfer_lines::Model {
+        id: uuid::Uuid::new_v4(),
+        stock_transfer_id: uuid::Uuid::nil(),
+        from_drink_id: uuid::Uuid::new_v4(),
+        to_drink_id: uuid::Uuid::new_v4(),
+        quantity_requested,
+        quantity_dispatched,
+        quantity_received: None,
+        unit_cost: Decimal::ZERO,
+        created_at: now,
+        updated_at: now,
+    }
+}
@@ -2,4 +2,8 @@ pub mod macros;
 pub mod models;
 pub mod shared;
 pub mod file_methods;
-pub mod json_validator;
\ No newline at end of file
+pub mod json_validator;
+pub mod csv;
+pub mod time;
+#[cfg(test)]
+pub mod fixtures;
\ No newline at end of file
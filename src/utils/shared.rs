@@ -77,6 +77,15 @@ pub async fn get_media_by_user(
     Ok(medias)
 }
 
+pub fn slugify(value: &str) -> String {
+    value
+        .to_lowercase()
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
 pub async fn gen_num(
     model: &str,
     name: String,
@@ -95,6 +104,10 @@ pub async fn gen_num(
             .count(state.pg_db.get_ref())
             .await
             .unwrap_or(0),
+        "BKN" => entity::bookings::Entity::find()
+            .count(state.pg_db.get_ref())
+            .await
+            .unwrap_or(0),
         _ => 0,
     };
 
@@ -0,0 +1,33 @@
+use chrono::{DateTime, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use chrono_tz::Tz;
+
+/// The timezone the branch keeps its hours in. A name that isn't a known IANA zone reads as UTC.
+pub fn branch_timezone(branch: &entity::branches::Model) -> Tz {
+    branch.timezone.parse().unwrap_or(Tz::UTC)
+}
+
+/// The instant the venue's clock reads `at`. A time a clock change skips is read an hour on,
+/// and one it repeats as the first time round.
+pub fn venue_instant(at: NaiveDateTime, tz: Tz) -> DateTime<Utc> {
+    (0..3)
+        .find_map(|hour| {
+            tz.from_local_datetime(&(at + chrono::Duration::hours(hour)))
+                .earliest()
+        })
+        .map(|instant| instant.with_timezone(&Utc))
+        .unwrap_or_else(|| at.and_utc())
+}
+
+/// When the venue's day `date` starts.
+pub fn day_start(date: NaiveDate, tz: Tz) -> DateTime<Utc> {
+    venue_instant(date.and_hms_opt(0, 0, 0).unwrap_or_default(), tz)
+}
+
+/// The instants bounding the venue's days `start` through `end`, ends included: from the start
+/// of the first day up to, but not including, the start of the day after the last.
+pub fn day_bounds(start: NaiveDate, end: NaiveDate, tz: Tz) -> (DateTime<Utc>, DateTime<Utc>) {
+    (
+        day_start(start, tz),
+        day_start(end.succ_opt().unwrap_or(end), tz),
+    )
+}